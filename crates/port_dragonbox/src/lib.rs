@@ -735,7 +735,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
   let exponent = (bits >> SIGNIFICAND_BITS & ((1u64 << EXPONENT_BITS) - 1)) as i32;
   let sign = (bits >> (SIGNIFICAND_BITS + EXPONENT_BITS)) > 0;
 
-  let even = significand % 2 == 0;
+  let even = significand.is_multiple_of(2);
   let mut two_fc = significand * 2;
   let mut binary_exponent = exponent;
 
@@ -754,7 +754,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
       let zi = (cache_high + (cache_high >> (SIGNIFICAND_BITS + 1)))
         >> (TOTAL_BITS - SIGNIFICAND_BITS - 1 - beta);
 
-      if binary_exponent < 2 || binary_exponent > 3 {
+      if !(2..=3).contains(&binary_exponent) {
         xi += 1;
       }
 
@@ -769,7 +769,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
         return without_trailing_zeros(decimal_significand, minus_k + 1, sign);
       }
 
-      decimal_significand = ((cache_high >> (TOTAL_BITS - SIGNIFICAND_BITS - 2 - beta)) + 1) / 2;
+      decimal_significand = (cache_high >> (TOTAL_BITS - SIGNIFICAND_BITS - 2 - beta)).div_ceil(2);
 
       if binary_exponent == -77 && decimal_significand % 2 == 0 {
         decimal_significand -= 1;
@@ -791,7 +791,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
   let beta = binary_exponent + floor_log2_pow10(-minus_k);
 
   let deltai = cache_high >> (TOTAL_BITS - 1 - beta);
-  let z_result = compute_mul(((two_fc | 1) << beta) as u64, cache);
+  let z_result = compute_mul((two_fc | 1) << beta, cache);
 
   let mut decimal_significand = if ((2u64 << SIGNIFICAND_BITS) * 1000 - 1) <= 15534100272597517998 {
     ((z_result.integer_part as u128 * 4722366482869645214) >> 72) as u64
@@ -799,7 +799,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
     z_result.integer_part / 100
   };
 
-  let mut r = (z_result.integer_part - 1000 * decimal_significand) as u64;
+  let mut r = z_result.integer_part - 1000 * decimal_significand;
 
   if r < deltai {
     if r == 0 && z_result.is_integer && !even {
@@ -818,7 +818,7 @@ pub fn to_decimal(x: f64) -> (u64, i32, bool) {
 
   decimal_significand *= 10;
 
-  let dist = (r - (deltai / 2) + 50) as u64;
+  let dist = r - (deltai / 2) + 50;
   let approx_y_parity = ((dist ^ 50) & 1) != 0;
 
   let prod = (dist * 656) as u32;
@@ -27,28 +27,14 @@ SOFTWARE.
 */
 
 pub fn hex_digit_value(c: char) -> u64 {
-  if c >= 'a' {
-    (c as u64 & 0xF) + 9
-  } else {
-    c as u64 & 0xF
+  match c {
+    'a'..='f' | 'A'..='F' => (c as u64 & 0xF) + 9,
+    _ => c as u64 & 0xF,
   }
 }
 
 pub fn parse_hexadecimal(digits: &[char]) -> f64 {
-  if digits.len() > 64 {
-    let mut result = 0f64;
-    for digit in digits {
-      result = result.mul_add(16f64, hex_digit_value(*digit) as f64)
-    }
-    result
-  } else {
-    let mut result = 0u64;
-    for digit in digits {
-      result <<= 4;
-      result |= hex_digit_value(*digit);
-    }
-    result as f64
-  }
+  parse_power_of_two(digits, 4, hex_digit_value)
 }
 
 pub fn parse_decimal(digits: &[char]) -> f64 {
@@ -64,36 +50,37 @@ pub fn parse_decimal(digits: &[char]) -> f64 {
   }
 }
 
+// a decimal with a fraction or an exponent, like oxc this leaves it to rust's parser, which rounds
+// correctly however many digits there are
+pub fn parse_decimal_float(digits: &[char]) -> f64 {
+  String::from_iter(digits).parse::<f64>().unwrap()
+}
+
 pub fn parse_octal(digits: &[char]) -> f64 {
-  if digits.len() > 64 {
-    let mut result = 0f64;
-    for digit in digits {
-      result = result.mul_add(8f64, (*digit as u8 & 0xF) as f64)
-    }
-    result
-  } else {
-    let mut result = 0u64;
-    for digit in digits {
-      result <<= 3;
-      result |= *digit as u64 & 0xF;
-    }
-    result as f64
-  }
+  parse_power_of_two(digits, 3, |digit| digit as u64 & 0x7)
 }
 
 pub fn parse_binary(digits: &[char]) -> f64 {
-  if digits.len() > 64 {
-    let mut result = 0f64;
-    for digit in digits {
-      result = result.mul_add(2f64, (*digit as u8 & 1) as f64)
-    }
-    result
-  } else {
-    let mut result = 0u64;
-    for digit in digits {
-      result <<= 1;
-      result |= *digit as u64 & 1;
+  parse_power_of_two(digits, 1, |digit| digit as u64 & 1)
+}
+
+// the first 64 significant bits with any set bit after them folded into the lowest one, which
+// is far below where a double rounds, so converting that rounds the same as the exact value
+// would. scaling by a power of two after it is exact
+fn parse_power_of_two(digits: &[char], bits: u32, digit_value: impl Fn(char) -> u64) -> f64 {
+  let mut result = 0u64;
+  let mut dropped_bits = 0i32;
+  let mut sticky = false;
+
+  for digit in digits {
+    let value = digit_value(*digit);
+    if result >> (64 - bits) == 0 {
+      result = result << bits | value;
+    } else {
+      dropped_bits += bits as i32;
+      sticky |= value != 0;
     }
-    result as f64
   }
+
+  (result | sticky as u64) as f64 * 2f64.powi(dropped_bits)
 }
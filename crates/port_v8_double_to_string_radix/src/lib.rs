@@ -30,9 +30,16 @@ THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
 OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
-#![feature(float_next_up_down)]
+use std::fmt;
 
-pub fn double_to_string_radix(mut value: f64, radix: u8) -> String {
+pub fn double_to_string_radix(value: f64, radix: u8) -> String {
+  let mut output = String::new();
+  write_double_radix(value, radix, &mut output).unwrap();
+  output
+}
+
+// the digits are built in a buffer on the stack, so nothing is allocated for the output
+pub fn write_double_radix(mut value: f64, radix: u8, output: &mut impl fmt::Write) -> fmt::Result {
   const CHARS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
   const BUFFER_SIZE: usize = 2200;
 
@@ -63,26 +70,24 @@ pub fn double_to_string_radix(mut value: f64, radix: u8) -> String {
       fraction_cursor += 1;
       fraction -= digit as f64;
 
-      if fraction > 0.5 || (fraction == 0.5 && (digit & 1) != 0) {
-        if fraction + delta > 1.0 {
-          loop {
-            fraction_cursor -= 1;
-            if fraction_cursor == BUFFER_SIZE / 2 {
-              integer += 1.0;
-              break;
-            }
-
-            let c = buffer[fraction_cursor];
-            digit = if c > b'9' { c - b'a' + 10 } else { c - b'0' };
-
-            if digit + 1 < radix {
-              buffer[fraction_cursor] = CHARS[digit as usize + 1];
-              fraction_cursor += 1;
-              break;
-            }
+      if (fraction > 0.5 || (fraction == 0.5 && (digit & 1) != 0)) && fraction + delta > 1.0 {
+        loop {
+          fraction_cursor -= 1;
+          if fraction_cursor == BUFFER_SIZE / 2 {
+            integer += 1.0;
+            break;
+          }
+
+          let c = buffer[fraction_cursor];
+          digit = if c > b'9' { c - b'a' + 10 } else { c - b'0' };
+
+          if digit + 1 < radix {
+            buffer[fraction_cursor] = CHARS[digit as usize + 1];
+            fraction_cursor += 1;
+            break;
           }
-          break;
         }
+        break;
       }
 
       if fraction < delta {
@@ -91,13 +96,24 @@ pub fn double_to_string_radix(mut value: f64, radix: u8) -> String {
     }
   }
 
-  while (((integer / radixf).to_bits() >> 52) & 0x7FF) as i32 - 1023 > 0 {
+  // digits below the precision of the double are zeros, the exponent's the one of the integer
+  // significand the way v8's Double::Exponent has it
+  while (((integer / radixf).to_bits() >> 52) & 0x7FF) as i32 - 1075 > 0 {
     integer /= radixf;
     integer_cursor -= 1;
     buffer[integer_cursor] = b'0';
   }
 
+  // v8 takes the remainder of what the divisions above leave, which isn't always an integer, so
+  // the last digits come out the way they do there. once what's left is an integer below 2^53 the
+  // remainders and divisions are exact, and u64 division gives the same digits without the fmod
+  let mut exact = None;
   loop {
+    if integer.fract() == 0.0 && integer < 9007199254740992.0 {
+      exact = Some(integer as u64);
+      break;
+    }
+
     let remainder = integer % radixf;
     integer_cursor -= 1;
     buffer[integer_cursor] = CHARS[remainder as usize];
@@ -107,14 +123,21 @@ pub fn double_to_string_radix(mut value: f64, radix: u8) -> String {
     }
   }
 
+  if let Some(mut integer) = exact {
+    loop {
+      integer_cursor -= 1;
+      buffer[integer_cursor] = CHARS[(integer % radix as u64) as usize];
+      integer /= radix as u64;
+      if integer == 0 {
+        break;
+      }
+    }
+  }
+
   if negative {
     integer_cursor -= 1;
     buffer[integer_cursor] = b'-';
   }
 
-  String::from_iter(
-    buffer[integer_cursor..fraction_cursor]
-      .iter()
-      .map(|c| *c as char),
-  )
+  output.write_str(std::str::from_utf8(&buffer[integer_cursor..fraction_cursor]).unwrap())
 }
@@ -0,0 +1,18 @@
+let [first, , third = 3, ...others] = list;
+const { a, b: { c }, d = 4 } = object;
+[a, , b] = [b, a];
+({ a, b: [c] } = object);
+var add = (x, y = 1, ...z) => x + y, block = () => { return; };
+function* gen(n) { yield; yield n; yield* other(); }
+class Point extends Base {
+  constructor(x) { super(x); this.x = x; }
+  static create() { return new.target; }
+  get size() { return 1; }
+  set size(value) {}
+  *[Symbol.iterator]() {}
+  'quoted'() {}
+}
+for (const item of items) {}
+`plain`; `a${b}c${d}e`; tag`raw\u{41}${x}`;
+f(...args); [1, ...rest];
+var shorthand = { a, method() {}, [key]: value, *g() {} };
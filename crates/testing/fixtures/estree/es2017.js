@@ -0,0 +1,8 @@
+async function load(url) {
+  const response = await fetch(url);
+  return response.json();
+}
+const run = async () => { await load("a"); };
+const one = async x => x ** 2;
+var o = { async method() {}, async *stream() { yield 1; } };
+class C { async m() { for (;;) await 0; } }
@@ -0,0 +1,9 @@
+a?.b;
+a?.[0]?.c();
+a?.b.c(d)?.e;
+(a?.b).c;
+x = a ?? b;
+y = 10n + 123_456n;
+import("./module.js").then(load);
+z = a ||= b, a &&= c, a ??= d;
+n = 1_000_000.000_1;
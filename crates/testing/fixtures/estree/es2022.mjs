@@ -0,0 +1,15 @@
+class Counter {
+  #count = 0;
+  static #instances;
+  static created = 0;
+  label = "counter";
+  ["computed"] = 1;
+  #increment() { this.#count++; }
+  get #value() { return this.#count; }
+  static {
+    Counter.created = 0;
+  }
+  static has(object) { return #count in object; }
+}
+await Promise.resolve(import.meta.url);
+const match = /(?<year>\d{4})/d.exec(text);
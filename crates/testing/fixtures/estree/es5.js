@@ -0,0 +1,24 @@
+"use sloppy";
+'also a directive';
+var a = 1, b;
+function f(x, y) {
+  "use strict";
+  if (x) return y; else if (y) { return; }
+  for (var i = 0; i < 10; i++) continue;
+  for (var k in x) ;
+  while (x) break;
+  do x--; while (x > 0);
+  switch (y) {
+    case 1:
+      y = 2;
+      break;
+    default:
+  }
+  try { g(); } catch (e) { throw e; } finally { h(); }
+  outer: for (;;) { break outer; }
+  return typeof x === "undefined" ? void 0 : !x, -y;
+}
+with (a) b;
+debugger;
+var o = { get p() { return this.q; }, set p(v) {}, 'q': 1, 2: /re[/]g/gi, r: new F, s: new G(1, 2) };
+delete o.p, a instanceof f, a in o, ~a >>> 2, (a + b) * a % 3;
@@ -0,0 +1,8 @@
+const element = (
+  <div className="box" data-id={id} {...props}>
+    text &amp; more {value} {/* empty */}
+    <Foo.Bar baz />
+    <ns:tag attr='single' />
+    <>fragment</>
+  </div>
+);
@@ -0,0 +1,5 @@
+// line terminators and astral characters count as javascript sees them
+var 𝒳 = "😀";
+var after = `a
+b${𝒳}`;
+ var last = "é";
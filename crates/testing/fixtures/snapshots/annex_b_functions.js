@@ -0,0 +1,4 @@
+l: m: function f() {}
+if (x) function g() {}
+else function h() {}
+while (x) l: break l;
@@ -0,0 +1,30 @@
+class Point extends Base {
+  static #count = 0;
+  x = 1;
+  constructor(x) {
+    super();
+    this.x = x;
+  }
+  get length() {
+    return Math.hypot(this.x);
+  }
+  set length(value) {}
+  static async *values() {
+    yield* [this.x];
+  }
+  static {
+    Point.#count++;
+  }
+  has(other) {
+    return #count in other;
+  }
+}
+
+function* generator(a = new.target) {
+  yield;
+}
+
+class Keys {
+  1n() {}
+  static 2n = 0;
+}
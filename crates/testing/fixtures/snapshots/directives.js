@@ -0,0 +1,10 @@
+"use\x20strict";
+with (a) {}
+
+function strict() {
+  "use strict";
+  return this;
+}
+
+class A {}
+var octal = 010;
@@ -0,0 +1,7 @@
+const view = (
+  <Layout.Main title="home" {...props} ns:attr={value}>
+    text &amp; more
+    {items.map((item) => <Item key={item.id} />)}
+    <>{/* nothing */}</>
+  </Layout.Main>
+);
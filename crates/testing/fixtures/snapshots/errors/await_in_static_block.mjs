@@ -0,0 +1,5 @@
+class A {
+  static {
+    await 1;
+  }
+}
@@ -0,0 +1,5 @@
+class A {
+  m() {
+    x = 010;
+  }
+}
@@ -0,0 +1 @@
+for (async of y);
@@ -0,0 +1 @@
+do a: b: function f() {} while (0);
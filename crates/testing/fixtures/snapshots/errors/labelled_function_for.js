@@ -0,0 +1 @@
+for (;;) l: function f() {}
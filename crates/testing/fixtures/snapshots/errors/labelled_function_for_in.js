@@ -0,0 +1 @@
+for (x in y) l: function f() {}
@@ -0,0 +1 @@
+for (x of y) l: function f() {}
@@ -0,0 +1 @@
+while (1) l: function f() {}
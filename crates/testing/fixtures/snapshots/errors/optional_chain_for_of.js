@@ -0,0 +1 @@
+for (a?.b of c);
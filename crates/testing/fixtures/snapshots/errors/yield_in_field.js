@@ -0,0 +1,5 @@
+function* g() {
+  class A {
+    x = yield;
+  }
+}
@@ -0,0 +1,13 @@
+a = b ? c : d;
+x ??= y || z && !w;
+f(1, ...args)?.g[h]?.(i);
+new Target;
+typeof void -1n, 0x10, 1e21, .5;
+`a${b}c${d}e`;
+tag`\unicode ${x}`;
+/[a-z]+/giu.test("s");
+({ a, b: 1, [c]: 2, get d() {}, set d(v) {}, async *e() {} });
+[1, , ...g];
+(async (a, { b } = {}, ...c) => a)(this);
+({ 1n: 0, get 0x10n() {}, 123456789012345678901234567890n: 1 });
+[a.b, { c: d[0] }, ...e] = (f.g) = h?.i;
@@ -0,0 +1,2 @@
+const data = await import("./data.json");
+console.log(import.meta.url, data);
@@ -0,0 +1,6 @@
+class A {
+  x = 1;
+  static {
+    this.y = 2;
+  }
+}
@@ -0,0 +1,28 @@
+var a = 1, b;
+let [x, , ...rest] = list;
+const { key, other: renamed = 2, ...more } = object;
+
+outer: for (let i = 0; i < 10; i++) {
+  if (i % 2) continue outer;
+  else break;
+}
+
+for (const item of items) {}
+for (var key in object) {}
+do { a--; } while (a > 0);
+while (false);
+
+switch (a) {
+  case 1:
+    a = 2;
+  default:
+}
+
+try {
+  throw new Error("no");
+} catch ({ message }) {
+} finally {
+  debugger;
+}
+
+var { 1n: one } = numbers;
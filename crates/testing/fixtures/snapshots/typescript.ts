@@ -0,0 +1,20 @@
+interface Point {
+  x: number;
+  y?: number;
+}
+type Pair<T> = [T, T];
+function first<T>(pair: Pair<T>): T {
+  return pair[0];
+}
+const id = <T,>(value: T): T => value;
+let n = value as number;
+let m = maybe!.field;
+x = a ? (b) : c => d;
+x = a ? (b): string => b : c;
+x = a ? (b) : (c): d => d;
+class Box<T> {
+  constructor(private readonly value: T) {}
+  get(): T {
+    return this.value;
+  }
+}
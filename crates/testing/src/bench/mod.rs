@@ -0,0 +1,130 @@
+use std::{error::Error, hint::black_box, process::ExitCode, time::Instant};
+
+use tower_parser::number::{es_number_to_string, write_number};
+
+use crate::bigint::Random;
+
+mod previous;
+
+const SAMPLES: usize = 10_000;
+
+// times formatting numbers of each kind with the implementation from before write_number, with
+// es_number_to_string allocating a string for each, and with write_number writing them all to one
+// reused buffer. the round count can be given, and only a release build gives numbers worth
+// comparing
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let rounds = match args.first() {
+    Some(rounds) => rounds
+      .parse()
+      .map_err(|_| format!("`{rounds}` isn't a round count"))?,
+    None => 50,
+  };
+
+  let mut random = Random(0x9e37_79b9_7f4a_7c15);
+  let mut sample = |value: &mut dyn FnMut(&mut Random) -> f64| {
+    (0..SAMPLES).map(|_| value(&mut random)).collect::<Vec<_>>()
+  };
+
+  // integers, which write_number writes without finding the shortest digits
+  let integers = [
+    (
+      "small integers",
+      10,
+      sample(&mut |random| random.below(2_000_000) as f64 - 1_000_000.0),
+    ),
+    (
+      "safe integers",
+      10,
+      sample(&mut |random| random.below(1 << 53) as f64),
+    ),
+    (
+      "hex integers",
+      16,
+      sample(&mut |random| random.below(1 << 32) as f64),
+    ),
+  ];
+
+  // everything else needs the shortest digits that round-trip, from dragonbox or the radix
+  // conversion
+  let shortest = [
+    (
+      "short decimals",
+      10,
+      sample(&mut |random| random.below(1_000_000) as f64 / 100.0),
+    ),
+    (
+      "fractions",
+      10,
+      sample(&mut |random| (random.next() >> 11) as f64 / (1u64 << 53) as f64),
+    ),
+    (
+      "large integers",
+      10,
+      sample(&mut |random| (random.next() >> 11) as f64 * 2f64.powi(random.below(200) as i32)),
+    ),
+    (
+      "doubles",
+      10,
+      sample(&mut |random| loop {
+        let value = f64::from_bits(random.next());
+        if value.is_finite() {
+          break value;
+        }
+      }),
+    ),
+    (
+      "hex fractions",
+      16,
+      sample(&mut |random| random.below(1 << 53) as f64 / (1u64 << 20) as f64),
+    ),
+  ];
+
+  for (heading, sets) in [
+    ("integers", &integers[..]),
+    ("shortest round-trip", &shortest[..]),
+  ] {
+    println!("{heading}");
+    println!(
+      "  {:<16} {:>10} {:>10} {:>13}",
+      "", "previous", "to_string", "write_number"
+    );
+
+    for (name, radix, values) in sets {
+      let previous = time(rounds, values.len(), || {
+        for value in values {
+          black_box(previous::es_number_to_string(black_box(*value), *radix));
+        }
+      });
+
+      let to_string = time(rounds, values.len(), || {
+        for value in values {
+          black_box(es_number_to_string(black_box(*value), *radix));
+        }
+      });
+
+      let mut output = String::with_capacity(values.len() * 32);
+      let write = time(rounds, values.len(), || {
+        output.clear();
+        for value in values {
+          write_number(black_box(*value), *radix, &mut output).unwrap();
+        }
+        black_box(&output);
+      });
+
+      println!("  {name:<16} {previous:>7.1} ns {to_string:>7.1} ns {write:>10.1} ns");
+    }
+  }
+
+  Ok(ExitCode::SUCCESS)
+}
+
+// nanoseconds per value, the best of the rounds so a stray interruption doesn't count
+fn time(rounds: usize, count: usize, mut run: impl FnMut()) -> f64 {
+  (0..rounds)
+    .map(|_| {
+      let start = Instant::now();
+      run();
+      start.elapsed().as_nanos() as f64 / count as f64
+    })
+    .fold(f64::INFINITY, f64::min)
+}
@@ -0,0 +1,481 @@
+use std::{cmp::Ordering, error::Error, process::ExitCode};
+
+use tower_ast::bigint::{string_to_bigint, BigInt};
+
+// random samples of each property, the seed can be given to reproduce a failure
+const SAMPLES: usize = 20_000;
+const LARGE_SAMPLES: usize = 300;
+
+// checks the arithmetic and conversions of BigInt against i128 and f64 for values that fit, and
+// against identities for values of many parts, large enough to take the Karatsuba and long
+// division paths
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let seed = match args.first() {
+    Some(seed) => seed.parse().map_err(|_| format!("`{seed}` isn't a seed"))?,
+    None => 0x2545_f491_4f6c_dd1d,
+  };
+
+  let mut checks = Checks {
+    random: Random(seed),
+    checked: 0,
+    failures: 0,
+  };
+
+  checks.small_values();
+  checks.large_values();
+  checks.conversions();
+
+  println!(
+    "{} passed, {} failed, seed {seed}",
+    checks.checked - checks.failures,
+    checks.failures
+  );
+
+  Ok(if checks.failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+// xorshift64*, enough to spread the samples over the interesting widths
+pub struct Random(pub u64);
+
+impl Random {
+  pub fn next(&mut self) -> u64 {
+    self.0 ^= self.0 >> 12;
+    self.0 ^= self.0 << 25;
+    self.0 ^= self.0 >> 27;
+    self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+  }
+
+  pub fn below(&mut self, bound: u64) -> u64 {
+    self.next() % bound
+  }
+
+  // a value of a random width up to `bits`, with a random sign. narrow values and the edges of
+  // the parts are where the carries go wrong, so they come up as often as wide ones
+  fn signed(&mut self, bits: u32) -> i128 {
+    let width = self.below(bits as u64 + 1) as u32;
+    let value = ((self.next() as u128) << 64 | self.next() as u128) >> (128 - width.max(1));
+    let value = if width == 0 { 0 } else { value as i128 };
+
+    if self.next() & 1 == 1 {
+      -value
+    } else {
+      value
+    }
+  }
+
+  fn large(&mut self, max_parts: u64) -> BigInt {
+    let parts = (0..self.below(max_parts) + 1)
+      .map(|_| self.next())
+      .collect::<Vec<_>>();
+    let value = BigInt::new(parts);
+
+    if self.next() & 1 == 1 {
+      -value
+    } else {
+      value
+    }
+  }
+}
+
+struct Checks {
+  random: Random,
+  checked: usize,
+  failures: usize,
+}
+
+impl Checks {
+  fn check(&mut self, name: &str, actual: &BigInt, expected: &BigInt, operands: &[&dyn ToString]) {
+    self.checked += 1;
+    if actual != expected {
+      self.failures += 1;
+      let operands = operands
+        .iter()
+        .map(|operand| operand.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+      println!("FAIL {name}({operands}) = {actual}, expected {expected}");
+    }
+  }
+
+  fn small_values(&mut self) {
+    let big = BigInt::from;
+
+    for _ in 0..SAMPLES {
+      // sums and the bitwise operators of values up to 126 bits can't overflow an i128
+      let (a, b) = (self.random.signed(126), self.random.signed(126));
+      let (x, y) = (big(a), big(b));
+
+      self.check("add", &(&x + &y), &big(a + b), &[&a, &b]);
+      self.check("sub", &(&x - &y), &big(a - b), &[&a, &b]);
+      self.check("neg", &-&x, &big(-a), &[&a]);
+      self.check("and", &(&x & &y), &big(a & b), &[&a, &b]);
+      self.check("or", &(&x | &y), &big(a | b), &[&a, &b]);
+      self.check("xor", &(&x ^ &y), &big(a ^ b), &[&a, &b]);
+      self.check("not", &!&x, &big(!a), &[&a]);
+
+      self.checked += 1;
+      if x.cmp(&y) != a.cmp(&b) || (x == y) != (a == b) {
+        self.failures += 1;
+        println!("FAIL cmp({a}, {b}) = {:?}", x.cmp(&y));
+      }
+
+      if b != 0 {
+        // i128 division truncates and the remainder takes the sign of the dividend, as in js
+        self.check("div", &(&x / &y), &big(a / b), &[&a, &b]);
+        self.check("rem", &(&x % &y), &big(a % b), &[&a, &b]);
+      } else {
+        self.checked += 1;
+        if x.checked_div(&y).is_some() || x.checked_rem(&y).is_some() {
+          self.failures += 1;
+          println!("FAIL div({a}, 0) isn't an error");
+        }
+      }
+
+      self.checked += 1;
+      if x.to_string() != a.to_string() {
+        self.failures += 1;
+        println!("FAIL display({a}) = {x}");
+      }
+
+      // products of 63 bit values
+      let (a, b) = (self.random.signed(63), self.random.signed(63));
+      self.check("mul", &(big(a) * big(b)), &big(a * b), &[&a, &b]);
+
+      // shifts by amounts that keep the result in 127 bits, and past the last bit
+      let a = self.random.signed(100);
+      let left = self.random.below(27);
+      let right = self.random.below(140);
+      let expected_right = big(a >> right.min(127));
+      self.check(
+        "shl",
+        &(&big(a) << left as usize),
+        &big(a << left),
+        &[&a, &left],
+      );
+      self.check(
+        "shr",
+        &(&big(a) >> right as usize),
+        &expected_right,
+        &[&a, &right],
+      );
+
+      let (left, right) = (left as i128, right as i128);
+      let shifted = big(a).shift_left(&big(left)).unwrap();
+      self.check("shift_left", &shifted, &big(a << left), &[&a, &left]);
+      let shifted = big(a).shift_right(&big(right)).unwrap();
+      self.check("shift_right", &shifted, &expected_right, &[&a, &right]);
+      let shifted = big(a).shift_left(&big(-right)).unwrap();
+      self.check("shift_left", &shifted, &expected_right, &[&a, &-right]);
+
+      // small bases to powers that stay under 127 bits
+      let base = self.random.signed(7);
+      let exponent = self.random.below(18) as u32;
+      let power = big(base).pow(&big(exponent as i128)).unwrap();
+      self.check("pow", &power, &big(base.pow(exponent)), &[&base, &exponent]);
+
+      // the literal forms of an unsigned value
+      let value = self.random.signed(128).unsigned_abs();
+      let chars = |text: String| text.chars().collect::<Vec<_>>();
+      let expected = BigInt::from(value);
+      let from_hex = BigInt::from_hex_str(&chars(format!("{value:x}")));
+      let from_upper_hex = BigInt::from_hex_str(&chars(format!("{value:X}")));
+      let from_octal = BigInt::from_octal_str(&chars(format!("{value:o}")));
+      let from_binary = BigInt::from_binary_str(&chars(format!("{value:b}")));
+      let from_decimal = BigInt::from_decimal_str(&chars(format!("{value}")));
+      self.check("from_hex_str", &from_hex, &expected, &[&value]);
+      self.check("from_hex_str", &from_upper_hex, &expected, &[&value]);
+      self.check("from_octal_str", &from_octal, &expected, &[&value]);
+      self.check("from_binary_str", &from_binary, &expected, &[&value]);
+      self.check("from_decimal_str", &from_decimal, &expected, &[&value]);
+    }
+
+    // the edges the spec calls out
+    let (zero, one, minus_one) = (big(0), big(1), big(-1));
+    self.checked += 3;
+    if big(2).pow(&minus_one).is_some() || one.shift_left(&big(1 << 40)).is_some() {
+      self.failures += 1;
+      println!("FAIL a negative exponent or a huge shift isn't an error");
+    }
+    if zero.pow(&zero) != Some(one.clone()) || minus_one.pow(&big(1 << 80)) != Some(one.clone()) {
+      self.failures += 1;
+      println!("FAIL 0 ** 0 or -1 ** 2^80 isn't 1");
+    }
+    if minus_one.shift_right(&big(1 << 80)) != Some(minus_one.clone())
+      || (&minus_one + &one).is_negative()
+    {
+      self.failures += 1;
+      println!("FAIL -1 >> 2^80 isn't -1 or -1 + 1 is negative");
+    }
+  }
+
+  fn large_values(&mut self) {
+    for _ in 0..LARGE_SAMPLES {
+      let a = self.random.large(120);
+      let b = self.random.large(120);
+      let c = self.random.large(60);
+
+      // the product against distributivity, a sum of products of other sizes
+      let product = &a * &b;
+      let split = &(&a * &c) + &(&a * &(&b - &c));
+      self.check("mul", &product, &split, &[&a, &b, &c]);
+
+      let square = &(&a + &b) * &(&a + &b);
+      let expanded = &(&(&a * &a) + &(&(&a * &b) << 1)) + &(&b * &b);
+      self.check("square", &square, &expanded, &[&a, &b]);
+
+      // q·b + r = a with |r| < |b| and r as negative as a
+      let (quotient, remainder) = a.checked_div_rem(&c).unwrap();
+      self.check("div_rem", &(&(&quotient * &c) + &remainder), &a, &[&a, &c]);
+      self.checked += 1;
+      if remainder.abs() >= c.abs()
+        || (!remainder.is_zero() && remainder.is_negative() != a.is_negative())
+      {
+        self.failures += 1;
+        println!("FAIL rem({a}, {c}) = {remainder}");
+      }
+
+      self.check("div", &(&product / &b), &a, &[&product, &b]);
+      self.check("rem", &(&product % &b), &BigInt::zero(), &[&product, &b]);
+
+      let shift = self.random.below(300) as usize;
+      let power = BigInt::from(2u64).pow(&BigInt::from(shift as u64)).unwrap();
+      self.check("shl", &(&a << shift), &(&a * &power), &[&a, &shift]);
+      self.check("shr", &(&(&a << shift) >> shift), &a, &[&a, &shift]);
+
+      // two's complement identities
+      self.check("xor", &(&(&a ^ &b) ^ &b), &a, &[&a, &b]);
+      self.check("and_or", &(&(&a & &b) + &(&a | &b)), &(&a + &b), &[&a, &b]);
+      self.check("not_and", &!(&a & &b), &(&!&a | &!&b), &[&a, &b]);
+    }
+  }
+
+  fn expect(&mut self, passed: bool, message: impl FnOnce() -> String) {
+    self.checked += 1;
+    if !passed {
+      self.failures += 1;
+      println!("FAIL {}", message());
+    }
+  }
+
+  fn conversions(&mut self) {
+    let big = BigInt::from;
+
+    for (text, expected) in STRING_CASES {
+      let actual = string_to_bigint(text);
+      let expected = expected.map(big);
+      self.expect(actual == expected, || {
+        format!("string_to_bigint({text:?}) = {actual:?}, expected {expected:?}")
+      });
+    }
+
+    for (value, number, expected) in COMPARE_CASES {
+      let value = string_to_bigint(value).unwrap();
+      let actual = value.partial_cmp_f64(*number);
+      self.expect(actual == *expected, || {
+        format!("partial_cmp_f64({value}, {number}) = {actual:?}, expected {expected:?}")
+      });
+    }
+
+    // the largest double and what rounds up past it, ties go to the even significand
+    let two = big(2);
+    let power = |exponent: i128| two.pow(&big(exponent)).unwrap();
+    let halfway = &power(1024) - &power(970);
+    for (value, expected) in [
+      (&power(53) + &big(1), 2f64.powi(53)),
+      (&power(53) + &big(3), 2f64.powi(53) + 4.0),
+      (&halfway - &big(1), f64::MAX),
+      (halfway.clone(), f64::INFINITY),
+      (-&halfway, f64::NEG_INFINITY),
+      (&power(1100) + &power(1047), 2f64.powi(1100)),
+      (
+        &(&power(1100) + &power(1047)) + &big(1),
+        2f64.powi(1100) * (1.0 + 2f64.powi(-52)),
+      ),
+    ] {
+      let actual = value.to_f64();
+      self.expect(actual == expected, || {
+        format!("to_f64({value}) = {actual:e}, expected {expected:e}")
+      });
+    }
+
+    for value in [0.5, -1e-300, f64::NAN, f64::INFINITY] {
+      self.expect(BigInt::from_f64(value).is_none(), || {
+        format!("from_f64({value}) isn't an error")
+      });
+    }
+
+    self.expect(
+      BigInt::from(-1i64).as_uint_n(1 << 40).is_none()
+        && BigInt::from(5i64).as_uint_n(1 << 60) == Some(big(5))
+        && BigInt::from(-5i64).as_int_n(1 << 60) == big(-5),
+      || "as_uint_n or as_int_n of a huge width".to_string(),
+    );
+
+    for _ in 0..SAMPLES {
+      let a = self.random.signed(127);
+      let x = big(a);
+
+      let radix = self.random.below(35) as u32 + 2;
+      let actual = x.to_string_radix(radix);
+      let expected = radix_string(a, radix);
+      self.expect(actual == expected, || {
+        format!("to_string_radix({a}, {radix}) = {actual}, expected {expected}")
+      });
+
+      // rust converts an i128 to the nearest double with ties to even too
+      let actual = x.to_f64();
+      self.expect(actual == a as f64, || {
+        format!("to_f64({a}) = {actual:e}, expected {:e}", a as f64)
+      });
+
+      // any integral double, most of them too large for an i128
+      let number = f64::from_bits(self.random.next()).trunc();
+      if number.is_finite() {
+        let value = BigInt::from_f64(number).unwrap();
+        let round_trip = value.to_f64();
+        self.expect(round_trip == number, || {
+          format!("from_f64({number:e}) = {value}, back to {round_trip:e}")
+        });
+        if number.abs() < 2f64.powi(126) {
+          self.check("from_f64", &value, &big(number as i128), &[&number]);
+        }
+      }
+
+      // against a fraction whose floor is b, a is below it exactly when a <= b
+      let b = self.random.signed(50);
+      let fraction = b as f64 + 0.5;
+      let expected = if a <= b {
+        Ordering::Less
+      } else {
+        Ordering::Greater
+      };
+      let actual = x.partial_cmp_f64(fraction);
+      self.expect(actual == Some(expected), || {
+        format!("partial_cmp_f64({a}, {fraction}) = {actual:?}, expected {expected:?}")
+      });
+
+      // wrapping to the low bits of the two's complement of the value
+      let bits = self.random.below(129) as u32;
+      let mask = u128::MAX.checked_shr(128 - bits).unwrap_or(0);
+      let unsigned = a as u128 & mask;
+      let signed = match bits {
+        0 => 0,
+        _ => ((unsigned << (128 - bits)) as i128) >> (128 - bits),
+      };
+      let actual = x.as_uint_n(bits as u64).unwrap();
+      self.check("as_uint_n", &actual, &BigInt::from(unsigned), &[&a, &bits]);
+      self.check(
+        "as_int_n",
+        &x.as_int_n(bits as u64),
+        &big(signed),
+        &[&a, &bits],
+      );
+    }
+
+    for _ in 0..LARGE_SAMPLES {
+      let a = self.random.large(40);
+      let chars = |text: String| text.chars().collect::<Vec<_>>();
+      let hex = a.abs().to_string_radix(16);
+      let decimal = a.abs().to_string_radix(10);
+      let from_hex = BigInt::from_hex_str(&chars(hex));
+      let from_decimal = BigInt::from_decimal_str(&chars(decimal));
+      self.check("to_string_radix", &from_hex, &a.abs(), &[&a]);
+      self.check("to_string_radix", &from_decimal, &a.abs(), &[&a]);
+    }
+  }
+}
+
+// StringToBigInt of each text, None where it's a SyntaxError
+const STRING_CASES: &[(&str, Option<i128>)] = &[
+  ("", Some(0)),
+  (" \n\t\u{2028}", Some(0)),
+  ("  123  ", Some(123)),
+  ("-42", Some(-42)),
+  ("+7", Some(7)),
+  ("00012", Some(12)),
+  ("-0", Some(0)),
+  ("0x1F", Some(31)),
+  ("0o17", Some(15)),
+  ("0B101", Some(5)),
+  ("\u{feff}0XfF\u{3000}", Some(255)),
+  ("170141183460469231731687303715884105727", Some(i128::MAX)),
+  ("-0x10", None),
+  ("+0b1", None),
+  ("0x", None),
+  ("-", None),
+  ("1n", None),
+  ("1_000", None),
+  ("1.0", None),
+  ("1.", None),
+  ("1e3", None),
+  ("Infinity", None),
+  ("12 34", None),
+  ("0x1g", None),
+  ("0o8", None),
+];
+
+// the order of a BigInt, as its decimal digits, and a double
+const COMPARE_CASES: &[(&str, f64, Option<Ordering>)] = &[
+  ("10", 10.5, Some(Ordering::Less)),
+  ("10", 9.5, Some(Ordering::Greater)),
+  ("-10", -10.5, Some(Ordering::Greater)),
+  ("-10", -9.5, Some(Ordering::Less)),
+  ("10", 10.0, Some(Ordering::Equal)),
+  ("0", -0.0, Some(Ordering::Equal)),
+  ("0", 5e-324, Some(Ordering::Less)),
+  ("0", -5e-324, Some(Ordering::Greater)),
+  ("1", f64::NAN, None),
+  (
+    "9007199254740993",
+    9007199254740992.0,
+    Some(Ordering::Greater),
+  ),
+  (
+    "-9007199254740993",
+    -9007199254740992.0,
+    Some(Ordering::Less),
+  ),
+  (
+    "0x100000000000000000000000000000000000000000",
+    f64::INFINITY,
+    Some(Ordering::Less),
+  ),
+  (
+    "0x100000000000000000000000000000000000000000",
+    f64::NEG_INFINITY,
+    Some(Ordering::Greater),
+  ),
+  (
+    "0x100000000000000000000000000000000000000000",
+    1e300,
+    Some(Ordering::Less),
+  ),
+  (
+    "0x100000000000000000000000000000000000000000",
+    1e49,
+    Some(Ordering::Greater),
+  ),
+];
+
+// the digits of an i128 in any radix, what to_string_radix should give
+fn radix_string(value: i128, radix: u32) -> String {
+  let mut magnitude = value.unsigned_abs();
+  let mut digits = Vec::new();
+
+  loop {
+    digits.push(std::char::from_digit((magnitude % radix as u128) as u32, radix).unwrap());
+    magnitude /= radix as u128;
+    if magnitude == 0 {
+      break;
+    }
+  }
+
+  if value < 0 {
+    digits.push('-');
+  }
+  digits.iter().rev().collect()
+}
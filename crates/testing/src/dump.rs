@@ -0,0 +1,774 @@
+use std::fmt::Write;
+
+use tower_ast::{
+  binding::{BindingPattern, BindingPatternInitializer, BindingRestElement},
+  class::{ClassDefinition, ClassElement, ClassElementName},
+  expression::{ArrayElement, Expression, MemberName},
+  function::{Argument, ConciseBody, FormalParameters, FunctionDefinition},
+  jsx::{
+    JsxAttribute, JsxAttributeName, JsxAttributeValue, JsxChild, JsxElement, JsxElementName,
+    JsxFragment,
+  },
+  object::{ObjectProperty, PropertyName},
+  statement::{Block, ForBinding, ForInitializer, LexicalKind, Statement},
+  Identifier, Program, PropertyKey, SourceType, Span,
+};
+use tower_parser::number::es_number_to_string;
+
+// a stable text form of the tree for snapshots, a node a line indented under its parent
+//
+// Declaration 0..10 let
+//   Binding 4..9
+//     Identifier 4..5 "x"
+//     init: Number 8..9 1
+pub fn dump_program(program: &Program) -> String {
+  let mut dumper = Dumper {
+    depth: 0,
+    label: None,
+    output: String::new(),
+  };
+
+  let source_type = match program.source_type {
+    SourceType::Module => "module",
+    SourceType::Script => "script",
+  };
+  let await_flag = if program.top_level_await {
+    " top-level-await"
+  } else {
+    ""
+  };
+
+  dumper.node(
+    format_args!("Program {:?} {source_type}{await_flag}", program.span),
+    |dumper| dumper.statements(&program.statement_list),
+  );
+  dumper.output
+}
+
+struct Dumper {
+  depth: usize,
+  // names the next node in the slot of its parent it fills
+  label: Option<&'static str>,
+  output: String,
+}
+
+impl Dumper {
+  fn line(&mut self, text: std::fmt::Arguments) {
+    self.output.push_str(&"  ".repeat(self.depth));
+    if let Some(label) = self.label.take() {
+      self.output.push_str(label);
+      self.output.push_str(": ");
+    }
+
+    self.output.write_fmt(text).unwrap();
+    self.output.push('\n');
+  }
+
+  fn node(&mut self, text: std::fmt::Arguments, children: impl FnOnce(&mut Self)) {
+    self.line(text);
+    self.depth += 1;
+    children(self);
+    self.depth -= 1;
+  }
+
+  fn labeled(&mut self, label: &'static str, dump: impl FnOnce(&mut Self)) {
+    self.label = Some(label);
+    dump(self);
+    self.label = None;
+  }
+
+  fn identifier(&mut self, kind: &str, identifier: &Identifier) {
+    self.line(format_args!(
+      "{kind} {:?} {:?}",
+      identifier.span, identifier.name
+    ));
+  }
+
+  fn property_key(&mut self, key: &PropertyKey) {
+    self.line(format_args!("Key {:?} {:?}", key.span, key.name));
+  }
+
+  fn statements(&mut self, statements: &[Statement]) {
+    for statement in statements {
+      self.statement(statement);
+    }
+  }
+
+  fn block(&mut self, kind: &str, block: &Block) {
+    self.node(format_args!("{kind} {:?}", block.span), |dumper| {
+      dumper.statements(&block.statements)
+    });
+  }
+
+  fn statement(&mut self, statement: &Statement) {
+    match statement {
+      Statement::Block(block) => self.block("Block", block),
+      Statement::Break(statement) => self.node(format_args!("Break {:?}", statement.span), |d| {
+        if let Some(label) = &statement.label {
+          d.identifier("Label", label);
+        }
+      }),
+      Statement::Class(definition) => self.class("ClassDeclaration", definition),
+      Statement::Continue(statement) => {
+        self.node(format_args!("Continue {:?}", statement.span), |d| {
+          if let Some(label) = &statement.label {
+            d.identifier("Label", label);
+          }
+        })
+      }
+      Statement::Debugger(span) => self.line(format_args!("Debugger {span:?}")),
+      Statement::DoWhile(statement) => {
+        self.node(format_args!("DoWhile {:?}", statement.span), |d| {
+          d.statement(&statement.body);
+          d.labeled("test", |d| d.expression(&statement.condition));
+        })
+      }
+      Statement::Expression(statement) => self.node(
+        format_args!("ExpressionStatement {:?}", statement.span),
+        |d| d.expression(&statement.expression),
+      ),
+      Statement::Empty(span) => self.line(format_args!("Empty {span:?}")),
+      Statement::For(statement) => self.node(format_args!("For {:?}", statement.span), |d| {
+        match &statement.initializer {
+          Some(ForInitializer::Expression(expression)) => {
+            d.labeled("init", |d| d.expression(expression))
+          }
+          Some(ForInitializer::Lexical(declaration)) => d.labeled("init", |d| {
+            d.declaration(
+              lexical_kind(declaration.kind),
+              declaration.span,
+              &declaration.bindings,
+            )
+          }),
+          Some(ForInitializer::Variable(declaration)) => d.labeled("init", |d| {
+            d.declaration("var", declaration.span, &declaration.bindings)
+          }),
+          None => {}
+        }
+
+        if let Some(condition) = &statement.condition {
+          d.labeled("test", |d| d.expression(condition));
+        }
+
+        if let Some(update) = &statement.update {
+          d.labeled("update", |d| d.expression(update));
+        }
+
+        d.statement(&statement.body);
+      }),
+      Statement::ForIn(statement) => self.node(format_args!("ForIn {:?}", statement.span), |d| {
+        d.for_binding(&statement.binding);
+        d.expression(&statement.expression);
+        d.statement(&statement.body);
+      }),
+      Statement::ForOf(statement) => {
+        let await_flag = if statement.r#await { " await" } else { "" };
+        self.node(
+          format_args!("ForOf {:?}{await_flag}", statement.span),
+          |d| {
+            d.for_binding(&statement.binding);
+            d.expression(&statement.expression);
+            d.statement(&statement.body);
+          },
+        )
+      }
+      Statement::Function(definition) => self.function("FunctionDeclaration", definition),
+      Statement::If(statement) => self.node(format_args!("If {:?}", statement.span), |d| {
+        d.labeled("test", |d| d.expression(&statement.condition));
+        d.statement(&statement.consequent);
+        if let Some(alternate) = &statement.alternate {
+          d.labeled("else", |d| d.statement(alternate));
+        }
+      }),
+      Statement::Label(statement) => self.node(format_args!("Labeled {:?}", statement.span), |d| {
+        d.identifier("Label", &statement.label);
+        d.statement(&statement.statement);
+      }),
+      Statement::Lexical(declaration) => self.declaration(
+        lexical_kind(declaration.kind),
+        declaration.span,
+        &declaration.bindings,
+      ),
+      Statement::Return(statement) => self.node(format_args!("Return {:?}", statement.span), |d| {
+        if let Some(argument) = &statement.argument {
+          d.expression(argument);
+        }
+      }),
+      Statement::Switch(statement) => self.node(format_args!("Switch {:?}", statement.span), |d| {
+        d.expression(&statement.expression);
+        for case in &statement.cases {
+          let kind = if case.expression.is_some() {
+            "Case"
+          } else {
+            "Default"
+          };
+          d.node(format_args!("{kind} {:?}", case.span), |d| {
+            if let Some(expression) = &case.expression {
+              d.labeled("test", |d| d.expression(expression));
+            }
+            d.statements(&case.body);
+          });
+        }
+      }),
+      Statement::Throw(statement) => self.node(format_args!("Throw {:?}", statement.span), |d| {
+        d.expression(&statement.argument)
+      }),
+      Statement::Try(statement) => self.node(format_args!("Try {:?}", statement.span), |d| {
+        d.block("Block", &statement.body);
+        if let Some(catch) = &statement.catch {
+          d.node(format_args!("Catch {:?}", catch.span), |d| {
+            if let Some(parameter) = &catch.parameter {
+              d.labeled("param", |d| d.pattern(parameter));
+            }
+            d.block("Block", &catch.body);
+          });
+        }
+        if let Some(finally) = &statement.finally {
+          d.block("Finally", finally);
+        }
+      }),
+      Statement::Variable(declaration) => {
+        self.declaration("var", declaration.span, &declaration.bindings)
+      }
+      Statement::With(statement) => self.node(format_args!("With {:?}", statement.span), |d| {
+        d.expression(&statement.expression);
+        d.statement(&statement.body);
+      }),
+      Statement::While(statement) => self.node(format_args!("While {:?}", statement.span), |d| {
+        d.labeled("test", |d| d.expression(&statement.condition));
+        d.statement(&statement.body);
+      }),
+    }
+  }
+
+  fn declaration(&mut self, kind: &str, span: Span, bindings: &[BindingPatternInitializer]) {
+    self.node(format_args!("Declaration {span:?} {kind}"), |d| {
+      for binding in bindings {
+        d.binding(binding);
+      }
+    });
+  }
+
+  fn for_binding(&mut self, binding: &ForBinding) {
+    match binding {
+      ForBinding::Expression(expression) => self.labeled("left", |d| d.expression(expression)),
+      ForBinding::Lexical(kind, pattern, span) => self.labeled("left", |d| {
+        d.node(
+          format_args!("Declaration {span:?} {}", lexical_kind(*kind)),
+          |d| d.pattern(pattern),
+        )
+      }),
+      ForBinding::Variable(binding, span) => self.labeled("left", |d| {
+        d.node(format_args!("Declaration {span:?} var"), |d| {
+          d.binding(binding)
+        })
+      }),
+    }
+  }
+
+  fn binding(&mut self, binding: &BindingPatternInitializer) {
+    match &binding.initializer {
+      None => self.pattern(&binding.pattern),
+      Some(initializer) => self.node(format_args!("Binding {:?}", binding.span), |d| {
+        d.pattern(&binding.pattern);
+        d.labeled("init", |d| d.expression(initializer));
+      }),
+    }
+  }
+
+  fn rest(&mut self, rest: &BindingRestElement) {
+    self.node(format_args!("Rest {:?}", rest.span), |d| {
+      d.pattern(&rest.pattern)
+    });
+  }
+
+  fn pattern(&mut self, pattern: &BindingPattern) {
+    match pattern {
+      BindingPattern::Array(pattern) => {
+        self.node(format_args!("ArrayPattern {:?}", pattern.span), |d| {
+          for element in &pattern.elements {
+            match element {
+              Some(binding) => d.binding(binding),
+              None => d.line(format_args!("Hole")),
+            }
+          }
+          if let Some(rest) = &pattern.rest {
+            d.rest(rest);
+          }
+        })
+      }
+      BindingPattern::Identifier(identifier) => self.identifier("Identifier", identifier),
+      BindingPattern::Object(pattern) => {
+        self.node(format_args!("ObjectPattern {:?}", pattern.span), |d| {
+          for property in &pattern.properties {
+            d.node(format_args!("Property {:?}", property.span), |d| {
+              d.property_name(&property.property);
+              d.binding(&property.binding);
+            });
+          }
+          if let Some(rest) = &pattern.rest {
+            d.rest(rest);
+          }
+        })
+      }
+    }
+  }
+
+  fn parameters(&mut self, parameters: &FormalParameters) {
+    self.node(format_args!("Parameters {:?}", parameters.span), |d| {
+      for binding in &parameters.bindings {
+        d.binding(binding);
+      }
+      if let Some(rest) = &parameters.rest {
+        d.rest(rest);
+      }
+    });
+  }
+
+  fn function(&mut self, kind: &str, definition: &FunctionDefinition) {
+    let flags = flags(&[
+      (definition.r#async, "async"),
+      (definition.generator, "generator"),
+    ]);
+    self.node(format_args!("{kind} {:?}{flags}", definition.span), |d| {
+      if let Some(identifier) = &definition.identifier {
+        d.identifier("Identifier", identifier);
+      }
+      d.parameters(&definition.parameters);
+      d.block("Body", &definition.body);
+    });
+  }
+
+  fn property_name(&mut self, name: &PropertyName) {
+    match name {
+      PropertyName::Computed(expression) => self.labeled("computed", |d| d.expression(expression)),
+      PropertyName::Static(key) => self.property_key(key),
+    }
+  }
+
+  fn class_element_name(&mut self, name: &ClassElementName) {
+    match name {
+      ClassElementName::Computed(expression) => {
+        self.labeled("computed", |d| d.expression(expression))
+      }
+      ClassElementName::Private(identifier) => self.identifier("PrivateName", identifier),
+      ClassElementName::Static(key) => self.property_key(key),
+    }
+  }
+
+  fn decorators(&mut self, decorators: &[Expression]) {
+    for decorator in decorators {
+      self.labeled("decorator", |d| d.expression(decorator));
+    }
+  }
+
+  fn class(&mut self, kind: &str, definition: &ClassDefinition) {
+    self.node(format_args!("{kind} {:?}", definition.span), |d| {
+      d.decorators(&definition.decorators);
+      if let Some(identifier) = &definition.identifier {
+        d.identifier("Identifier", identifier);
+      }
+      if let Some(heritage) = &definition.heritage {
+        d.labeled("extends", |d| d.expression(heritage));
+      }
+
+      d.node(format_args!("ClassBody {:?}", definition.body_span), |d| {
+        for element in &definition.body {
+          d.class_element(element);
+        }
+      });
+    });
+  }
+
+  fn class_element(&mut self, element: &ClassElement) {
+    match element {
+      ClassElement::Accessor(accessor) => {
+        let flags = flags(&[(accessor.r#static, "static")]);
+        self.node(format_args!("Accessor {:?}{flags}", accessor.span), |d| {
+          d.decorators(&accessor.decorators);
+          d.class_element_name(&accessor.name);
+          if let Some(value) = &accessor.value {
+            d.labeled("value", |d| d.expression(value));
+          }
+        })
+      }
+      ClassElement::Field(field) => {
+        let flags = flags(&[(field.r#static, "static")]);
+        self.node(format_args!("Field {:?}{flags}", field.span), |d| {
+          d.decorators(&field.decorators);
+          d.class_element_name(&field.name);
+          if let Some(value) = &field.value {
+            d.labeled("value", |d| d.expression(value));
+          }
+        })
+      }
+      ClassElement::Getter(getter) => {
+        let flags = flags(&[(getter.r#static, "static")]);
+        self.node(format_args!("Getter {:?}{flags}", getter.span), |d| {
+          d.decorators(&getter.decorators);
+          d.class_element_name(&getter.name);
+          d.line(format_args!("Parameters {:?}", getter.parameters_span));
+          d.block("Body", &getter.body);
+        })
+      }
+      ClassElement::Method(method) => {
+        let flags = flags(&[
+          (method.r#static, "static"),
+          (method.r#async, "async"),
+          (method.generator, "generator"),
+        ]);
+        self.node(format_args!("Method {:?}{flags}", method.span), |d| {
+          d.decorators(&method.decorators);
+          d.class_element_name(&method.name);
+          d.parameters(&method.parameters);
+          d.block("Body", &method.body);
+        })
+      }
+      ClassElement::Setter(setter) => {
+        let flags = flags(&[(setter.r#static, "static")]);
+        self.node(format_args!("Setter {:?}{flags}", setter.span), |d| {
+          d.decorators(&setter.decorators);
+          d.class_element_name(&setter.name);
+          d.node(
+            format_args!("Parameters {:?}", setter.parameters_span),
+            |d| d.binding(&setter.parameter),
+          );
+          d.block("Body", &setter.body);
+        })
+      }
+      ClassElement::Static(block) => self.node(format_args!("StaticBlock {:?}", block.span), |d| {
+        d.statements(&block.statements)
+      }),
+    }
+  }
+
+  fn arguments(&mut self, arguments: &[Argument]) {
+    for argument in arguments {
+      match argument {
+        Argument::Positional(expression) => self.expression(expression),
+        Argument::Spread(spread) => self.node(format_args!("Spread {:?}", spread.span), |d| {
+          d.expression(&spread.argument)
+        }),
+      }
+    }
+  }
+
+  fn member_name(&mut self, name: &MemberName) {
+    match name {
+      MemberName::Computed(expression) => self.labeled("computed", |d| d.expression(expression)),
+      MemberName::Private(identifier) => self.identifier("PrivateName", identifier),
+      MemberName::Static(identifier) => self.identifier("Key", identifier),
+    }
+  }
+
+  fn expression(&mut self, expression: &Expression) {
+    match expression {
+      Expression::Array(literal) => self.node(format_args!("Array {:?}", literal.span), |d| {
+        for element in &literal.elements {
+          match element {
+            ArrayElement::Elision => d.line(format_args!("Hole")),
+            ArrayElement::Expression(expression) => d.expression(expression),
+            ArrayElement::Spread(spread) => d.node(format_args!("Spread {:?}", spread.span), |d| {
+              d.expression(&spread.argument)
+            }),
+          }
+        }
+      }),
+      Expression::ArrowFunction(definition) => {
+        let flags = flags(&[(definition.r#async, "async")]);
+        self.node(
+          format_args!("ArrowFunction {:?}{flags}", definition.span),
+          |d| {
+            d.parameters(&definition.parameters);
+            match &definition.body {
+              ConciseBody::Block(block) => d.block("Body", block),
+              ConciseBody::Expression(expression) => d.expression(expression),
+            }
+          },
+        )
+      }
+      Expression::Assignment(expression) => self.node(
+        format_args!("Assignment {:?} {:?}", expression.span, expression.op),
+        |d| {
+          d.expression(&expression.left);
+          d.expression(&expression.right);
+        },
+      ),
+      Expression::BigInt(literal) => {
+        self.line(format_args!("BigInt {:?} {}n", literal.span, literal.value))
+      }
+      Expression::BinaryOp(expression) => self.node(
+        format_args!("Binary {:?} {:?}", expression.span, expression.op),
+        |d| {
+          d.expression(&expression.left);
+          d.expression(&expression.right);
+        },
+      ),
+      Expression::Boolean(literal) => {
+        self.line(format_args!("Boolean {:?} {}", literal.span, literal.value))
+      }
+      Expression::Call(expression) => {
+        let flags = flags(&[(expression.optional, "optional")]);
+        self.node(format_args!("Call {:?}{flags}", expression.span), |d| {
+          d.labeled("callee", |d| d.expression(&expression.callee));
+          d.arguments(&expression.arguments);
+        })
+      }
+      Expression::Chain(expression) => self
+        .node(format_args!("Chain {:?}", expression.span()), |d| {
+          d.expression(expression)
+        }),
+      Expression::Class(definition) => self.class("ClassExpression", definition),
+      Expression::Conditional(expression) => {
+        self.node(format_args!("Conditional {:?}", expression.span), |d| {
+          d.labeled("test", |d| d.expression(&expression.condition));
+          d.expression(&expression.consequent);
+          d.expression(&expression.alternate);
+        })
+      }
+      Expression::Group(expression) => self
+        .node(format_args!("Group {:?}", expression.span), |d| {
+          d.expression(&expression.expression)
+        }),
+      Expression::Function(definition) => self.function("FunctionExpression", definition),
+      Expression::Identifier(identifier) => self.identifier("Identifier", identifier),
+      Expression::Import(expression) => self
+        .node(format_args!("Import {:?}", expression.span), |d| {
+          d.expression(&expression.argument)
+        }),
+      Expression::ImportMeta(span) => self.line(format_args!("ImportMeta {span:?}")),
+      Expression::In(expression) => self.node(format_args!("In {:?}", expression.span), |d| {
+        d.member_name(&expression.name);
+        d.expression(&expression.argument);
+      }),
+      Expression::JsxElement(element) => self.jsx_element(element),
+      Expression::JsxFragment(fragment) => self.jsx_fragment(fragment),
+      Expression::List(expression) => self.node(format_args!("List {:?}", expression.span), |d| {
+        for expression in &expression.expressions {
+          d.expression(expression);
+        }
+      }),
+      Expression::Member(expression) => {
+        let flags = flags(&[(expression.optional, "optional")]);
+        self.node(format_args!("Member {:?}{flags}", expression.span), |d| {
+          d.expression(&expression.object);
+          d.member_name(&expression.property);
+        })
+      }
+      Expression::NewTarget(span) => self.line(format_args!("NewTarget {span:?}")),
+      Expression::New(expression) => self.node(format_args!("New {:?}", expression.span), |d| {
+        d.labeled("callee", |d| d.expression(&expression.callee));
+        match &expression.arguments {
+          Some(arguments) => d.arguments(arguments),
+          None => d.line(format_args!("NoArguments")),
+        }
+      }),
+      Expression::Null(span) => self.line(format_args!("Null {span:?}")),
+      Expression::Number(literal) => self.line(format_args!(
+        "Number {:?} {}",
+        literal.span,
+        es_number_to_string(literal.value, 10)
+      )),
+      Expression::Object(literal) => self.node(format_args!("Object {:?}", literal.span), |d| {
+        for property in &literal.properties {
+          d.object_property(property);
+        }
+      }),
+      Expression::RegExp(literal) => self.line(format_args!(
+        "RegExp {:?} /{}/{}",
+        literal.span, literal.source, literal.flags
+      )),
+      Expression::String(literal) => self.line(format_args!(
+        "String {:?} {:?}",
+        literal.span, literal.value
+      )),
+      Expression::Super(span) => self.line(format_args!("Super {span:?}")),
+      Expression::TaggedTemplate(literal) => {
+        self.node(format_args!("TaggedTemplate {:?}", literal.span), |d| {
+          d.labeled("tag", |d| d.expression(&literal.tag));
+          for (index, span) in literal.string_spans.iter().enumerate() {
+            match literal.strings[index] {
+              Some(cooked) => d.line(format_args!(
+                "Quasi {span:?} {:?} raw {:?}",
+                cooked, literal.raw_strings[index]
+              )),
+              None => d.line(format_args!(
+                "Quasi {span:?} invalid raw {:?}",
+                literal.raw_strings[index]
+              )),
+            }
+            if let Some(substitution) = literal.substitutions.get(index) {
+              d.expression(substitution);
+            }
+          }
+        })
+      }
+      Expression::Template(literal) => {
+        self.node(format_args!("Template {:?}", literal.span), |d| {
+          for (index, span) in literal.string_spans.iter().enumerate() {
+            d.line(format_args!("Quasi {span:?} {:?}", literal.strings[index]));
+            if let Some(substitution) = literal.substitutions.get(index) {
+              d.expression(substitution);
+            }
+          }
+        })
+      }
+      Expression::This(span) => self.line(format_args!("This {span:?}")),
+      Expression::UnaryOp(expression) => self.node(
+        format_args!("Unary {:?} {:?}", expression.span, expression.op),
+        |d| d.expression(&expression.argument),
+      ),
+      Expression::Yield(expression) => {
+        let flags = flags(&[(expression.delegate, "delegate")]);
+        self.node(format_args!("Yield {:?}{flags}", expression.span), |d| {
+          if let Some(argument) = &expression.argument {
+            d.expression(argument);
+          }
+        })
+      }
+    }
+  }
+
+  fn object_property(&mut self, property: &ObjectProperty) {
+    match property {
+      ObjectProperty::Getter(getter) => self.node(format_args!("Getter {:?}", getter.span), |d| {
+        d.property_name(&getter.property);
+        d.line(format_args!("Parameters {:?}", getter.parameters_span));
+        d.block("Body", &getter.body);
+      }),
+      ObjectProperty::Method(method) => {
+        let flags = flags(&[(method.r#async, "async"), (method.generator, "generator")]);
+        self.node(format_args!("Method {:?}{flags}", method.span), |d| {
+          d.property_name(&method.property);
+          d.parameters(&method.parameters);
+          d.block("Body", &method.body);
+        })
+      }
+      ObjectProperty::Property(definition) => {
+        self.node(format_args!("Property {:?}", definition.span), |d| {
+          d.property_name(&definition.property);
+          d.expression(&definition.expression);
+        })
+      }
+      ObjectProperty::Setter(setter) => self.node(format_args!("Setter {:?}", setter.span), |d| {
+        d.property_name(&setter.property);
+        d.node(
+          format_args!("Parameters {:?}", setter.parameters_span),
+          |d| d.binding(&setter.parameter),
+        );
+        d.block("Body", &setter.body);
+      }),
+      ObjectProperty::Shorthand(identifier) => self.identifier("Shorthand", identifier),
+      ObjectProperty::Spread(spread) => self.node(format_args!("Spread {:?}", spread.span), |d| {
+        d.expression(&spread.argument)
+      }),
+    }
+  }
+
+  fn jsx_element_name(&mut self, name: &JsxElementName) {
+    match name {
+      JsxElementName::Identifier(identifier) => self.identifier("JsxName", identifier),
+      JsxElementName::Member(member) => {
+        self.node(format_args!("JsxMember {:?}", member.span), |d| {
+          d.jsx_element_name(&member.object);
+          d.identifier("JsxName", &member.property);
+        })
+      }
+      JsxElementName::Namespaced(name) => {
+        self.node(format_args!("JsxNamespaced {:?}", name.span), |d| {
+          d.identifier("JsxName", &name.namespace);
+          d.identifier("JsxName", &name.name);
+        })
+      }
+    }
+  }
+
+  fn jsx_element(&mut self, element: &JsxElement) {
+    let flags = flags(&[(element.self_closing, "self-closing")]);
+    self.node(format_args!("JsxElement {:?}{flags}", element.span), |d| {
+      d.line(format_args!("Opening {:?}", element.opening_span));
+      d.jsx_element_name(&element.name);
+      for attribute in &element.attributes {
+        match attribute {
+          JsxAttribute::Attribute(attribute) => {
+            d.node(format_args!("JsxAttribute {:?}", attribute.span), |d| {
+              match &attribute.name {
+                JsxAttributeName::Identifier(identifier) => d.identifier("JsxName", identifier),
+                JsxAttributeName::Namespaced(name) => {
+                  d.jsx_element_name(&JsxElementName::Namespaced(name))
+                }
+              }
+              match &attribute.value {
+                Some(JsxAttributeValue::Element(element)) => d.jsx_element(element),
+                Some(JsxAttributeValue::Expression(container)) => d
+                  .node(format_args!("JsxExpression {:?}", container.span), |d| {
+                    d.expression(&container.expression)
+                  }),
+                Some(JsxAttributeValue::Fragment(fragment)) => d.jsx_fragment(fragment),
+                Some(JsxAttributeValue::String(literal)) => d.line(format_args!(
+                  "String {:?} {:?}",
+                  literal.span, literal.value
+                )),
+                None => {}
+              }
+            })
+          }
+          JsxAttribute::Spread(container) => d
+            .node(format_args!("JsxSpread {:?}", container.span), |d| {
+              d.expression(&container.expression)
+            }),
+        }
+      }
+      d.jsx_children(&element.children);
+      if let Some(span) = element.closing_span {
+        d.line(format_args!("Closing {span:?}"));
+      }
+    });
+  }
+
+  fn jsx_fragment(&mut self, fragment: &JsxFragment) {
+    self.node(format_args!("JsxFragment {:?}", fragment.span), |d| {
+      d.line(format_args!("Opening {:?}", fragment.opening_span));
+      d.jsx_children(&fragment.children);
+      d.line(format_args!("Closing {:?}", fragment.closing_span));
+    });
+  }
+
+  fn jsx_children(&mut self, children: &[JsxChild]) {
+    for child in children {
+      match child {
+        JsxChild::Element(element) => self.jsx_element(element),
+        JsxChild::Empty(span) => self.line(format_args!("JsxEmpty {span:?}")),
+        JsxChild::Expression(container) => self
+          .node(format_args!("JsxExpression {:?}", container.span), |d| {
+            d.expression(&container.expression)
+          }),
+        JsxChild::Fragment(fragment) => self.jsx_fragment(fragment),
+        JsxChild::Spread(container) => self
+          .node(format_args!("JsxSpread {:?}", container.span), |d| {
+            d.expression(&container.expression)
+          }),
+        JsxChild::Text(text) => self.line(format_args!(
+          "JsxText {:?} {:?} raw {:?}",
+          text.span, text.value, text.raw
+        )),
+      }
+    }
+  }
+}
+
+fn lexical_kind(kind: LexicalKind) -> &'static str {
+  match kind {
+    LexicalKind::Const => "const",
+    LexicalKind::Let => "let",
+  }
+}
+
+// the names of the flags that are set, each after a space
+fn flags(flags: &[(bool, &str)]) -> String {
+  flags
+    .iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| format!(" {name}"))
+    .collect()
+}
@@ -0,0 +1,119 @@
+use std::{
+  error::Error,
+  fs::{read_dir, read_to_string},
+  path::Path,
+  process::ExitCode,
+};
+
+use bumpalo::Bump;
+use tower_ast::SourceType;
+use tower_codegen::{
+  estree::program_to_estree,
+  json::{parse_json, JsonValue},
+};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+// compares the ESTree json of each fixture with the acorn output next to it, `name.js` and
+// `name.json`. the fixtures are the ones in the repo when no files are given
+pub fn run(files: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut files = files.to_vec();
+
+  if files.is_empty() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/estree");
+    for entry in read_dir(directory)? {
+      let path = entry?.path();
+      if path
+        .extension()
+        .is_some_and(|extension| extension != "json")
+      {
+        files.push(path.to_string_lossy().into_owned());
+      }
+    }
+
+    files.sort();
+  }
+
+  let mut failures = 0;
+  for file in &files {
+    if let Err(message) = check_fixture(file) {
+      failures += 1;
+      println!("FAIL {file}: {message}");
+    }
+  }
+
+  println!("{} passed, {failures} failed", files.len() - failures);
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+fn check_fixture(file: &str) -> Result<(), String> {
+  let source = read_to_string(file).map_err(|error| error.to_string())?;
+  let expected_file = Path::new(file).with_extension("json");
+  let expected = read_to_string(&expected_file)
+    .map_err(|error| format!("{}: {error}", expected_file.display()))?;
+  let expected = parse_json(&expected)?;
+
+  let source_type = if file.ends_with(".mjs") {
+    SourceType::Module
+  } else {
+    SourceType::Script
+  };
+
+  let mut options = ParseOptions::new(source_type);
+  options.jsx = file.ends_with(".jsx");
+
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+
+  compare(&expected, &program_to_estree(program, chars), "$")
+}
+
+// the order of the fields doesn't matter, acorn sets them in whatever order it parses them
+fn compare(expected: &JsonValue, actual: &JsonValue, path: &str) -> Result<(), String> {
+  match (expected, actual) {
+    (JsonValue::Array(expected), JsonValue::Array(actual)) => {
+      if expected.len() != actual.len() {
+        return Err(format!(
+          "{path} has {} elements, acorn has {}",
+          actual.len(),
+          expected.len()
+        ));
+      }
+
+      for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+        compare(expected, actual, &format!("{path}[{index}]"))?;
+      }
+
+      Ok(())
+    }
+    (JsonValue::Object(expected), JsonValue::Object(actual)) => {
+      for (name, _) in actual {
+        if !expected
+          .iter()
+          .any(|(expected_name, _)| expected_name == name)
+        {
+          return Err(format!("{path}.{name} isn't in the acorn output"));
+        }
+      }
+
+      for (name, expected) in expected {
+        let actual = actual
+          .iter()
+          .find(|(actual_name, _)| actual_name == name)
+          .map(|(_, value)| value)
+          .ok_or(format!("{path}.{name} is missing"))?;
+        compare(expected, actual, &format!("{path}.{name}"))?;
+      }
+
+      Ok(())
+    }
+    (expected, actual) if expected == actual => Ok(()),
+    (expected, actual) => Err(format!("{path} is {actual:?}, acorn has {expected:?}")),
+  }
+}
@@ -0,0 +1,302 @@
+use std::{error::Error, fmt::Debug, process::ExitCode};
+
+use tower_parser::number::{
+  f16_bits_to_f64, f16_round, f64_to_f16_bits, float16_to_string, float32_to_string,
+};
+
+use crate::bigint::Random;
+
+const FLOAT32_SAMPLES: usize = 100_000;
+
+// checks every binary16 bit pattern: the value each one stands for, how the doubles around and
+// between them round, and the shortest digits that read back as them. binary32 printing is
+// checked against the standard library's shortest digits for random values. the seed can be
+// given to reproduce a failure
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let seed = match args.first() {
+    Some(seed) => seed.parse().map_err(|_| format!("`{seed}` isn't a seed"))?,
+    None => 0x9e37_79b9_7f4a_7c15,
+  };
+
+  let mut checks = Checks {
+    checked: 0,
+    failures: 0,
+  };
+
+  checks.values();
+  checks.rounding();
+  checks.float16_digits();
+  checks.float32_digits(&mut Random(seed));
+
+  for (bits, expected) in FLOAT16_STRINGS {
+    checks.expect(
+      "float16_to_string",
+      &format!("{bits:#06x}"),
+      float16_to_string(*bits),
+      expected.to_string(),
+    );
+  }
+
+  for (value, expected) in FLOAT32_STRINGS {
+    checks.expect(
+      "float32_to_string",
+      value,
+      float32_to_string(*value),
+      expected.to_string(),
+    );
+  }
+
+  println!(
+    "{} passed, {} failed",
+    checks.checked - checks.failures,
+    checks.failures
+  );
+
+  Ok(if checks.failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+struct Checks {
+  checked: usize,
+  failures: usize,
+}
+
+impl Checks {
+  fn expect<T: PartialEq + Debug>(
+    &mut self,
+    name: &str,
+    input: &dyn Debug,
+    actual: T,
+    expected: T,
+  ) {
+    self.checked += 1;
+    if actual != expected {
+      self.failures += 1;
+      println!("FAIL {name}({input:?}) = {actual:?}, expected {expected:?}");
+    }
+  }
+
+  // the positive patterns up to Infinity count up from 0 in steps of the spacing, 2^-24 for the
+  // subnormals and the smallest exponent and doubling with each one after it. the negative ones
+  // mirror them, and every pattern past Infinity is NaN. each comes back as the same bits
+  fn values(&mut self) {
+    for bits in 0..=0x7c00u16 {
+      let value = f16_bits_to_f64(bits);
+      let negative = f16_bits_to_f64(bits | 0x8000);
+      self.expect(
+        "f16_bits_to_f64",
+        &bits,
+        negative.to_bits(),
+        (-value).to_bits(),
+      );
+
+      let expected = match bits {
+        0 => 0.0,
+        0x7c00 => f64::INFINITY,
+        _ => {
+          let spacing = 2f64.powi(((bits - 1) >> 10).max(1) as i32 - 25);
+          f16_bits_to_f64(bits - 1) + spacing
+        }
+      };
+      self.expect("f16_bits_to_f64", &bits, value, expected);
+
+      self.expect("f64_to_f16_bits", &value, f64_to_f16_bits(value), bits);
+      let negative_bits = bits | 0x8000;
+      self.expect(
+        "f64_to_f16_bits",
+        &negative,
+        f64_to_f16_bits(negative),
+        negative_bits,
+      );
+    }
+
+    self.expect("f16_bits_to_f64", &0x3c00, f16_bits_to_f64(0x3c00), 1.0);
+    self.expect("f16_bits_to_f64", &0x7bff, f16_bits_to_f64(0x7bff), 65504.0);
+
+    for bits in (0x7c01..=0x7fffu16).chain(0xfc01..=0xffff) {
+      self.expect(
+        "f16_bits_to_f64",
+        &bits,
+        f16_bits_to_f64(bits).is_nan(),
+        true,
+      );
+    }
+    let nan = f64_to_f16_bits(f64::NAN);
+    self.expect(
+      "f64_to_f16_bits",
+      &f64::NAN,
+      f16_bits_to_f64(nan).is_nan(),
+      true,
+    );
+  }
+
+  // between two neighbours the halfway double goes to the even one and the doubles either side
+  // of it to the nearer one. past the largest value it's halfway to the next power of two that
+  // rounds to Infinity
+  fn rounding(&mut self) {
+    for bits in 0..0x7c00u16 {
+      let low = f16_bits_to_f64(bits);
+      let high = match bits {
+        0x7bff => 65536.0,
+        _ => f16_bits_to_f64(bits + 1),
+      };
+      let halfway = (low + high) / 2.0;
+      let even = if bits & 1 == 0 { bits } else { bits + 1 };
+
+      for (sign, sign_bit) in [(1.0, 0), (-1.0, 0x8000)] {
+        let cases = [
+          (low.next_up(), bits),
+          (halfway.next_down(), bits),
+          (halfway, even),
+          (halfway.next_up(), bits + 1),
+          (high.next_down(), bits + 1),
+        ];
+        for (value, expected) in cases {
+          let value = sign * value;
+          let expected = expected | sign_bit;
+          self.expect("f64_to_f16_bits", &value, f64_to_f16_bits(value), expected);
+          self.expect(
+            "f16_round",
+            &value,
+            f16_round(value).to_bits(),
+            f16_bits_to_f64(expected).to_bits(),
+          );
+        }
+      }
+    }
+
+    let edges = [
+      (f64::MIN_POSITIVE, 0),
+      (5e-324, 0),
+      (-5e-324, 0x8000),
+      (1e300, 0x7c00),
+      (-f64::MAX, 0xfc00),
+      (f64::INFINITY, 0x7c00),
+      (f64::NEG_INFINITY, 0xfc00),
+    ];
+    for (value, expected) in edges {
+      self.expect("f64_to_f16_bits", &value, f64_to_f16_bits(value), expected);
+    }
+  }
+
+  // every finite pattern reads back from its digits, and no fewer digits do. the reference tries
+  // each count from one up, the nearest digits of that many and their neighbours, and of the
+  // ones that read back takes the closest
+  fn float16_digits(&mut self) {
+    for bits in (1..0x7c00u16).chain(0x8001..0xfc00) {
+      let value = f16_bits_to_f64(bits);
+      let text = float16_to_string(bits);
+      let Ok(parsed) = text.parse::<f64>() else {
+        self.expect("float16_to_string", &bits, text, String::from("a number"));
+        continue;
+      };
+
+      let expected = (1..=17)
+        .find_map(|count| {
+          let nearest = format!("{value:.*e}", count - 1);
+          let (digits, exponent) = nearest.split_once('e').unwrap();
+          let digits = digits.replace('.', "").parse::<i64>().unwrap();
+          let exponent = exponent.parse::<i32>().unwrap() - count as i32 + 1;
+
+          [digits, digits - 1, digits + 1]
+            .into_iter()
+            .map(|digits| format!("{digits}e{exponent}").parse::<f64>().unwrap())
+            .filter(|candidate| f64_to_f16_bits(*candidate) == bits)
+            .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        })
+        .unwrap();
+
+      self.expect("float16_to_string", &(bits, &text), parsed, expected);
+    }
+  }
+
+  // the standard library prints the shortest digits that read back as the f32, the closest of
+  // them, so its exponential form has the same digits. apart from when the value is halfway
+  // between two, where Number::toString takes the even one and the standard library needn't
+  fn float32_digits(&mut self, random: &mut Random) {
+    let powers = (-149..=127).map(|exponent| 2f32.powi(exponent));
+    let edges = [
+      f32::MAX,
+      f32::MIN_POSITIVE,
+      1e-45,
+      0.1,
+      1.0 / 3.0,
+      16777216.0,
+    ];
+    let samples = (0..FLOAT32_SAMPLES).map(|_| f32::from_bits(random.next() as u32));
+
+    for value in powers.chain(edges).chain(samples) {
+      if !value.is_finite() || value == 0.0 {
+        continue;
+      }
+
+      let text = float32_to_string(value);
+      let Ok(actual) = text.parse::<f64>() else {
+        self.expect("float32_to_string", &value, text, String::from("a number"));
+        continue;
+      };
+      let expected = format!("{value:e}").parse::<f64>().unwrap();
+
+      let (digits, exponent) = decimal_digits(actual);
+      let (expected_digits, expected_exponent) = decimal_digits(expected);
+      let halfway = format!("{}5e{}", digits.min(expected_digits), exponent - 1);
+      let even_tie = exponent == expected_exponent
+        && (digits - expected_digits).abs() == 1
+        && digits % 2 == 0
+        && halfway.parse::<f64>() == Ok(value.abs() as f64);
+
+      if !even_tie {
+        self.expect("float32_to_string", &(value, &text), actual, expected);
+      }
+    }
+  }
+}
+
+// the digits of a short decimal as an integer and the exponent of the last one
+fn decimal_digits(value: f64) -> (i64, i32) {
+  let text = format!("{:e}", value.abs());
+  let (digits, exponent) = text.split_once('e').unwrap();
+  let fraction_digits = digits
+    .split_once('.')
+    .map_or(0, |(_, fraction)| fraction.len());
+
+  let digits = digits.replace('.', "").parse().unwrap();
+  (
+    digits,
+    exponent.parse::<i32>().unwrap() - fraction_digits as i32,
+  )
+}
+
+const FLOAT16_STRINGS: &[(u16, &str)] = &[
+  (0x0000, "0"),
+  (0x8000, "0"),
+  (0x7c00, "Infinity"),
+  (0xfc00, "-Infinity"),
+  (0x7e00, "NaN"),
+  (0x3c00, "1"),
+  (0xc000, "-2"),
+  (0x3555, "0.3333"),
+  (0x7bff, "65500"),
+  (0x0001, "6e-8"),
+  (0x0400, "0.00006104"),
+  (0x2e66, "0.1"),
+  (0x6400, "1024"),
+];
+
+const FLOAT32_STRINGS: &[(f32, &str)] = &[
+  (0.0, "0"),
+  (f32::NAN, "NaN"),
+  (f32::NEG_INFINITY, "-Infinity"),
+  (0.1, "0.1"),
+  (-1.5, "-1.5"),
+  (16777216.0, "16777216"),
+  (f32::MAX, "3.4028235e+38"),
+  (1e-45, "1e-45"),
+  (1e21, "1e+21"),
+  (1e20, "100000000000000000000"),
+  (0.000001, "0.000001"),
+  (1e-7, "1e-7"),
+];
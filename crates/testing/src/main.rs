@@ -1,3 +1,42 @@
-fn main() {
-  println!("Hello, world!");
+use std::{env, error::Error, process::ExitCode};
+
+mod bench;
+mod bigint;
+mod dump;
+mod estree;
+mod float16;
+mod number;
+mod roundtrip;
+mod scope;
+mod snapshot;
+mod sourcemap;
+mod string;
+mod test262;
+mod unicode;
+mod visit;
+
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+  let args = env::args().skip(1).collect::<Vec<String>>();
+
+  match args.first().map(String::as_str) {
+    Some("bench") => bench::run(&args[1..]),
+    Some("bigint") => bigint::run(&args[1..]),
+    Some("estree") => estree::run(&args[1..]),
+    Some("float16") => float16::run(&args[1..]),
+    Some("number") => number::run(&args[1..]),
+    Some("roundtrip") => roundtrip::run(&args[1..]),
+    Some("scope") => scope::run(&args[1..]),
+    Some("snapshot") => snapshot::run(&args[1..]),
+    Some("sourcemap") => sourcemap::run(&args[1..]),
+    Some("string") => string::run(&args[1..]),
+    Some("test262") => test262::run(&args[1..]),
+    Some("unicode") => unicode::run(&args[1..]),
+    Some("visit") => visit::run(&args[1..]),
+    _ => {
+      eprintln!(
+        "usage: testing bench|bigint|estree|float16|number|roundtrip|scope|snapshot|sourcemap|string|test262|unicode|visit [file.js ...]"
+      );
+      Ok(ExitCode::FAILURE)
+    }
+  }
 }
@@ -0,0 +1,643 @@
+use std::{error::Error, fmt::Debug, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_ast::{bigint::BigInt, expression::Expression, statement::Statement, SourceType};
+use tower_parser::{
+  number::{
+    es_number_to_string, parse_float, parse_int, string_to_number, to_exponential, to_fixed,
+    to_precision,
+  },
+  parser::{parse_source_with_options, ParseOptions},
+};
+
+use crate::bigint::Random;
+
+const SAMPLES: usize = 20_000;
+
+// checks the conversions between strings and numbers against the cases below, what a runtime
+// gives for each of them, and the formatters against the exact decimal expansion of random
+// doubles. the seed can be given to reproduce a failure
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let seed = match args.first() {
+    Some(seed) => seed.parse().map_err(|_| format!("`{seed}` isn't a seed"))?,
+    None => 0x9e37_79b9_7f4a_7c15,
+  };
+
+  let mut checks = Checks {
+    checked: 0,
+    failures: 0,
+  };
+
+  for (text, expected) in STRING_TO_NUMBER {
+    checks.number("string_to_number", text, string_to_number(text), *expected);
+  }
+
+  for (text, expected) in PARSE_FLOAT {
+    checks.number("parse_float", text, parse_float(text), *expected);
+  }
+
+  for (text, radix, expected) in PARSE_INT {
+    checks.number(
+      "parse_int",
+      &(text, radix),
+      parse_int(text, *radix),
+      *expected,
+    );
+  }
+
+  for (value, radix, expected) in TO_STRING {
+    let actual = es_number_to_string(*value, *radix);
+    checks.expect("to_string", &(value, radix), actual, expected.to_string());
+  }
+  checks.integers(&mut Random(seed));
+
+  for (value, digits, expected) in TO_FIXED {
+    let expected = expected.map(String::from);
+    checks.expect(
+      "to_fixed",
+      &(value, digits),
+      to_fixed(*value, *digits),
+      expected,
+    );
+  }
+
+  for (value, digits, expected) in TO_EXPONENTIAL {
+    let expected = expected.map(String::from);
+    let actual = to_exponential(*value, *digits);
+    checks.expect("to_exponential", &(value, digits), actual, expected);
+  }
+
+  for (value, digits, expected) in TO_PRECISION {
+    let expected = expected.map(String::from);
+    let actual = to_precision(*value, *digits);
+    checks.expect("to_precision", &(value, digits), actual, expected);
+  }
+
+  for (source, expected) in LITERALS {
+    checks.number("literal", source, literal_value(source), *expected);
+  }
+  checks.long_literals();
+
+  checks.formatting(&mut Random(seed));
+
+  println!(
+    "{} passed, {} failed",
+    checks.checked - checks.failures,
+    checks.failures
+  );
+
+  Ok(if checks.failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+struct Checks {
+  checked: usize,
+  failures: usize,
+}
+
+impl Checks {
+  fn expect<T: PartialEq + Debug>(
+    &mut self,
+    name: &str,
+    input: &dyn Debug,
+    actual: T,
+    expected: T,
+  ) {
+    self.checked += 1;
+    if actual != expected {
+      self.failures += 1;
+      println!("FAIL {name}{input:?} = {actual:?}, expected {expected:?}");
+    }
+  }
+
+  // NaN is NaN and the sign of zero matters
+  fn number(&mut self, name: &str, input: &dyn Debug, actual: f64, expected: f64) {
+    self.checked += 1;
+    if actual.to_bits() != expected.to_bits() && !(actual.is_nan() && expected.is_nan()) {
+      self.failures += 1;
+      println!("FAIL {name}({input:?}) = {actual:?}, expected {expected:?}");
+    }
+  }
+
+  // integers next to and exactly between two doubles, too long for any shortcut, in every radix
+  // a literal can have. and the decimals for halfway between the denormals at the bottom
+  fn long_literals(&mut self) {
+    let one = BigInt::from(1u64);
+    let power = |exponent: usize| &one << exponent;
+    let sum = |terms: &[&BigInt]| terms.iter().fold(BigInt::zero(), |sum, term| &sum + *term);
+
+    let halfway_to_infinity = &power(1024) - &power(970);
+    let integers = [
+      (sum(&[&power(64), &power(11)]), 2f64.powi(64)),
+      (sum(&[&power(64), &power(11), &one]), 2f64.powi(64) + 4096.0),
+      (sum(&[&power(200), &power(147)]), 2f64.powi(200)),
+      (
+        sum(&[&power(200), &power(147), &one]),
+        2f64.powi(200) + 2f64.powi(148),
+      ),
+      (
+        sum(&[&power(200), &power(147), &power(148)]),
+        2f64.powi(200) + 2f64.powi(149),
+      ),
+      (&halfway_to_infinity - &one, f64::MAX),
+      (halfway_to_infinity, f64::INFINITY),
+      (&power(1024) - &one, f64::INFINITY),
+    ];
+
+    for (value, expected) in &integers {
+      for (prefix, radix) in [("", 10), ("0x", 16), ("0o", 8), ("0b", 2)] {
+        let source = format!("{prefix}{}", value.to_string_radix(radix));
+        self.number("literal", &source, literal_value(&source), *expected);
+      }
+    }
+
+    // 2^-1075 is 5^1075 / 10^1075, half the smallest denormal, and 3·2^-1075 halfway between it
+    // and the next one
+    let five = BigInt::from(5u64);
+    let decimal = |numerator: BigInt, places: usize| {
+      let digits = numerator.to_string();
+      format!("0.{}{digits}", "0".repeat(places - digits.len()))
+    };
+    let smallest_half = decimal(five.pow(&BigInt::from(1075u64)).unwrap(), 1075);
+    let three_halves = decimal(
+      &BigInt::from(3u64) * &five.pow(&BigInt::from(1075u64)).unwrap(),
+      1075,
+    );
+
+    let decimals = [
+      (smallest_half.clone(), 0.0),
+      (format!("{smallest_half}1"), 5e-324),
+      (format!("{smallest_half}e-0"), 0.0),
+      (three_halves.clone(), 1e-323),
+      (format!("{three_halves}e0"), 1e-323),
+    ];
+
+    for (source, expected) in &decimals {
+      self.number("literal", source, literal_value(source), *expected);
+    }
+  }
+
+  // safe integers of every width in every radix, which skip dragonbox and the radix conversion
+  fn integers(&mut self, random: &mut Random) {
+    for _ in 0..SAMPLES {
+      let magnitude = random.next() >> (11 + random.below(53));
+      let value = if random.next() & 1 == 1 {
+        -(magnitude as i64)
+      } else {
+        magnitude as i64
+      };
+      let radix = 2 + random.below(35) as u32;
+
+      let expected = BigInt::from(value).to_string_radix(radix);
+      let actual = es_number_to_string(value as f64, radix as u8);
+      self.expect("to_string", &(value, radix), actual, expected);
+    }
+  }
+
+  // every digit count for random doubles, denormals, halves and short decimals, where ties and
+  // carries into a new digit happen
+  fn formatting(&mut self, random: &mut Random) {
+    for index in 0..SAMPLES {
+      let value = match index % 4 {
+        0 => f64::from_bits(random.next()),
+        1 => f64::from_bits(random.below(1 << 52)),
+        2 => (random.below(1 << 20) as f64 + 0.5) / (1 << random.below(12)) as f64,
+        _ => random.below(100_000) as f64 / 1000.0,
+      };
+      if !value.is_finite() {
+        continue;
+      }
+
+      let digits = random.below(101) as usize;
+      let (expansion, point) = exact_decimal(value);
+      let sign = if value < 0.0 { "-" } else { "" };
+
+      if value.abs() < 1e21 {
+        let (integer, fraction) = match round_half_up(&expansion, point + digits as i32) {
+          n if n.len() <= digits => ("0".to_string(), "0".repeat(digits - n.len()) + &n),
+          n => {
+            let (integer, fraction) = n.split_at(n.len() - digits);
+            (integer.to_string(), fraction.to_string())
+          }
+        };
+        let point = if digits == 0 { "" } else { "." };
+        let expected = format!("{sign}{integer}{point}{fraction}");
+        let actual = to_fixed(value, digits as f64);
+        self.expect("to_fixed", &(value, digits), actual, Some(expected));
+      }
+
+      if value == 0.0 {
+        continue;
+      }
+
+      let count = digits.max(1);
+      let mut significand = round_half_up(&expansion, count as i32);
+      let mut exponent = point - 1;
+      if significand.len() > count {
+        significand.truncate(count);
+        exponent += 1;
+      }
+
+      let (first, rest) = significand.split_at(1);
+      let fraction_point = if rest.is_empty() { "" } else { "." };
+      let exponent_sign = if exponent < 0 { "-" } else { "+" };
+      let exponential = format!(
+        "{sign}{first}{fraction_point}{rest}e{exponent_sign}{}",
+        exponent.abs()
+      );
+
+      let actual = to_exponential(value, Some(count as f64 - 1.0));
+      self.expect(
+        "to_exponential",
+        &(value, count - 1),
+        actual,
+        Some(exponential.clone()),
+      );
+
+      let expected = if exponent < -6 || exponent >= count as i32 {
+        exponential
+      } else if exponent < 0 {
+        format!(
+          "{sign}0.{}{significand}",
+          "0".repeat((-exponent - 1) as usize)
+        )
+      } else {
+        let (integer, fraction) = significand.split_at(exponent as usize + 1);
+        let point = if fraction.is_empty() { "" } else { "." };
+        format!("{sign}{integer}{point}{fraction}")
+      };
+      let actual = to_precision(value, Some(count as f64));
+      self.expect("to_precision", &(value, count), actual, Some(expected));
+    }
+  }
+}
+
+// the value of a numeric literal as the parser reads it, NaN when it doesn't parse as one
+fn literal_value(source: &str) -> f64 {
+  let allocator = Bump::new();
+  let source = format!("{source};").chars().collect::<Vec<_>>();
+  let chars = allocator.alloc_slice_copy(&source);
+
+  let options = ParseOptions::new(SourceType::Script);
+  let Ok(program) = parse_source_with_options(&allocator, chars, options) else {
+    return f64::NAN;
+  };
+  match program.statement_list[..] {
+    [Statement::Expression(statement)] => match statement.expression {
+      Expression::Number(literal) => literal.value,
+      _ => f64::NAN,
+    },
+    _ => f64::NAN,
+  }
+}
+
+// the digits of the exact value of a double without leading or trailing zeros, and where the
+// point goes, the value is 0.digits·10^point. every double is a finite decimal with at most 1074
+// digits after the point, which rust prints exactly
+fn exact_decimal(value: f64) -> (String, i32) {
+  let text = format!("{:.1100}", value.abs());
+  let (integer, fraction) = text.split_once('.').unwrap();
+  let digits = format!("{integer}{fraction}");
+
+  let leading = digits.len() - digits.trim_start_matches('0').len();
+  let digits = digits.trim_matches('0').to_string();
+  (digits, integer.len() as i32 - leading as i32)
+}
+
+// the integer made of the first `keep` digits, rounded up when the next one is 5 or more, as
+// decimal digits without leading zeros
+fn round_half_up(digits: &str, keep: i32) -> String {
+  if keep < 0 {
+    return "0".to_string();
+  }
+
+  let keep = keep as usize;
+  let mut kept = digits.bytes().take(keep).collect::<Vec<_>>();
+  kept.resize(keep, b'0');
+
+  if digits
+    .as_bytes()
+    .get(keep)
+    .is_some_and(|digit| *digit >= b'5')
+  {
+    let mut index = kept.len();
+    loop {
+      if index == 0 {
+        kept.insert(0, b'1');
+        break;
+      }
+      index -= 1;
+      if kept[index] == b'9' {
+        kept[index] = b'0';
+      } else {
+        kept[index] += 1;
+        break;
+      }
+    }
+  }
+
+  let text = String::from_utf8(kept).unwrap();
+  match text.trim_start_matches('0') {
+    "" => "0".to_string(),
+    trimmed => trimmed.to_string(),
+  }
+}
+
+const NAN: f64 = f64::NAN;
+const INFINITY: f64 = f64::INFINITY;
+
+const STRING_TO_NUMBER: &[(&str, f64)] = &[
+  ("", 0.0),
+  (" \t\n\r\u{b}\u{c}\u{a0}\u{feff}\u{2028}\u{3000}", 0.0),
+  ("42", 42.0),
+  ("  42  ", 42.0),
+  ("\u{2029}-42\u{1680}", -42.0),
+  ("+1.5", 1.5),
+  ("-0", -0.0),
+  ("+0", 0.0),
+  ("00017", 17.0),
+  ("1.", 1.0),
+  (".5", 0.5),
+  ("-.5e1", -5.0),
+  ("1e3", 1000.0),
+  ("1E-3", 0.001),
+  ("1e+3", 1000.0),
+  ("1e1000", INFINITY),
+  ("-1e1000", -INFINITY),
+  ("1e-400", 0.0),
+  ("Infinity", INFINITY),
+  ("+Infinity", INFINITY),
+  ("-Infinity", -INFINITY),
+  ("0x1F", 31.0),
+  ("0XfF", 255.0),
+  ("0o17", 15.0),
+  ("0O17", 15.0),
+  ("0b101", 5.0),
+  ("0B11", 3.0),
+  ("0x20000000000003", 9007199254740996.0),
+  ("0x1fffffffffffffffff", 5.902958103587057e20),
+  ("0o2000000000000000000001", 1.8446744073709552e19),
+  ("12345678901234567890123", 1.2345678901234568e22),
+  ("0.1", 0.1),
+  ("9007199254740993", 9007199254740992.0),
+  // what a literal can have but a string can't
+  ("1_000", NAN),
+  ("1n", NAN),
+  ("-0x10", NAN),
+  ("+0b1", NAN),
+  ("0x", NAN),
+  ("0b2", NAN),
+  ("0o8", NAN),
+  ("0xg", NAN),
+  (".", NAN),
+  ("+", NAN),
+  ("e5", NAN),
+  ("1e", NAN),
+  ("1e+", NAN),
+  ("1.2.3", NAN),
+  ("infinity", NAN),
+  ("INFINITY", NAN),
+  ("NaN", NAN),
+  ("inf", NAN),
+  ("12px", NAN),
+  ("1 2", NAN),
+  ("\u{85}1", NAN),
+  ("\u{200b}1", NAN),
+  ("١", NAN),
+];
+
+const PARSE_FLOAT: &[(&str, f64)] = &[
+  ("2.75abc", 2.75),
+  ("  \n-2.5e2x", -250.0),
+  ("1e", 1.0),
+  ("1e+", 1.0),
+  ("1e-2e3", 0.01),
+  (".5.5", 0.5),
+  ("5.", 5.0),
+  ("-.5", -0.5),
+  ("-0", -0.0),
+  ("Infinityx", INFINITY),
+  ("-Infinity", -INFINITY),
+  ("0x10", 0.0),
+  ("1_000", 1.0),
+  ("", NAN),
+  (".", NAN),
+  ("-", NAN),
+  ("abc", NAN),
+  ("Infinit", NAN),
+  ("e5", NAN),
+  ("1 ", 1.0),
+  (" 1", 1.0),
+];
+
+const PARSE_INT: &[(&str, i32, f64)] = &[
+  ("42px", 0, 42.0),
+  ("  -42", 0, -42.0),
+  ("+7", 0, 7.0),
+  ("-0", 0, -0.0),
+  ("0x1F", 0, 31.0),
+  ("0X1f", 16, 31.0),
+  ("-0xff", 0, -255.0),
+  ("0x1F", 10, 0.0),
+  ("0x", 0, NAN),
+  ("0x", 16, NAN),
+  ("1e3", 0, 1.0),
+  ("3.99", 0, 3.0),
+  ("101", 2, 5.0),
+  ("102", 2, 2.0),
+  ("777", 8, 511.0),
+  ("z", 36, 35.0),
+  ("Z", 36, 35.0),
+  ("zz", 37, NAN),
+  ("10", 1, NAN),
+  ("10", -1, NAN),
+  ("12", 3, 5.0),
+  ("0b11", 0, 0.0),
+  ("0o17", 0, 0.0),
+  ("", 0, NAN),
+  ("-", 0, NAN),
+  ("abc", 0, NAN),
+  ("abc", 16, 2748.0),
+  ("\u{feff}\u{2028}12", 0, 12.0),
+  ("Infinity", 0, NAN),
+  ("12345678901234567890123", 0, 1.2345678901234568e22),
+  ("9007199254740993", 10, 9007199254740992.0),
+  // 2^53 + 1 and 2^53 + 3 in radix 32 and 36 are exactly halfway, ties go to even
+  ("80000000001", 32, 9007199254740992.0),
+  ("80000000003", 32, 9007199254740996.0),
+  ("2gosa7pa2gx", 36, 9007199254740992.0),
+  ("2gosa7pa2gz", 36, 9007199254740996.0),
+];
+
+// None where the digits are out of range, a RangeError
+// halfway cases and their neighbours, what a runtime gives for each
+const LITERALS: &[(&str, f64)] = &[
+  ("9007199254740993", 9007199254740992.0),
+  ("9007199254740995", 9007199254740996.0),
+  ("9_007_199_254_740_993", 9007199254740992.0),
+  ("9007199254740993.0000000000000000001", 9007199254740994.0),
+  ("18446744073709553664", 1.8446744073709552e+19),
+  ("18446744073709553665", 1.8446744073709556e+19),
+  ("18446744073709552639", 1.8446744073709552e+19),
+  ("1e23", 1e+23),
+  ("8.41e21", 8.41e+21),
+  ("5e-324", 5e-324),
+  ("2.4703282292062328e-324", 5e-324),
+  ("2.4703282292062327e-324", 0.0),
+  ("1.7976931348623157e308", 1.7976931348623157e+308),
+  ("1.7976931348623158e308", 1.7976931348623157e+308),
+  ("1.7976931348623159e308", INFINITY),
+  (".30000000000000004", 0.30000000000000004),
+  ("1_0.0_1e1_0", 100100000000.0),
+  ("2.2250738585072011e-308", 2.225073858507201e-308),
+  ("2.2250738585072012e-308", 2.2250738585072014e-308),
+  ("1e-400", 0.0),
+  ("1e400", INFINITY),
+  ("123456789012345678901234567890e-10", 1.2345678901234567e+19),
+  ("0x20000000000001", 9007199254740992.0),
+  ("0x20000000000003", 9007199254740996.0),
+  ("0xfffffffffffffc00", 1.8446744073709552e+19),
+  ("0xfffffffffffffbff", 1.844674407370955e+19),
+  ("0x10000000000000800", 1.8446744073709552e+19),
+  ("0x10000000000000801", 1.8446744073709556e+19),
+  ("0x1000000000000080000000000000000", 1.329227995784916e+36),
+  ("0x1000000000000080000000000000001", 1.3292279957849162e+36),
+  ("0x1000000000000180000000000000000", 1.3292279957849165e+36),
+  ("0x1fffffffffffffffff", 5.902958103587057e+20),
+  (
+    "0x0000000000000000000000000020000000000001",
+    9007199254740992.0,
+  ),
+  ("0X1F_FFFF_FFFF_FFFF_FFFF", 5.902958103587057e+20),
+  ("0o2000000000000000000001", 1.8446744073709552e+19),
+  ("0o2000000000000000004000", 1.8446744073709552e+19),
+  ("0o2000000000000000004001", 1.8446744073709556e+19),
+  ("0o2000000000000000014000", 1.844674407370956e+19),
+  (
+    "0o2000000000000000004000000000000001",
+    1.2676506002282297e+30,
+  ),
+];
+
+const TO_STRING: &[(f64, u8, &str)] = &[
+  (0.0, 10, "0"),
+  (-0.0, 10, "0"),
+  (NAN, 10, "NaN"),
+  (INFINITY, 16, "Infinity"),
+  (-INFINITY, 10, "-Infinity"),
+  (-255.0, 16, "-ff"),
+  (35.0, 36, "z"),
+  (9007199254740991.0, 10, "9007199254740991"),
+  (
+    -9007199254740991.0,
+    2,
+    "-11111111111111111111111111111111111111111111111111111",
+  ),
+  (9007199254740992.0, 10, "9007199254740992"),
+  (9007199254740992.0, 16, "20000000000000"),
+  (1e21, 10, "1e+21"),
+  (123456789012345680000.0, 10, "123456789012345680000"),
+  (-123.456, 10, "-123.456"),
+  (0.000001, 10, "0.000001"),
+  (1e-7, 10, "1e-7"),
+  (5e-324, 10, "5e-324"),
+  (0.5, 2, "0.1"),
+  (-255.5, 16, "-ff.8"),
+  (1152921504606846976.0, 16, "1000000000000000"),
+  (1e21, 16, "3635c9adc5dea00000"),
+  // past 2^53 the digits are v8's, from the remainders of what dividing by the radix leaves
+  (1e20, 7, "344015313561621001540000"),
+  (1e20, 36, "l3r41ifs0p800"),
+  (9007199254740994.0, 3, "1121202011211211122211100012101111"),
+  (123456789.123, 36, "21i3v9.4feor"),
+  (
+    1.79e308,
+    7,
+    "42233405125233604314000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+  ),
+];
+
+const TO_FIXED: &[(f64, f64, Option<&str>)] = &[
+  (0.0, 0.0, Some("0")),
+  (-0.0, 2.0, Some("0.00")),
+  (0.5, 0.0, Some("1")),
+  (1.5, 0.0, Some("2")),
+  (2.5, 0.0, Some("3")),
+  (-2.5, 0.0, Some("-3")),
+  (1.005, 2.0, Some("1.00")),
+  (1.255, 2.0, Some("1.25")),
+  (123.456, 2.0, Some("123.46")),
+  (0.1, 20.0, Some("0.10000000000000000555")),
+  (0.000001, 2.0, Some("0.00")),
+  (-0.000001, 2.0, Some("-0.00")),
+  (1e21, 2.0, Some("1e+21")),
+  (-1e21, 2.0, Some("-1e+21")),
+  (999999999999999900000.0, 2.0, Some("999999999999999868928.00")),
+  (5e-324, 2.0, Some("0.00")),
+  (
+    5e-324,
+    100.0,
+    Some("0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+  ),
+  (
+    1.0,
+    100.0,
+    Some("1.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+  ),
+  (NAN, 2.0, Some("NaN")),
+  (-INFINITY, 2.0, Some("-Infinity")),
+  (1.0, 101.0, None),
+  (1.0, -1.0, None),
+  (NAN, 101.0, None),
+  (1.0, INFINITY, None),
+];
+
+const TO_EXPONENTIAL: &[(f64, Option<f64>, Option<&str>)] = &[
+  (0.0, None, Some("0e+0")),
+  (-0.0, None, Some("0e+0")),
+  (0.0, Some(2.0), Some("0.00e+0")),
+  (123456.0, Some(2.0), Some("1.23e+5")),
+  (123456.0, None, Some("1.23456e+5")),
+  (-123456.0, Some(0.0), Some("-1e+5")),
+  (0.00015, Some(1.0), Some("1.5e-4")),
+  (1.25, Some(1.0), Some("1.3e+0")),
+  (1.35, Some(1.0), Some("1.4e+0")),
+  (9.5, Some(0.0), Some("1e+1")),
+  (-1.5, Some(0.0), Some("-2e+0")),
+  (5e-324, None, Some("5e-324")),
+  (5e-324, Some(2.0), Some("4.94e-324")),
+  (f64::MAX, None, Some("1.7976931348623157e+308")),
+  (f64::MAX, Some(0.0), Some("2e+308")),
+  (1e21, None, Some("1e+21")),
+  (INFINITY, Some(200.0), Some("Infinity")),
+  (NAN, Some(-1.0), Some("NaN")),
+  (1.0, Some(101.0), None),
+  (1.0, Some(-1.0), None),
+];
+
+const TO_PRECISION: &[(f64, Option<f64>, Option<&str>)] = &[
+  (0.0, Some(1.0), Some("0")),
+  (0.0, Some(3.0), Some("0.00")),
+  (-0.0, Some(2.0), Some("0.0")),
+  (123.456, Some(4.0), Some("123.5")),
+  (123.456, Some(2.0), Some("1.2e+2")),
+  (123.0, Some(3.0), Some("123")),
+  (99.99, Some(3.0), Some("100")),
+  (99.99, Some(2.0), Some("1.0e+2")),
+  (0.000123, Some(2.0), Some("0.00012")),
+  (0.000001, Some(1.0), Some("0.000001")),
+  (0.0000001, Some(1.0), Some("1e-7")),
+  (1e21, Some(3.0), Some("1.00e+21")),
+  (5e-324, Some(3.0), Some("4.94e-324")),
+  (2.5, Some(1.0), Some("3")),
+  (0.5, Some(1.0), Some("0.5")),
+  (-1.25, Some(2.0), Some("-1.3")),
+  (1.5, None, Some("1.5")),
+  (NAN, None, Some("NaN")),
+  (NAN, Some(0.0), Some("NaN")),
+  (1.0, Some(0.0), None),
+  (1.0, Some(101.0), None),
+];
@@ -0,0 +1,214 @@
+use std::{error::Error, fs::read_to_string, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_ast::{
+  expression::Expression,
+  fold::{walk_expression, Fold},
+  Program, SourceType,
+};
+use tower_codegen::{print_program, PrintOptions};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+// parses each source, prints it back in both modes and checks the printed code parses to the same
+// tree, the samples below run when no files are given
+pub fn run(files: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let sources = read_sources(files)?;
+
+  let mut failures = 0;
+  for sample in &sources {
+    if let Err(message) = check_source(&sample.source, sample.options) {
+      failures += 1;
+      println!("FAIL {}: {message}", sample.name);
+    }
+  }
+
+  println!("{} passed, {failures} failed", sources.len() - failures);
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+pub struct Sample {
+  pub name: String,
+  pub source: String,
+  pub options: ParseOptions,
+}
+
+// each file, or the samples below when there are no files
+pub fn read_sources(files: &[String]) -> Result<Vec<Sample>, Box<dyn Error>> {
+  let mut sources = Vec::new();
+
+  if files.is_empty() {
+    for (index, source) in SCRIPTS.iter().enumerate() {
+      let mut options = ParseOptions::new(SourceType::Script);
+      options.decorators = true;
+      sources.push(Sample {
+        name: format!("script #{index}"),
+        source: source.to_string(),
+        options,
+      });
+    }
+
+    for (index, source) in MODULES.iter().enumerate() {
+      let options = ParseOptions::new(SourceType::Module);
+      sources.push(Sample {
+        name: format!("module #{index}"),
+        source: source.to_string(),
+        options,
+      });
+    }
+
+    for (index, source) in JSX.iter().enumerate() {
+      let mut options = ParseOptions::new(SourceType::Module);
+      options.jsx = true;
+      sources.push(Sample {
+        name: format!("jsx #{index}"),
+        source: source.to_string(),
+        options,
+      });
+    }
+  } else {
+    for file in files {
+      let source_type = if file.ends_with(".mjs") {
+        SourceType::Module
+      } else {
+        SourceType::Script
+      };
+
+      let mut options = ParseOptions::new(source_type);
+      options.decorators = true;
+      options.jsx = file.ends_with(".jsx");
+      sources.push(Sample {
+        name: file.clone(),
+        source: read_to_string(file)?,
+        options,
+      });
+    }
+  }
+
+  Ok(sources)
+}
+
+fn check_source(source: &str, options: ParseOptions) -> Result<(), String> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+  let expected = normalize(&allocator, program);
+
+  for print_options in [PrintOptions::readable(), PrintOptions::compact()] {
+    let printed = print_program(program, print_options);
+    let chars = allocator.alloc_slice_copy(&printed.chars().collect::<Vec<char>>());
+    let reparsed = parse_source_with_options(&allocator, chars, options)
+      .map_err(|error| format!("the printed code doesn't parse: {error:?}\n{printed}"))?;
+
+    if normalize(&allocator, reparsed) != expected {
+      return Err(format!("the printed code parses differently\n{printed}"));
+    }
+  }
+
+  Ok(())
+}
+
+// the debug output of the program with parenthesized expressions unwrapped and spans blanked, the
+// printer only keeps the parentheses that are needed and moves everything around
+fn normalize<'a>(allocator: &'a Bump, program: &Program<'a>) -> String {
+  let program = StripGroups { allocator }.fold_program(program.clone());
+  strip_spans(&format!("{program:?}"))
+}
+
+// spans are debug printed as `start..end`
+fn strip_spans(debug: &str) -> String {
+  let chars = debug.chars().collect::<Vec<char>>();
+  let mut output = String::with_capacity(debug.len());
+  let mut index = 0;
+
+  while index < chars.len() {
+    let digits = |from: usize| {
+      chars[from..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count()
+    };
+
+    let start = digits(index);
+    if start > 0 && chars[index + start..].starts_with(&['.', '.']) {
+      let end = digits(index + start + 2);
+      if end > 0 {
+        output.push('_');
+        index += start + 2 + end;
+        continue;
+      }
+    }
+
+    output.push(chars[index]);
+    index += 1;
+  }
+
+  output
+}
+
+struct StripGroups<'a> {
+  allocator: &'a Bump,
+}
+
+impl<'a> Fold<'a> for StripGroups<'a> {
+  fn allocator(&self) -> &'a Bump {
+    self.allocator
+  }
+
+  fn fold_expression(&mut self, expression: Expression<'a>) -> Expression<'a> {
+    match expression {
+      Expression::Group(group) => self.fold_expression(group.expression),
+      expression => walk_expression(self, expression),
+    }
+  }
+}
+
+const SCRIPTS: &[&str] = &[
+  "var a = 1, b = 'two', c = \"th'ree\", d = `fo${ur}`;",
+  "a = b ? c : d ? e : f; (a ? b : c) ? d : e;",
+  "a + b * c; (a + b) * c; a - (b - c); (a - b) - c; a ** b ** c; (a ** b) ** c; (-a) ** b;",
+  "a ?? b; (a || b) ?? c; a || (b ?? c); (a, b); f((a, b)); x = (a, b);",
+  "a in b; for (var i = ('x' in o); i < 1; i++) {} for (x = (a in b);;) break;",
+  "- -a; + +a; - --a; + ++a; a++ + +b; a-- - -b; !(!a); typeof typeof a; void 0;",
+  "new (a())(); new (a.b())(); new a.b(); new (a().b)(); (new a).b; new new a()();",
+  "(function () {})(); (function f() {}).call(this); (class {}); (class A extends (B, C) {});",
+  "({ a: 1 }); ({}).toString(); ({ a } = b); [a, b] = [b, a]; (a, { b } = c);",
+  "x => ({}); x => ({ a } = b); async (a, b) => { await a; }; (a, ...b) => a + b;",
+  "1..toString(); 1.5.toFixed(); 0.5; 0.; 0e1; 1.e3; 5e-7; 1e21; 123456789012345680000; (1e21).x; a[0]; a?.b; a?.[b]; a?.(b); (a?.b).c; (a?.b)();",
+  "if (a) if (b) c(); else d(); if (a) { if (b) c(); } else d(); if (a) b(); else if (c) d(); else e();",
+  "for (let i = 0; i < 10; i++) continue; for (const x of y) {} for (var k in o) ; for (;;) {}",
+  "a: for (;;) { b: while (true) { break; continue; } } do x(); while (y); do {} while (z);",
+  "switch (x) { case 1: case 2: a(); break; default: b(); }",
+  "try { a(); } catch (e) { b(); } finally { c(); } try {} catch { } try {} catch ({ a, b: [c] }) {}",
+  "function* g(a = 1, { b, c: d = 2 }, [e, , f], ...g) { yield; yield a; yield* b; }",
+  "async function f() { for await (const x of y) {} return await z; }",
+  "class A extends B { constructor() { super(); } static m() {} get x() { return 1; } set x(v) {} *g() {} async a() {} #p = 1; static q; [k] = 2; 'quoted key'() {} 1() {} #m() { return #p in this; } }",
+  "var o = { a, b: 2, 'c d': 3, 4: 5, [e]: 6, get f() { return 1; }, set f(v) {}, g() {}, *h() {}, async i() {} };",
+  "a = /ab+c/gi; b = /[/]/; c = /\\//; d = a / /re/.exec(b).length; e = a++ / 2;",
+  "'\\n\\r\\t\\\\\\u2028\\u2029\\0\\x01'; \"\\0\\x31\"; `a\\`b\\${c}${d}`; tag`raw\\n${x}\\u{41}`;",
+  "a = b = c; a += 1; a **= 2; a ||= b; a &&= c; a ??= d; a >>>= 1;",
+  "a < b > c <= d >= e == f != g === h !== i; a << b >> c >>> d; a & b | c ^ d; !a && b || c;",
+  "let x = function () { return this; }; const y = class Y {}; var z = (a, b) => a + b, w = a => a;",
+  "throw new Error('x'); debugger; ; with (o) x;",
+  "import('x').then(f); x = new.target; a = b => c => d;",
+  "x = (a ? b : c).d; x = (a = b).c; x = (yield); x = a ? (b, c) : d;",
+  "@dec class A { @dec m() {} @dec.a.b() static f = 1; @(a[b]) g() {} accessor x = 1; }",
+  "var 𝒳 = 1; '\\uD83D'; x = { 'a-b': 1, '1.0': 2, '': 3, 0x10: 4 };",
+  "let\nx = 1; (let)[0] = 1; async\nfunction f() {}",
+  "'\\uDE00\\uD83D'; `\\uD800${a}\\u{DFFF}`; x = { '\\uD800': 1, '\\x41': 2 }; '\\xE9\\u00e9';",
+];
+
+const MODULES: &[&str] = &[
+  "await x; for await (const a of b) {} const { a, ...b } = c; let [d, ...e] = f;",
+  "x = import.meta.url; class A { static #x = 1; static has(o) { return #x in o; } }",
+];
+
+const JSX: &[&str] = &[
+  "x = <div a=\"1\" b='\"' c={d} {...e}>text &amp; {f} {} {...g}<br /><>frag</></div>;",
+  "x = <a.b.c d:e=\"f\" g><h:i /></a.b.c>; y = a < <b /> ? 1 : 2;",
+];
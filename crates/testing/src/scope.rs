@@ -0,0 +1,324 @@
+use std::{error::Error, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_ast::{
+  scope::{analyze, DeclarationKind, ScopeTree},
+  SourceType, Span,
+};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+use crate::roundtrip::read_sources;
+
+// resolves the references in the cases below against what they should resolve to, then checks
+// the scope tree of each sample or file is consistent
+pub fn run(files: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut failures = 0;
+  let mut checked = 0;
+
+  if files.is_empty() {
+    for (source, expected) in CASES {
+      checked += 1;
+      if let Err(message) = check_case(source, ParseOptions::new(SourceType::Script), expected) {
+        failures += 1;
+        println!("FAIL {source}\n{message}");
+      }
+    }
+
+    for (source, expected) in MODULE_CASES {
+      checked += 1;
+      if let Err(message) = check_case(source, ParseOptions::new(SourceType::Module), expected) {
+        failures += 1;
+        println!("FAIL {source}\n{message}");
+      }
+    }
+
+    for (source, expected) in JSX_CASES {
+      let mut options = ParseOptions::new(SourceType::Module);
+      options.jsx = true;
+
+      checked += 1;
+      if let Err(message) = check_case(source, options, expected) {
+        failures += 1;
+        println!("FAIL {source}\n{message}");
+      }
+    }
+  }
+
+  for sample in read_sources(files)? {
+    checked += 1;
+    if let Err(message) = check_tree(&sample.source, sample.options) {
+      failures += 1;
+      println!("FAIL {}: {message}", sample.name);
+    }
+  }
+
+  println!("{} passed, {failures} failed", checked - failures);
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+fn check_case(source: &str, options: ParseOptions, expected: &[&str]) -> Result<(), String> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+  let tree = analyze(program, options.annex_b);
+
+  let actual = describe(&tree, chars);
+  if actual != expected {
+    return Err(format!(
+      "expected\n  {}\nfound\n  {}",
+      expected.join("\n  "),
+      actual.join("\n  ")
+    ));
+  }
+
+  Ok(())
+}
+
+// a line for each reference, `name#2 -> let#1` when the second `name` in the source resolves to
+// the let declared by the first, followed by the flags of the reference and its declaration
+fn describe(tree: &ScopeTree, source: &[char]) -> Vec<String> {
+  let mut lines = Vec::new();
+
+  for reference in &tree.references {
+    let mut line = format!("{}{}", reference.name, occurrence(source, reference.span));
+    match reference.declaration {
+      Some(id) => {
+        let declaration = &tree[id];
+        let kind = match declaration.kind {
+          DeclarationKind::Arguments => "arguments",
+          DeclarationKind::CatchParameter => "catch",
+          DeclarationKind::Class => "class",
+          DeclarationKind::Const => "const",
+          DeclarationKind::Function => "function",
+          DeclarationKind::Let => "let",
+          DeclarationKind::Parameter => "param",
+          DeclarationKind::Var => "var",
+        };
+
+        line += &format!(" -> {kind}");
+        if declaration.kind != DeclarationKind::Arguments {
+          line += &occurrence(source, declaration.span);
+        }
+
+        if declaration.captured {
+          line += " captured";
+        }
+      }
+      None => line += " -> global",
+    }
+
+    if reference.write {
+      line += " write";
+    }
+
+    if reference.dynamic {
+      line += " dynamic";
+    }
+
+    lines.push(line);
+  }
+
+  lines
+}
+
+// `#n` for the nth whole word occurrence of the name at the span
+fn occurrence(source: &[char], span: Span) -> String {
+  let name = &source[span.start as usize..span.end as usize];
+  let word = |c: &char| c.is_alphanumeric() || *c == '_' || *c == '$';
+
+  let count = (0..=span.start as usize)
+    .filter(|start| {
+      source[*start..].starts_with(name)
+        && !source[..*start].last().is_some_and(word)
+        && !source.get(start + name.len()).is_some_and(word)
+    })
+    .count();
+
+  format!("#{count}")
+}
+
+// every resolved reference has a declaration of its name in one of its scopes, and every scope
+// and declaration is where the tree says it is
+fn check_tree(source: &str, options: ParseOptions) -> Result<(), String> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+  let tree = analyze(program, options.annex_b);
+
+  for (index, scope) in tree.scopes.iter().enumerate() {
+    let parent = scope.parent.map(|parent| &tree[parent]);
+    if index > 0 && parent.is_none() {
+      return Err(format!("scope {index} has no parent"));
+    }
+
+    if let Some(parent) = parent {
+      if scope.span.start < parent.span.start || scope.span.end > parent.span.end {
+        return Err(format!("scope {index} isn't inside its parent"));
+      }
+    }
+
+    for id in &scope.declarations {
+      if tree.lookup(tree[*id].scope, tree[*id].name) != Some(*id) {
+        return Err(format!(
+          "`{}` isn't found where it's declared",
+          tree[*id].name
+        ));
+      }
+    }
+  }
+
+  for (index, reference) in tree.references.iter().enumerate() {
+    if tree.reference_at(reference.span).map(|id| tree[id].span) != Some(reference.span) {
+      return Err(format!("reference {index} can't be found by its span"));
+    }
+
+    let Some(id) = reference.declaration else {
+      continue;
+    };
+
+    let declaration = &tree[id];
+    if declaration.name != reference.name {
+      return Err(format!(
+        "`{}` resolves to `{}`",
+        reference.name, declaration.name
+      ));
+    }
+
+    if tree.lookup(reference.scope, reference.name) != Some(id) {
+      return Err(format!(
+        "`{}` resolves past its nearest declaration",
+        reference.name
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+const CASES: &[(&str, &[&str])] = &[
+  (
+    "var a = 1; function f(b) { return a + b + c; }",
+    &["a#2 -> var#1 captured", "b#2 -> param#1", "c#1 -> global"],
+  ),
+  (
+    "let x = 1; { let x = 2; x; } x = 3; x++;",
+    &["x#3 -> let#2", "x#4 -> let#1 write", "x#5 -> let#1 write"],
+  ),
+  (
+    "f(); function f() { return v; var v; }",
+    &["f#1 -> function#2", "v#1 -> var#2"],
+  ),
+  (
+    "try {} catch (e) { (() => e)(); }",
+    &["e#2 -> catch#1 captured"],
+  ),
+  (
+    "class A { static m() { return A; } } const B = class C { m() { return C; } };",
+    &["A#2 -> class#1 captured", "C#2 -> class#1 captured"],
+  ),
+  (
+    "function f(a = b, { c } = a) { var b; return () => arguments[c]; }",
+    &[
+      "b#1 -> var#2",
+      "a#2 -> param#1",
+      "arguments#1 -> arguments captured",
+      "c#2 -> param#1 captured",
+    ],
+  ),
+  (
+    "var o = {}; var x; with (o) { x; } x;",
+    &["o#2 -> var#1", "x#2 -> var#1 dynamic", "x#3 -> var#1"],
+  ),
+  (
+    "var y; function g() { eval(code); y; } y;",
+    &[
+      "eval#1 -> global dynamic",
+      "code#1 -> global dynamic",
+      "y#2 -> var#1 captured dynamic",
+      "y#3 -> var#1 captured",
+    ],
+  ),
+  (
+    "function g() { 'use strict'; eval(c); c; } class K { m() { eval(c); } } function s() { eval(c); c; }",
+    &[
+      "eval#1 -> global",
+      "c#1 -> global",
+      "c#2 -> global",
+      "eval#2 -> global",
+      "c#3 -> global",
+      "eval#3 -> global dynamic",
+      "c#4 -> global dynamic",
+      "c#5 -> global dynamic",
+    ],
+  ),
+  (
+    "function h(eval) { eval(z); z; }",
+    &["eval#2 -> param#1", "z#1 -> global", "z#2 -> global"],
+  ),
+  (
+    "let a, b; [a, { b }] = [1, {}]; for (const c of [a]) c; for (b in a);",
+    &[
+      "a#2 -> let#1 write",
+      "b#2 -> let#1 write",
+      "a#3 -> let#1",
+      "c#2 -> const#1",
+      "b#3 -> let#1 write",
+      "a#4 -> let#1",
+    ],
+  ),
+  (
+    "for (let i = 0; i < 3; i++) setTimeout(() => i); i;",
+    &[
+      "i#2 -> let#1 captured",
+      "i#3 -> let#1 captured write",
+      "setTimeout#1 -> global",
+      "i#4 -> let#1 captured",
+      "i#5 -> global",
+    ],
+  ),
+  (
+    "switch (k) { case 1: let k = 2; k; } class D { static { var k; k; } }",
+    &["k#1 -> global", "k#3 -> let#2", "k#5 -> var#4"],
+  ),
+  (
+    "var n = { m(n) { return n; }, get g() { return n; } };",
+    &["n#3 -> param#2", "n#4 -> var#1 captured"],
+  ),
+  (
+    "{ function f() {} f(); } f(); let g; { function g() {} } g; if (x) { function* h() {} } h;",
+    &[
+      "f#2 -> function#1",
+      "f#3 -> var#1",
+      "g#3 -> let#1",
+      "x#1 -> global",
+      "h#2 -> global",
+    ],
+  ),
+  (
+    "function p(q) { { function q() {} } try {} catch (r) { { function r() {} } } return q + r; }",
+    &["q#3 -> param#1", "r#3 -> var#2"],
+  ),
+  ("'use strict'; { function f() {} } f;", &["f#2 -> global"]),
+];
+
+const MODULE_CASES: &[(&str, &[&str])] = &[(
+  "{ function f() {} } f; eval(c); c;",
+  &[
+    "f#2 -> global",
+    "eval#1 -> global",
+    "c#1 -> global",
+    "c#2 -> global",
+  ],
+)];
+
+const JSX_CASES: &[(&str, &[&str])] = &[(
+  "const Box = 1; const ui = <Box><div /><UI.Panel /></Box>;",
+  &["Box#2 -> const#1", "UI#1 -> global"],
+)];
@@ -0,0 +1,191 @@
+use std::{
+  error::Error,
+  fs::{read_dir, read_to_string, write},
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  process::ExitCode,
+};
+
+use bumpalo::Bump;
+use tower_ast::SourceType;
+use tower_parser::{
+  diagnostic::{render_error, Lines},
+  parser::{parse_source_with_options, ParseOptions},
+};
+
+use crate::dump::dump_program;
+
+// the lines of context kept around each change in a diff
+const CONTEXT: usize = 3;
+
+// parses each fixture and compares the dump of its tree, or the diagnostic when it doesn't parse,
+// with the snapshot next to it, `name.js` and `name.ast`. `--bless` writes the snapshots that are
+// missing or differ instead. the fixtures are the ones in the repo when no files are given
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let bless = args.iter().any(|arg| arg == "--bless");
+  let mut files = args
+    .iter()
+    .filter(|arg| *arg != "--bless")
+    .map(PathBuf::from)
+    .collect::<Vec<_>>();
+
+  if files.is_empty() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/snapshots");
+    collect_fixtures(&directory, &mut files)?;
+  }
+
+  let mut failures = 0;
+  let mut blessed = 0;
+
+  for file in &files {
+    let source = read_to_string(file).map_err(|error| format!("{}: {error}", file.display()))?;
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    let actual = snapshot(&name, &source, parse_options(file));
+
+    let snapshot_file = file.with_extension("ast");
+    let expected = match read_to_string(&snapshot_file) {
+      Ok(expected) => Some(expected),
+      Err(error) if error.kind() == ErrorKind::NotFound => None,
+      Err(error) => return Err(format!("{}: {error}", snapshot_file.display()).into()),
+    };
+
+    if expected.as_deref() == Some(actual.as_str()) {
+      continue;
+    }
+
+    if bless {
+      write(&snapshot_file, &actual)
+        .map_err(|error| format!("{}: {error}", snapshot_file.display()))?;
+      blessed += 1;
+      println!("BLESSED {}", snapshot_file.display());
+      continue;
+    }
+
+    failures += 1;
+    match expected {
+      Some(expected) => {
+        println!("FAIL {}", file.display());
+        print!("{}", diff(&expected, &actual));
+      }
+      None => println!(
+        "FAIL {}: there's no snapshot, run with --bless to write it",
+        file.display()
+      ),
+    }
+  }
+
+  println!(
+    "{} passed, {failures} failed, {blessed} blessed",
+    files.len() - failures - blessed
+  );
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+// every source below the directory, in a stable order
+pub fn collect_fixtures(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+  let mut entries = read_dir(path)
+    .map_err(|error| format!("{}: {error}", path.display()))?
+    .map(|entry| entry.map(|entry| entry.path()))
+    .collect::<Result<Vec<_>, _>>()?;
+  entries.sort();
+
+  for entry in entries {
+    if entry.is_dir() {
+      collect_fixtures(&entry, files)?;
+    } else if entry
+      .extension()
+      .is_some_and(|extension| matches!(extension.to_str(), Some("js" | "mjs" | "jsx" | "ts")))
+    {
+      files.push(entry);
+    }
+  }
+
+  Ok(())
+}
+
+// .mjs files are modules, .jsx files accept jsx and .ts files typescript
+pub fn parse_options(file: &Path) -> ParseOptions {
+  let extension = file.extension().and_then(|extension| extension.to_str());
+  let source_type = if extension == Some("mjs") {
+    SourceType::Module
+  } else {
+    SourceType::Script
+  };
+
+  let mut options = ParseOptions::new(source_type);
+  options.jsx = extension == Some("jsx");
+  options.typescript = extension == Some("ts");
+  options
+}
+
+fn snapshot(name: &str, source: &str, options: ParseOptions) -> String {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+
+  match parse_source_with_options(&allocator, chars, options) {
+    Ok(program) => dump_program(program),
+    Err(error) => render_error(name, chars, &Lines::new(chars), &error),
+  }
+}
+
+// a unified diff of the lines, `-` for the snapshot and `+` for what the parser gives now
+fn diff(expected: &str, actual: &str) -> String {
+  let expected = expected.lines().collect::<Vec<_>>();
+  let actual = actual.lines().collect::<Vec<_>>();
+
+  // the longest common subsequence of the suffixes starting at each pair of lines
+  let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+  for i in (0..expected.len()).rev() {
+    for j in (0..actual.len()).rev() {
+      lengths[i][j] = if expected[i] == actual[j] {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+
+  let mut lines = Vec::new();
+  let (mut i, mut j) = (0, 0);
+  while i < expected.len() || j < actual.len() {
+    if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+      lines.push((' ', expected[i]));
+      i += 1;
+      j += 1;
+    } else if j < actual.len() && (i == expected.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+      lines.push(('+', actual[j]));
+      j += 1;
+    } else {
+      lines.push(('-', expected[i]));
+      i += 1;
+    }
+  }
+
+  // only the changes and the lines of context around them
+  let near_change = |index: usize| {
+    let start = index.saturating_sub(CONTEXT);
+    let end = (index + CONTEXT + 1).min(lines.len());
+    lines[start..end].iter().any(|(kind, _)| *kind != ' ')
+  };
+
+  let mut output = String::new();
+  let mut skipped = false;
+  for (index, (kind, line)) in lines.iter().enumerate() {
+    if near_change(index) {
+      if skipped {
+        output.push_str("  ...\n");
+        skipped = false;
+      }
+      output.push_str(&format!("{kind} {line}\n"));
+    } else {
+      skipped = true;
+    }
+  }
+
+  output
+}
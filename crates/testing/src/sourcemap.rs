@@ -0,0 +1,186 @@
+use std::{error::Error, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_codegen::{
+  print_program_with_source_map,
+  source_map::{read_vlq, write_vlq, LineIndex, SourceFile, SourceMap},
+  PrintOptions,
+};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+use crate::roundtrip::read_sources;
+
+// prints each source with a source map and checks every named mapping points at that name in both
+// the output and the source, then that the map survives json and composing with a second print
+pub fn run(files: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut failures = 0;
+
+  if let Err(message) = check_vlq() {
+    failures += 1;
+    println!("FAIL vlq: {message}");
+  }
+
+  let sources = read_sources(files)?;
+  for sample in &sources {
+    if let Err(message) = check_source(&sample.name, &sample.source, sample.options) {
+      failures += 1;
+      println!("FAIL {}: {message}", sample.name);
+    }
+  }
+
+  println!("{} passed, {failures} failed", sources.len() + 1 - failures);
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+fn check_vlq() -> Result<(), String> {
+  let known = [
+    (0, "A"),
+    (1, "C"),
+    (-1, "D"),
+    (15, "e"),
+    (16, "gB"),
+    (-16, "hB"),
+    (1000, "w+B"),
+  ];
+
+  for (value, expected) in known {
+    let mut output = String::new();
+    write_vlq(&mut output, value);
+    if output != expected {
+      return Err(format!("{value} encodes as {output}, not {expected}"));
+    }
+  }
+
+  let mut values = (-2000..2000).collect::<Vec<i64>>();
+  values.extend([i32::MIN as i64, i32::MAX as i64, 1 << 40, -(1 << 40)]);
+
+  let mut output = String::new();
+  for value in &values {
+    write_vlq(&mut output, *value);
+  }
+
+  let mut chars = output.chars().peekable();
+  for value in &values {
+    let decoded = read_vlq(&mut chars)?;
+    if decoded != *value {
+      return Err(format!("{value} decodes as {decoded}"));
+    }
+  }
+
+  Ok(())
+}
+
+fn check_source(name: &str, source: &str, options: ParseOptions) -> Result<(), String> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+
+  let file = SourceFile {
+    name: name.to_string(),
+    text: chars,
+    include_content: true,
+    output_name: Some("readable.js".to_string()),
+  };
+  let (readable, readable_map) =
+    print_program_with_source_map(program, PrintOptions::readable(), &file);
+  check_names(&readable, &readable_map, &[source])?;
+
+  let json = readable_map.to_json();
+  let reread = SourceMap::from_json(&json)?;
+  if reread != readable_map {
+    return Err(format!("the map reads back differently\n{json}"));
+  }
+
+  // printing the readable output again, the composed map goes from the compact output all the way
+  // back to the source
+  let readable_chars = allocator.alloc_slice_copy(&readable.chars().collect::<Vec<char>>());
+  let reparsed = parse_source_with_options(&allocator, readable_chars, options)
+    .map_err(|error| format!("the printed code doesn't parse: {error:?}\n{readable}"))?;
+
+  let file = SourceFile {
+    name: "readable.js".to_string(),
+    text: readable_chars,
+    include_content: false,
+    output_name: Some("compact.js".to_string()),
+  };
+  let (compact, compact_map) =
+    print_program_with_source_map(reparsed, PrintOptions::compact(), &file);
+  check_names(&compact, &compact_map, &[&readable])?;
+
+  let composed = compact_map.compose(&readable_map);
+  if composed.sources != [name] || composed.sources_content != [Some(source.to_string())] {
+    return Err("the composed map lost the original source".to_string());
+  }
+
+  check_names(&compact, &composed, &[source])
+}
+
+// every mapping has to land in the source, and the ones with a name on that name in both texts
+fn check_names(output: &str, map: &SourceMap, sources: &[&str]) -> Result<(), String> {
+  let output_chars = output.chars().collect::<Vec<char>>();
+  let output_lines = LineIndex::new(&output_chars);
+  let source_chars = sources
+    .iter()
+    .map(|source| source.chars().collect::<Vec<char>>())
+    .collect::<Vec<_>>();
+  let source_lines = source_chars
+    .iter()
+    .map(|chars| LineIndex::new(chars))
+    .collect::<Vec<_>>();
+
+  let mut named = 0;
+  for (line, mappings) in map.lines.iter().enumerate() {
+    for mapping in mappings {
+      let Some(original) = mapping.original else {
+        continue;
+      };
+
+      let generated = output_lines
+        .offset(line as u32, mapping.generated_column)
+        .ok_or(format!(
+          "{line}:{} isn't in the output",
+          mapping.generated_column
+        ))?;
+      let source = original.source as usize;
+      let position = source_lines
+        .get(source)
+        .ok_or(format!("source {source} doesn't exist"))?
+        .offset(original.line, original.column)
+        .ok_or(format!(
+          "{}:{} isn't in the source",
+          original.line, original.column
+        ))?;
+
+      let Some(name) = original.name else {
+        continue;
+      };
+
+      let name = map.names[name as usize].chars().collect::<Vec<char>>();
+      for (what, chars, offset) in [
+        ("output", &output_chars, generated),
+        ("source", &source_chars[source], position),
+      ] {
+        // private names are mapped from their `#`
+        let offset = offset + (chars.get(offset) == Some(&'#')) as usize;
+        if !chars[offset..].starts_with(&name) {
+          let found = chars[offset..].iter().take(20).collect::<String>();
+          let name = name.iter().collect::<String>();
+          return Err(format!("`{name}` is mapped to `{found}` in the {what}"));
+        }
+      }
+
+      named += 1;
+    }
+  }
+
+  match named {
+    0 if output.chars().any(char::is_alphabetic) => Err("nothing was mapped by name".to_string()),
+    _ => Ok(()),
+  }
+}
@@ -0,0 +1,324 @@
+use std::{
+  borrow::Cow,
+  collections::hash_map::DefaultHasher,
+  error::Error,
+  fmt::Debug,
+  hash::{Hash, Hasher},
+  process::ExitCode,
+};
+
+use bumpalo::Bump;
+use tower_ast::{
+  expression::Expression,
+  object::{ObjectProperty, PropertyName},
+  statement::Statement,
+  string::{JsStr, JsString},
+  SourceType,
+};
+use tower_codegen::{print_program, PrintOptions};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+// checks string values: their length, indexing, equality and order in code units whichever form
+// they're held in, what the lexer reads escapes as, and that lone surrogates print back
+pub fn run(_args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut checks = Checks {
+    checked: 0,
+    failures: 0,
+  };
+
+  checks.code_units();
+  checks.forms();
+
+  for (source, expected) in LITERALS {
+    checks.expect(
+      "string_literal",
+      source,
+      literal_value(source),
+      expected.map(JsString::from_code_units),
+    );
+  }
+
+  for (source, expected) in TEMPLATES {
+    checks.expect(
+      "template_literal",
+      source,
+      template_value(source),
+      expected.map(JsString::from_code_units),
+    );
+  }
+
+  for (source, expected) in PROPERTY_KEYS {
+    checks.expect(
+      "property_key",
+      source,
+      property_key(source),
+      Some(JsString::from_code_units(expected)),
+    );
+  }
+
+  for (source, expected) in PRINTED {
+    checks.expect(
+      "print_string_literal",
+      source,
+      printed(source),
+      expected.to_string(),
+    );
+  }
+
+  println!(
+    "{} passed, {} failed",
+    checks.checked - checks.failures,
+    checks.failures
+  );
+
+  Ok(if checks.failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+struct Checks {
+  checked: usize,
+  failures: usize,
+}
+
+impl Checks {
+  fn expect<T: PartialEq + Debug>(
+    &mut self,
+    name: &str,
+    input: &dyn Debug,
+    actual: T,
+    expected: T,
+  ) {
+    self.checked += 1;
+    if actual != expected {
+      self.failures += 1;
+      println!("FAIL {name}({input:?}) = {actual:?}, expected {expected:?}");
+    }
+  }
+
+  // the length and the code unit at each index are what `.length` and charCodeAt give
+  fn code_units(&mut self) {
+    for (text, units) in CODE_UNITS {
+      let string = JsStr::from(*text);
+      self.expect("len", text, string.len(), units.len());
+      self.expect(
+        "code_units",
+        text,
+        string.code_units().collect::<Vec<_>>(),
+        units.to_vec(),
+      );
+      for (index, unit) in units.iter().enumerate() {
+        self.expect(
+          "code_unit_at",
+          &(text, index),
+          string.code_unit_at(index),
+          Some(*unit),
+        );
+      }
+      self.expect(
+        "code_unit_at",
+        &(text, units.len()),
+        string.code_unit_at(units.len()),
+        None,
+      );
+
+      let owned = JsString::from_code_units(units);
+      self.expect("from_code_units", text, owned.as_js_str(), string);
+      self.expect(
+        "from_code_units",
+        text,
+        owned.as_js_str().to_string_lossy(),
+        Cow::Borrowed(*text),
+      );
+    }
+
+    let lone = JsString::from_code_units(&[0x61, 0xd800]);
+    self.expect("len", &lone, lone.len(), 2);
+    self.expect(
+      "is_well_formed",
+      &lone,
+      lone.as_js_str().is_well_formed(),
+      false,
+    );
+    self.expect("as_str", &lone, lone.as_js_str().as_str(), None);
+    self.expect(
+      "to_string_lossy",
+      &lone,
+      lone.as_js_str().to_string_lossy().into_owned(),
+      String::from("a\u{fffd}"),
+    );
+    self.expect(
+      "code_points",
+      &lone,
+      lone.as_js_str().code_points().collect::<Vec<_>>(),
+      vec![0x61, 0xd800],
+    );
+  }
+
+  // the same code units built up different ways are equal, hash the same and sort in code unit
+  // order, where U+FFFF comes after the surrogates of 😀 though it's the smaller code point
+  fn forms(&mut self) {
+    let allocator = Bump::new();
+
+    let mut latin1 = JsString::new();
+    latin1.push('a');
+    latin1.push('é');
+    let utf8 = JsStr::from("aé");
+    self.expect("eq", &"aé", latin1.as_js_str(), utf8);
+    self.expect("hash", &"aé", hash(latin1.as_js_str()), hash(utf8));
+
+    // halves pushed one at a time pair up in the arena
+    let mut halves = JsString::new();
+    halves.push_code_point(0xd83d);
+    halves.push_code_unit(0xde00);
+    let allocated = halves.alloc_in(&allocator);
+    self.expect("alloc_in", &halves, allocated.as_str(), Some("😀"));
+    self.expect("eq", &halves, halves.as_js_str(), JsStr::from("😀"));
+    self.expect(
+      "hash",
+      &halves,
+      hash(halves.as_js_str()),
+      hash(JsStr::from("😀")),
+    );
+
+    let mut joined = JsString::from("a");
+    joined.push_js_str(JsStr::from("😀"));
+    joined.push_js_str(JsString::from_code_units(&[0xdc00]).as_js_str());
+    self.expect(
+      "push_js_str",
+      &joined,
+      joined.as_js_str().code_units().collect::<Vec<_>>(),
+      vec![0x61, 0xd83d, 0xde00, 0xdc00],
+    );
+
+    let mut sorted = ["\u{ffff}", "😀", "é", "a", "", "ab"].map(JsStr::from);
+    sorted.sort();
+    self.expect(
+      "cmp",
+      &"sort",
+      sorted.map(|string| string.as_str().unwrap()),
+      ["", "a", "ab", "é", "😀", "\u{ffff}"],
+    );
+  }
+}
+
+fn hash(string: JsStr) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  string.hash(&mut hasher);
+  hasher.finish()
+}
+
+fn parse_expression<T>(source: &str, read: impl FnOnce(&Expression) -> Option<T>) -> Option<T> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&format!("({source});").chars().collect::<Vec<_>>());
+  let options = ParseOptions::new(SourceType::Script);
+  let program = parse_source_with_options(&allocator, chars, options).ok()?;
+
+  match program.statement_list[..] {
+    [Statement::Expression(statement)] => match statement.expression {
+      Expression::Group(group) => read(&group.expression),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+// the value of a string literal as the parser reads it, none when it doesn't parse
+fn literal_value(source: &str) -> Option<JsString> {
+  parse_expression(source, |expression| match expression {
+    Expression::String(literal) => Some(literal.value.to_js_string()),
+    _ => None,
+  })
+}
+
+// the cooked value of a template without substitutions, either plain or tagged, where an invalid
+// escape has no cooked value
+fn template_value(source: &str) -> Option<JsString> {
+  parse_expression(source, |expression| match expression {
+    Expression::Template(literal) => Some(literal.strings[0].to_js_string()),
+    Expression::TaggedTemplate(literal) => literal.strings[0].map(|cooked| cooked.to_js_string()),
+    _ => None,
+  })
+}
+
+fn property_key(source: &str) -> Option<JsString> {
+  parse_expression(source, |expression| match expression {
+    Expression::Object(literal) => match &literal.properties[..] {
+      [ObjectProperty::Property(definition)] => match &definition.property {
+        PropertyName::Static(key) => Some(key.name.to_js_string()),
+        _ => None,
+      },
+      _ => None,
+    },
+    _ => None,
+  })
+}
+
+fn printed(source: &str) -> String {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<_>>());
+  let options = ParseOptions::new(SourceType::Script);
+  match parse_source_with_options(&allocator, chars, options) {
+    Ok(program) => print_program(program, PrintOptions::compact()),
+    Err(error) => format!("{error:?}"),
+  }
+}
+
+const CODE_UNITS: &[(&str, &[u16])] = &[
+  ("", &[]),
+  ("abc", &[0x61, 0x62, 0x63]),
+  ("é", &[0xe9]),
+  ("a€b", &[0x61, 0x20ac, 0x62]),
+  ("😀", &[0xd83d, 0xde00]),
+  ("a😀b", &[0x61, 0xd83d, 0xde00, 0x62]),
+];
+
+const LITERALS: &[(&str, Option<&[u16]>)] = &[
+  (r#""\x41""#, Some(&[0x41])),
+  (r#""\x4a\x6B""#, Some(&[0x4a, 0x6b])),
+  (r#""A""#, Some(&[0x41])),
+  (r#""é""#, Some(&[0xe9])),
+  (r#""\uD800""#, Some(&[0xd800])),
+  (r#""\uDE00\uD83D""#, Some(&[0xde00, 0xd83d])),
+  (r#""😀""#, Some(&[0xd83d, 0xde00])),
+  (r#""\u{1F600}""#, Some(&[0xd83d, 0xde00])),
+  (r#""\u{0000000041}""#, Some(&[0x41])),
+  (r#""\u{10FFFF}""#, Some(&[0xdbff, 0xdfff])),
+  (r#""\u{D800}""#, Some(&[0xd800])),
+  ("'😀'", Some(&[0xd83d, 0xde00])),
+  (r#""\0\b\v""#, Some(&[0, 8, 0xb])),
+  (r#""\101""#, Some(&[0x41])),
+  (r#""\u{110000}""#, None),
+  (r#""\u{}""#, None),
+  (r#""\u{12g}""#, None),
+  (r#""\u12""#, None),
+  (r#""\uD8""#, None),
+  (r#""\xZ1""#, None),
+  (r#""\x1""#, None),
+];
+
+const TEMPLATES: &[(&str, Option<&[u16]>)] = &[
+  (r"`\uD800`", Some(&[0xd800])),
+  (r"`\u{1F600}\x41`", Some(&[0xd83d, 0xde00, 0x41])),
+  (r"`\u{110000}`", None),
+  (r"tag`\u{110000}`", None),
+  (r"tag`\xZ1`", None),
+  (r"tag`\uD800`", Some(&[0xd800])),
+];
+
+const PROPERTY_KEYS: &[(&str, &[u16])] = &[
+  (r"{ a: 1 }", &[0x61]),
+  (r#"{ "a": 1 }"#, &[0x61]),
+  (r#"{ "\uD800": 1 }"#, &[0xd800]),
+  (r"{ 0x10: 1 }", &[0x31, 0x36]),
+];
+
+const PRINTED: &[(&str, &str)] = &[
+  (r#""\uD800";"#, r#""\uD800";"#),
+  (r#""😀";"#, r#""😀";"#),
+  (r"`\uDFFF${a}`;", r"`\uDFFF${a}`;"),
+  (r#"({ "\uD800": 1 });"#, r#"({"\uD800":1});"#),
+  (r#"({ "\x61": 1 });"#, r"({a:1});"),
+];
@@ -0,0 +1,121 @@
+use std::{
+  collections::BTreeMap,
+  error::Error,
+  fs::{read_to_string, write},
+  io::ErrorKind,
+  path::Path,
+};
+
+use super::Outcome;
+
+const HEADER: &str =
+  "# the test262 outcome of each test, `pass`, `parsed`, `fail` or `skip` and its path below test/
+# written by `cargo run -p testing -- test262 <test262 checkout> --update-baseline`
+";
+
+const REVISION: &str = "# test262 revision ";
+
+#[derive(Debug, Default)]
+pub struct Baseline {
+  // the commit of the test262 checkout the outcomes are from
+  pub revision: Option<String>,
+  // the outcome label of each test by its name
+  pub outcomes: BTreeMap<String, String>,
+}
+
+// a missing baseline is an empty one
+pub fn read_baseline(path: &Path) -> Result<Baseline, Box<dyn Error>> {
+  let text = match read_to_string(path) {
+    Ok(text) => text,
+    Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Baseline::default()),
+    Err(error) => return Err(format!("{}: {error}", path.display()).into()),
+  };
+
+  let mut baseline = Baseline::default();
+  for (index, line) in text.lines().enumerate() {
+    if let Some(revision) = line.strip_prefix(REVISION) {
+      baseline.revision = Some(revision.trim().to_string());
+      continue;
+    }
+
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+
+    let (outcome, name) = line
+      .split_once(' ')
+      .filter(|(outcome, _)| matches!(*outcome, "pass" | "parsed" | "fail" | "skip"))
+      .ok_or_else(|| {
+        format!(
+          "{}:{}: `{line}` isn't an outcome",
+          path.display(),
+          index + 1
+        )
+      })?;
+    baseline
+      .outcomes
+      .insert(name.to_string(), outcome.to_string());
+  }
+
+  Ok(baseline)
+}
+
+// sorted by name so updating it gives small diffs
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> Result<(), Box<dyn Error>> {
+  let mut text = HEADER.to_string();
+  if let Some(revision) = &baseline.revision {
+    text += &format!("{REVISION}{revision}\n");
+  }
+
+  for (name, outcome) in &baseline.outcomes {
+    text += &format!("{outcome} {name}\n");
+  }
+
+  write(path, text).map_err(|error| format!("{}: {error}", path.display()).into())
+}
+
+// prints the tests that passed or parsed in the baseline and fail or are skipped now as a diff,
+// then the ones that pass or parse for the first time, and returns how many regressed
+pub fn report_changes(baseline: &Baseline, outcomes: &BTreeMap<String, Outcome>) -> usize {
+  let mut regressions = Vec::new();
+  let mut fixed = Vec::new();
+  let mut new = 0;
+
+  for (name, outcome) in outcomes {
+    match (baseline.outcomes.get(name).map(String::as_str), outcome) {
+      (None, _) => new += 1,
+      (Some(label @ ("pass" | "parsed")), Outcome::Fail(message) | Outcome::Skip(message)) => {
+        regressions.push((name, label, outcome.label(), message))
+      }
+      (Some("fail" | "skip"), Outcome::Pass | Outcome::Parsed(_)) => fixed.push(name),
+      _ => {}
+    }
+  }
+
+  if !regressions.is_empty() {
+    println!(
+      "\n{} regressed, these passed or parsed in the baseline:",
+      regressions.len()
+    );
+    for (name, before, after, message) in &regressions {
+      println!("- {before} {name}");
+      println!("+ {after} {name}: {message}");
+    }
+  }
+
+  if !fixed.is_empty() {
+    println!(
+      "\n{} newly passing, update the baseline to keep them that way:",
+      fixed.len()
+    );
+    for name in &fixed {
+      println!("  {name}");
+    }
+  }
+
+  if new > 0 {
+    println!("\n{new} tests aren't in the baseline yet");
+  }
+
+  regressions.len()
+}
@@ -0,0 +1,98 @@
+// the yaml between `/*---` and `---*/` at the top of every test, only as much of yaml as test262
+// uses for the keys the runner needs
+#[derive(Debug, Default)]
+pub struct Metadata {
+  pub features: Vec<String>,
+  pub flags: Vec<String>,
+  pub includes: Vec<String>,
+  pub negative: Option<Negative>,
+}
+
+#[derive(Debug, Default)]
+pub struct Negative {
+  pub phase: String,
+  pub r#type: String,
+}
+
+impl Metadata {
+  pub fn has_flag(&self, flag: &str) -> bool {
+    self.flags.iter().any(|candidate| candidate == flag)
+  }
+}
+
+pub fn read_metadata(source: &str) -> Result<Metadata, String> {
+  let start = source.find("/*---").ok_or("there's no metadata")? + 5;
+  let end = source[start..]
+    .find("---*/")
+    .ok_or("the metadata isn't closed")?
+    + start;
+
+  let mut metadata = Metadata::default();
+  let mut key = "";
+
+  for line in source[start..end].lines() {
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    // anything indented belongs to the last key, a list item or a field of `negative`
+    if line.starts_with([' ', '\t']) {
+      let line = line.trim();
+      if let Some(item) = line.strip_prefix('-') {
+        if let Some(list) = list(&mut metadata, key) {
+          list.push(unquote(item));
+        }
+      } else if let ("negative", Some((field, value))) = (key, line.split_once(':')) {
+        let negative = metadata.negative.get_or_insert_with(Negative::default);
+        match field.trim() {
+          "phase" => negative.phase = unquote(value),
+          "type" => negative.r#type = unquote(value),
+          _ => {}
+        }
+      }
+
+      continue;
+    }
+
+    let (name, value) = line
+      .split_once(':')
+      .ok_or_else(|| format!("`{line}` isn't a key"))?;
+    key = name.trim();
+
+    // `[a, b]` on the same line, the other keys with values are descriptions and the like
+    let value = value.trim();
+    if let Some(items) = value
+      .strip_prefix('[')
+      .and_then(|value| value.strip_suffix(']'))
+    {
+      if let Some(list) = list(&mut metadata, key) {
+        list.extend(
+          items
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(unquote),
+        );
+      }
+    }
+  }
+
+  Ok(metadata)
+}
+
+fn list<'m>(metadata: &'m mut Metadata, key: &str) -> Option<&'m mut Vec<String>> {
+  match key {
+    "features" => Some(&mut metadata.features),
+    "flags" => Some(&mut metadata.flags),
+    "includes" => Some(&mut metadata.includes),
+    _ => None,
+  }
+}
+
+fn unquote(value: &str) -> String {
+  let value = value.trim();
+  value
+    .strip_prefix(['"', '\''])
+    .and_then(|value| value.strip_suffix(['"', '\'']))
+    .unwrap_or(value)
+    .to_string()
+}
@@ -0,0 +1,441 @@
+use std::{
+  collections::BTreeMap,
+  error::Error,
+  fs::{read_dir, read_to_string},
+  panic::{self, AssertUnwindSafe},
+  path::{Path, PathBuf},
+  process::{Command, ExitCode},
+  thread,
+};
+
+use baseline::{read_baseline, report_changes, write_baseline, Baseline};
+use bumpalo::Bump;
+use metadata::{read_metadata, Metadata, Negative};
+use tower_ast::SourceType;
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+mod baseline;
+mod metadata;
+
+const USAGE: &str = "usage: testing test262 <test262 checkout> [directory or file ...] [options]
+
+options:
+  --verbose                  print every failing and skipped test, and the tests that only
+                             parsed with what they'd need a runtime for
+  --update-baseline          write the outcomes of this run into the baseline
+  --baseline <file>          the baseline to compare with, fixtures/test262/baseline.txt by default
+  --features <a,b>           only run tests that use one of these features
+  --skip-features <a,b>      skip tests that use any of these, instead of the ones listed in
+                             fixtures/test262/skip_features.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+  Module,
+  NonStrict,
+  Strict,
+}
+
+// a test passes when parsing is all it checks, a test that has to run or resolve its imports to
+// pass is only parsed, with what it needs a runtime for
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+  Pass,
+  Parsed(String),
+  Fail(String),
+  Skip(String),
+}
+
+impl Outcome {
+  pub fn label(&self) -> &'static str {
+    match self {
+      Outcome::Pass => "pass",
+      Outcome::Parsed(_) => "parsed",
+      Outcome::Fail(_) => "fail",
+      Outcome::Skip(_) => "skip",
+    }
+  }
+}
+
+// the `features:` tags that decide which tests run
+struct FeatureFilter {
+  only: Vec<String>,
+  skip: Vec<String>,
+}
+
+#[derive(Default)]
+struct Counts {
+  fail: usize,
+  parsed: usize,
+  pass: usize,
+  skip: usize,
+}
+
+// runs the tests under `test/` of a test262 checkout, or only the directories and files given,
+// prints how many passed, were only parsed, failed and were skipped in each directory and
+// compares the outcomes with the baseline, a test that passed or parsed there and fails or is
+// skipped now makes the run fail, as does a baseline without any outcomes
+pub fn run(args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/test262");
+  let mut baseline_path = fixtures.join("baseline.txt");
+  let mut update_baseline = false;
+  let mut verbose = false;
+  let mut filter = FeatureFilter {
+    only: Vec::new(),
+    skip: read_feature_list(&fixtures.join("skip_features.txt"))?,
+  };
+  let mut paths = Vec::new();
+
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    let mut value = || args.next().ok_or(format!("{arg} needs a value\n\n{USAGE}"));
+    let features = |value: &String| {
+      value
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>()
+    };
+
+    match arg.as_str() {
+      "--baseline" => baseline_path = PathBuf::from(value()?),
+      "--features" => filter.only = features(value()?),
+      "--skip-features" => filter.skip = features(value()?),
+      "--update-baseline" => update_baseline = true,
+      "--verbose" => verbose = true,
+      _ if arg.starts_with("--") => return Err(format!("unknown option {arg}\n\n{USAGE}").into()),
+      _ => paths.push(arg),
+    }
+  }
+
+  let Some((root, selected)) = paths.split_first() else {
+    eprintln!("{USAGE}");
+    return Ok(ExitCode::FAILURE);
+  };
+  let root = PathBuf::from(root);
+
+  let mut files = Vec::new();
+  if selected.is_empty() {
+    collect_tests(&root.join("test"), &mut files)?;
+  }
+
+  for path in selected {
+    collect_tests(&root.join(path), &mut files)?;
+  }
+
+  // parsing deeply nested tests needs more stack than the main thread has
+  let root_dir = root.clone();
+  let outcomes = thread::Builder::new()
+    .stack_size(256 << 20)
+    .spawn(move || {
+      let hook = panic::take_hook();
+      panic::set_hook(Box::new(|_| {}));
+      let outcomes = files
+        .iter()
+        .filter_map(|file| Some((test_name(&root_dir, file), run_test(file, &filter)?)))
+        .collect::<BTreeMap<_, _>>();
+      panic::set_hook(hook);
+      outcomes
+    })?
+    .join()
+    .map_err(|_| "the runner panicked")?;
+
+  let mut directories = BTreeMap::<String, Counts>::new();
+  let mut total = Counts::default();
+
+  for (name, outcome) in &outcomes {
+    let directory = name.rsplit_once('/').map_or("", |(directory, _)| directory);
+    let counts = directories.entry(directory.to_string()).or_default();
+
+    match outcome {
+      Outcome::Pass => {
+        counts.pass += 1;
+        total.pass += 1;
+      }
+      Outcome::Parsed(reason) => {
+        counts.parsed += 1;
+        total.parsed += 1;
+        if verbose {
+          println!("PARSED {name}: {reason}");
+        }
+      }
+      Outcome::Fail(message) => {
+        counts.fail += 1;
+        total.fail += 1;
+        if verbose {
+          println!("FAIL {name}: {message}");
+        }
+      }
+      Outcome::Skip(reason) => {
+        counts.skip += 1;
+        total.skip += 1;
+        if verbose {
+          println!("SKIP {name}: {reason}");
+        }
+      }
+    }
+  }
+
+  println!(
+    "{:>7} {:>7} {:>7} {:>7}  directory",
+    "pass", "parsed", "fail", "skip"
+  );
+  for (directory, counts) in &directories {
+    println!(
+      "{:>7} {:>7} {:>7} {:>7}  {directory}",
+      counts.pass, counts.parsed, counts.fail, counts.skip
+    );
+  }
+
+  let run = total.pass + total.parsed + total.fail;
+  println!(
+    "{:>7} {:>7} {:>7} {:>7}  total",
+    total.pass, total.parsed, total.fail, total.skip
+  );
+  println!(
+    "\n{:.1}% of the tests run parse the way they should, {:.1}% pass without a runtime",
+    100.0 * (total.pass + total.parsed) as f64 / run.max(1) as f64,
+    100.0 * total.pass as f64 / run.max(1) as f64
+  );
+
+  let mut baseline = read_baseline(&baseline_path)?;
+  let revision = read_revision(&root);
+  if update_baseline {
+    // outcomes from another revision of the tests aren't kept
+    let revision = revision.ok_or_else(|| {
+      format!(
+        "can't tell the revision of {}, the baseline records it",
+        root.display()
+      )
+    })?;
+    if baseline.revision.as_ref() != Some(&revision) {
+      baseline = Baseline {
+        revision: Some(revision),
+        outcomes: BTreeMap::new(),
+      };
+    }
+
+    for (name, outcome) in &outcomes {
+      baseline
+        .outcomes
+        .insert(name.clone(), outcome.label().to_string());
+    }
+
+    write_baseline(&baseline_path, &baseline)?;
+    println!(
+      "\nwrote {} outcomes to {}",
+      baseline.outcomes.len(),
+      baseline_path.display()
+    );
+    return Ok(ExitCode::SUCCESS);
+  }
+
+  if baseline.outcomes.is_empty() {
+    println!(
+      "\n{} has no outcomes to compare with, write them with --update-baseline",
+      baseline_path.display()
+    );
+    return Ok(ExitCode::FAILURE);
+  }
+
+  if let (Some(expected), Some(revision)) = (&baseline.revision, &revision) {
+    if expected != revision {
+      println!("\nthe baseline is from test262 {expected}, the checkout is at {revision}");
+    }
+  }
+
+  let regressions = report_changes(&baseline, &outcomes);
+  Ok(if regressions == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+// the commit the checkout is at
+fn read_revision(root: &Path) -> Option<String> {
+  let output = Command::new("git")
+    .arg("-C")
+    .arg(root)
+    .args(["rev-parse", "HEAD"])
+    .output()
+    .ok()?;
+
+  let revision = String::from_utf8(output.stdout).ok()?;
+  (output.status.success() && !revision.trim().is_empty()).then(|| revision.trim().to_string())
+}
+
+// one feature a line, `#` starts a comment
+fn read_feature_list(path: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+  let list = read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))?;
+
+  Ok(
+    list
+      .lines()
+      .map(|line| line.split('#').next().unwrap_or_default().trim())
+      .filter(|line| !line.is_empty())
+      .map(String::from)
+      .collect(),
+  )
+}
+
+// every `.js` file under the path, fixtures are only there to be imported by other tests
+fn collect_tests(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+  if path.is_file() {
+    files.push(path.to_path_buf());
+    return Ok(());
+  }
+
+  let mut entries = read_dir(path)
+    .map_err(|error| format!("{}: {error}", path.display()))?
+    .map(|entry| entry.map(|entry| entry.path()))
+    .collect::<Result<Vec<_>, _>>()?;
+  entries.sort();
+
+  for entry in entries {
+    let name = entry.file_name().unwrap_or_default().to_string_lossy();
+    if entry.is_dir() {
+      collect_tests(&entry, files)?;
+    } else if name.ends_with(".js") && !name.contains("_FIXTURE") {
+      files.push(entry);
+    }
+  }
+
+  Ok(())
+}
+
+// the path of a test below `test/` with forward slashes
+fn test_name(root: &Path, file: &Path) -> String {
+  let relative = file
+    .strip_prefix(root.join("test"))
+    .or_else(|_| file.strip_prefix(root))
+    .unwrap_or(file);
+
+  relative
+    .components()
+    .map(|component| component.as_os_str().to_string_lossy())
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+// a test passes when every mode it runs in fails to parse the way it should, and is parsed when
+// every mode parses but the test still has to run, or to resolve its imports for a `resolution`
+// negative test. tests without the features asked for with `--features` aren't part of the run
+fn run_test(file: &Path, filter: &FeatureFilter) -> Option<Outcome> {
+  let source = match read_to_string(file) {
+    Ok(source) => source,
+    Err(error) => return Some(Outcome::Fail(error.to_string())),
+  };
+
+  let metadata = match read_metadata(&source) {
+    Ok(metadata) => metadata,
+    Err(message) => return Some(Outcome::Skip(message)),
+  };
+
+  let uses = |features: &[String]| {
+    metadata
+      .features
+      .iter()
+      .find(|feature| features.contains(feature))
+      .cloned()
+  };
+
+  if !filter.only.is_empty() && uses(&filter.only).is_none() {
+    return None;
+  }
+
+  if let Some(feature) = uses(&filter.skip) {
+    return Some(Outcome::Skip(format!("uses {feature}")));
+  }
+
+  for mode in modes(&metadata) {
+    if let Err(message) = run_mode(&source, &metadata, mode) {
+      return Some(Outcome::Fail(format!("{mode:?}: {message}")));
+    }
+  }
+
+  match parse_negative(&metadata) {
+    Some(_) => Some(Outcome::Pass),
+    None => Some(Outcome::Parsed(runtime_reason(&metadata))),
+  }
+}
+
+// what a test that parses still needs a runtime for, and the harness files it includes besides
+// assert.js and sta.js, which every test gets
+fn runtime_reason(metadata: &Metadata) -> String {
+  let reason = match &metadata.negative {
+    Some(negative) => format!(
+      "expects a {} in the {} phase",
+      negative.r#type, negative.phase
+    ),
+    None => "has to run".to_string(),
+  };
+
+  if metadata.includes.is_empty() {
+    reason
+  } else {
+    format!("{reason}, includes {}", metadata.includes.join(", "))
+  }
+}
+
+// early errors are reported in the parse phase, older tests call it `early`
+fn parse_negative(metadata: &Metadata) -> Option<&Negative> {
+  metadata
+    .negative
+    .as_ref()
+    .filter(|negative| matches!(negative.phase.as_str(), "parse" | "early"))
+}
+
+// module tests only run as modules, raw tests exactly as written, anything else in both strict
+// and sloppy mode unless its flags say otherwise
+fn modes(metadata: &Metadata) -> Vec<Mode> {
+  if metadata.has_flag("module") {
+    vec![Mode::Module]
+  } else if metadata.has_flag("raw") || metadata.has_flag("noStrict") {
+    vec![Mode::NonStrict]
+  } else if metadata.has_flag("onlyStrict") {
+    vec![Mode::Strict]
+  } else {
+    vec![Mode::NonStrict, Mode::Strict]
+  }
+}
+
+fn run_mode(source: &str, metadata: &Metadata, mode: Mode) -> Result<(), String> {
+  let source = match mode {
+    Mode::Strict => format!("\"use strict\";\n{source}"),
+    Mode::Module | Mode::NonStrict => source.to_string(),
+  };
+
+  let source_type = match mode {
+    Mode::Module => SourceType::Module,
+    Mode::NonStrict | Mode::Strict => SourceType::Script,
+  };
+
+  // the decorator tests run like the rest, they need the option as well as the feature
+  let mut options = ParseOptions::new(source_type);
+  options.decorators = true;
+
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+    parse_source_with_options(&allocator, chars, options).map(|_| ())
+  }))
+  .map_err(|panic| {
+    let message = panic
+      .downcast_ref::<&str>()
+      .map(|message| message.to_string())
+      .or_else(|| panic.downcast_ref::<String>().cloned())
+      .unwrap_or_default();
+    format!("the parser panicked: {message}")
+  })?;
+
+  match (parsed, parse_negative(metadata)) {
+    (Ok(()), Some(negative)) => Err(format!("parsed, expected a {}", negative.r#type)),
+    (Ok(()), None) | (Err(_), Some(_)) => Ok(()),
+    (Err(error), None) => Err(format!(
+      "{} at {}..{}",
+      error.code.message(),
+      error.span.start,
+      error.span.end
+    )),
+  }
+}
@@ -0,0 +1,508 @@
+use std::{error::Error, fmt::Debug, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_ast::SourceType;
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+use unicode_id::{casing, properties::Property};
+
+// checks the Unicode tables: which names and aliases RegExp property escapes accept, samples of
+// the code points in them, that patterns using them validate like V8's, and case mapping of
+// strings and code points like toUpperCase, toLowerCase and the RegExp `i` flag do
+pub fn run(_args: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut checks = Checks {
+    checked: 0,
+    failures: 0,
+  };
+
+  println!(
+    "Unicode {} properties, {} casing",
+    unicode_id::properties::UNICODE_VERSION,
+    casing::UNICODE_VERSION
+  );
+
+  for (name, value, same_as) in ALIASES {
+    checks.expect(
+      "lookup",
+      &(name, value),
+      Property::lookup(name, *value),
+      Property::lookup(same_as.0, same_as.1),
+    );
+  }
+
+  for (name, value) in UNKNOWN {
+    checks.expect(
+      "lookup",
+      &(name, value),
+      Property::lookup(name, *value),
+      None,
+    );
+  }
+
+  for (name, value, inside, outside) in CONTAINS {
+    let Some(property) = Property::lookup(name, *value) else {
+      checks.expect("lookup", &(name, value), None, Some(()));
+      continue;
+    };
+
+    for c in *inside {
+      checks.expect("contains", &(name, value, c), property.contains(*c), true);
+    }
+    for c in *outside {
+      checks.expect("contains", &(name, value, c), property.contains(*c), false);
+    }
+  }
+
+  for (name, sequence, has) in STRINGS {
+    let property = Property::lookup(name, None);
+    checks.expect(
+      "has_strings",
+      name,
+      property.map(Property::has_strings),
+      Some(true),
+    );
+    checks.expect(
+      "strings",
+      &(name, sequence),
+      property.is_some_and(|property| property.strings().any(|string| string == *sequence)),
+      *has,
+    );
+  }
+
+  for (source, valid) in PATTERNS {
+    checks.expect("validate_regexp", source, validates(source), *valid);
+  }
+
+  for (text, expected) in UPPERCASE {
+    checks.expect(
+      "to_uppercase",
+      text,
+      casing::to_uppercase(&code_points(text)),
+      code_points(expected),
+    );
+  }
+
+  for (text, expected) in LOWERCASE {
+    checks.expect(
+      "to_lowercase",
+      text,
+      casing::to_lowercase(&code_points(text)),
+      code_points(expected),
+    );
+  }
+
+  for (c, folded, legacy) in CANONICALIZE {
+    checks.expect("simple_fold", c, casing::simple_fold(*c), *folded);
+    checks.expect(
+      "canonicalize",
+      &(c, true),
+      casing::canonicalize(*c, true),
+      *folded,
+    );
+    checks.expect(
+      "canonicalize",
+      &(c, false),
+      casing::canonicalize(*c, false),
+      *legacy,
+    );
+  }
+
+  println!(
+    "{} passed, {} failed",
+    checks.checked - checks.failures,
+    checks.failures
+  );
+
+  Ok(if checks.failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+struct Checks {
+  checked: usize,
+  failures: usize,
+}
+
+impl Checks {
+  fn expect<T: PartialEq + Debug>(
+    &mut self,
+    name: &str,
+    input: &dyn Debug,
+    actual: T,
+    expected: T,
+  ) {
+    self.checked += 1;
+    if actual != expected {
+      self.failures += 1;
+      println!("FAIL {name}({input:?}) = {actual:?}, expected {expected:?}");
+    }
+  }
+}
+
+fn code_points(text: &str) -> Vec<u32> {
+  text.chars().map(|c| c as u32).collect()
+}
+
+fn validates(source: &str) -> bool {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&format!("{source};").chars().collect::<Vec<_>>());
+  let options = ParseOptions::new(SourceType::Script);
+  parse_source_with_options(&allocator, chars, options).is_ok()
+}
+
+type Lookup<'a> = (&'a str, Option<&'a str>);
+
+// names that mean the same property as another one
+const ALIASES: &[(&str, Option<&str>, Lookup)] = &[
+  ("gc", Some("Lu"), ("Lu", None)),
+  ("General_Category", Some("Uppercase_Letter"), ("Lu", None)),
+  ("Letter", None, ("L", None)),
+  ("digit", None, ("Nd", None)),
+  ("punct", None, ("P", None)),
+  ("Combining_Mark", None, ("M", None)),
+  ("Cased_Letter", None, ("LC", None)),
+  ("sc", Some("Grek"), ("Script", Some("Greek"))),
+  ("scx", Some("Latn"), ("Script_Extensions", Some("Latin"))),
+  ("sc", Some("Zyyy"), ("sc", Some("Common"))),
+  ("sc", Some("Qaai"), ("sc", Some("Inherited"))),
+  ("sc", Some("Zinh"), ("sc", Some("Inherited"))),
+  ("WSpace", None, ("White_Space", None)),
+  ("space", None, ("White_Space", None)),
+  ("Alpha", None, ("Alphabetic", None)),
+  ("IDS", None, ("ID_Start", None)),
+  ("XIDC", None, ("XID_Continue", None)),
+  ("EPres", None, ("Emoji_Presentation", None)),
+  ("ExtPict", None, ("Extended_Pictographic", None)),
+  ("CWCM", None, ("Changes_When_Casemapped", None)),
+];
+
+const UNKNOWN: &[Lookup] = &[
+  ("L&", None),
+  ("lu", None),
+  ("letter", None),
+  ("Script", None),
+  ("Greek", None),
+  ("sc", Some("L")),
+  ("gc", Some("Greek")),
+  ("sc", Some("Hrkt")),
+  ("sc", Some("Katakana_Or_Hiragana")),
+  ("ID_Start", Some("Y")),
+  ("Block", Some("Basic_Latin")),
+  ("InBasic_Latin", None),
+  ("Any", Some("Y")),
+  ("RGI_Emoji", Some("Y")),
+  ("Other_Alphabetic", None),
+];
+
+type Samples<'a> = (&'a str, Option<&'a str>, &'a [u32], &'a [u32]);
+
+// code points in the property and code points outside of it
+const CONTAINS: &[Samples] = &[
+  ("Any", None, &[0, 0xd800, 0x10ffff], &[0x110000]),
+  ("ASCII", None, &[0, 0x41, 0x7f], &[0x80, 0xe9]),
+  (
+    "Assigned",
+    None,
+    &[0x41, 0x4e00, 0x1f600],
+    &[0x378, 0xe01f0, 0x10ffff],
+  ),
+  (
+    "Lu",
+    None,
+    &[0x41, 0xc0, 0x391, 0x1d400],
+    &[0x61, 0x1c5, 0x30],
+  ),
+  ("Lt", None, &[0x1c5, 0x1f88], &[0x41, 0x61]),
+  (
+    "L",
+    None,
+    &[0x41, 0x61, 0x1c5, 0x2b0, 0x4e00, 0x20000],
+    &[0x30, 0x300, 0x20],
+  ),
+  ("LC", None, &[0x41, 0x61, 0x1c5], &[0x2b0, 0x4e00]),
+  ("Nd", None, &[0x30, 0x660, 0x1d7ce], &[0xbd, 0x2160]),
+  ("N", None, &[0x30, 0xbd, 0x2160], &[0x41]),
+  ("Cn", None, &[0x378, 0xfffe, 0x10ffff], &[0x41, 0xe000]),
+  ("C", None, &[0, 0xad, 0xd800, 0xe000, 0x378], &[0x41]),
+  ("Co", None, &[0xe000, 0xf0000, 0x10fffd], &[0x10ffff]),
+  ("Zs", None, &[0x20, 0xa0, 0x3000], &[0x9, 0x2028]),
+  (
+    "sc",
+    Some("Greek"),
+    &[0x391, 0x3b1, 0x1f00],
+    &[0x41, 0x342, 0x300],
+  ),
+  ("sc", Some("Latin"), &[0x41, 0xe9, 0xff21], &[0x391, 0x30]),
+  (
+    "sc",
+    Some("Han"),
+    &[0x4e00, 0x3005, 0x20000, 0x30000],
+    &[0x3042, 0x3001],
+  ),
+  (
+    "sc",
+    Some("Common"),
+    &[0x20, 0x30, 0x3001, 0x1f600],
+    &[0x41, 0x300],
+  ),
+  (
+    "sc",
+    Some("Inherited"),
+    &[0x300, 0x200c, 0xfe0f],
+    &[0x41, 0x3042],
+  ),
+  ("sc", Some("Zzzz"), &[0x378, 0x10ffff, 0xe000], &[0x41]),
+  (
+    "scx",
+    Some("Greek"),
+    &[0x391, 0x342, 0x345, 0x1dc0],
+    &[0x41, 0x5d0],
+  ),
+  (
+    "scx",
+    Some("Hira"),
+    &[0x3042, 0x3001, 0x30fc, 0x3099],
+    &[0x30a2, 0x4e00],
+  ),
+  ("scx", Some("Latin"), &[0x41, 0x363, 0x10fb], &[0x391]),
+  ("scx", Some("Zyyy"), &[0x20, 0x1f600], &[0x3001, 0x30fc]),
+  ("scx", Some("Zinh"), &[0x20d0, 0xfe0f], &[0x342, 0x3099]),
+  (
+    "White_Space",
+    None,
+    &[0x9, 0x20, 0x85, 0xa0, 0x2028, 0x3000],
+    &[0x200b, 0xfeff, 0x41],
+  ),
+  (
+    "ASCII_Hex_Digit",
+    None,
+    &[0x30, 0x46, 0x66],
+    &[0x47, 0xff10],
+  ),
+  (
+    "Hex_Digit",
+    None,
+    &[0x30, 0x66, 0xff10, 0xff46],
+    &[0x47, 0x660],
+  ),
+  (
+    "Alphabetic",
+    None,
+    &[0x41, 0x345, 0x4e00, 0x2160],
+    &[0x30, 0x300],
+  ),
+  (
+    "Lowercase",
+    None,
+    &[0x61, 0xaa, 0x2b0, 0x2170],
+    &[0x41, 0x1c5],
+  ),
+  ("Uppercase", None, &[0x41, 0x2160, 0x1f130], &[0x61, 0x1c5]),
+  (
+    "ID_Start",
+    None,
+    &[0x41, 0x2118, 0x4e00, 0x20000],
+    &[0x24, 0x30, 0x5f, 0x2e2f],
+  ),
+  (
+    "ID_Continue",
+    None,
+    &[0x30, 0x5f, 0xb7, 0x300, 0xe0100],
+    &[0x20, 0x24, 0x2e2f],
+  ),
+  (
+    "Pattern_White_Space",
+    None,
+    &[0x9, 0x20, 0x200e, 0x2029],
+    &[0xa0, 0x3000],
+  ),
+  (
+    "Noncharacter_Code_Point",
+    None,
+    &[0xfdd0, 0xfffe, 0x10ffff],
+    &[0xfffd],
+  ),
+  (
+    "Regional_Indicator",
+    None,
+    &[0x1f1e6, 0x1f1ff],
+    &[0x1f1e5, 0x1f200],
+  ),
+  ("Emoji", None, &[0x23, 0xa9, 0x1f600], &[0x41, 0x2610]),
+  (
+    "Emoji_Presentation",
+    None,
+    &[0x231a, 0x1f600],
+    &[0x23, 0xa9],
+  ),
+  ("Emoji_Modifier", None, &[0x1f3fb, 0x1f3ff], &[0x1f600]),
+  (
+    "Extended_Pictographic",
+    None,
+    &[0xa9, 0x1f600, 0x1fffd],
+    &[0x23, 0x1f1e6],
+  ),
+  (
+    "Default_Ignorable_Code_Point",
+    None,
+    &[0xad, 0x200b, 0xe0000, 0xe0fff],
+    &[0x20],
+  ),
+  (
+    "Changes_When_NFKC_Casefolded",
+    None,
+    &[0x41, 0xa0, 0xad],
+    &[0x61],
+  ),
+  (
+    "Bidi_Mirrored",
+    None,
+    &[0x28, 0x29, 0x3c, 0x2208],
+    &[0x41, 0x2d],
+  ),
+  ("RGI_Emoji", None, &[0x231a, 0x1f600], &[0x23, 0x41, 0xa9]),
+  (
+    "Basic_Emoji",
+    None,
+    &[0x231a, 0x1f3fb, 0x1f600],
+    &[0x23, 0xa9],
+  ),
+  ("Emoji_Keycap_Sequence", None, &[], &[0x23, 0x31]),
+];
+
+// properties of strings, a sequence of more than one code point, and if it's in the property
+const STRINGS: &[(&str, &str, bool)] = &[
+  ("RGI_Emoji", "#\u{fe0f}\u{20e3}", true),
+  ("RGI_Emoji", "\u{a9}\u{fe0f}", true),
+  ("RGI_Emoji", "\u{1f1fa}\u{1f1f8}", true),
+  ("RGI_Emoji", "\u{1f44d}\u{1f3fd}", true),
+  (
+    "RGI_Emoji",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    true,
+  ),
+  ("RGI_Emoji", "#\u{20e3}", false),
+  ("RGI_Emoji", "\u{1f1fa}\u{1f1fa}", false),
+  ("Basic_Emoji", "\u{a9}\u{fe0f}", true),
+  ("Basic_Emoji", "\u{1f1fa}\u{1f1f8}", false),
+  ("Emoji_Keycap_Sequence", "0\u{fe0f}\u{20e3}", true),
+  ("Emoji_Keycap_Sequence", "\u{1f44d}\u{1f3fd}", false),
+  ("RGI_Emoji_Flag_Sequence", "\u{1f1ef}\u{1f1f5}", true),
+  (
+    "RGI_Emoji_Tag_Sequence",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+    true,
+  ),
+  ("RGI_Emoji_Modifier_Sequence", "\u{270b}\u{1f3ff}", true),
+  (
+    "RGI_Emoji_ZWJ_Sequence",
+    "\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}",
+    true,
+  ),
+  ("RGI_Emoji_ZWJ_Sequence", "\u{1f1fa}\u{1f1f8}", false),
+];
+
+// regular expression literals and if they're valid, the same as V8 reads them
+const PATTERNS: &[(&str, bool)] = &[
+  (r"/\p{Script=Greek}/u", true),
+  (r"/\p{sc=Grek}\P{scx=Hira}/u", true),
+  (r"/\p{General_Category=Decimal_Number}/u", true),
+  (r"/\p{L}\p{Lu}\P{N}/u", true),
+  (r"/[\p{digit}a-z]/u", true),
+  (r"/\p{WSpace}/u", true),
+  (r"/\p{Any}\p{ASCII}\P{Assigned}/u", true),
+  (r"/\p{RGI_Emoji}/v", true),
+  (r"/[\p{RGI_Emoji}--\q{x}]/v", true),
+  (r"/[\p{Emoji_Keycap_Sequence}&&\p{RGI_Emoji}]/v", true),
+  (r"/[^\p{Emoji}]/v", true),
+  (r"/\p{Lu}/", true),
+  (r"/\p{Foo}/", true),
+  (r"/\p{RGI_Emoji}/u", false),
+  (r"/\P{RGI_Emoji}/v", false),
+  (r"/[^\p{RGI_Emoji}]/v", false),
+  (r"/[^[\p{RGI_Emoji}]]/v", false),
+  (r"/[\p{RGI_Emoji}]/u", false),
+  (r"/\p{Script}/u", false),
+  (r"/\p{sc=L}/u", false),
+  (r"/\p{lu}/u", false),
+  (r"/\p{L&}/u", false),
+  (r"/\p{ID_Start=Y}/u", false),
+  (r"/\p{Script=Hrkt}/u", false),
+  (r"/\p{Block=Basic_Latin}/u", false),
+  (r"/\p{InBasic_Latin}/u", false),
+  (r"/\p{Foo}/u", false),
+  (r"/[\p{L}-z]/u", false),
+];
+
+const UPPERCASE: &[(&str, &str)] = &[
+  ("abc", "ABC"),
+  ("ß", "SS"),
+  ("straße", "STRASSE"),
+  ("ŉ", "ʼN"),
+  ("ﬃ", "FFI"),
+  ("ΐ", "\u{399}\u{308}\u{301}"),
+  ("ᾳ", "ΑΙ"),
+  ("ǅ", "Ǆ"),
+  ("ı", "I"),
+  ("ſ", "S"),
+  ("µ", "Μ"),
+  ("ÿ", "Ÿ"),
+  ("\u{10428}", "\u{10400}"),
+  ("\u{1e922}", "\u{1e900}"),
+  ("İ", "İ"),
+  ("中", "中"),
+];
+
+// a capital sigma is a final sigma after a cased letter when no cased letter follows, skipping
+// case-ignorable code points like `.` and combining marks on either side
+const LOWERCASE: &[(&str, &str)] = &[
+  ("ABC", "abc"),
+  ("İ", "i\u{307}"),
+  ("ẞ", "ß"),
+  ("ǅ", "ǆ"),
+  ("\u{212a}", "k"),
+  ("\u{2126}", "ω"),
+  ("\u{10400}", "\u{10428}"),
+  ("Σ", "σ"),
+  ("ΑΣ", "ας"),
+  ("ΑΣ Α", "ας α"),
+  ("ΑΣΑ", "ασα"),
+  ("Α.Σ", "α.ς"),
+  ("ΑΣ.Α", "ασ.α"),
+  ("ΑΣ\u{301}", "ας\u{301}"),
+  ("ΑΣ\u{301}Α", "ασ\u{301}α"),
+  (" Σ", " σ"),
+  ("1Σ", "1σ"),
+  ("ΣΣ", "σς"),
+];
+
+// code points, their simple case folding that the `u` and `v` flags compare, and the uppercase
+// the legacy Canonicalize compares without them
+const CANONICALIZE: &[(u32, u32, u32)] = &[
+  (0x41, 0x61, 0x41),
+  (0x61, 0x61, 0x41),
+  (0x30, 0x30, 0x30),
+  (0xdf, 0xdf, 0xdf),
+  (0x1e9e, 0xdf, 0x1e9e),
+  (0x131, 0x131, 0x131),
+  (0x130, 0x130, 0x130),
+  (0x17f, 0x73, 0x17f),
+  (0x212a, 0x6b, 0x212a),
+  (0x2126, 0x3c9, 0x2126),
+  (0xb5, 0x3bc, 0x39c),
+  (0xff, 0xff, 0x178),
+  (0x3a3, 0x3c3, 0x3a3),
+  (0x3c2, 0x3c3, 0x3a3),
+  (0x3d0, 0x3b2, 0x392),
+  (0x1c4, 0x1c6, 0x1c4),
+  (0x1c5, 0x1c6, 0x1c4),
+  (0x1c6, 0x1c6, 0x1c4),
+  (0x149, 0x149, 0x149),
+  (0xfb03, 0xfb03, 0xfb03),
+  (0x1f80, 0x1f80, 0x1f80),
+  (0x1f88, 0x1f80, 0x1f88),
+  (0xd800, 0xd800, 0xd800),
+  (0x10400, 0x10428, 0x10400),
+  (0x10428, 0x10428, 0x10428),
+];
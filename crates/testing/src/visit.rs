@@ -0,0 +1,396 @@
+use std::{collections::BTreeMap, error::Error, fs::read_to_string, path::Path, process::ExitCode};
+
+use bumpalo::Bump;
+use tower_ast::{
+  expression::Expression,
+  fold::Fold,
+  statement::Statement,
+  visit::{walk_expression, walk_statement, Visit},
+  visit_mut::VisitMut,
+  Identifier, Program,
+};
+use tower_codegen::{print_program, PrintOptions};
+use tower_parser::parser::{parse_source_with_options, ParseOptions};
+
+use crate::{
+  dump::dump_program,
+  roundtrip::{read_sources, Sample},
+  snapshot::{collect_fixtures, parse_options},
+};
+
+// walks each source with the three traversals of the ast. the statements and expressions a visit
+// reaches are counted by kind against the snapshot dump of the tree, then every identifier is
+// renamed with a mutable visit and renamed back with a fold, which has to print the same code as
+// the original. the roundtrip samples and the snapshot fixtures run when no files are given
+pub fn run(files: &[String]) -> Result<ExitCode, Box<dyn Error>> {
+  let mut sources = read_sources(files)?;
+  if files.is_empty() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/snapshots");
+    let mut fixtures = Vec::new();
+    collect_fixtures(&directory, &mut fixtures)?;
+
+    // the fixtures that don't parse are the ones for diagnostics
+    for file in fixtures
+      .iter()
+      .filter(|file| !file.starts_with(directory.join("errors")))
+    {
+      sources.push(fixture(file)?);
+    }
+  }
+
+  let mut failures = 0;
+  for sample in &sources {
+    if let Err(message) = check_source(&sample.source, sample.options) {
+      failures += 1;
+      println!("FAIL {}: {message}", sample.name);
+    }
+  }
+
+  println!("{} passed, {failures} failed", sources.len() - failures);
+
+  Ok(if failures == 0 {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+fn fixture(file: &Path) -> Result<Sample, Box<dyn Error>> {
+  let source = read_to_string(file).map_err(|error| format!("{}: {error}", file.display()))?;
+  Ok(Sample {
+    name: file
+      .file_name()
+      .unwrap_or_default()
+      .to_string_lossy()
+      .to_string(),
+    source,
+    options: parse_options(file),
+  })
+}
+
+fn check_source(source: &str, options: ParseOptions) -> Result<(), String> {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&source.chars().collect::<Vec<char>>());
+  let program = parse_source_with_options(&allocator, chars, options)
+    .map_err(|error| format!("the source doesn't parse: {error:?}"))?;
+
+  check_counts(program)?;
+  check_rename(&allocator, program, options)
+}
+
+fn check_counts(program: &Program) -> Result<(), String> {
+  let mut counter = Counter {
+    identifiers: 0,
+    kinds: BTreeMap::new(),
+  };
+  counter.visit_program(program);
+
+  let dumped = dump_kinds(&dump_program(program));
+  if counter.kinds != dumped {
+    return Err(format!(
+      "the visit reaches\n  {:?}\nthe dump has\n  {:?}",
+      counter.kinds, dumped
+    ));
+  }
+
+  Ok(())
+}
+
+// the first word of each line of the dump, after the slot a node fills in its parent, for the
+// kinds the visit counts
+fn dump_kinds(dump: &str) -> BTreeMap<&'static str, usize> {
+  let mut kinds = BTreeMap::new();
+
+  for line in dump.lines() {
+    let mut words = line.split_whitespace();
+    let word = match words.next() {
+      Some(label) if label.ends_with(':') => words.next(),
+      word => word,
+    };
+
+    let kind = STATEMENT_KINDS
+      .iter()
+      .chain(EXPRESSION_KINDS)
+      .find(|kind| Some(**kind) == word);
+    if let Some(kind) = kind {
+      *kinds.entry(*kind).or_default() += 1;
+    }
+  }
+
+  kinds
+}
+
+// the identifiers renamed by the mutable visit and back by the fold are the ones a visit reaches,
+// the renamed tree prints to code that parses and prints the same again, and the original tree
+// isn't changed by either
+fn check_rename<'a>(
+  allocator: &'a Bump,
+  program: &'a Program<'a>,
+  options: ParseOptions,
+) -> Result<(), String> {
+  let print = |program: &Program| print_program(program, PrintOptions::readable());
+  let original = print(program);
+
+  let mut counter = Counter {
+    identifiers: 0,
+    kinds: BTreeMap::new(),
+  };
+  counter.visit_program(program);
+
+  let mut rename = Rename {
+    allocator,
+    identifiers: 0,
+  };
+  let mut renamed = program.clone();
+  rename.visit_program(&mut renamed);
+  if rename.identifiers != counter.identifiers {
+    return Err(format!(
+      "the mutable visit renames {} identifiers, the visit reaches {}",
+      rename.identifiers, counter.identifiers
+    ));
+  }
+
+  let printed = print(&renamed);
+  if counter.identifiers > 0 && printed == original {
+    return Err(format!("renaming didn't change the code\n{printed}"));
+  }
+
+  let chars = allocator.alloc_slice_copy(&printed.chars().collect::<Vec<char>>());
+  let reparsed = parse_source_with_options(allocator, chars, options)
+    .map_err(|error| format!("the renamed code doesn't parse: {error:?}\n{printed}"))?;
+  if print(reparsed) != printed {
+    return Err(format!("the renamed code prints differently\n{printed}"));
+  }
+
+  let mut restore = Restore {
+    allocator,
+    identifiers: 0,
+  };
+  let restored = restore.fold_program(renamed);
+  if restore.identifiers != counter.identifiers {
+    return Err(format!(
+      "the fold renames {} identifiers back, the visit reaches {}",
+      restore.identifiers, counter.identifiers
+    ));
+  }
+
+  let printed = print(&restored);
+  if printed != original {
+    return Err(format!(
+      "renaming back prints\n{printed}\ninstead of\n{original}"
+    ));
+  }
+
+  if print(program) != original {
+    return Err("the original tree changed".to_string());
+  }
+
+  Ok(())
+}
+
+// the dump names these unambiguously, blocks, declarations, identifiers, strings and jsx elements
+// also stand for nodes that aren't statements or expressions there
+const STATEMENT_KINDS: &[&str] = &[
+  "Break",
+  "ClassDeclaration",
+  "Continue",
+  "Debugger",
+  "DoWhile",
+  "ExpressionStatement",
+  "Empty",
+  "For",
+  "ForIn",
+  "ForOf",
+  "FunctionDeclaration",
+  "If",
+  "Labeled",
+  "Return",
+  "Switch",
+  "Throw",
+  "Try",
+  "With",
+  "While",
+];
+
+const EXPRESSION_KINDS: &[&str] = &[
+  "Array",
+  "ArrowFunction",
+  "Assignment",
+  "BigInt",
+  "Binary",
+  "Boolean",
+  "Call",
+  "Chain",
+  "ClassExpression",
+  "Conditional",
+  "Group",
+  "FunctionExpression",
+  "Import",
+  "ImportMeta",
+  "In",
+  "List",
+  "Member",
+  "NewTarget",
+  "New",
+  "Null",
+  "Number",
+  "Object",
+  "RegExp",
+  "Super",
+  "TaggedTemplate",
+  "Template",
+  "This",
+  "Unary",
+  "Yield",
+];
+
+fn statement_kind(statement: &Statement) -> Option<&'static str> {
+  match statement {
+    Statement::Block(_) | Statement::Lexical(_) | Statement::Variable(_) => None,
+    Statement::Break(_) => Some("Break"),
+    Statement::Class(_) => Some("ClassDeclaration"),
+    Statement::Continue(_) => Some("Continue"),
+    Statement::Debugger(_) => Some("Debugger"),
+    Statement::DoWhile(_) => Some("DoWhile"),
+    Statement::Expression(_) => Some("ExpressionStatement"),
+    Statement::Empty(_) => Some("Empty"),
+    Statement::For(_) => Some("For"),
+    Statement::ForIn(_) => Some("ForIn"),
+    Statement::ForOf(_) => Some("ForOf"),
+    Statement::Function(_) => Some("FunctionDeclaration"),
+    Statement::If(_) => Some("If"),
+    Statement::Label(_) => Some("Labeled"),
+    Statement::Return(_) => Some("Return"),
+    Statement::Switch(_) => Some("Switch"),
+    Statement::Throw(_) => Some("Throw"),
+    Statement::Try(_) => Some("Try"),
+    Statement::With(_) => Some("With"),
+    Statement::While(_) => Some("While"),
+  }
+}
+
+fn expression_kind(expression: &Expression) -> Option<&'static str> {
+  match expression {
+    Expression::Identifier(_)
+    | Expression::JsxElement(_)
+    | Expression::JsxFragment(_)
+    | Expression::String(_) => None,
+    Expression::Array(_) => Some("Array"),
+    Expression::ArrowFunction(_) => Some("ArrowFunction"),
+    Expression::Assignment(_) => Some("Assignment"),
+    Expression::BigInt(_) => Some("BigInt"),
+    Expression::BinaryOp(_) => Some("Binary"),
+    Expression::Boolean(_) => Some("Boolean"),
+    Expression::Call(_) => Some("Call"),
+    Expression::Chain(_) => Some("Chain"),
+    Expression::Class(_) => Some("ClassExpression"),
+    Expression::Conditional(_) => Some("Conditional"),
+    Expression::Group(_) => Some("Group"),
+    Expression::Function(_) => Some("FunctionExpression"),
+    Expression::Import(_) => Some("Import"),
+    Expression::ImportMeta(_) => Some("ImportMeta"),
+    Expression::In(_) => Some("In"),
+    Expression::List(_) => Some("List"),
+    Expression::Member(_) => Some("Member"),
+    Expression::NewTarget(_) => Some("NewTarget"),
+    Expression::New(_) => Some("New"),
+    Expression::Null(_) => Some("Null"),
+    Expression::Number(_) => Some("Number"),
+    Expression::Object(_) => Some("Object"),
+    Expression::RegExp(_) => Some("RegExp"),
+    Expression::Super(_) => Some("Super"),
+    Expression::TaggedTemplate(_) => Some("TaggedTemplate"),
+    Expression::Template(_) => Some("Template"),
+    Expression::This(_) => Some("This"),
+    Expression::UnaryOp(_) => Some("Unary"),
+    Expression::Yield(_) => Some("Yield"),
+  }
+}
+
+struct Counter {
+  identifiers: usize,
+  kinds: BTreeMap<&'static str, usize>,
+}
+
+impl<'a> Visit<'a> for Counter {
+  fn visit_statement(&mut self, statement: &'a Statement<'a>) {
+    if let Some(kind) = statement_kind(statement) {
+      *self.kinds.entry(kind).or_default() += 1;
+    }
+
+    walk_statement(self, statement);
+  }
+
+  fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+    if let Some(kind) = expression_kind(expression) {
+      *self.kinds.entry(kind).or_default() += 1;
+    }
+
+    walk_expression(self, expression);
+  }
+
+  fn visit_identifier_reference(&mut self, _identifier: &'a Identifier<'a>) {
+    self.identifiers += 1;
+  }
+
+  fn visit_binding_identifier(&mut self, _identifier: &'a Identifier<'a>) {
+    self.identifiers += 1;
+  }
+}
+
+// appends a `$` to every identifier
+struct Rename<'a> {
+  allocator: &'a Bump,
+  identifiers: usize,
+}
+
+impl<'a> Rename<'a> {
+  fn rename(&mut self, identifier: &mut Identifier<'a>) {
+    self.identifiers += 1;
+    identifier.name = self.allocator.alloc_str(&format!("{}$", identifier.name));
+  }
+}
+
+impl<'a> VisitMut<'a> for Rename<'a> {
+  fn allocator(&self) -> &'a Bump {
+    self.allocator
+  }
+
+  fn visit_identifier_reference(&mut self, identifier: &mut Identifier<'a>) {
+    self.rename(identifier);
+  }
+
+  fn visit_binding_identifier(&mut self, identifier: &mut Identifier<'a>) {
+    self.rename(identifier);
+  }
+}
+
+// takes the `$` back off
+struct Restore<'a> {
+  allocator: &'a Bump,
+  identifiers: usize,
+}
+
+impl<'a> Restore<'a> {
+  fn restore(&mut self, identifier: Identifier<'a>) -> Identifier<'a> {
+    self.identifiers += 1;
+    Identifier {
+      name: identifier.name.strip_suffix('$').unwrap_or(identifier.name),
+      ..identifier
+    }
+  }
+}
+
+impl<'a> Fold<'a> for Restore<'a> {
+  fn allocator(&self) -> &'a Bump {
+    self.allocator
+  }
+
+  fn fold_identifier_reference(&mut self, identifier: Identifier<'a>) -> Identifier<'a> {
+    self.restore(identifier)
+  }
+
+  fn fold_binding_identifier(&mut self, identifier: Identifier<'a>) -> Identifier<'a> {
+    self.restore(identifier)
+  }
+}
@@ -0,0 +1,234 @@
+use std::{
+  env,
+  fs::read_to_string,
+  io::{self, Read},
+  panic::{self, AssertUnwindSafe},
+  process::ExitCode,
+};
+
+use bumpalo::Bump;
+use tower_ast::SourceType;
+use tower_codegen::{estree::program_to_estree, json::stringify, print_program, PrintOptions};
+use tower_parser::{
+  diagnostic::{render_error, Lines},
+  parser::{parse_source_with_options, tokenize, ParseOptions},
+};
+
+const USAGE: &str = "usage: tower <command> [options] [file ...]
+
+commands:
+  tokens    print the tokens the parser reads, up to the first syntax error
+  parse     print the syntax tree, with --json as ESTree
+  check     report syntax errors, exits with 1 if there are any
+  print     print the program back as javascript, with --compact on as few bytes as it can
+  run       run the program
+
+options:
+  --module, --script    how to parse the sources, .mjs files are modules and anything else a
+                        script unless one of these is given
+  --decorators          accept decorators, when tower is built with the decorators feature
+  --jsx                 accept jsx
+  --typescript          accept typescript and leave the types out
+  --json, --compact     see parse and print
+
+without files, or with `-` as a file, the source is read from stdin";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+  Check,
+  Parse,
+  Print,
+  Run,
+  Tokens,
+}
+
+struct Options {
+  compact: bool,
+  decorators: bool,
+  json: bool,
+  jsx: bool,
+  source_type: Option<SourceType>,
+  typescript: bool,
+}
+
+struct Input {
+  name: String,
+  source: String,
+}
+
+fn main() -> ExitCode {
+  let args = env::args().skip(1).collect::<Vec<String>>();
+
+  let command = match args.first().map(String::as_str) {
+    Some("check") => Command::Check,
+    Some("parse") => Command::Parse,
+    Some("print") => Command::Print,
+    Some("run") => Command::Run,
+    Some("tokens") => Command::Tokens,
+    Some("help" | "--help" | "-h") => {
+      println!("{USAGE}");
+      return ExitCode::SUCCESS;
+    }
+    Some(command) => return usage_error(&format!("unknown command `{command}`")),
+    None => return usage_error("no command given"),
+  };
+
+  let mut options = Options {
+    compact: false,
+    decorators: false,
+    json: false,
+    jsx: false,
+    source_type: None,
+    typescript: false,
+  };
+  let mut files = Vec::new();
+
+  for arg in &args[1..] {
+    match arg.as_str() {
+      "--compact" => options.compact = true,
+      "--decorators" if cfg!(feature = "decorators") => options.decorators = true,
+      "--decorators" => {
+        return usage_error("tower is built without the decorators feature");
+      }
+      "--json" => options.json = true,
+      "--jsx" => options.jsx = true,
+      "--module" => options.source_type = Some(SourceType::Module),
+      "--script" => options.source_type = Some(SourceType::Script),
+      "--typescript" => options.typescript = true,
+      "-" => files.push(arg.clone()),
+      _ if arg.starts_with('-') => return usage_error(&format!("unknown option `{arg}`")),
+      _ => files.push(arg.clone()),
+    }
+  }
+
+  if files.is_empty() {
+    files.push("-".to_string());
+  }
+
+  let mut failed = false;
+  for file in &files {
+    let input = match read_input(file) {
+      Ok(input) => input,
+      Err(error) => {
+        eprintln!("error: can't read {file}: {error}");
+        failed = true;
+        continue;
+      }
+    };
+
+    let source_type = options.source_type.unwrap_or(if file.ends_with(".mjs") {
+      SourceType::Module
+    } else {
+      SourceType::Script
+    });
+
+    let mut parse_options = ParseOptions::new(source_type);
+    parse_options.decorators = options.decorators;
+    parse_options.jsx = options.jsx || file.ends_with(".jsx");
+    parse_options.typescript = options.typescript || file.ends_with(".ts");
+
+    // a panic is a bug in tower, it's reported after the panic message and the other files still
+    // run
+    let executed = panic::catch_unwind(AssertUnwindSafe(|| {
+      execute(command, &options, parse_options, &input)
+    }));
+    match executed {
+      Ok(true) => {}
+      Ok(false) => failed = true,
+      Err(_) => {
+        eprintln!("error: {}: tower panicked, this is a bug", input.name);
+        failed = true;
+      }
+    }
+  }
+
+  if failed {
+    ExitCode::FAILURE
+  } else {
+    ExitCode::SUCCESS
+  }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+  eprintln!("error: {message}\n\n{USAGE}");
+  ExitCode::from(2)
+}
+
+fn read_input(file: &str) -> io::Result<Input> {
+  if file == "-" {
+    let mut source = String::new();
+    io::stdin().read_to_string(&mut source)?;
+    return Ok(Input {
+      name: "<stdin>".to_string(),
+      source,
+    });
+  }
+
+  Ok(Input {
+    name: file.to_string(),
+    source: read_to_string(file)?,
+  })
+}
+
+// runs the command on one source and reports what went wrong, false if anything did
+fn execute(
+  command: Command,
+  options: &Options,
+  parse_options: ParseOptions,
+  input: &Input,
+) -> bool {
+  let allocator = Bump::new();
+  let chars = allocator.alloc_slice_copy(&input.source.chars().collect::<Vec<char>>());
+  let lines = Lines::new(chars);
+
+  if command == Command::Tokens {
+    // the tokens up to a syntax error are still printed
+    let (tokens, parsed) = tokenize(&allocator, chars, parse_options);
+    for (token, span) in tokens {
+      let (line, column) = lines.position(span.start as usize);
+      println!("{line}:{column} {token:?}");
+    }
+
+    return match parsed {
+      Ok(()) => true,
+      Err(error) => {
+        eprint!("{}", render_error(&input.name, chars, &lines, &error));
+        false
+      }
+    };
+  }
+
+  let program = match parse_source_with_options(&allocator, chars, parse_options) {
+    Ok(program) => program,
+    Err(error) => {
+      eprint!("{}", render_error(&input.name, chars, &lines, &error));
+      return false;
+    }
+  };
+
+  match command {
+    Command::Check => {}
+    Command::Parse if options.json => {
+      println!("{}", stringify(&program_to_estree(program, chars), true));
+    }
+    Command::Parse => println!("{program:#?}"),
+    Command::Print => {
+      let print_options = if options.compact {
+        PrintOptions::compact()
+      } else {
+        PrintOptions::readable()
+      };
+      print!("{}", print_program(program, print_options));
+    }
+    Command::Run => {
+      eprintln!(
+        "error: {}: there's no runtime to run it with yet",
+        input.name
+      );
+      return false;
+    }
+    Command::Tokens => unreachable!(),
+  }
+
+  true
+}
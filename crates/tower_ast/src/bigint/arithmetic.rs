@@ -0,0 +1,173 @@
+use std::{
+  cmp::Ordering,
+  ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use super::{magnitude, BigInt, MAX_BITS};
+
+// the operators on owned values borrow them, the work is the same
+macro_rules! forward_binary_op {
+  ($trait:ident, $method:ident) => {
+    impl $trait for BigInt {
+      type Output = BigInt;
+
+      fn $method(self, other: BigInt) -> BigInt {
+        (&self).$method(&other)
+      }
+    }
+
+    impl $trait<&BigInt> for BigInt {
+      type Output = BigInt;
+
+      fn $method(self, other: &BigInt) -> BigInt {
+        (&self).$method(other)
+      }
+    }
+  };
+}
+
+pub(super) use forward_binary_op;
+
+impl BigInt {
+  // self + other with the other given as its sign and magnitude, subtraction flips the sign
+  fn add_signed(&self, other_negative: bool, other_parts: &[u64]) -> BigInt {
+    if self.negative == other_negative {
+      return BigInt::from_parts(self.negative, magnitude::add(&self.parts, other_parts));
+    }
+
+    match magnitude::compare(&self.parts, other_parts) {
+      Ordering::Less => {
+        BigInt::from_parts(other_negative, magnitude::sub(other_parts, &self.parts))
+      }
+      _ => BigInt::from_parts(self.negative, magnitude::sub(&self.parts, other_parts)),
+    }
+  }
+
+  // the quotient truncated towards zero and the remainder with the sign of the dividend, what
+  // BigInt::divide and BigInt::remainder need. None when dividing by zero, a RangeError
+  pub fn checked_div_rem(&self, divisor: &BigInt) -> Option<(BigInt, BigInt)> {
+    if divisor.is_zero() {
+      return None;
+    }
+
+    let (quotient, remainder) = magnitude::div_rem(&self.parts, &divisor.parts);
+    Some((
+      BigInt::from_parts(self.negative != divisor.negative, quotient),
+      BigInt::from_parts(self.negative, remainder),
+    ))
+  }
+
+  pub fn checked_div(&self, divisor: &BigInt) -> Option<BigInt> {
+    self.checked_div_rem(divisor).map(|(quotient, _)| quotient)
+  }
+
+  pub fn checked_rem(&self, divisor: &BigInt) -> Option<BigInt> {
+    self
+      .checked_div_rem(divisor)
+      .map(|(_, remainder)| remainder)
+  }
+
+  // BigInt::exponentiate, None for a negative exponent and for results over MAX_BITS, both a
+  // RangeError
+  pub fn pow(&self, exponent: &BigInt) -> Option<BigInt> {
+    if exponent.negative {
+      return None;
+    }
+
+    // the bases whose powers never grow
+    if exponent.is_zero() {
+      return Some(BigInt::from(1u64));
+    }
+    if self.is_zero() || self.parts == [1] {
+      let negative = self.negative && exponent.parts[0] & 1 == 1;
+      return Some(BigInt::from_parts(negative, self.parts.clone()));
+    }
+
+    let exponent = match exponent.parts[..] {
+      [exponent] if (self.bit_length() - 1).saturating_mul(exponent) < MAX_BITS => exponent,
+      _ => return None,
+    };
+
+    let mut result = BigInt::from(1u64);
+    let mut base = self.clone();
+    let mut remaining = exponent;
+    loop {
+      if remaining & 1 == 1 {
+        result = &result * &base;
+      }
+      remaining >>= 1;
+      if remaining == 0 {
+        break;
+      }
+      base = &base * &base;
+    }
+
+    Some(result)
+  }
+}
+
+impl Neg for &BigInt {
+  type Output = BigInt;
+
+  fn neg(self) -> BigInt {
+    BigInt::from_parts(!self.negative, self.parts.clone())
+  }
+}
+
+impl Neg for BigInt {
+  type Output = BigInt;
+
+  fn neg(self) -> BigInt {
+    BigInt::from_parts(!self.negative, self.parts)
+  }
+}
+
+impl Add for &BigInt {
+  type Output = BigInt;
+
+  fn add(self, other: &BigInt) -> BigInt {
+    self.add_signed(other.negative, &other.parts)
+  }
+}
+
+impl Sub for &BigInt {
+  type Output = BigInt;
+
+  fn sub(self, other: &BigInt) -> BigInt {
+    self.add_signed(!other.negative, &other.parts)
+  }
+}
+
+impl Mul for &BigInt {
+  type Output = BigInt;
+
+  fn mul(self, other: &BigInt) -> BigInt {
+    BigInt::from_parts(
+      self.negative != other.negative,
+      magnitude::mul(&self.parts, &other.parts),
+    )
+  }
+}
+
+// like the primitive integers these panic when dividing by zero, see checked_div
+impl Div for &BigInt {
+  type Output = BigInt;
+
+  fn div(self, other: &BigInt) -> BigInt {
+    self.checked_div(other).expect("division by zero")
+  }
+}
+
+impl Rem for &BigInt {
+  type Output = BigInt;
+
+  fn rem(self, other: &BigInt) -> BigInt {
+    self.checked_rem(other).expect("division by zero")
+  }
+}
+
+forward_binary_op!(Add, add);
+forward_binary_op!(Sub, sub);
+forward_binary_op!(Mul, mul);
+forward_binary_op!(Div, div);
+forward_binary_op!(Rem, rem);
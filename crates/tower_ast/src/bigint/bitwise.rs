@@ -0,0 +1,162 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use super::{arithmetic::forward_binary_op, magnitude, BigInt, MAX_BITS};
+
+// the bitwise operators act as if on infinitely sign extended two's complement, so negative
+// values are converted to it with one part more than either magnitude has, where the sign lives
+impl BigInt {
+  fn to_twos_complement(&self, length: usize) -> Vec<u64> {
+    let mut parts = self.parts.clone();
+    parts.resize(length, 0);
+
+    if self.negative {
+      let mut carry = true;
+      for part in &mut parts {
+        (*part, carry) = (!*part).overflowing_add(carry as u64);
+      }
+    }
+
+    parts
+  }
+
+  fn from_twos_complement(mut parts: Vec<u64>) -> BigInt {
+    let negative = parts.last().is_some_and(|last| last >> 63 == 1);
+
+    if negative {
+      let mut carry = true;
+      for part in &mut parts {
+        (*part, carry) = (!*part).overflowing_add(carry as u64);
+      }
+    }
+
+    BigInt::from_parts(negative, parts)
+  }
+
+  fn bitwise(&self, other: &BigInt, op: impl Fn(u64, u64) -> u64) -> BigInt {
+    let length = self.parts.len().max(other.parts.len()) + 1;
+    let a = self.to_twos_complement(length);
+    let b = other.to_twos_complement(length);
+
+    BigInt::from_twos_complement(a.iter().zip(&b).map(|(a, b)| op(*a, *b)).collect())
+  }
+
+  // BigInt::leftShift, self·2^amount, a negative amount shifts right rounding towards -infinity.
+  // None when the result would be over MAX_BITS, a RangeError
+  pub fn shift_left(&self, amount: &BigInt) -> Option<BigInt> {
+    if amount.negative {
+      return Some(self.shift_right_by(&amount.parts));
+    }
+
+    if self.is_zero() {
+      return Some(BigInt::zero());
+    }
+
+    match amount.parts[..] {
+      [] => Some(self.clone()),
+      [amount] if self.bit_length().saturating_add(amount) <= MAX_BITS => {
+        Some(self << amount as usize)
+      }
+      _ => None,
+    }
+  }
+
+  // BigInt::signedRightShift, the same as shifting left by -amount. there's no unsigned right
+  // shift, BigInt::unsignedRightShift always throws a TypeError
+  pub fn shift_right(&self, amount: &BigInt) -> Option<BigInt> {
+    self.shift_left(&-amount)
+  }
+
+  // by a magnitude, anything past the last bit leaves 0 or -1
+  fn shift_right_by(&self, amount: &[u64]) -> BigInt {
+    match amount {
+      [] => self.clone(),
+      [amount] if *amount < self.bit_length() => self >> *amount as usize,
+      _ if self.negative => BigInt::from(-1i64),
+      _ => BigInt::zero(),
+    }
+  }
+}
+
+impl Shl<usize> for &BigInt {
+  type Output = BigInt;
+
+  fn shl(self, amount: usize) -> BigInt {
+    BigInt::from_parts(self.negative, magnitude::shl(&self.parts, amount))
+  }
+}
+
+// an arithmetic shift, it rounds towards -infinity like the shift of a primitive integer
+impl Shr<usize> for &BigInt {
+  type Output = BigInt;
+
+  fn shr(self, amount: usize) -> BigInt {
+    let shifted = BigInt::from_parts(self.negative, magnitude::shr(&self.parts, amount));
+
+    if self.negative && magnitude::any_below(&self.parts, amount) {
+      &shifted - &BigInt::from(1u64)
+    } else {
+      shifted
+    }
+  }
+}
+
+impl Shl<usize> for BigInt {
+  type Output = BigInt;
+
+  fn shl(self, amount: usize) -> BigInt {
+    &self << amount
+  }
+}
+
+impl Shr<usize> for BigInt {
+  type Output = BigInt;
+
+  fn shr(self, amount: usize) -> BigInt {
+    &self >> amount
+  }
+}
+
+impl BitAnd for &BigInt {
+  type Output = BigInt;
+
+  fn bitand(self, other: &BigInt) -> BigInt {
+    self.bitwise(other, |a, b| a & b)
+  }
+}
+
+impl BitOr for &BigInt {
+  type Output = BigInt;
+
+  fn bitor(self, other: &BigInt) -> BigInt {
+    self.bitwise(other, |a, b| a | b)
+  }
+}
+
+impl BitXor for &BigInt {
+  type Output = BigInt;
+
+  fn bitxor(self, other: &BigInt) -> BigInt {
+    self.bitwise(other, |a, b| a ^ b)
+  }
+}
+
+// -x - 1
+impl Not for &BigInt {
+  type Output = BigInt;
+
+  fn not(self) -> BigInt {
+    -self - BigInt::from(1u64)
+  }
+}
+
+impl Not for BigInt {
+  type Output = BigInt;
+
+  fn not(self) -> BigInt {
+    !&self
+  }
+}
+
+forward_binary_op!(BitAnd, bitand);
+forward_binary_op!(BitOr, bitor);
+forward_binary_op!(BitXor, bitxor);
@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+use super::{magnitude, BigInt, MAX_BITS};
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+impl BigInt {
+  // Number::toString of a BigInt, lowercase digits in a radix from 2 to 36
+  pub fn to_string_radix(&self, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix {radix} is out of range");
+
+    // peel off as many digits as fit in a part at a time
+    let mut chunk_digits = 1;
+    let mut chunk = radix as u64;
+    while let Some(next) = chunk.checked_mul(radix as u64) {
+      chunk = next;
+      chunk_digits += 1;
+    }
+
+    let mut digits = Vec::new();
+    let mut parts = self.parts.clone();
+    while !parts.is_empty() {
+      let (quotient, mut remainder) = magnitude::div_rem_small(&parts, chunk);
+      parts = quotient;
+
+      for _ in 0..chunk_digits {
+        if parts.is_empty() && remainder == 0 {
+          break;
+        }
+        digits.push(DIGITS[(remainder % radix as u64) as usize]);
+        remainder /= radix as u64;
+      }
+    }
+
+    if digits.is_empty() {
+      digits.push(b'0');
+    }
+    if self.negative {
+      digits.push(b'-');
+    }
+
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+  }
+
+  // BigInt(number) and NumberToBigInt, None when the number isn't an integer, a RangeError
+  pub fn from_f64(value: f64) -> Option<BigInt> {
+    if !value.is_finite() || value.trunc() != value {
+      return None;
+    }
+
+    // value = mantissa·2^exponent with the hidden bit in the mantissa
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = match biased_exponent {
+      0 => (fraction, -1074),
+      _ => (fraction | 1 << 52, biased_exponent - 1075),
+    };
+
+    let magnitude = if exponent >= 0 {
+      magnitude::shl(&[mantissa], exponent as usize)
+    } else {
+      // an integer, so the bits shifted out are all zero
+      magnitude::shr(&[mantissa], -exponent as usize)
+    };
+
+    Some(BigInt::from_parts(value < 0.0, magnitude))
+  }
+
+  // Number(bigint), rounded to the nearest double with ties to even, ±Infinity past the largest
+  pub fn to_f64(&self) -> f64 {
+    let length = self.bit_length();
+
+    let magnitude = if length <= 64 {
+      self.parts.first().copied().unwrap_or(0) as f64
+    } else {
+      // the top 64 bits with the rest folded into the lowest one, far below the rounding bit, so
+      // a tie only stays one when everything below it is zero
+      let shift = (length - 64) as usize;
+      let top = magnitude::shr(&self.parts, shift)[0];
+      let sticky = magnitude::any_below(&self.parts, shift) as u64;
+
+      let exponent = shift.min(2048) as i32;
+      (top | sticky) as f64 * 2f64.powi(exponent)
+    };
+
+    if self.negative {
+      -magnitude
+    } else {
+      magnitude
+    }
+  }
+
+  // the value when it isn't negative and fits in a u64
+  pub fn to_u64(&self) -> Option<u64> {
+    match self.parts[..] {
+      _ if self.negative => None,
+      [] => Some(0),
+      [part] => Some(part),
+      _ => None,
+    }
+  }
+
+  // the exact order of a BigInt and a Number, None against NaN
+  pub fn partial_cmp_f64(&self, value: f64) -> Option<Ordering> {
+    if value.is_nan() {
+      return None;
+    }
+    if value == f64::INFINITY {
+      return Some(Ordering::Less);
+    }
+    if value == f64::NEG_INFINITY {
+      return Some(Ordering::Greater);
+    }
+
+    // an integer at or below the floor of a fraction is below the fraction, one above the floor
+    // is above it
+    let floor = value.floor();
+    let ordering = self.cmp(&BigInt::from_f64(floor)?);
+    Some(if floor != value && ordering == Ordering::Equal {
+      Ordering::Less
+    } else {
+      ordering
+    })
+  }
+
+  // BigInt.asUintN, the value modulo 2^bits. None when that's more than MAX_BITS, which only a
+  // negative value can make
+  pub fn as_uint_n(&self, bits: u64) -> Option<BigInt> {
+    if !self.negative && bits >= self.bit_length() {
+      return Some(self.clone());
+    }
+    if bits > MAX_BITS {
+      return None;
+    }
+
+    let low = BigInt::new(magnitude::low_bits(&self.parts, bits as usize));
+    if self.negative && !low.is_zero() {
+      Some(&(&BigInt::from(1u64) << bits as usize) - &low)
+    } else {
+      Some(low)
+    }
+  }
+
+  // BigInt.asIntN, the value modulo 2^bits in the range [-2^(bits - 1), 2^(bits - 1))
+  pub fn as_int_n(&self, bits: u64) -> BigInt {
+    if bits == 0 {
+      return BigInt::zero();
+    }
+
+    // a value that already fits comes back as it is, this also keeps huge widths from allocating
+    if bits > self.bit_length() {
+      return self.clone();
+    }
+
+    let unsigned = self.as_uint_n(bits).unwrap();
+    if unsigned.bit_length() == bits {
+      &unsigned - &(&BigInt::from(1u64) << bits as usize)
+    } else {
+      unsigned
+    }
+  }
+}
+
+// StringToBigInt, None when the text isn't an integer, a SyntaxError. it's the grammar of
+// StringToNumber without fractions, exponents, Infinity or a sign on prefixed literals, and
+// unlike a literal it has no separators and no `n`
+pub fn string_to_bigint(text: &str) -> Option<BigInt> {
+  let text = text.trim_matches(is_string_whitespace);
+  let chars = text.chars().collect::<Vec<_>>();
+
+  let (radix, digits) = match chars[..] {
+    ['0', 'x' | 'X', ..] => (16, &chars[2..]),
+    ['0', 'o' | 'O', ..] => (8, &chars[2..]),
+    ['0', 'b' | 'B', ..] => (2, &chars[2..]),
+    _ => (10, &chars[..]),
+  };
+
+  let (negative, digits) = match (radix, digits) {
+    (10, ['-', rest @ ..]) => (true, rest),
+    (10, ['+', rest @ ..]) => (false, rest),
+    _ => (false, digits),
+  };
+
+  // only an empty string, or one of only whitespace, is 0n without digits
+  if digits.is_empty() && !text.is_empty() {
+    return None;
+  }
+  if !digits.iter().all(|digit| digit.is_digit(radix)) {
+    return None;
+  }
+
+  let value = match radix {
+    16 => BigInt::from_hex_str(digits),
+    8 => BigInt::from_octal_str(digits),
+    2 => BigInt::from_binary_str(digits),
+    _ => BigInt::from_decimal_str(digits),
+  };
+
+  Some(if negative { -value } else { value })
+}
+
+// WhiteSpace and LineTerminator, what StringToBigInt and StringToNumber trim from both ends.
+// that's unicode's White_Space without the next line control, and the byte order mark
+pub fn is_string_whitespace(c: char) -> bool {
+  c == '\u{FEFF}' || (c.is_whitespace() && c != '\u{0085}')
+}
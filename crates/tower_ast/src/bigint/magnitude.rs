@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+
+// the arithmetic on magnitudes, little endian u64 limbs without the sign. the results never end
+// in zero limbs, the inputs may
+
+// below this many limbs the schoolbook product is faster than splitting
+const KARATSUBA_THRESHOLD: usize = 32;
+
+pub fn trim(parts: &mut Vec<u64>) {
+  while parts.last() == Some(&0) {
+    parts.pop();
+  }
+}
+
+fn trimmed(parts: &[u64]) -> &[u64] {
+  let length = parts
+    .iter()
+    .rposition(|part| *part != 0)
+    .map_or(0, |i| i + 1);
+  &parts[..length]
+}
+
+pub fn compare(a: &[u64], b: &[u64]) -> Ordering {
+  let (a, b) = (trimmed(a), trimmed(b));
+  a.len()
+    .cmp(&b.len())
+    .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+  let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+  let mut sum = Vec::with_capacity(long.len() + 1);
+  let mut carry = false;
+
+  for (i, part) in long.iter().enumerate() {
+    let (value, next_carry) = part.carrying_add(short.get(i).copied().unwrap_or(0), carry);
+    sum.push(value);
+    carry = next_carry;
+  }
+
+  if carry {
+    sum.push(1);
+  }
+
+  trim(&mut sum);
+  sum
+}
+
+// a - b where a >= b
+pub fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+  let mut difference = Vec::with_capacity(a.len());
+  let mut borrow = false;
+
+  for (i, part) in a.iter().enumerate() {
+    let (value, next_borrow) = part.borrowing_sub(b.get(i).copied().unwrap_or(0), borrow);
+    difference.push(value);
+    borrow = next_borrow;
+  }
+
+  debug_assert!(!borrow && trimmed(b).len() <= a.len());
+  trim(&mut difference);
+  difference
+}
+
+pub fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+  let (a, b) = (trimmed(a), trimmed(b));
+  if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+    return schoolbook_mul(a, b);
+  }
+
+  // a = a1·B^m + a0 and b = b1·B^m + b0, then
+  // a·b = z2·B^2m + (z1 - z2 - z0)·B^m + z0 with z1 = (a0 + a1)·(b0 + b1)
+  let m = a.len().max(b.len()) / 2;
+  let (a0, a1) = a.split_at(m.min(a.len()));
+  let (b0, b1) = b.split_at(m.min(b.len()));
+
+  let z0 = mul(a0, b0);
+  let z2 = mul(a1, b1);
+  let z1 = mul(&add(a0, a1), &add(b0, b1));
+  let middle = sub(&sub(&z1, &z0), &z2);
+
+  let mut product = vec![0; a.len() + b.len()];
+  add_at(&mut product, &z0, 0);
+  add_at(&mut product, &middle, m);
+  add_at(&mut product, &z2, 2 * m);
+
+  trim(&mut product);
+  product
+}
+
+fn schoolbook_mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+  if a.is_empty() || b.is_empty() {
+    return Vec::new();
+  }
+
+  let mut product = vec![0; a.len() + b.len()];
+  for (i, x) in a.iter().enumerate() {
+    let mut carry = 0;
+    for (j, y) in b.iter().enumerate() {
+      let value = *x as u128 * *y as u128 + product[i + j] as u128 + carry as u128;
+      product[i + j] = value as u64;
+      carry = (value >> 64) as u64;
+    }
+    product[i + b.len()] = carry;
+  }
+
+  trim(&mut product);
+  product
+}
+
+// target += value·B^offset, the target has room for the sum
+fn add_at(target: &mut [u64], value: &[u64], offset: usize) {
+  let mut carry = false;
+  let mut i = offset;
+
+  for part in value {
+    (target[i], carry) = target[i].carrying_add(*part, carry);
+    i += 1;
+  }
+
+  while carry {
+    (target[i], carry) = target[i].overflowing_add(1);
+    i += 1;
+  }
+}
+
+// the quotient and remainder of a / b for b != 0, Knuth's algorithm D
+pub fn div_rem(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+  let (a, b) = (trimmed(a), trimmed(b));
+  assert!(!b.is_empty(), "division by zero");
+
+  if compare(a, b) == Ordering::Less {
+    return (Vec::new(), a.to_vec());
+  }
+
+  if let [divisor] = b {
+    let (quotient, remainder) = div_rem_small(a, *divisor);
+    let mut remainder = vec![remainder];
+    trim(&mut remainder);
+    return (quotient, remainder);
+  }
+
+  // normalize so the top limb of the divisor has its high bit set, which keeps each estimated
+  // quotient digit at most two too large
+  let shift = b.last().unwrap().leading_zeros() as usize;
+  let divisor = shl(b, shift);
+  let mut dividend = shl(a, shift);
+  dividend.resize(a.len() + 1, 0);
+
+  let n = divisor.len();
+  let top = divisor[n - 1] as u128;
+  let next = divisor[n - 2] as u128;
+  let mut quotient = vec![0; dividend.len() - n];
+
+  for j in (0..quotient.len()).rev() {
+    let numerator = (dividend[j + n] as u128) << 64 | dividend[j + n - 1] as u128;
+    let mut estimate = numerator / top;
+    let mut remainder = numerator % top;
+
+    while estimate >> 64 != 0 || estimate * next > (remainder << 64 | dividend[j + n - 2] as u128) {
+      estimate -= 1;
+      remainder += top;
+      if remainder >> 64 != 0 {
+        break;
+      }
+    }
+
+    // dividend[j..] -= estimate·divisor
+    let mut carry = 0u64;
+    let mut borrow = false;
+    for i in 0..n {
+      let product = estimate * divisor[i] as u128 + carry as u128;
+      carry = (product >> 64) as u64;
+      (dividend[i + j], borrow) = dividend[i + j].borrowing_sub(product as u64, borrow);
+    }
+    let (value, overflow) = dividend[j + n].overflowing_sub(carry);
+    let (value, borrow) = value.overflowing_sub(borrow as u64);
+    dividend[j + n] = value;
+
+    // the estimate was one too large, add the divisor back
+    if overflow || borrow {
+      estimate -= 1;
+      let mut carry = false;
+      for i in 0..n {
+        (dividend[i + j], carry) = dividend[i + j].carrying_add(divisor[i], carry);
+      }
+      dividend[j + n] = dividend[j + n].wrapping_add(carry as u64);
+    }
+
+    quotient[j] = estimate as u64;
+  }
+
+  trim(&mut quotient);
+  (quotient, shr(&dividend[..n], shift))
+}
+
+pub fn div_rem_small(a: &[u64], divisor: u64) -> (Vec<u64>, u64) {
+  let mut quotient = vec![0; a.len()];
+  let mut remainder = 0u64;
+
+  for (i, part) in a.iter().enumerate().rev() {
+    let value = (remainder as u128) << 64 | *part as u128;
+    quotient[i] = (value / divisor as u128) as u64;
+    remainder = (value % divisor as u128) as u64;
+  }
+
+  trim(&mut quotient);
+  (quotient, remainder)
+}
+
+pub fn shl(a: &[u64], shift: usize) -> Vec<u64> {
+  let (limbs, bits) = (shift / 64, shift % 64);
+  let mut shifted = vec![0; limbs];
+  shifted.reserve(a.len() + 1);
+
+  if bits == 0 {
+    shifted.extend_from_slice(a);
+  } else {
+    let mut carry = 0;
+    for part in a {
+      shifted.push(part << bits | carry);
+      carry = part >> (64 - bits);
+    }
+    shifted.push(carry);
+  }
+
+  trim(&mut shifted);
+  shifted
+}
+
+pub fn shr(a: &[u64], shift: usize) -> Vec<u64> {
+  let (limbs, bits) = (shift / 64, shift % 64);
+  if limbs >= a.len() {
+    return Vec::new();
+  }
+
+  let a = &a[limbs..];
+  let mut shifted = Vec::with_capacity(a.len());
+  for (i, part) in a.iter().enumerate() {
+    let high = match (bits, a.get(i + 1)) {
+      (0, _) | (_, None) => 0,
+      (_, Some(next)) => next << (64 - bits),
+    };
+    shifted.push(part >> bits | high);
+  }
+
+  trim(&mut shifted);
+  shifted
+}
+
+// whether any of the bits below `shift` are set, what a right shift drops
+pub fn any_below(a: &[u64], shift: usize) -> bool {
+  let (limbs, bits) = (shift / 64, shift % 64);
+  a.iter().take(limbs).any(|part| *part != 0)
+    || (bits != 0
+      && a
+        .get(limbs)
+        .is_some_and(|part| part & ((1 << bits) - 1) != 0))
+}
+
+// the magnitude modulo 2^bits
+pub fn low_bits(a: &[u64], bits: usize) -> Vec<u64> {
+  let (limbs, bits) = (bits / 64, bits % 64);
+  let mut low = a[..limbs.min(a.len())].to_vec();
+
+  if bits != 0 {
+    if let Some(part) = a.get(limbs) {
+      low.push(part & ((1 << bits) - 1));
+    }
+  }
+
+  trim(&mut low);
+  low
+}
@@ -0,0 +1,159 @@
+use std::{cmp::Ordering, fmt};
+
+mod arithmetic;
+mod bitwise;
+mod conversion;
+mod magnitude;
+
+pub use conversion::{is_string_whitespace, string_to_bigint};
+
+// an arbitrary precision integer, a sign and the magnitude in little endian u64 parts. the parts
+// never end in a zero part and zero is never negative, so equal values are equal structurally
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+  negative: bool,
+  parts: Vec<u64>,
+}
+
+// the largest result in bits an operation makes before it gives up, what a runtime reports as a
+// RangeError. engines have limits of the same size
+pub const MAX_BITS: u64 = 1 << 30;
+
+impl BigInt {
+  pub fn new(parts: Vec<u64>) -> Self {
+    Self::from_parts(false, parts)
+  }
+
+  fn from_parts(negative: bool, mut parts: Vec<u64>) -> Self {
+    magnitude::trim(&mut parts);
+    Self {
+      negative: negative && !parts.is_empty(),
+      parts,
+    }
+  }
+
+  pub fn zero() -> Self {
+    Self::default()
+  }
+
+  pub fn is_zero(&self) -> bool {
+    self.parts.is_empty()
+  }
+
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+  // the number of bits of the magnitude
+  pub fn bit_length(&self) -> u64 {
+    match self.parts.last() {
+      None => 0,
+      Some(last) => self.parts.len() as u64 * 64 - last.leading_zeros() as u64,
+    }
+  }
+
+  pub fn abs(&self) -> Self {
+    Self::from_parts(false, self.parts.clone())
+  }
+
+  pub fn from_decimal_str(chars: &[char]) -> Self {
+    let mut self_ = BigInt::new(Vec::new());
+    for digit in chars {
+      let mut carry = *digit as u64 & 15;
+
+      for i in 0..self_.parts.len() {
+        (self_.parts[i], carry) = self_.parts[i].carrying_mul(10, carry);
+      }
+
+      if carry > 0 {
+        self_.parts.push(carry);
+      }
+    }
+    self_
+  }
+
+  pub fn from_octal_str(chars: &[char]) -> Self {
+    Self::from_power_of_two_digits(chars, 3)
+  }
+
+  pub fn from_hex_str(chars: &[char]) -> Self {
+    Self::from_power_of_two_digits(chars, 4)
+  }
+
+  pub fn from_binary_str(chars: &[char]) -> Self {
+    Self::from_power_of_two_digits(chars, 1)
+  }
+
+  // digits of `bits` bits each, the last one lowest. a digit can straddle two parts
+  fn from_power_of_two_digits(chars: &[char], bits: usize) -> Self {
+    let mut parts = vec![0u64; (chars.len() * bits).div_ceil(64)];
+
+    for (index, c) in chars.iter().rev().enumerate() {
+      let value = c.to_digit(16).unwrap_or(0) as u64;
+      let bit_index = index * bits;
+      let (part_index, part_bit_index) = (bit_index / 64, bit_index % 64);
+
+      parts[part_index] |= value << part_bit_index;
+      if part_bit_index + bits > 64 {
+        parts[part_index + 1] |= value >> (64 - part_bit_index);
+      }
+    }
+
+    BigInt::new(parts)
+  }
+}
+
+impl From<u64> for BigInt {
+  fn from(value: u64) -> Self {
+    Self::new(vec![value])
+  }
+}
+
+impl From<i64> for BigInt {
+  fn from(value: i64) -> Self {
+    Self::from_parts(value < 0, vec![value.unsigned_abs()])
+  }
+}
+
+impl From<u128> for BigInt {
+  fn from(value: u128) -> Self {
+    Self::new(vec![value as u64, (value >> 64) as u64])
+  }
+}
+
+impl From<i128> for BigInt {
+  fn from(value: i128) -> Self {
+    let magnitude = value.unsigned_abs();
+    Self::from_parts(value < 0, vec![magnitude as u64, (magnitude >> 64) as u64])
+  }
+}
+
+impl Ord for BigInt {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self.negative, other.negative) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => magnitude::compare(&self.parts, &other.parts),
+      (true, true) => magnitude::compare(&other.parts, &self.parts),
+    }
+  }
+}
+
+impl PartialOrd for BigInt {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl fmt::Display for BigInt {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.to_string_radix(10))
+  }
+}
+
+// the value as a literal, so trees print the number rather than the parts
+impl fmt::Debug for BigInt {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{self}n")
+  }
+}
@@ -0,0 +1,56 @@
+use bumpalo::collections::Vec;
+
+use super::{expression::Expression, object::PropertyName, Identifier, Span};
+
+#[derive(Debug, Clone, Copy)]
+pub enum BindingPattern<'a> {
+  Array(&'a ArrayBindingPattern<'a>),
+  Identifier(&'a Identifier<'a>),
+  Object(&'a ObjectBindingPattern<'a>),
+}
+
+impl BindingPattern<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      BindingPattern::Array(pattern) => pattern.span,
+      BindingPattern::Identifier(identifier) => identifier.span,
+      BindingPattern::Object(pattern) => pattern.span,
+    }
+  }
+}
+
+// the span covers the pattern and its initializer
+#[derive(Debug, Clone, Copy)]
+pub struct BindingPatternInitializer<'a> {
+  pub initializer: Option<Expression<'a>>,
+  pub pattern: BindingPattern<'a>,
+  pub span: Span,
+}
+
+// `...pattern` at the end of parameters and patterns
+#[derive(Debug, Clone, Copy)]
+pub struct BindingRestElement<'a> {
+  pub pattern: BindingPattern<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayBindingPattern<'a> {
+  pub elements: Vec<'a, Option<BindingPatternInitializer<'a>>>,
+  pub rest: Option<BindingRestElement<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectBindingPattern<'a> {
+  pub properties: Vec<'a, ObjectBindingProperty<'a>>,
+  pub rest: Option<BindingRestElement<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectBindingProperty<'a> {
+  pub binding: BindingPatternInitializer<'a>,
+  pub property: PropertyName<'a>,
+  pub span: Span,
+}
@@ -0,0 +1,109 @@
+use bumpalo::collections::Vec;
+
+use super::{
+  binding::BindingPatternInitializer,
+  expression::Expression,
+  function::FormalParameters,
+  statement::{Block, Statement},
+  Identifier, PropertyKey, Span,
+};
+
+// the body span runs from the opening to the closing brace
+#[derive(Debug, Clone)]
+pub struct ClassDefinition<'a> {
+  pub identifier: Option<Identifier<'a>>,
+  pub heritage: Option<Expression<'a>>,
+  pub body: Vec<'a, ClassElement<'a>>,
+  pub body_span: Span,
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ClassElement<'a> {
+  Accessor(&'a ClassAccessor<'a>),
+  Field(&'a ClassField<'a>),
+  Getter(&'a ClassGetter<'a>),
+  Method(&'a ClassMethod<'a>),
+  Setter(&'a ClassSetter<'a>),
+  Static(&'a ClassStaticBlock<'a>),
+}
+
+impl ClassElement<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      ClassElement::Accessor(accessor) => accessor.span,
+      ClassElement::Field(field) => field.span,
+      ClassElement::Getter(getter) => getter.span,
+      ClassElement::Method(method) => method.span,
+      ClassElement::Setter(setter) => setter.span,
+      ClassElement::Static(block) => block.span,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ClassElementName<'a> {
+  Computed(Expression<'a>),
+  // the span of a private name includes its `#`
+  Private(Identifier<'a>),
+  Static(PropertyKey<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassAccessor<'a> {
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub name: ClassElementName<'a>,
+  pub span: Span,
+  pub r#static: bool,
+  pub value: Option<Expression<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassField<'a> {
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub name: ClassElementName<'a>,
+  pub span: Span,
+  pub r#static: bool,
+  pub value: Option<Expression<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassMethod<'a> {
+  pub r#async: bool,
+  pub body: Block<'a>,
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub generator: bool,
+  pub name: ClassElementName<'a>,
+  pub parameters: FormalParameters<'a>,
+  pub span: Span,
+  pub r#static: bool,
+}
+
+// the parameters span runs from the opening to the closing parenthesis
+#[derive(Debug, Clone)]
+pub struct ClassGetter<'a> {
+  pub body: Block<'a>,
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub name: ClassElementName<'a>,
+  pub parameters_span: Span,
+  pub span: Span,
+  pub r#static: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassSetter<'a> {
+  pub body: Block<'a>,
+  pub decorators: Vec<'a, Expression<'a>>,
+  pub name: ClassElementName<'a>,
+  pub parameter: BindingPatternInitializer<'a>,
+  pub parameters_span: Span,
+  pub span: Span,
+  pub r#static: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassStaticBlock<'a> {
+  pub span: Span,
+  pub statements: Vec<'a, Statement<'a>>,
+}
@@ -0,0 +1,239 @@
+use bumpalo::collections::Vec;
+
+use crate::bigint::BigInt;
+
+use super::{
+  class::ClassDefinition,
+  function::{Argument, ArrowFunctionDefinition, FunctionDefinition},
+  jsx::{JsxElement, JsxFragment},
+  object::ObjectProperty,
+  op::{AssignmentOpExpression, BinaryOpExpression, UnaryOpExpression},
+  string::JsStr,
+  Identifier, Span,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Expression<'a> {
+  Array(&'a ArrayLiteral<'a>),
+  ArrowFunction(&'a ArrowFunctionDefinition<'a>),
+  Assignment(&'a AssignmentOpExpression<'a>),
+  BigInt(&'a BigIntLiteral),
+  BinaryOp(&'a BinaryOpExpression<'a>),
+  Boolean(&'a BooleanLiteral),
+  Call(&'a CallExpression<'a>),
+  // an optional chain has the span of the expression it wraps
+  Chain(&'a Expression<'a>),
+  Class(&'a ClassDefinition<'a>),
+  Conditional(&'a ConditionalExpression<'a>),
+  Group(&'a GroupExpression<'a>),
+  Function(&'a FunctionDefinition<'a>),
+  Identifier(&'a Identifier<'a>),
+  Import(&'a ImportCall<'a>),
+  ImportMeta(Span),
+  In(&'a InExpression<'a>),
+  JsxElement(&'a JsxElement<'a>),
+  JsxFragment(&'a JsxFragment<'a>),
+  List(&'a ListExpression<'a>),
+  Member(&'a MemberExpression<'a>),
+  NewTarget(Span),
+  New(&'a NewExpression<'a>),
+  Null(Span),
+  Number(&'a NumberLiteral),
+  Object(&'a ObjectLiteral<'a>),
+  RegExp(&'a RegExpLiteral<'a>),
+  String(&'a StringLiteral<'a>),
+  Super(Span),
+  TaggedTemplate(&'a TaggedTemplateLiteral<'a>),
+  Template(&'a TemplateLiteral<'a>),
+  This(Span),
+  UnaryOp(&'a UnaryOpExpression<'a>),
+  Yield(&'a YieldExpression<'a>),
+}
+
+impl Expression<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      Expression::Array(literal) => literal.span,
+      Expression::ArrowFunction(definition) => definition.span,
+      Expression::Assignment(expression) => expression.span,
+      Expression::BigInt(literal) => literal.span,
+      Expression::BinaryOp(expression) => expression.span,
+      Expression::Boolean(literal) => literal.span,
+      Expression::Call(expression) => expression.span,
+      Expression::Chain(expression) => expression.span(),
+      Expression::Class(definition) => definition.span,
+      Expression::Conditional(expression) => expression.span,
+      Expression::Group(expression) => expression.span,
+      Expression::Function(definition) => definition.span,
+      Expression::Identifier(identifier) => identifier.span,
+      Expression::Import(expression) => expression.span,
+      Expression::ImportMeta(span)
+      | Expression::NewTarget(span)
+      | Expression::Null(span)
+      | Expression::Super(span)
+      | Expression::This(span) => *span,
+      Expression::In(expression) => expression.span,
+      Expression::JsxElement(element) => element.span,
+      Expression::JsxFragment(fragment) => fragment.span,
+      Expression::List(expression) => expression.span,
+      Expression::Member(expression) => expression.span,
+      Expression::New(expression) => expression.span,
+      Expression::Number(literal) => literal.span,
+      Expression::Object(literal) => literal.span,
+      Expression::RegExp(literal) => literal.span,
+      Expression::String(literal) => literal.span,
+      Expression::TaggedTemplate(literal) => literal.span,
+      Expression::Template(literal) => literal.span,
+      Expression::UnaryOp(expression) => expression.span,
+      Expression::Yield(expression) => expression.span,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrayLiteral<'a> {
+  pub elements: Vec<'a, ArrayElement<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ArrayElement<'a> {
+  Elision,
+  Expression(Expression<'a>),
+  Spread(SpreadElement<'a>),
+}
+
+// `...argument` in array literals, arguments and object literals
+#[derive(Debug, Clone, Copy)]
+pub struct SpreadElement<'a> {
+  pub argument: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectLiteral<'a> {
+  pub properties: Vec<'a, ObjectProperty<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct BigIntLiteral {
+  pub span: Span,
+  pub value: BigInt,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BooleanLiteral {
+  pub span: Span,
+  pub value: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NumberLiteral {
+  pub span: Span,
+  pub value: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StringLiteral<'a> {
+  pub span: Span,
+  pub value: JsStr<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RegExpLiteral<'a> {
+  pub flags: &'a str,
+  pub source: &'a str,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GroupExpression<'a> {
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListExpression<'a> {
+  pub expressions: Vec<'a, Expression<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImportCall<'a> {
+  pub argument: Expression<'a>,
+  pub span: Span,
+}
+
+// `string_spans` has the span of each string between the backticks and substitutions
+#[derive(Debug, Clone)]
+pub struct TemplateLiteral<'a> {
+  pub span: Span,
+  pub string_spans: Vec<'a, Span>,
+  pub strings: Vec<'a, JsStr<'a>>,
+  pub substitutions: Vec<'a, Expression<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum MemberName<'a> {
+  Computed(Expression<'a>),
+  // the span of a private name includes its `#`
+  Private(Identifier<'a>),
+  Static(Identifier<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MemberExpression<'a> {
+  pub object: Expression<'a>,
+  pub optional: bool,
+  pub property: MemberName<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InExpression<'a> {
+  pub name: MemberName<'a>,
+  pub argument: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaggedTemplateLiteral<'a> {
+  pub raw_strings: Vec<'a, JsStr<'a>>,
+  pub span: Span,
+  pub string_spans: Vec<'a, Span>,
+  pub strings: Vec<'a, Option<JsStr<'a>>>,
+  pub substitutions: Vec<'a, Expression<'a>>,
+  pub tag: Expression<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewExpression<'a> {
+  pub arguments: Option<Vec<'a, Argument<'a>>>,
+  pub callee: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct CallExpression<'a> {
+  pub arguments: Vec<'a, Argument<'a>>,
+  pub callee: Expression<'a>,
+  pub optional: bool,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionalExpression<'a> {
+  pub alternate: Expression<'a>,
+  pub condition: Expression<'a>,
+  pub consequent: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct YieldExpression<'a> {
+  pub argument: Option<Expression<'a>>,
+  // `yield*`
+  pub delegate: bool,
+  pub span: Span,
+}
@@ -0,0 +1,1418 @@
+use bumpalo::{collections::Vec, Bump};
+
+use crate::{
+  binding::{
+    ArrayBindingPattern, BindingPattern, BindingPatternInitializer, BindingRestElement,
+    ObjectBindingPattern, ObjectBindingProperty,
+  },
+  class::{
+    ClassAccessor, ClassDefinition, ClassElement, ClassElementName, ClassField, ClassGetter,
+    ClassMethod, ClassSetter, ClassStaticBlock,
+  },
+  expression::{
+    ArrayElement, ArrayLiteral, CallExpression, ConditionalExpression, Expression, GroupExpression,
+    ImportCall, InExpression, ListExpression, MemberExpression, MemberName, NewExpression,
+    ObjectLiteral, SpreadElement, TaggedTemplateLiteral, TemplateLiteral, YieldExpression,
+  },
+  function::{
+    Argument, ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition,
+  },
+  jsx::{
+    JsxAttribute, JsxAttributeValue, JsxChild, JsxElement, JsxElementName, JsxExpressionContainer,
+    JsxFragment,
+  },
+  object::{
+    ObjectGetter, ObjectMethod, ObjectProperty, ObjectSetter, PropertyDefinition, PropertyName,
+  },
+  op::{AssignmentOpExpression, BinaryOpExpression, UnaryOpExpression},
+  statement::{
+    Block, CatchBlock, DoWhileStatement, ExpressionStatement, ForBinding, ForInStatement,
+    ForInitializer, ForOfStatement, ForStatement, IfStatement, LabelStatement, LexicalDeclaration,
+    ReturnStatement, Statement, SwitchCase, SwitchStatement, ThrowStatement, TryStatement,
+    VariableDeclaration, WhileStatement, WithStatement,
+  },
+  Identifier, Program,
+};
+
+// a fold takes every node by value and builds the node that replaces it, so a transform can swap
+// a node for one of a different kind, e.g. an expression statement for an empty one. the rebuilt
+// nodes are allocated in the arena and the original tree is left untouched
+pub trait Fold<'a> {
+  fn allocator(&self) -> &'a Bump;
+
+  fn fold_program(&mut self, program: Program<'a>) -> Program<'a> {
+    walk_program(self, program)
+  }
+
+  fn fold_statement_list(&mut self, statements: Vec<'a, Statement<'a>>) -> Vec<'a, Statement<'a>> {
+    walk_statement_list(self, statements)
+  }
+
+  fn fold_statement(&mut self, statement: Statement<'a>) -> Statement<'a> {
+    walk_statement(self, statement)
+  }
+
+  fn fold_variable_declaration(
+    &mut self,
+    bindings: Vec<'a, BindingPatternInitializer<'a>>,
+  ) -> Vec<'a, BindingPatternInitializer<'a>> {
+    walk_variable_declaration(self, bindings)
+  }
+
+  fn fold_lexical_declaration(
+    &mut self,
+    declaration: LexicalDeclaration<'a>,
+  ) -> LexicalDeclaration<'a> {
+    walk_lexical_declaration(self, declaration)
+  }
+
+  fn fold_if_statement(&mut self, statement: IfStatement<'a>) -> IfStatement<'a> {
+    walk_if_statement(self, statement)
+  }
+
+  fn fold_do_while_statement(&mut self, statement: DoWhileStatement<'a>) -> DoWhileStatement<'a> {
+    walk_do_while_statement(self, statement)
+  }
+
+  fn fold_while_statement(&mut self, statement: WhileStatement<'a>) -> WhileStatement<'a> {
+    walk_while_statement(self, statement)
+  }
+
+  fn fold_for_statement(&mut self, statement: ForStatement<'a>) -> ForStatement<'a> {
+    walk_for_statement(self, statement)
+  }
+
+  fn fold_for_initializer(&mut self, initializer: ForInitializer<'a>) -> ForInitializer<'a> {
+    walk_for_initializer(self, initializer)
+  }
+
+  fn fold_for_in_statement(&mut self, statement: ForInStatement<'a>) -> ForInStatement<'a> {
+    walk_for_in_statement(self, statement)
+  }
+
+  fn fold_for_of_statement(&mut self, statement: ForOfStatement<'a>) -> ForOfStatement<'a> {
+    walk_for_of_statement(self, statement)
+  }
+
+  fn fold_for_binding(&mut self, binding: ForBinding<'a>) -> ForBinding<'a> {
+    walk_for_binding(self, binding)
+  }
+
+  fn fold_switch_statement(&mut self, statement: SwitchStatement<'a>) -> SwitchStatement<'a> {
+    walk_switch_statement(self, statement)
+  }
+
+  fn fold_switch_case(&mut self, case: SwitchCase<'a>) -> SwitchCase<'a> {
+    walk_switch_case(self, case)
+  }
+
+  fn fold_with_statement(&mut self, statement: WithStatement<'a>) -> WithStatement<'a> {
+    walk_with_statement(self, statement)
+  }
+
+  fn fold_label_statement(&mut self, statement: LabelStatement<'a>) -> LabelStatement<'a> {
+    walk_label_statement(self, statement)
+  }
+
+  fn fold_try_statement(&mut self, statement: TryStatement<'a>) -> TryStatement<'a> {
+    walk_try_statement(self, statement)
+  }
+
+  fn fold_catch_block(&mut self, catch: CatchBlock<'a>) -> CatchBlock<'a> {
+    walk_catch_block(self, catch)
+  }
+
+  fn fold_expression(&mut self, expression: Expression<'a>) -> Expression<'a> {
+    walk_expression(self, expression)
+  }
+
+  fn fold_identifier_reference(&mut self, identifier: Identifier<'a>) -> Identifier<'a> {
+    identifier
+  }
+
+  fn fold_binding_identifier(&mut self, identifier: Identifier<'a>) -> Identifier<'a> {
+    identifier
+  }
+
+  fn fold_array_element(&mut self, element: ArrayElement<'a>) -> ArrayElement<'a> {
+    walk_array_element(self, element)
+  }
+
+  fn fold_template_literal(&mut self, literal: TemplateLiteral<'a>) -> TemplateLiteral<'a> {
+    walk_template_literal(self, literal)
+  }
+
+  fn fold_tagged_template_literal(
+    &mut self,
+    literal: TaggedTemplateLiteral<'a>,
+  ) -> TaggedTemplateLiteral<'a> {
+    walk_tagged_template_literal(self, literal)
+  }
+
+  fn fold_member_expression(&mut self, expression: MemberExpression<'a>) -> MemberExpression<'a> {
+    walk_member_expression(self, expression)
+  }
+
+  fn fold_member_name(&mut self, name: MemberName<'a>) -> MemberName<'a> {
+    walk_member_name(self, name)
+  }
+
+  fn fold_in_expression(&mut self, expression: InExpression<'a>) -> InExpression<'a> {
+    walk_in_expression(self, expression)
+  }
+
+  fn fold_call_expression(&mut self, expression: CallExpression<'a>) -> CallExpression<'a> {
+    walk_call_expression(self, expression)
+  }
+
+  fn fold_new_expression(&mut self, expression: NewExpression<'a>) -> NewExpression<'a> {
+    walk_new_expression(self, expression)
+  }
+
+  fn fold_argument(&mut self, argument: Argument<'a>) -> Argument<'a> {
+    walk_argument(self, argument)
+  }
+
+  fn fold_conditional_expression(
+    &mut self,
+    expression: ConditionalExpression<'a>,
+  ) -> ConditionalExpression<'a> {
+    walk_conditional_expression(self, expression)
+  }
+
+  fn fold_yield_expression(&mut self, expression: YieldExpression<'a>) -> YieldExpression<'a> {
+    walk_yield_expression(self, expression)
+  }
+
+  fn fold_unary_op_expression(
+    &mut self,
+    expression: UnaryOpExpression<'a>,
+  ) -> UnaryOpExpression<'a> {
+    walk_unary_op_expression(self, expression)
+  }
+
+  fn fold_binary_op_expression(
+    &mut self,
+    expression: BinaryOpExpression<'a>,
+  ) -> BinaryOpExpression<'a> {
+    walk_binary_op_expression(self, expression)
+  }
+
+  fn fold_assignment_op_expression(
+    &mut self,
+    expression: AssignmentOpExpression<'a>,
+  ) -> AssignmentOpExpression<'a> {
+    walk_assignment_op_expression(self, expression)
+  }
+
+  fn fold_object_property(&mut self, property: ObjectProperty<'a>) -> ObjectProperty<'a> {
+    walk_object_property(self, property)
+  }
+
+  fn fold_property_name(&mut self, name: PropertyName<'a>) -> PropertyName<'a> {
+    walk_property_name(self, name)
+  }
+
+  fn fold_property_definition(
+    &mut self,
+    definition: PropertyDefinition<'a>,
+  ) -> PropertyDefinition<'a> {
+    walk_property_definition(self, definition)
+  }
+
+  fn fold_object_method(&mut self, method: ObjectMethod<'a>) -> ObjectMethod<'a> {
+    walk_object_method(self, method)
+  }
+
+  fn fold_object_getter(&mut self, getter: ObjectGetter<'a>) -> ObjectGetter<'a> {
+    walk_object_getter(self, getter)
+  }
+
+  fn fold_object_setter(&mut self, setter: ObjectSetter<'a>) -> ObjectSetter<'a> {
+    walk_object_setter(self, setter)
+  }
+
+  fn fold_function_definition(
+    &mut self,
+    definition: FunctionDefinition<'a>,
+  ) -> FunctionDefinition<'a> {
+    walk_function_definition(self, definition)
+  }
+
+  fn fold_arrow_function_definition(
+    &mut self,
+    definition: ArrowFunctionDefinition<'a>,
+  ) -> ArrowFunctionDefinition<'a> {
+    walk_arrow_function_definition(self, definition)
+  }
+
+  fn fold_formal_parameters(&mut self, parameters: FormalParameters<'a>) -> FormalParameters<'a> {
+    walk_formal_parameters(self, parameters)
+  }
+
+  fn fold_binding_pattern(&mut self, pattern: BindingPattern<'a>) -> BindingPattern<'a> {
+    walk_binding_pattern(self, pattern)
+  }
+
+  fn fold_binding_pattern_initializer(
+    &mut self,
+    binding: BindingPatternInitializer<'a>,
+  ) -> BindingPatternInitializer<'a> {
+    walk_binding_pattern_initializer(self, binding)
+  }
+
+  fn fold_array_binding_pattern(
+    &mut self,
+    pattern: ArrayBindingPattern<'a>,
+  ) -> ArrayBindingPattern<'a> {
+    walk_array_binding_pattern(self, pattern)
+  }
+
+  fn fold_object_binding_pattern(
+    &mut self,
+    pattern: ObjectBindingPattern<'a>,
+  ) -> ObjectBindingPattern<'a> {
+    walk_object_binding_pattern(self, pattern)
+  }
+
+  fn fold_object_binding_property(
+    &mut self,
+    property: ObjectBindingProperty<'a>,
+  ) -> ObjectBindingProperty<'a> {
+    walk_object_binding_property(self, property)
+  }
+
+  fn fold_class_definition(&mut self, definition: ClassDefinition<'a>) -> ClassDefinition<'a> {
+    walk_class_definition(self, definition)
+  }
+
+  fn fold_class_element(&mut self, element: ClassElement<'a>) -> ClassElement<'a> {
+    walk_class_element(self, element)
+  }
+
+  fn fold_class_element_name(&mut self, name: ClassElementName<'a>) -> ClassElementName<'a> {
+    walk_class_element_name(self, name)
+  }
+
+  fn fold_class_accessor(&mut self, accessor: ClassAccessor<'a>) -> ClassAccessor<'a> {
+    walk_class_accessor(self, accessor)
+  }
+
+  fn fold_class_field(&mut self, field: ClassField<'a>) -> ClassField<'a> {
+    walk_class_field(self, field)
+  }
+
+  fn fold_class_method(&mut self, method: ClassMethod<'a>) -> ClassMethod<'a> {
+    walk_class_method(self, method)
+  }
+
+  fn fold_class_getter(&mut self, getter: ClassGetter<'a>) -> ClassGetter<'a> {
+    walk_class_getter(self, getter)
+  }
+
+  fn fold_class_setter(&mut self, setter: ClassSetter<'a>) -> ClassSetter<'a> {
+    walk_class_setter(self, setter)
+  }
+
+  fn fold_class_static_block(&mut self, block: ClassStaticBlock<'a>) -> ClassStaticBlock<'a> {
+    walk_class_static_block(self, block)
+  }
+
+  fn fold_jsx_element(&mut self, element: JsxElement<'a>) -> JsxElement<'a> {
+    walk_jsx_element(self, element)
+  }
+
+  fn fold_jsx_element_name(&mut self, name: JsxElementName<'a>) -> JsxElementName<'a> {
+    name
+  }
+
+  fn fold_jsx_attribute(&mut self, attribute: JsxAttribute<'a>) -> JsxAttribute<'a> {
+    walk_jsx_attribute(self, attribute)
+  }
+
+  fn fold_jsx_attribute_value(&mut self, value: JsxAttributeValue<'a>) -> JsxAttributeValue<'a> {
+    walk_jsx_attribute_value(self, value)
+  }
+
+  fn fold_jsx_child(&mut self, child: JsxChild<'a>) -> JsxChild<'a> {
+    walk_jsx_child(self, child)
+  }
+}
+
+fn fold_vec<'a, V: Fold<'a> + ?Sized, T>(
+  visitor: &mut V,
+  nodes: Vec<'a, T>,
+  mut fold: impl FnMut(&mut V, T) -> T,
+) -> Vec<'a, T> {
+  let allocator = visitor.allocator();
+  Vec::from_iter_in(nodes.into_iter().map(|node| fold(visitor, node)), allocator)
+}
+
+fn fold_expressions<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expressions: Vec<'a, Expression<'a>>,
+) -> Vec<'a, Expression<'a>> {
+  fold_vec(visitor, expressions, |visitor, expression| {
+    visitor.fold_expression(expression)
+  })
+}
+
+fn fold_block<'a, V: Fold<'a> + ?Sized>(visitor: &mut V, block: Block<'a>) -> Block<'a> {
+  Block {
+    span: block.span,
+    statements: visitor.fold_statement_list(block.statements),
+  }
+}
+
+fn fold_spread<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  spread: SpreadElement<'a>,
+) -> SpreadElement<'a> {
+  SpreadElement {
+    argument: visitor.fold_expression(spread.argument),
+    span: spread.span,
+  }
+}
+
+fn fold_rest<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  rest: BindingRestElement<'a>,
+) -> BindingRestElement<'a> {
+  BindingRestElement {
+    pattern: visitor.fold_binding_pattern(rest.pattern),
+    span: rest.span,
+  }
+}
+
+fn fold_jsx_fragment<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  fragment: &'a JsxFragment<'a>,
+) -> &'a JsxFragment<'a> {
+  let fragment = fragment.clone();
+  let children = fold_vec(visitor, fragment.children, |visitor, child| {
+    visitor.fold_jsx_child(child)
+  });
+  visitor.allocator().alloc(JsxFragment {
+    children,
+    ..fragment
+  })
+}
+
+fn fold_jsx_container<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  container: &'a JsxExpressionContainer<'a>,
+) -> &'a JsxExpressionContainer<'a> {
+  let container = JsxExpressionContainer {
+    expression: visitor.fold_expression(container.expression),
+    span: container.span,
+  };
+  visitor.allocator().alloc(container)
+}
+
+pub fn walk_program<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  program: Program<'a>,
+) -> Program<'a> {
+  Program {
+    statement_list: visitor.fold_statement_list(program.statement_list),
+    ..program
+  }
+}
+
+pub fn walk_statement_list<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statements: Vec<'a, Statement<'a>>,
+) -> Vec<'a, Statement<'a>> {
+  fold_vec(visitor, statements, |visitor, statement| {
+    visitor.fold_statement(statement)
+  })
+}
+
+pub fn walk_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: Statement<'a>,
+) -> Statement<'a> {
+  let allocator = visitor.allocator();
+
+  match statement {
+    Statement::Block(block) => {
+      Statement::Block(allocator.alloc(fold_block(visitor, block.clone())))
+    }
+    Statement::Break(_) | Statement::Continue(_) | Statement::Debugger(_) | Statement::Empty(_) => {
+      statement
+    }
+    Statement::Class(definition) => {
+      Statement::Class(allocator.alloc(visitor.fold_class_definition(definition.clone())))
+    }
+    Statement::DoWhile(statement) => {
+      Statement::DoWhile(allocator.alloc(visitor.fold_do_while_statement(*statement)))
+    }
+    Statement::Expression(statement) => {
+      Statement::Expression(allocator.alloc(ExpressionStatement {
+        expression: visitor.fold_expression(statement.expression),
+        span: statement.span,
+      }))
+    }
+    Statement::For(statement) => {
+      Statement::For(allocator.alloc(visitor.fold_for_statement(*statement)))
+    }
+    Statement::ForIn(statement) => {
+      Statement::ForIn(allocator.alloc(visitor.fold_for_in_statement(*statement)))
+    }
+    Statement::ForOf(statement) => {
+      Statement::ForOf(allocator.alloc(visitor.fold_for_of_statement(*statement)))
+    }
+    Statement::Function(definition) => {
+      Statement::Function(allocator.alloc(visitor.fold_function_definition(definition.clone())))
+    }
+    Statement::If(statement) => {
+      Statement::If(allocator.alloc(visitor.fold_if_statement(*statement)))
+    }
+    Statement::Label(statement) => {
+      Statement::Label(allocator.alloc(visitor.fold_label_statement(*statement)))
+    }
+    Statement::Lexical(declaration) => {
+      Statement::Lexical(allocator.alloc(visitor.fold_lexical_declaration(declaration.clone())))
+    }
+    Statement::Return(statement) => Statement::Return(
+      allocator.alloc(ReturnStatement {
+        argument: statement
+          .argument
+          .map(|argument| visitor.fold_expression(argument)),
+        span: statement.span,
+      }),
+    ),
+    Statement::Switch(statement) => {
+      Statement::Switch(allocator.alloc(visitor.fold_switch_statement(statement.clone())))
+    }
+    Statement::Throw(statement) => Statement::Throw(allocator.alloc(ThrowStatement {
+      argument: visitor.fold_expression(statement.argument),
+      span: statement.span,
+    })),
+    Statement::Try(statement) => {
+      Statement::Try(allocator.alloc(visitor.fold_try_statement(statement.clone())))
+    }
+    Statement::Variable(declaration) => Statement::Variable(allocator.alloc(VariableDeclaration {
+      bindings: visitor.fold_variable_declaration(declaration.bindings.clone()),
+      span: declaration.span,
+    })),
+    Statement::With(statement) => {
+      Statement::With(allocator.alloc(visitor.fold_with_statement(*statement)))
+    }
+    Statement::While(statement) => {
+      Statement::While(allocator.alloc(visitor.fold_while_statement(*statement)))
+    }
+  }
+}
+
+pub fn walk_variable_declaration<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  bindings: Vec<'a, BindingPatternInitializer<'a>>,
+) -> Vec<'a, BindingPatternInitializer<'a>> {
+  fold_vec(visitor, bindings, |visitor, binding| {
+    visitor.fold_binding_pattern_initializer(binding)
+  })
+}
+
+pub fn walk_lexical_declaration<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  declaration: LexicalDeclaration<'a>,
+) -> LexicalDeclaration<'a> {
+  LexicalDeclaration {
+    bindings: visitor.fold_variable_declaration(declaration.bindings),
+    kind: declaration.kind,
+    span: declaration.span,
+  }
+}
+
+pub fn walk_if_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: IfStatement<'a>,
+) -> IfStatement<'a> {
+  IfStatement {
+    condition: visitor.fold_expression(statement.condition),
+    consequent: visitor.fold_statement(statement.consequent),
+    alternate: statement
+      .alternate
+      .map(|alternate| visitor.fold_statement(alternate)),
+    span: statement.span,
+  }
+}
+
+pub fn walk_do_while_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: DoWhileStatement<'a>,
+) -> DoWhileStatement<'a> {
+  DoWhileStatement {
+    body: visitor.fold_statement(statement.body),
+    condition: visitor.fold_expression(statement.condition),
+    span: statement.span,
+  }
+}
+
+pub fn walk_while_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: WhileStatement<'a>,
+) -> WhileStatement<'a> {
+  WhileStatement {
+    condition: visitor.fold_expression(statement.condition),
+    body: visitor.fold_statement(statement.body),
+    span: statement.span,
+  }
+}
+
+pub fn walk_for_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: ForStatement<'a>,
+) -> ForStatement<'a> {
+  ForStatement {
+    initializer: statement
+      .initializer
+      .map(|initializer| visitor.fold_for_initializer(initializer)),
+    condition: statement
+      .condition
+      .map(|condition| visitor.fold_expression(condition)),
+    update: statement
+      .update
+      .map(|update| visitor.fold_expression(update)),
+    body: visitor.fold_statement(statement.body),
+    span: statement.span,
+  }
+}
+
+pub fn walk_for_initializer<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  initializer: ForInitializer<'a>,
+) -> ForInitializer<'a> {
+  let allocator = visitor.allocator();
+
+  match initializer {
+    ForInitializer::Expression(expression) => {
+      ForInitializer::Expression(visitor.fold_expression(expression))
+    }
+    ForInitializer::Lexical(declaration) => ForInitializer::Lexical(
+      allocator.alloc(visitor.fold_lexical_declaration(declaration.clone())),
+    ),
+    ForInitializer::Variable(declaration) => {
+      ForInitializer::Variable(allocator.alloc(VariableDeclaration {
+        bindings: visitor.fold_variable_declaration(declaration.bindings.clone()),
+        span: declaration.span,
+      }))
+    }
+  }
+}
+
+pub fn walk_for_in_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: ForInStatement<'a>,
+) -> ForInStatement<'a> {
+  ForInStatement {
+    binding: visitor.fold_for_binding(statement.binding),
+    expression: visitor.fold_expression(statement.expression),
+    body: visitor.fold_statement(statement.body),
+    span: statement.span,
+  }
+}
+
+pub fn walk_for_of_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: ForOfStatement<'a>,
+) -> ForOfStatement<'a> {
+  ForOfStatement {
+    r#await: statement.r#await,
+    binding: visitor.fold_for_binding(statement.binding),
+    expression: visitor.fold_expression(statement.expression),
+    body: visitor.fold_statement(statement.body),
+    span: statement.span,
+  }
+}
+
+pub fn walk_for_binding<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  binding: ForBinding<'a>,
+) -> ForBinding<'a> {
+  match binding {
+    ForBinding::Expression(expression) => {
+      ForBinding::Expression(visitor.fold_expression(expression))
+    }
+    ForBinding::Lexical(kind, pattern, span) => {
+      ForBinding::Lexical(kind, visitor.fold_binding_pattern(pattern), span)
+    }
+    ForBinding::Variable(binding, span) => {
+      ForBinding::Variable(visitor.fold_binding_pattern_initializer(binding), span)
+    }
+  }
+}
+
+pub fn walk_switch_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: SwitchStatement<'a>,
+) -> SwitchStatement<'a> {
+  let expression = visitor.fold_expression(statement.expression);
+  SwitchStatement {
+    cases: fold_vec(visitor, statement.cases, |visitor, case| {
+      visitor.fold_switch_case(case)
+    }),
+    expression,
+    span: statement.span,
+  }
+}
+
+pub fn walk_switch_case<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  case: SwitchCase<'a>,
+) -> SwitchCase<'a> {
+  SwitchCase {
+    expression: case
+      .expression
+      .map(|expression| visitor.fold_expression(expression)),
+    body: visitor.fold_statement_list(case.body),
+    span: case.span,
+  }
+}
+
+pub fn walk_with_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: WithStatement<'a>,
+) -> WithStatement<'a> {
+  WithStatement {
+    expression: visitor.fold_expression(statement.expression),
+    body: visitor.fold_statement(statement.body),
+    span: statement.span,
+  }
+}
+
+pub fn walk_label_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: LabelStatement<'a>,
+) -> LabelStatement<'a> {
+  LabelStatement {
+    label: statement.label,
+    span: statement.span,
+    statement: visitor.fold_statement(statement.statement),
+  }
+}
+
+pub fn walk_try_statement<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: TryStatement<'a>,
+) -> TryStatement<'a> {
+  TryStatement {
+    body: fold_block(visitor, statement.body),
+    catch: statement.catch.map(|catch| visitor.fold_catch_block(catch)),
+    finally: statement
+      .finally
+      .map(|finally| fold_block(visitor, finally)),
+    span: statement.span,
+  }
+}
+
+pub fn walk_catch_block<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  catch: CatchBlock<'a>,
+) -> CatchBlock<'a> {
+  CatchBlock {
+    parameter: catch
+      .parameter
+      .map(|parameter| visitor.fold_binding_pattern(parameter)),
+    body: fold_block(visitor, catch.body),
+    span: catch.span,
+  }
+}
+
+pub fn walk_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: Expression<'a>,
+) -> Expression<'a> {
+  let allocator = visitor.allocator();
+
+  match expression {
+    Expression::Array(literal) => {
+      let elements = fold_vec(visitor, literal.elements.clone(), |visitor, element| {
+        visitor.fold_array_element(element)
+      });
+      Expression::Array(allocator.alloc(ArrayLiteral {
+        elements,
+        span: literal.span,
+      }))
+    }
+    Expression::ArrowFunction(definition) => Expression::ArrowFunction(
+      allocator.alloc(visitor.fold_arrow_function_definition(definition.clone())),
+    ),
+    Expression::Assignment(expression) => {
+      Expression::Assignment(allocator.alloc(visitor.fold_assignment_op_expression(*expression)))
+    }
+    Expression::BigInt(_)
+    | Expression::Boolean(_)
+    | Expression::ImportMeta(_)
+    | Expression::NewTarget(_)
+    | Expression::Null(_)
+    | Expression::Number(_)
+    | Expression::RegExp(_)
+    | Expression::String(_)
+    | Expression::Super(_)
+    | Expression::This(_) => expression,
+    Expression::BinaryOp(expression) => {
+      Expression::BinaryOp(allocator.alloc(visitor.fold_binary_op_expression(*expression)))
+    }
+    Expression::Call(expression) => {
+      Expression::Call(allocator.alloc(visitor.fold_call_expression(expression.clone())))
+    }
+    Expression::Chain(expression) => {
+      Expression::Chain(allocator.alloc(visitor.fold_expression(*expression)))
+    }
+    Expression::Class(definition) => {
+      Expression::Class(allocator.alloc(visitor.fold_class_definition(definition.clone())))
+    }
+    Expression::Conditional(expression) => {
+      Expression::Conditional(allocator.alloc(visitor.fold_conditional_expression(*expression)))
+    }
+    Expression::Group(expression) => Expression::Group(allocator.alloc(GroupExpression {
+      expression: visitor.fold_expression(expression.expression),
+      span: expression.span,
+    })),
+    Expression::Function(definition) => {
+      Expression::Function(allocator.alloc(visitor.fold_function_definition(definition.clone())))
+    }
+    Expression::Identifier(identifier) => {
+      Expression::Identifier(allocator.alloc(visitor.fold_identifier_reference(*identifier)))
+    }
+    Expression::Import(expression) => Expression::Import(allocator.alloc(ImportCall {
+      argument: visitor.fold_expression(expression.argument),
+      span: expression.span,
+    })),
+    Expression::In(expression) => {
+      Expression::In(allocator.alloc(visitor.fold_in_expression(*expression)))
+    }
+    Expression::JsxElement(element) => {
+      Expression::JsxElement(allocator.alloc(visitor.fold_jsx_element(element.clone())))
+    }
+    Expression::JsxFragment(fragment) => {
+      Expression::JsxFragment(fold_jsx_fragment(visitor, fragment))
+    }
+    Expression::List(expression) => Expression::List(allocator.alloc(ListExpression {
+      expressions: fold_expressions(visitor, expression.expressions.clone()),
+      span: expression.span,
+    })),
+    Expression::Member(expression) => {
+      Expression::Member(allocator.alloc(visitor.fold_member_expression(*expression)))
+    }
+    Expression::New(expression) => {
+      Expression::New(allocator.alloc(visitor.fold_new_expression(expression.clone())))
+    }
+    Expression::Object(literal) => {
+      let properties = fold_vec(visitor, literal.properties.clone(), |visitor, property| {
+        visitor.fold_object_property(property)
+      });
+      Expression::Object(allocator.alloc(ObjectLiteral {
+        properties,
+        span: literal.span,
+      }))
+    }
+    Expression::TaggedTemplate(literal) => Expression::TaggedTemplate(
+      allocator.alloc(visitor.fold_tagged_template_literal(literal.clone())),
+    ),
+    Expression::Template(literal) => {
+      Expression::Template(allocator.alloc(visitor.fold_template_literal(literal.clone())))
+    }
+    Expression::UnaryOp(expression) => {
+      Expression::UnaryOp(allocator.alloc(visitor.fold_unary_op_expression(*expression)))
+    }
+    Expression::Yield(expression) => {
+      Expression::Yield(allocator.alloc(visitor.fold_yield_expression(*expression)))
+    }
+  }
+}
+
+pub fn walk_array_element<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  element: ArrayElement<'a>,
+) -> ArrayElement<'a> {
+  match element {
+    ArrayElement::Elision => ArrayElement::Elision,
+    ArrayElement::Expression(expression) => {
+      ArrayElement::Expression(visitor.fold_expression(expression))
+    }
+    ArrayElement::Spread(spread) => ArrayElement::Spread(fold_spread(visitor, spread)),
+  }
+}
+
+pub fn walk_template_literal<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: TemplateLiteral<'a>,
+) -> TemplateLiteral<'a> {
+  TemplateLiteral {
+    span: literal.span,
+    string_spans: literal.string_spans,
+    strings: literal.strings,
+    substitutions: fold_expressions(visitor, literal.substitutions),
+  }
+}
+
+pub fn walk_tagged_template_literal<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: TaggedTemplateLiteral<'a>,
+) -> TaggedTemplateLiteral<'a> {
+  let tag = visitor.fold_expression(literal.tag);
+  TaggedTemplateLiteral {
+    raw_strings: literal.raw_strings,
+    span: literal.span,
+    string_spans: literal.string_spans,
+    strings: literal.strings,
+    substitutions: fold_expressions(visitor, literal.substitutions),
+    tag,
+  }
+}
+
+pub fn walk_member_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: MemberExpression<'a>,
+) -> MemberExpression<'a> {
+  MemberExpression {
+    object: visitor.fold_expression(expression.object),
+    optional: expression.optional,
+    property: visitor.fold_member_name(expression.property),
+    span: expression.span,
+  }
+}
+
+pub fn walk_member_name<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  name: MemberName<'a>,
+) -> MemberName<'a> {
+  match name {
+    MemberName::Computed(expression) => MemberName::Computed(visitor.fold_expression(expression)),
+    MemberName::Private(_) | MemberName::Static(_) => name,
+  }
+}
+
+pub fn walk_in_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: InExpression<'a>,
+) -> InExpression<'a> {
+  InExpression {
+    name: visitor.fold_member_name(expression.name),
+    argument: visitor.fold_expression(expression.argument),
+    span: expression.span,
+  }
+}
+
+pub fn walk_call_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: CallExpression<'a>,
+) -> CallExpression<'a> {
+  let callee = visitor.fold_expression(expression.callee);
+  CallExpression {
+    arguments: fold_vec(visitor, expression.arguments, |visitor, argument| {
+      visitor.fold_argument(argument)
+    }),
+    callee,
+    optional: expression.optional,
+    span: expression.span,
+  }
+}
+
+pub fn walk_new_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: NewExpression<'a>,
+) -> NewExpression<'a> {
+  let callee = visitor.fold_expression(expression.callee);
+  NewExpression {
+    arguments: expression.arguments.map(|arguments| {
+      fold_vec(visitor, arguments, |visitor, argument| {
+        visitor.fold_argument(argument)
+      })
+    }),
+    callee,
+    span: expression.span,
+  }
+}
+
+pub fn walk_argument<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  argument: Argument<'a>,
+) -> Argument<'a> {
+  match argument {
+    Argument::Positional(expression) => Argument::Positional(visitor.fold_expression(expression)),
+    Argument::Spread(spread) => Argument::Spread(fold_spread(visitor, spread)),
+  }
+}
+
+pub fn walk_conditional_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: ConditionalExpression<'a>,
+) -> ConditionalExpression<'a> {
+  ConditionalExpression {
+    condition: visitor.fold_expression(expression.condition),
+    consequent: visitor.fold_expression(expression.consequent),
+    alternate: visitor.fold_expression(expression.alternate),
+    span: expression.span,
+  }
+}
+
+pub fn walk_yield_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: YieldExpression<'a>,
+) -> YieldExpression<'a> {
+  YieldExpression {
+    argument: expression
+      .argument
+      .map(|argument| visitor.fold_expression(argument)),
+    delegate: expression.delegate,
+    span: expression.span,
+  }
+}
+
+pub fn walk_unary_op_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: UnaryOpExpression<'a>,
+) -> UnaryOpExpression<'a> {
+  UnaryOpExpression {
+    op: expression.op,
+    argument: visitor.fold_expression(expression.argument),
+    span: expression.span,
+  }
+}
+
+pub fn walk_binary_op_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: BinaryOpExpression<'a>,
+) -> BinaryOpExpression<'a> {
+  BinaryOpExpression {
+    op: expression.op,
+    left: visitor.fold_expression(expression.left),
+    right: visitor.fold_expression(expression.right),
+    span: expression.span,
+  }
+}
+
+pub fn walk_assignment_op_expression<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: AssignmentOpExpression<'a>,
+) -> AssignmentOpExpression<'a> {
+  AssignmentOpExpression {
+    op: expression.op,
+    left: visitor.fold_expression(expression.left),
+    right: visitor.fold_expression(expression.right),
+    span: expression.span,
+  }
+}
+
+pub fn walk_object_property<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  property: ObjectProperty<'a>,
+) -> ObjectProperty<'a> {
+  let allocator = visitor.allocator();
+
+  match property {
+    ObjectProperty::Getter(getter) => {
+      ObjectProperty::Getter(allocator.alloc(visitor.fold_object_getter(getter.clone())))
+    }
+    ObjectProperty::Method(method) => {
+      ObjectProperty::Method(allocator.alloc(visitor.fold_object_method(method.clone())))
+    }
+    ObjectProperty::Property(definition) => {
+      ObjectProperty::Property(allocator.alloc(visitor.fold_property_definition(*definition)))
+    }
+    ObjectProperty::Setter(setter) => {
+      ObjectProperty::Setter(allocator.alloc(visitor.fold_object_setter(setter.clone())))
+    }
+    ObjectProperty::Shorthand(identifier) => {
+      ObjectProperty::Shorthand(allocator.alloc(visitor.fold_identifier_reference(*identifier)))
+    }
+    ObjectProperty::Spread(spread) => ObjectProperty::Spread(fold_spread(visitor, spread)),
+  }
+}
+
+pub fn walk_property_name<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  name: PropertyName<'a>,
+) -> PropertyName<'a> {
+  match name {
+    PropertyName::Computed(expression) => {
+      PropertyName::Computed(visitor.fold_expression(expression))
+    }
+    PropertyName::Static(_) => name,
+  }
+}
+
+pub fn walk_property_definition<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: PropertyDefinition<'a>,
+) -> PropertyDefinition<'a> {
+  PropertyDefinition {
+    property: visitor.fold_property_name(definition.property),
+    expression: visitor.fold_expression(definition.expression),
+    span: definition.span,
+  }
+}
+
+pub fn walk_object_method<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  method: ObjectMethod<'a>,
+) -> ObjectMethod<'a> {
+  ObjectMethod {
+    r#async: method.r#async,
+    generator: method.generator,
+    property: visitor.fold_property_name(method.property),
+    parameters: visitor.fold_formal_parameters(method.parameters),
+    body: fold_block(visitor, method.body),
+    span: method.span,
+  }
+}
+
+pub fn walk_object_getter<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  getter: ObjectGetter<'a>,
+) -> ObjectGetter<'a> {
+  ObjectGetter {
+    property: visitor.fold_property_name(getter.property),
+    parameters_span: getter.parameters_span,
+    body: fold_block(visitor, getter.body),
+    span: getter.span,
+  }
+}
+
+pub fn walk_object_setter<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  setter: ObjectSetter<'a>,
+) -> ObjectSetter<'a> {
+  ObjectSetter {
+    property: visitor.fold_property_name(setter.property),
+    parameter: visitor.fold_binding_pattern_initializer(setter.parameter),
+    parameters_span: setter.parameters_span,
+    body: fold_block(visitor, setter.body),
+    span: setter.span,
+  }
+}
+
+pub fn walk_function_definition<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: FunctionDefinition<'a>,
+) -> FunctionDefinition<'a> {
+  FunctionDefinition {
+    r#async: definition.r#async,
+    generator: definition.generator,
+    identifier: definition
+      .identifier
+      .map(|identifier| visitor.fold_binding_identifier(identifier)),
+    parameters: visitor.fold_formal_parameters(definition.parameters),
+    body: fold_block(visitor, definition.body),
+    span: definition.span,
+  }
+}
+
+pub fn walk_arrow_function_definition<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: ArrowFunctionDefinition<'a>,
+) -> ArrowFunctionDefinition<'a> {
+  let parameters = visitor.fold_formal_parameters(definition.parameters);
+  let body = match definition.body {
+    ConciseBody::Block(block) => ConciseBody::Block(fold_block(visitor, block)),
+    ConciseBody::Expression(expression) => {
+      ConciseBody::Expression(visitor.fold_expression(expression))
+    }
+  };
+
+  ArrowFunctionDefinition {
+    r#async: definition.r#async,
+    body,
+    parameters,
+    span: definition.span,
+  }
+}
+
+pub fn walk_formal_parameters<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  parameters: FormalParameters<'a>,
+) -> FormalParameters<'a> {
+  FormalParameters {
+    bindings: visitor.fold_variable_declaration(parameters.bindings),
+    rest: parameters.rest.map(|rest| fold_rest(visitor, rest)),
+    span: parameters.span,
+  }
+}
+
+pub fn walk_binding_pattern<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: BindingPattern<'a>,
+) -> BindingPattern<'a> {
+  let allocator = visitor.allocator();
+
+  match pattern {
+    BindingPattern::Array(pattern) => {
+      BindingPattern::Array(allocator.alloc(visitor.fold_array_binding_pattern(pattern.clone())))
+    }
+    BindingPattern::Identifier(identifier) => {
+      BindingPattern::Identifier(allocator.alloc(visitor.fold_binding_identifier(*identifier)))
+    }
+    BindingPattern::Object(pattern) => {
+      BindingPattern::Object(allocator.alloc(visitor.fold_object_binding_pattern(pattern.clone())))
+    }
+  }
+}
+
+pub fn walk_binding_pattern_initializer<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  binding: BindingPatternInitializer<'a>,
+) -> BindingPatternInitializer<'a> {
+  BindingPatternInitializer {
+    pattern: visitor.fold_binding_pattern(binding.pattern),
+    initializer: binding
+      .initializer
+      .map(|initializer| visitor.fold_expression(initializer)),
+    span: binding.span,
+  }
+}
+
+pub fn walk_array_binding_pattern<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: ArrayBindingPattern<'a>,
+) -> ArrayBindingPattern<'a> {
+  ArrayBindingPattern {
+    elements: fold_vec(visitor, pattern.elements, |visitor, element| {
+      element.map(|element| visitor.fold_binding_pattern_initializer(element))
+    }),
+    rest: pattern.rest.map(|rest| fold_rest(visitor, rest)),
+    span: pattern.span,
+  }
+}
+
+pub fn walk_object_binding_pattern<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: ObjectBindingPattern<'a>,
+) -> ObjectBindingPattern<'a> {
+  ObjectBindingPattern {
+    properties: fold_vec(visitor, pattern.properties, |visitor, property| {
+      visitor.fold_object_binding_property(property)
+    }),
+    rest: pattern.rest.map(|rest| fold_rest(visitor, rest)),
+    span: pattern.span,
+  }
+}
+
+pub fn walk_object_binding_property<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  property: ObjectBindingProperty<'a>,
+) -> ObjectBindingProperty<'a> {
+  ObjectBindingProperty {
+    property: visitor.fold_property_name(property.property),
+    binding: visitor.fold_binding_pattern_initializer(property.binding),
+    span: property.span,
+  }
+}
+
+pub fn walk_class_definition<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: ClassDefinition<'a>,
+) -> ClassDefinition<'a> {
+  let decorators = fold_expressions(visitor, definition.decorators);
+  let identifier = definition
+    .identifier
+    .map(|identifier| visitor.fold_binding_identifier(identifier));
+  let heritage = definition
+    .heritage
+    .map(|heritage| visitor.fold_expression(heritage));
+
+  ClassDefinition {
+    identifier,
+    heritage,
+    body: fold_vec(visitor, definition.body, |visitor, element| {
+      visitor.fold_class_element(element)
+    }),
+    body_span: definition.body_span,
+    decorators,
+    span: definition.span,
+  }
+}
+
+pub fn walk_class_element<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  element: ClassElement<'a>,
+) -> ClassElement<'a> {
+  let allocator = visitor.allocator();
+
+  match element {
+    ClassElement::Accessor(accessor) => {
+      ClassElement::Accessor(allocator.alloc(visitor.fold_class_accessor(accessor.clone())))
+    }
+    ClassElement::Field(field) => {
+      ClassElement::Field(allocator.alloc(visitor.fold_class_field(field.clone())))
+    }
+    ClassElement::Getter(getter) => {
+      ClassElement::Getter(allocator.alloc(visitor.fold_class_getter(getter.clone())))
+    }
+    ClassElement::Method(method) => {
+      ClassElement::Method(allocator.alloc(visitor.fold_class_method(method.clone())))
+    }
+    ClassElement::Setter(setter) => {
+      ClassElement::Setter(allocator.alloc(visitor.fold_class_setter(setter.clone())))
+    }
+    ClassElement::Static(block) => {
+      ClassElement::Static(allocator.alloc(visitor.fold_class_static_block(block.clone())))
+    }
+  }
+}
+
+pub fn walk_class_element_name<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  name: ClassElementName<'a>,
+) -> ClassElementName<'a> {
+  match name {
+    ClassElementName::Computed(expression) => {
+      ClassElementName::Computed(visitor.fold_expression(expression))
+    }
+    ClassElementName::Private(_) | ClassElementName::Static(_) => name,
+  }
+}
+
+pub fn walk_class_accessor<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  accessor: ClassAccessor<'a>,
+) -> ClassAccessor<'a> {
+  ClassAccessor {
+    decorators: fold_expressions(visitor, accessor.decorators),
+    name: visitor.fold_class_element_name(accessor.name),
+    span: accessor.span,
+    r#static: accessor.r#static,
+    value: accessor.value.map(|value| visitor.fold_expression(value)),
+  }
+}
+
+pub fn walk_class_field<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  field: ClassField<'a>,
+) -> ClassField<'a> {
+  ClassField {
+    decorators: fold_expressions(visitor, field.decorators),
+    name: visitor.fold_class_element_name(field.name),
+    span: field.span,
+    r#static: field.r#static,
+    value: field.value.map(|value| visitor.fold_expression(value)),
+  }
+}
+
+pub fn walk_class_method<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  method: ClassMethod<'a>,
+) -> ClassMethod<'a> {
+  ClassMethod {
+    r#async: method.r#async,
+    decorators: fold_expressions(visitor, method.decorators),
+    generator: method.generator,
+    name: visitor.fold_class_element_name(method.name),
+    parameters: visitor.fold_formal_parameters(method.parameters),
+    body: fold_block(visitor, method.body),
+    span: method.span,
+    r#static: method.r#static,
+  }
+}
+
+pub fn walk_class_getter<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  getter: ClassGetter<'a>,
+) -> ClassGetter<'a> {
+  ClassGetter {
+    decorators: fold_expressions(visitor, getter.decorators),
+    name: visitor.fold_class_element_name(getter.name),
+    parameters_span: getter.parameters_span,
+    body: fold_block(visitor, getter.body),
+    span: getter.span,
+    r#static: getter.r#static,
+  }
+}
+
+pub fn walk_class_setter<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  setter: ClassSetter<'a>,
+) -> ClassSetter<'a> {
+  ClassSetter {
+    decorators: fold_expressions(visitor, setter.decorators),
+    name: visitor.fold_class_element_name(setter.name),
+    parameter: visitor.fold_binding_pattern_initializer(setter.parameter),
+    parameters_span: setter.parameters_span,
+    body: fold_block(visitor, setter.body),
+    span: setter.span,
+    r#static: setter.r#static,
+  }
+}
+
+pub fn walk_class_static_block<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  block: ClassStaticBlock<'a>,
+) -> ClassStaticBlock<'a> {
+  ClassStaticBlock {
+    span: block.span,
+    statements: visitor.fold_statement_list(block.statements),
+  }
+}
+
+pub fn walk_jsx_element<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  element: JsxElement<'a>,
+) -> JsxElement<'a> {
+  let name = visitor.fold_jsx_element_name(element.name);
+  let attributes = fold_vec(visitor, element.attributes, |visitor, attribute| {
+    visitor.fold_jsx_attribute(attribute)
+  });
+
+  JsxElement {
+    attributes,
+    children: fold_vec(visitor, element.children, |visitor, child| {
+      visitor.fold_jsx_child(child)
+    }),
+    name,
+    ..element
+  }
+}
+
+pub fn walk_jsx_attribute<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  attribute: JsxAttribute<'a>,
+) -> JsxAttribute<'a> {
+  match attribute {
+    JsxAttribute::Attribute(attribute) => {
+      let mut attribute = *attribute;
+      attribute.value = attribute
+        .value
+        .map(|value| visitor.fold_jsx_attribute_value(value));
+      JsxAttribute::Attribute(visitor.allocator().alloc(attribute))
+    }
+    JsxAttribute::Spread(container) => JsxAttribute::Spread(fold_jsx_container(visitor, container)),
+  }
+}
+
+pub fn walk_jsx_attribute_value<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  value: JsxAttributeValue<'a>,
+) -> JsxAttributeValue<'a> {
+  match value {
+    JsxAttributeValue::Element(element) => {
+      let element = visitor.fold_jsx_element(element.clone());
+      JsxAttributeValue::Element(visitor.allocator().alloc(element))
+    }
+    JsxAttributeValue::Expression(container) => {
+      JsxAttributeValue::Expression(fold_jsx_container(visitor, container))
+    }
+    JsxAttributeValue::Fragment(fragment) => {
+      JsxAttributeValue::Fragment(fold_jsx_fragment(visitor, fragment))
+    }
+    JsxAttributeValue::String(_) => value,
+  }
+}
+
+pub fn walk_jsx_child<'a, V: Fold<'a> + ?Sized>(
+  visitor: &mut V,
+  child: JsxChild<'a>,
+) -> JsxChild<'a> {
+  match child {
+    JsxChild::Element(element) => {
+      let element = visitor.fold_jsx_element(element.clone());
+      JsxChild::Element(visitor.allocator().alloc(element))
+    }
+    JsxChild::Empty(_) | JsxChild::Text(_) => child,
+    JsxChild::Expression(container) => JsxChild::Expression(fold_jsx_container(visitor, container)),
+    JsxChild::Fragment(fragment) => JsxChild::Fragment(fold_jsx_fragment(visitor, fragment)),
+    JsxChild::Spread(container) => JsxChild::Spread(fold_jsx_container(visitor, container)),
+  }
+}
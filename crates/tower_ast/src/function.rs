@@ -0,0 +1,46 @@
+use bumpalo::collections::Vec;
+
+use super::{
+  binding::{BindingPatternInitializer, BindingRestElement},
+  expression::{Expression, SpreadElement},
+  statement::Block,
+  Identifier, Span,
+};
+
+// the span runs from the opening to the closing parenthesis
+#[derive(Debug, Clone)]
+pub struct FormalParameters<'a> {
+  pub bindings: Vec<'a, BindingPatternInitializer<'a>>,
+  pub rest: Option<BindingRestElement<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct FunctionDefinition<'a> {
+  pub r#async: bool,
+  pub body: Block<'a>,
+  pub generator: bool,
+  pub identifier: Option<Identifier<'a>>,
+  pub parameters: FormalParameters<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Argument<'a> {
+  Positional(Expression<'a>),
+  Spread(SpreadElement<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ConciseBody<'a> {
+  Block(Block<'a>),
+  Expression(Expression<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ArrowFunctionDefinition<'a> {
+  pub r#async: bool,
+  pub body: ConciseBody<'a>,
+  pub parameters: FormalParameters<'a>,
+  pub span: Span,
+}
@@ -0,0 +1,110 @@
+use bumpalo::collections::Vec;
+
+use super::{
+  expression::{Expression, StringLiteral},
+  Identifier, Span,
+};
+
+// the closing span is the span of the closing tag, a self closing element has none
+#[derive(Debug, Clone)]
+pub struct JsxElement<'a> {
+  pub attributes: Vec<'a, JsxAttribute<'a>>,
+  pub children: Vec<'a, JsxChild<'a>>,
+  pub closing_span: Option<Span>,
+  pub name: JsxElementName<'a>,
+  pub opening_span: Span,
+  pub self_closing: bool,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsxFragment<'a> {
+  pub children: Vec<'a, JsxChild<'a>>,
+  pub closing_span: Span,
+  pub opening_span: Span,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsxElementName<'a> {
+  Identifier(Identifier<'a>),
+  Member(&'a JsxMemberExpression<'a>),
+  Namespaced(&'a JsxNamespacedName<'a>),
+}
+
+impl JsxElementName<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      JsxElementName::Identifier(identifier) => identifier.span,
+      JsxElementName::Member(member) => member.span,
+      JsxElementName::Namespaced(name) => name.span,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsxMemberExpression<'a> {
+  pub object: JsxElementName<'a>,
+  pub property: Identifier<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsxNamespacedName<'a> {
+  pub name: Identifier<'a>,
+  pub namespace: Identifier<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsxAttribute<'a> {
+  Attribute(&'a JsxNamedAttribute<'a>),
+  Spread(&'a JsxExpressionContainer<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsxNamedAttribute<'a> {
+  pub name: JsxAttributeName<'a>,
+  pub span: Span,
+  pub value: Option<JsxAttributeValue<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsxAttributeName<'a> {
+  Identifier(Identifier<'a>),
+  Namespaced(&'a JsxNamespacedName<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsxAttributeValue<'a> {
+  Element(&'a JsxElement<'a>),
+  Expression(&'a JsxExpressionContainer<'a>),
+  Fragment(&'a JsxFragment<'a>),
+  String(&'a StringLiteral<'a>),
+}
+
+// an expression between braces, the span includes the braces
+#[derive(Debug, Clone, Copy)]
+pub struct JsxExpressionContainer<'a> {
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JsxChild<'a> {
+  Element(&'a JsxElement<'a>),
+  // `{}` or `{/* comment */}`
+  Empty(Span),
+  Expression(&'a JsxExpressionContainer<'a>),
+  Fragment(&'a JsxFragment<'a>),
+  Spread(&'a JsxExpressionContainer<'a>),
+  Text(&'a JsxText<'a>),
+}
+
+// the value has the character references decoded, whitespace is kept as written
+#[derive(Debug, Clone, Copy)]
+pub struct JsxText<'a> {
+  pub raw: &'a str,
+  pub span: Span,
+  pub value: &'a str,
+}
@@ -1 +1,78 @@
+use std::fmt;
 
+use bumpalo::collections::Vec;
+use expression::Expression;
+use statement::Statement;
+use string::JsStr;
+
+pub mod bigint;
+pub mod binding;
+pub mod class;
+pub mod expression;
+pub mod fold;
+pub mod function;
+pub mod jsx;
+pub mod object;
+pub mod op;
+pub mod scope;
+pub mod statement;
+pub mod string;
+pub mod visit;
+pub mod visit_mut;
+
+#[derive(Debug, Clone, Copy)]
+pub enum SourceType {
+  Script,
+  Module,
+}
+
+#[derive(Debug, Clone)]
+pub struct Program<'a> {
+  pub source_type: SourceType,
+  pub span: Span,
+  pub statement_list: Vec<'a, Statement<'a>>,
+  pub top_level_await: bool,
+}
+
+// a range of character offsets in the source, nodes made by transforms have an empty span at 0
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+  pub start: u32,
+  pub end: u32,
+}
+
+impl Span {
+  pub fn new(start: usize, end: usize) -> Self {
+    Self {
+      start: start as u32,
+      end: end as u32,
+    }
+  }
+
+  pub fn to(self, end: Span) -> Self {
+    Self {
+      start: self.start,
+      end: end.end,
+    }
+  }
+}
+
+impl fmt::Debug for Span {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}..{}", self.start, self.end)
+  }
+}
+
+// an identifier, or a private name without its `#`
+#[derive(Debug, Clone, Copy)]
+pub struct Identifier<'a> {
+  pub name: &'a str,
+  pub span: Span,
+}
+
+// a property key written as an identifier, a string or a number, named by its string value
+#[derive(Debug, Clone, Copy)]
+pub struct PropertyKey<'a> {
+  pub name: JsStr<'a>,
+  pub span: Span,
+}
@@ -0,0 +1,71 @@
+use super::{
+  binding::BindingPatternInitializer,
+  expression::{Expression, SpreadElement},
+  function::FormalParameters,
+  statement::Block,
+  Identifier, PropertyKey, Span,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum PropertyName<'a> {
+  Computed(Expression<'a>),
+  Static(PropertyKey<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectMethod<'a> {
+  pub r#async: bool,
+  pub body: Block<'a>,
+  pub generator: bool,
+  pub parameters: FormalParameters<'a>,
+  pub property: PropertyName<'a>,
+  pub span: Span,
+}
+
+// the parameters span runs from the opening to the closing parenthesis
+#[derive(Debug, Clone)]
+pub struct ObjectGetter<'a> {
+  pub body: Block<'a>,
+  pub parameters_span: Span,
+  pub property: PropertyName<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectSetter<'a> {
+  pub body: Block<'a>,
+  pub parameter: BindingPatternInitializer<'a>,
+  pub parameters_span: Span,
+  pub property: PropertyName<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PropertyDefinition<'a> {
+  pub expression: Expression<'a>,
+  pub property: PropertyName<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum ObjectProperty<'a> {
+  Getter(&'a ObjectGetter<'a>),
+  Method(&'a ObjectMethod<'a>),
+  Property(&'a PropertyDefinition<'a>),
+  Setter(&'a ObjectSetter<'a>),
+  Shorthand(&'a Identifier<'a>),
+  Spread(SpreadElement<'a>),
+}
+
+impl ObjectProperty<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      ObjectProperty::Getter(getter) => getter.span,
+      ObjectProperty::Method(method) => method.span,
+      ObjectProperty::Property(definition) => definition.span,
+      ObjectProperty::Setter(setter) => setter.span,
+      ObjectProperty::Shorthand(identifier) => identifier.span,
+      ObjectProperty::Spread(spread) => spread.span,
+    }
+  }
+}
@@ -1,4 +1,4 @@
-use super::Expression;
+use super::{Expression, Span};
 
 #[derive(Debug, Clone, Copy)]
 pub enum UnaryOp {
@@ -20,6 +20,7 @@ pub enum UnaryOp {
 pub struct UnaryOpExpression<'a> {
   pub op: UnaryOp,
   pub argument: Expression<'a>,
+  pub span: Span,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -55,6 +56,7 @@ pub struct BinaryOpExpression<'a> {
   pub op: BinaryOp,
   pub left: Expression<'a>,
   pub right: Expression<'a>,
+  pub span: Span,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -82,4 +84,5 @@ pub struct AssignmentOpExpression<'a> {
   pub op: AssignmentOp,
   pub left: Expression<'a>,
   pub right: Expression<'a>,
+  pub span: Span,
 }
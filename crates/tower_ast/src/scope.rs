@@ -0,0 +1,922 @@
+use std::{collections::HashMap, ops::Index};
+
+use crate::{
+  binding::{BindingPattern, BindingPatternInitializer},
+  class::{ClassDefinition, ClassElement, ClassGetter, ClassMethod, ClassSetter, ClassStaticBlock},
+  expression::{ArrayElement, CallExpression, Expression},
+  function::{ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition},
+  jsx::JsxElementName,
+  object::{ObjectGetter, ObjectMethod, ObjectProperty, ObjectSetter},
+  op::{AssignmentOpExpression, UnaryOp, UnaryOpExpression},
+  statement::{
+    CatchBlock, ForBinding, ForInStatement, ForInitializer, ForOfStatement, ForStatement,
+    LexicalDeclaration, LexicalKind, Statement, SwitchStatement, TryStatement, WithStatement,
+  },
+  visit::{
+    walk_call_expression, walk_class_element_name, walk_expression, walk_statement,
+    walk_statement_list, Visit,
+  },
+  Identifier, Program, SourceType, Span,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScopeId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeclarationId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReferenceId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+  Arrow,
+  Block,
+  Catch,
+  Class,
+  Function,
+  Module,
+  Script,
+  StaticBlock,
+  // the body of a `with` statement, where any name can come from the object
+  With,
+}
+
+impl ScopeKind {
+  // the scopes `var` declarations are hoisted to
+  pub fn is_var_scope(self) -> bool {
+    matches!(
+      self,
+      ScopeKind::Arrow
+        | ScopeKind::Function
+        | ScopeKind::Module
+        | ScopeKind::Script
+        | ScopeKind::StaticBlock
+    )
+  }
+
+  // the scopes that can outlive the code that made them and so capture what they use
+  pub fn is_closure(self) -> bool {
+    matches!(
+      self,
+      ScopeKind::Arrow | ScopeKind::Function | ScopeKind::StaticBlock
+    )
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+  // the implicit `arguments` of a function, declared on first use
+  Arguments,
+  CatchParameter,
+  Class,
+  Const,
+  Function,
+  Let,
+  Parameter,
+  Var,
+}
+
+impl DeclarationKind {
+  // declarations of these kinds in the same scope are the same binding
+  fn merges(self) -> bool {
+    matches!(
+      self,
+      DeclarationKind::Function | DeclarationKind::Parameter | DeclarationKind::Var
+    )
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope<'a> {
+  // names a direct `eval` in here or in a nested scope can see
+  pub contains_eval: bool,
+  pub declarations: Vec<DeclarationId>,
+  // names can be added here at runtime, by the object of a `with` or by a direct `eval` in sloppy
+  // code
+  pub dynamic: bool,
+  // for closures, the declarations from outside that are used inside
+  pub captures: Vec<DeclarationId>,
+  pub kind: ScopeKind,
+  pub parent: Option<ScopeId>,
+  pub span: Span,
+  names: HashMap<&'a str, DeclarationId>,
+}
+
+// the span of an implicit declaration is the span of the scope it belongs to
+#[derive(Debug, Clone)]
+pub struct Declaration<'a> {
+  // used from inside a closure that doesn't declare it
+  pub captured: bool,
+  pub kind: DeclarationKind,
+  pub name: &'a str,
+  pub references: Vec<ReferenceId>,
+  pub scope: ScopeId,
+  pub span: Span,
+}
+
+// a reference without a declaration is a global or a free variable
+#[derive(Debug, Clone)]
+pub struct Reference<'a> {
+  pub declaration: Option<DeclarationId>,
+  // a `with` or a direct `eval` between the reference and its declaration can shadow it at runtime
+  pub dynamic: bool,
+  pub name: &'a str,
+  pub scope: ScopeId,
+  pub span: Span,
+  // assigned to, compound assignments and updates read the binding as well
+  pub write: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScopeTree<'a> {
+  pub declarations: Vec<Declaration<'a>>,
+  pub references: Vec<Reference<'a>>,
+  pub scopes: Vec<Scope<'a>>,
+  reference_spans: HashMap<Span, ReferenceId>,
+  declaration_spans: HashMap<Span, DeclarationId>,
+}
+
+impl<'a> ScopeTree<'a> {
+  pub fn root(&self) -> ScopeId {
+    ScopeId(0)
+  }
+
+  pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<DeclarationId> {
+    self
+      .ancestors(scope)
+      .find_map(|id| self[id].names.get(name).copied())
+  }
+
+  pub fn ancestors(&self, scope: ScopeId) -> impl Iterator<Item = ScopeId> + '_ {
+    std::iter::successors(Some(scope), |id| self[*id].parent)
+  }
+
+  // the reference made by the identifier with this span
+  pub fn reference_at(&self, span: Span) -> Option<ReferenceId> {
+    self.reference_spans.get(&span).copied()
+  }
+
+  // the declaration made by the binding identifier with this span
+  pub fn declaration_at(&self, span: Span) -> Option<DeclarationId> {
+    self.declaration_spans.get(&span).copied()
+  }
+
+  pub fn free_references(&self) -> impl Iterator<Item = &Reference<'a>> {
+    self
+      .references
+      .iter()
+      .filter(|reference| reference.declaration.is_none())
+  }
+}
+
+impl<'a> Index<ScopeId> for ScopeTree<'a> {
+  type Output = Scope<'a>;
+
+  fn index(&self, id: ScopeId) -> &Scope<'a> {
+    &self.scopes[id.0 as usize]
+  }
+}
+
+impl<'a> Index<DeclarationId> for ScopeTree<'a> {
+  type Output = Declaration<'a>;
+
+  fn index(&self, id: DeclarationId) -> &Declaration<'a> {
+    &self.declarations[id.0 as usize]
+  }
+}
+
+impl<'a> Index<ReferenceId> for ScopeTree<'a> {
+  type Output = Reference<'a>;
+
+  fn index(&self, id: ReferenceId) -> &Reference<'a> {
+    &self.references[id.0 as usize]
+  }
+}
+
+// declarations are collected in one walk and references resolved after it, so hoisted names and
+// uses before a `let` find their declaration. `annex_b` is the option the program was parsed with
+pub fn analyze<'a>(program: &'a Program<'a>, annex_b: bool) -> ScopeTree<'a> {
+  let (root, strict, annex_b) = match program.source_type {
+    SourceType::Module => (ScopeKind::Module, true, false),
+    SourceType::Script => (
+      ScopeKind::Script,
+      is_strict(&program.statement_list),
+      annex_b,
+    ),
+  };
+
+  let mut analyzer = Analyzer {
+    annex_b,
+    binding: None,
+    block_functions: Vec::new(),
+    current: ScopeId(0),
+    eval_calls: Vec::new(),
+    strict,
+    tree: ScopeTree {
+      declarations: Vec::new(),
+      references: Vec::new(),
+      scopes: Vec::new(),
+      reference_spans: HashMap::new(),
+      declaration_spans: HashMap::new(),
+    },
+  };
+
+  analyzer.enter(root, program.span);
+  analyzer.visit_statement_list(&program.statement_list);
+  analyzer.hoist_block_functions();
+  analyzer.resolve();
+  analyzer.tree
+}
+
+// a `use strict` directive in the prologue of a body, spelled without escapes
+fn is_strict(statements: &[Statement]) -> bool {
+  statements
+    .iter()
+    .map_while(|statement| match statement {
+      Statement::Expression(statement) => match &statement.expression {
+        Expression::String(literal) => Some(literal),
+        _ => None,
+      },
+      _ => None,
+    })
+    .any(|literal| literal.value == "use strict" && literal.span.end - literal.span.start == 12)
+}
+
+struct Analyzer<'a> {
+  annex_b: bool,
+  // the kind of declaration binding identifiers make while walking a pattern
+  binding: Option<DeclarationKind>,
+  // the functions declared in blocks of sloppy code, with the scope of the block
+  block_functions: Vec<(ScopeId, &'a str, Span)>,
+  current: ScopeId,
+  // the scope of each call to `eval`, the reference to its callee and whether the code is strict
+  eval_calls: Vec<(ScopeId, ReferenceId, bool)>,
+  strict: bool,
+  tree: ScopeTree<'a>,
+}
+
+impl<'a> Analyzer<'a> {
+  fn enter(&mut self, kind: ScopeKind, span: Span) -> ScopeId {
+    let parent = (!self.tree.scopes.is_empty()).then_some(self.current);
+    self.current = ScopeId(self.tree.scopes.len() as u32);
+    self.tree.scopes.push(Scope {
+      contains_eval: false,
+      declarations: Vec::new(),
+      dynamic: kind == ScopeKind::With,
+      captures: Vec::new(),
+      kind,
+      parent,
+      span,
+      names: HashMap::new(),
+    });
+    parent.unwrap_or(self.current)
+  }
+
+  fn scope_mut(&mut self, id: ScopeId) -> &mut Scope<'a> {
+    &mut self.tree.scopes[id.0 as usize]
+  }
+
+  fn var_scope(&self, scope: ScopeId) -> ScopeId {
+    self
+      .tree
+      .ancestors(scope)
+      .find(|id| self.tree[*id].kind.is_var_scope())
+      .unwrap_or(self.tree.root())
+  }
+
+  fn declare(&mut self, name: &'a str, span: Span, kind: DeclarationKind) -> DeclarationId {
+    let scope = match kind {
+      DeclarationKind::Var => self.var_scope(self.current),
+      _ => self.current,
+    };
+
+    let id = self.add_declaration(scope, name, span, kind);
+    if kind != DeclarationKind::Arguments {
+      self.tree.declaration_spans.insert(span, id);
+    }
+
+    id
+  }
+
+  fn add_declaration(
+    &mut self,
+    scope: ScopeId,
+    name: &'a str,
+    span: Span,
+    kind: DeclarationKind,
+  ) -> DeclarationId {
+    let existing = self.tree[scope].names.get(name).copied();
+    match existing {
+      Some(id) if kind.merges() && self.tree[id].kind.merges() => id,
+      _ => {
+        let id = DeclarationId(self.tree.declarations.len() as u32);
+        self.tree.declarations.push(Declaration {
+          captured: false,
+          kind,
+          name,
+          references: Vec::new(),
+          scope,
+          span,
+        });
+        let scope = self.scope_mut(scope);
+        scope.declarations.push(id);
+        scope.names.insert(name, id);
+        id
+      }
+    }
+  }
+
+  fn reference(&mut self, identifier: &'a Identifier<'a>, write: bool) -> ReferenceId {
+    let id = ReferenceId(self.tree.references.len() as u32);
+    self.tree.references.push(Reference {
+      declaration: None,
+      dynamic: false,
+      name: identifier.name,
+      scope: self.current,
+      span: identifier.span,
+      write,
+    });
+    self.tree.reference_spans.insert(identifier.span, id);
+    id
+  }
+
+  // walks a pattern, declaring each binding identifier in it as `kind`
+  fn bind(&mut self, kind: DeclarationKind, pattern: &'a BindingPattern<'a>) {
+    let outer = self.binding.replace(kind);
+    self.visit_binding_pattern(pattern);
+    self.binding = outer;
+  }
+
+  // an assignment target, the identifiers in it are written rather than read
+  fn target(&mut self, expression: &'a Expression<'a>) {
+    match expression {
+      Expression::Identifier(identifier) => {
+        self.reference(identifier, true);
+      }
+      Expression::Array(literal) => {
+        for element in &literal.elements {
+          match element {
+            ArrayElement::Elision => {}
+            ArrayElement::Expression(expression) => self.target(expression),
+            ArrayElement::Spread(spread) => self.target(&spread.argument),
+          }
+        }
+      }
+      Expression::Object(literal) => {
+        for property in &literal.properties {
+          match property {
+            ObjectProperty::Property(definition) => {
+              self.visit_property_name(&definition.property);
+              self.target(&definition.expression);
+            }
+            ObjectProperty::Shorthand(identifier) => {
+              self.reference(identifier, true);
+            }
+            ObjectProperty::Spread(spread) => self.target(&spread.argument),
+            _ => self.visit_object_property(property),
+          }
+        }
+      }
+      Expression::Group(group) => self.target(&group.expression),
+      _ => self.visit_expression(expression),
+    }
+  }
+
+  fn function(&mut self, definition: &'a FunctionDefinition<'a>, expression: bool) {
+    let outer = self.enter(ScopeKind::Function, definition.span);
+    let strict = self.strict;
+    self.strict |= is_strict(&definition.body.statements);
+    // the name of a function expression is only visible inside it
+    if let (true, Some(identifier)) = (expression, &definition.identifier) {
+      self.declare(identifier.name, identifier.span, DeclarationKind::Function);
+    }
+
+    self.visit_formal_parameters(&definition.parameters);
+    self.visit_statement_list(&definition.body.statements);
+    self.strict = strict;
+    self.current = outer;
+  }
+
+  // the body of a method, getter or setter, its name is evaluated outside
+  fn method(
+    &mut self,
+    span: Span,
+    parameters: Option<&'a FormalParameters<'a>>,
+    parameter: Option<&'a BindingPatternInitializer<'a>>,
+    statements: &'a [Statement<'a>],
+  ) {
+    let outer = self.enter(ScopeKind::Function, span);
+    let strict = self.strict;
+    self.strict |= is_strict(statements);
+    if let Some(parameters) = parameters {
+      self.visit_formal_parameters(parameters);
+    }
+
+    if let Some(parameter) = parameter {
+      self.parameter(parameter);
+    }
+
+    self.visit_statement_list(statements);
+    self.strict = strict;
+    self.current = outer;
+  }
+
+  fn parameter(&mut self, binding: &'a BindingPatternInitializer<'a>) {
+    self.bind(DeclarationKind::Parameter, &binding.pattern);
+    if let Some(initializer) = &binding.initializer {
+      self.visit_expression(initializer);
+    }
+  }
+
+  fn class(&mut self, definition: &'a ClassDefinition<'a>, expression: bool) {
+    for decorator in &definition.decorators {
+      self.visit_expression(decorator);
+    }
+
+    // all of a class is strict, its heritage too
+    let outer = self.enter(ScopeKind::Class, definition.span);
+    let strict = std::mem::replace(&mut self.strict, true);
+    if let (true, Some(identifier)) = (expression, &definition.identifier) {
+      self.declare(identifier.name, identifier.span, DeclarationKind::Class);
+    }
+
+    if let Some(heritage) = &definition.heritage {
+      self.visit_expression(heritage);
+    }
+
+    for element in &definition.body {
+      self.visit_class_element(element);
+    }
+
+    self.strict = strict;
+    self.current = outer;
+  }
+
+  fn block(&mut self, span: Span, statements: &'a [Statement<'a>]) {
+    let outer = self.enter(ScopeKind::Block, span);
+    self.visit_statement_list(statements);
+    self.current = outer;
+  }
+
+  fn for_in_of(
+    &mut self,
+    span: Span,
+    binding: &'a ForBinding<'a>,
+    expression: &'a Expression<'a>,
+    body: &'a Statement<'a>,
+  ) {
+    let outer =
+      matches!(binding, ForBinding::Lexical(..)).then(|| self.enter(ScopeKind::Block, span));
+    self.visit_for_binding(binding);
+    self.visit_expression(expression);
+    self.visit_statement(body);
+    if let Some(outer) = outer {
+      self.current = outer;
+    }
+  }
+
+  // annex B.3.3, a function in a block of sloppy code is also a var of the nearest var scope when
+  // a `var` in its place wouldn't clash with a lexical declaration around it
+  fn hoist_block_functions(&mut self) {
+    for (block, name, span) in std::mem::take(&mut self.block_functions) {
+      let var_scope = self.var_scope(block);
+      if name == "arguments" && self.tree[var_scope].kind != ScopeKind::Script {
+        continue;
+      }
+
+      let scopes = self.tree.ancestors(block).skip(1);
+      let clashes = scopes
+        .take_while(|scope| *scope != var_scope)
+        .chain([var_scope])
+        .any(|scope| match self.tree[scope].names.get(name) {
+          Some(id) if scope == var_scope => !self.tree[*id].kind.merges(),
+          Some(id) => self.tree[*id].kind != DeclarationKind::CatchParameter,
+          None => false,
+        });
+      if !clashes {
+        self.add_declaration(var_scope, name, span, DeclarationKind::Var);
+      }
+    }
+  }
+
+  fn resolve(&mut self) {
+    for index in 0..self.tree.references.len() {
+      let reference = &self.tree.references[index];
+      let name = reference.name;
+      let mut closures = Vec::new();
+      let mut found = None;
+
+      let ancestors = self.tree.ancestors(reference.scope).collect::<Vec<_>>();
+      for scope in ancestors {
+        if let Some(declaration) = self.tree[scope].names.get(name) {
+          found = Some(*declaration);
+          break;
+        }
+
+        if name == "arguments" && self.tree[scope].kind == ScopeKind::Function {
+          let span = self.tree[scope].span;
+          let outer = std::mem::replace(&mut self.current, scope);
+          found = Some(self.declare(name, span, DeclarationKind::Arguments));
+          self.current = outer;
+          break;
+        }
+
+        if self.tree[scope].kind.is_closure() {
+          closures.push(scope);
+        }
+      }
+
+      let id = ReferenceId(index as u32);
+      self.tree.references[index].declaration = found;
+      let Some(declaration) = found else {
+        continue;
+      };
+
+      self.tree.declarations[declaration.0 as usize]
+        .references
+        .push(id);
+      if !closures.is_empty() {
+        self.tree.declarations[declaration.0 as usize].captured = true;
+      }
+
+      for closure in closures {
+        let captures = &mut self.scope_mut(closure).captures;
+        if !captures.contains(&declaration) {
+          captures.push(declaration);
+        }
+      }
+    }
+
+    // `eval` is only direct when it's the global one. the code it runs sees every enclosing scope,
+    // and in sloppy code can declare new vars in the nearest var scope
+    for (scope, callee, strict) in std::mem::take(&mut self.eval_calls) {
+      if self.tree[callee].declaration.is_some() {
+        continue;
+      }
+
+      let ancestors = self.tree.ancestors(scope).collect::<Vec<_>>();
+      for ancestor in ancestors {
+        self.scope_mut(ancestor).contains_eval = true;
+      }
+
+      if !strict {
+        let var_scope = self.var_scope(scope);
+        self.scope_mut(var_scope).dynamic = true;
+      }
+    }
+
+    for index in 0..self.tree.references.len() {
+      let reference = &self.tree.references[index];
+      let declared_in = reference
+        .declaration
+        .map(|declaration| self.tree[declaration].scope);
+      let dynamic = self
+        .tree
+        .ancestors(reference.scope)
+        .take_while(|scope| Some(*scope) != declared_in)
+        .any(|scope| self.tree[scope].dynamic);
+      self.tree.references[index].dynamic = dynamic;
+    }
+  }
+}
+
+impl<'a> Visit<'a> for Analyzer<'a> {
+  fn visit_statement(&mut self, statement: &'a Statement<'a>) {
+    match statement {
+      Statement::Block(block) => self.block(block.span, &block.statements),
+      Statement::Class(definition) => {
+        if let Some(identifier) = &definition.identifier {
+          self.declare(identifier.name, identifier.span, DeclarationKind::Class);
+        }
+
+        self.class(definition, false);
+      }
+      Statement::Function(definition) => {
+        // functions in blocks are block scoped, plain ones in sloppy code get a var binding too
+        if let Some(identifier) = &definition.identifier {
+          self.declare(identifier.name, identifier.span, DeclarationKind::Function);
+          if self.annex_b
+            && !self.strict
+            && !definition.r#async
+            && !definition.generator
+            && !self.tree[self.current].kind.is_var_scope()
+          {
+            self
+              .block_functions
+              .push((self.current, identifier.name, identifier.span));
+          }
+        }
+
+        self.function(definition, false);
+      }
+      _ => walk_statement(self, statement),
+    }
+  }
+
+  fn visit_variable_declaration(&mut self, bindings: &'a [BindingPatternInitializer<'a>]) {
+    for binding in bindings {
+      self.bind(DeclarationKind::Var, &binding.pattern);
+      if let Some(initializer) = &binding.initializer {
+        self.visit_expression(initializer);
+      }
+    }
+  }
+
+  fn visit_lexical_declaration(&mut self, declaration: &'a LexicalDeclaration<'a>) {
+    let kind = match declaration.kind {
+      LexicalKind::Const => DeclarationKind::Const,
+      LexicalKind::Let => DeclarationKind::Let,
+    };
+
+    for binding in &declaration.bindings {
+      self.bind(kind, &binding.pattern);
+      if let Some(initializer) = &binding.initializer {
+        self.visit_expression(initializer);
+      }
+    }
+  }
+
+  fn visit_for_statement(&mut self, statement: &'a ForStatement<'a>) {
+    let lexical = matches!(statement.initializer, Some(ForInitializer::Lexical(_)));
+    let outer = lexical.then(|| self.enter(ScopeKind::Block, statement.span));
+
+    if let Some(initializer) = &statement.initializer {
+      self.visit_for_initializer(initializer);
+    }
+
+    if let Some(condition) = &statement.condition {
+      self.visit_expression(condition);
+    }
+
+    if let Some(update) = &statement.update {
+      self.visit_expression(update);
+    }
+
+    self.visit_statement(&statement.body);
+    if let Some(outer) = outer {
+      self.current = outer;
+    }
+  }
+
+  fn visit_for_in_statement(&mut self, statement: &'a ForInStatement<'a>) {
+    self.for_in_of(
+      statement.span,
+      &statement.binding,
+      &statement.expression,
+      &statement.body,
+    );
+  }
+
+  fn visit_for_of_statement(&mut self, statement: &'a ForOfStatement<'a>) {
+    self.for_in_of(
+      statement.span,
+      &statement.binding,
+      &statement.expression,
+      &statement.body,
+    );
+  }
+
+  fn visit_for_binding(&mut self, binding: &'a ForBinding<'a>) {
+    match binding {
+      ForBinding::Expression(expression) => self.target(expression),
+      ForBinding::Lexical(LexicalKind::Const, pattern, _) => {
+        self.bind(DeclarationKind::Const, pattern)
+      }
+      ForBinding::Lexical(LexicalKind::Let, pattern, _) => self.bind(DeclarationKind::Let, pattern),
+      ForBinding::Variable(binding, _) => {
+        self.bind(DeclarationKind::Var, &binding.pattern);
+        if let Some(initializer) = &binding.initializer {
+          self.visit_expression(initializer);
+        }
+      }
+    }
+  }
+
+  fn visit_switch_statement(&mut self, statement: &'a SwitchStatement<'a>) {
+    self.visit_expression(&statement.expression);
+
+    let outer = self.enter(ScopeKind::Block, statement.span);
+    for case in &statement.cases {
+      self.visit_switch_case(case);
+    }
+
+    self.current = outer;
+  }
+
+  fn visit_with_statement(&mut self, statement: &'a WithStatement<'a>) {
+    self.visit_expression(&statement.expression);
+
+    let outer = self.enter(ScopeKind::With, statement.body.span());
+    self.visit_statement(&statement.body);
+    self.current = outer;
+  }
+
+  fn visit_try_statement(&mut self, statement: &'a TryStatement<'a>) {
+    self.block(statement.body.span, &statement.body.statements);
+
+    if let Some(catch) = &statement.catch {
+      self.visit_catch_block(catch);
+    }
+
+    if let Some(finally) = &statement.finally {
+      self.block(finally.span, &finally.statements);
+    }
+  }
+
+  // the parameter and the body share a scope, redeclaring the parameter with `let` is an error
+  fn visit_catch_block(&mut self, catch: &'a CatchBlock<'a>) {
+    let outer = self.enter(ScopeKind::Catch, catch.span);
+    if let Some(parameter) = &catch.parameter {
+      self.bind(DeclarationKind::CatchParameter, parameter);
+    }
+
+    self.visit_statement_list(&catch.body.statements);
+    self.current = outer;
+  }
+
+  fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+    match expression {
+      Expression::Class(definition) => self.class(definition, true),
+      Expression::Function(definition) => self.function(definition, true),
+      _ => walk_expression(self, expression),
+    }
+  }
+
+  fn visit_identifier_reference(&mut self, identifier: &'a Identifier<'a>) {
+    self.reference(identifier, false);
+  }
+
+  fn visit_binding_identifier(&mut self, identifier: &'a Identifier<'a>) {
+    if let Some(kind) = self.binding {
+      self.declare(identifier.name, identifier.span, kind);
+    }
+  }
+
+  fn visit_call_expression(&mut self, expression: &'a CallExpression<'a>) {
+    if let Expression::Identifier(identifier) = &expression.callee {
+      if identifier.name == "eval" {
+        let callee = ReferenceId(self.tree.references.len() as u32);
+        self.eval_calls.push((self.current, callee, self.strict));
+      }
+    }
+
+    walk_call_expression(self, expression);
+  }
+
+  fn visit_unary_op_expression(&mut self, expression: &'a UnaryOpExpression<'a>) {
+    match expression.op {
+      UnaryOp::PostfixDecrement
+      | UnaryOp::PostfixIncrement
+      | UnaryOp::PrefixDecrement
+      | UnaryOp::PrefixIncrement => self.target(&expression.argument),
+      _ => self.visit_expression(&expression.argument),
+    }
+  }
+
+  fn visit_assignment_op_expression(&mut self, expression: &'a AssignmentOpExpression<'a>) {
+    self.target(&expression.left);
+    self.visit_expression(&expression.right);
+  }
+
+  fn visit_object_method(&mut self, method: &'a ObjectMethod<'a>) {
+    self.visit_property_name(&method.property);
+    self.method(
+      method.span,
+      Some(&method.parameters),
+      None,
+      &method.body.statements,
+    );
+  }
+
+  fn visit_object_getter(&mut self, getter: &'a ObjectGetter<'a>) {
+    self.visit_property_name(&getter.property);
+    self.method(getter.span, None, None, &getter.body.statements);
+  }
+
+  fn visit_object_setter(&mut self, setter: &'a ObjectSetter<'a>) {
+    self.visit_property_name(&setter.property);
+    self.method(
+      setter.span,
+      None,
+      Some(&setter.parameter),
+      &setter.body.statements,
+    );
+  }
+
+  fn visit_arrow_function_definition(&mut self, definition: &'a ArrowFunctionDefinition<'a>) {
+    let outer = self.enter(ScopeKind::Arrow, definition.span);
+    let strict = self.strict;
+    if let ConciseBody::Block(block) = &definition.body {
+      self.strict |= is_strict(&block.statements);
+    }
+
+    self.visit_formal_parameters(&definition.parameters);
+    match &definition.body {
+      ConciseBody::Block(block) => self.visit_statement_list(&block.statements),
+      ConciseBody::Expression(expression) => self.visit_expression(expression),
+    }
+
+    self.strict = strict;
+    self.current = outer;
+  }
+
+  fn visit_formal_parameters(&mut self, parameters: &'a FormalParameters<'a>) {
+    for binding in &parameters.bindings {
+      self.parameter(binding);
+    }
+
+    if let Some(rest) = &parameters.rest {
+      self.bind(DeclarationKind::Parameter, &rest.pattern);
+    }
+  }
+
+  fn visit_binding_pattern_initializer(&mut self, binding: &'a BindingPatternInitializer<'a>) {
+    self.visit_binding_pattern(&binding.pattern);
+    if let Some(initializer) = &binding.initializer {
+      let kind = self.binding.take();
+      self.visit_expression(initializer);
+      self.binding = kind;
+    }
+  }
+
+  fn visit_class_element(&mut self, element: &'a ClassElement<'a>) {
+    match element {
+      ClassElement::Getter(getter) => self.visit_class_getter(getter),
+      ClassElement::Method(method) => self.visit_class_method(method),
+      ClassElement::Setter(setter) => self.visit_class_setter(setter),
+      ClassElement::Static(block) => self.visit_class_static_block(block),
+      // field and accessor initializers are evaluated in the class scope
+      _ => crate::visit::walk_class_element(self, element),
+    }
+  }
+
+  fn visit_class_method(&mut self, method: &'a ClassMethod<'a>) {
+    for decorator in &method.decorators {
+      self.visit_expression(decorator);
+    }
+
+    walk_class_element_name(self, &method.name);
+    self.method(
+      method.span,
+      Some(&method.parameters),
+      None,
+      &method.body.statements,
+    );
+  }
+
+  fn visit_class_getter(&mut self, getter: &'a ClassGetter<'a>) {
+    for decorator in &getter.decorators {
+      self.visit_expression(decorator);
+    }
+
+    walk_class_element_name(self, &getter.name);
+    self.method(getter.span, None, None, &getter.body.statements);
+  }
+
+  fn visit_class_setter(&mut self, setter: &'a ClassSetter<'a>) {
+    for decorator in &setter.decorators {
+      self.visit_expression(decorator);
+    }
+
+    walk_class_element_name(self, &setter.name);
+    self.method(
+      setter.span,
+      None,
+      Some(&setter.parameter),
+      &setter.body.statements,
+    );
+  }
+
+  fn visit_class_static_block(&mut self, block: &'a ClassStaticBlock<'a>) {
+    let outer = self.enter(ScopeKind::StaticBlock, block.span);
+    walk_statement_list(self, &block.statements);
+    self.current = outer;
+  }
+
+  // a lowercase tag name is an intrinsic element, anything else refers to a component
+  fn visit_jsx_element_name(&mut self, name: &'a JsxElementName<'a>) {
+    match name {
+      JsxElementName::Identifier(identifier) => {
+        let intrinsic = identifier
+          .name
+          .starts_with(|c: char| c.is_ascii_lowercase())
+          || identifier.name.contains('-');
+        if !intrinsic {
+          self.reference(identifier, false);
+        }
+      }
+      JsxElementName::Member(member) => {
+        let mut object = &member.object;
+        while let JsxElementName::Member(member) = object {
+          object = &member.object;
+        }
+
+        if let JsxElementName::Identifier(identifier) = object {
+          self.reference(identifier, false);
+        }
+      }
+      JsxElementName::Namespaced(_) => {}
+    }
+  }
+}
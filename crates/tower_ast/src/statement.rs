@@ -0,0 +1,219 @@
+use bumpalo::collections::Vec;
+
+use super::{
+  binding::{BindingPattern, BindingPatternInitializer},
+  class::ClassDefinition,
+  expression::Expression,
+  function::FunctionDefinition,
+  Identifier, Span,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Statement<'a> {
+  Block(&'a Block<'a>),
+  Break(&'a JumpStatement<'a>),
+  Class(&'a ClassDefinition<'a>),
+  Continue(&'a JumpStatement<'a>),
+  Debugger(Span),
+  DoWhile(&'a DoWhileStatement<'a>),
+  Expression(&'a ExpressionStatement<'a>),
+  Empty(Span),
+  For(&'a ForStatement<'a>),
+  ForIn(&'a ForInStatement<'a>),
+  ForOf(&'a ForOfStatement<'a>),
+  Function(&'a FunctionDefinition<'a>),
+  If(&'a IfStatement<'a>),
+  Label(&'a LabelStatement<'a>),
+  Lexical(&'a LexicalDeclaration<'a>),
+  Return(&'a ReturnStatement<'a>),
+  Switch(&'a SwitchStatement<'a>),
+  Throw(&'a ThrowStatement<'a>),
+  Try(&'a TryStatement<'a>),
+  Variable(&'a VariableDeclaration<'a>),
+  With(&'a WithStatement<'a>),
+  While(&'a WhileStatement<'a>),
+}
+
+impl Statement<'_> {
+  pub fn span(&self) -> Span {
+    match self {
+      Statement::Block(block) => block.span,
+      Statement::Break(statement) | Statement::Continue(statement) => statement.span,
+      Statement::Class(definition) => definition.span,
+      Statement::Debugger(span) | Statement::Empty(span) => *span,
+      Statement::DoWhile(statement) => statement.span,
+      Statement::Expression(statement) => statement.span,
+      Statement::For(statement) => statement.span,
+      Statement::ForIn(statement) => statement.span,
+      Statement::ForOf(statement) => statement.span,
+      Statement::Function(definition) => definition.span,
+      Statement::If(statement) => statement.span,
+      Statement::Label(statement) => statement.span,
+      Statement::Lexical(declaration) => declaration.span,
+      Statement::Return(statement) => statement.span,
+      Statement::Switch(statement) => statement.span,
+      Statement::Throw(statement) => statement.span,
+      Statement::Try(statement) => statement.span,
+      Statement::Variable(declaration) => declaration.span,
+      Statement::With(statement) => statement.span,
+      Statement::While(statement) => statement.span,
+    }
+  }
+}
+
+// a block statement, also the body of functions, catch clauses and the like
+#[derive(Debug, Clone)]
+pub struct Block<'a> {
+  pub span: Span,
+  pub statements: Vec<'a, Statement<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JumpStatement<'a> {
+  pub label: Option<Identifier<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExpressionStatement<'a> {
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnStatement<'a> {
+  pub argument: Option<Expression<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ThrowStatement<'a> {
+  pub argument: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariableDeclaration<'a> {
+  pub bindings: Vec<'a, BindingPatternInitializer<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LexicalKind {
+  Const,
+  Let,
+}
+
+#[derive(Debug, Clone)]
+pub struct LexicalDeclaration<'a> {
+  pub bindings: Vec<'a, BindingPatternInitializer<'a>>,
+  pub kind: LexicalKind,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IfStatement<'a> {
+  pub alternate: Option<Statement<'a>>,
+  pub condition: Expression<'a>,
+  pub consequent: Statement<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DoWhileStatement<'a> {
+  pub condition: Expression<'a>,
+  pub body: Statement<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WhileStatement<'a> {
+  pub condition: Expression<'a>,
+  pub body: Statement<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ForInitializer<'a> {
+  Expression(Expression<'a>),
+  Lexical(&'a LexicalDeclaration<'a>),
+  Variable(&'a VariableDeclaration<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForStatement<'a> {
+  pub body: Statement<'a>,
+  pub condition: Option<Expression<'a>>,
+  pub initializer: Option<ForInitializer<'a>>,
+  pub span: Span,
+  pub update: Option<Expression<'a>>,
+}
+
+// the span of a declaration runs from its keyword to the end of the binding
+#[derive(Debug, Clone, Copy)]
+pub enum ForBinding<'a> {
+  Expression(Expression<'a>),
+  Lexical(LexicalKind, BindingPattern<'a>, Span),
+  // annex B allows an initializer in for-in heads
+  Variable(BindingPatternInitializer<'a>, Span),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForInStatement<'a> {
+  pub binding: ForBinding<'a>,
+  pub body: Statement<'a>,
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForOfStatement<'a> {
+  pub r#await: bool,
+  pub binding: ForBinding<'a>,
+  pub body: Statement<'a>,
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct SwitchStatement<'a> {
+  pub cases: Vec<'a, SwitchCase<'a>>,
+  pub expression: Expression<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct SwitchCase<'a> {
+  pub expression: Option<Expression<'a>>,
+  pub body: Vec<'a, Statement<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WithStatement<'a> {
+  pub expression: Expression<'a>,
+  pub body: Statement<'a>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LabelStatement<'a> {
+  pub label: Identifier<'a>,
+  pub span: Span,
+  pub statement: Statement<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TryStatement<'a> {
+  pub body: Block<'a>,
+  pub catch: Option<CatchBlock<'a>>,
+  pub finally: Option<Block<'a>>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct CatchBlock<'a> {
+  pub parameter: Option<BindingPattern<'a>>,
+  pub body: Block<'a>,
+  pub span: Span,
+}
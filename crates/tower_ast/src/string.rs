@@ -0,0 +1,392 @@
+use std::{
+  borrow::Cow,
+  char::decode_utf16,
+  cmp::Ordering,
+  fmt,
+  hash::{Hash, Hasher},
+  iter, str,
+};
+
+use bumpalo::Bump;
+
+// a JavaScript string value, any sequence of UTF-16 code units including lone surrogates. the
+// code units are held in the narrowest form that's cheap to get to: a byte each when they're all
+// below 0x100, UTF-8 when they're well formed, and as they are otherwise. lengths, indices and
+// the order are all in code units whatever the form
+#[derive(Clone, Copy)]
+pub struct JsStr<'a>(Repr<'a>);
+
+#[derive(Clone, Copy)]
+enum Repr<'a> {
+  Latin1(&'a [u8]),
+  // the length in code units, so it isn't counted again
+  Utf8(&'a str, usize),
+  Utf16(&'a [u16]),
+}
+
+impl<'a> JsStr<'a> {
+  pub fn len(&self) -> usize {
+    match self.0 {
+      Repr::Latin1(bytes) => bytes.len(),
+      Repr::Utf8(_, length) => length,
+      Repr::Utf16(units) => units.len(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  // the code unit at an index, what indexing and charCodeAt give. UTF-8 is walked from the start
+  pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+    match self.0 {
+      Repr::Latin1(bytes) => bytes.get(index).map(|byte| *byte as u16),
+      Repr::Utf8(text, _) => text.encode_utf16().nth(index),
+      Repr::Utf16(units) => units.get(index).copied(),
+    }
+  }
+
+  pub fn code_units(&self) -> CodeUnits<'a> {
+    CodeUnits(match self.0 {
+      Repr::Latin1(bytes) => CodeUnitsRepr::Latin1(bytes.iter()),
+      Repr::Utf8(text, _) => CodeUnitsRepr::Utf8(text.encode_utf16()),
+      Repr::Utf16(units) => CodeUnitsRepr::Utf16(units.iter()),
+    })
+  }
+
+  // the code points the way String.prototype[Symbol.iterator] sees them, a lone surrogate is one
+  // on its own
+  pub fn code_points(&self) -> impl Iterator<Item = u32> + 'a {
+    decode_utf16(self.code_units()).map(|point| match point {
+      Ok(c) => c as u32,
+      Err(error) => error.unpaired_surrogate() as u32,
+    })
+  }
+
+  // the text when it can be borrowed as UTF-8, which is always when it was made from a `str`
+  pub fn as_str(&self) -> Option<&'a str> {
+    match self.0 {
+      Repr::Latin1(bytes) if bytes.is_ascii() => Some(str::from_utf8(bytes).unwrap()),
+      Repr::Utf8(text, _) => Some(text),
+      _ => None,
+    }
+  }
+
+  // String.prototype.isWellFormed, no lone surrogates
+  pub fn is_well_formed(&self) -> bool {
+    match self.0 {
+      Repr::Latin1(_) | Repr::Utf8(..) => true,
+      Repr::Utf16(units) => decode_utf16(units.iter().copied()).all(|point| point.is_ok()),
+    }
+  }
+
+  // the text with each lone surrogate replaced by U+FFFD, borrowed when nothing needs replacing
+  pub fn to_string_lossy(&self) -> Cow<'a, str> {
+    match self.as_str() {
+      Some(text) => Cow::Borrowed(text),
+      None => Cow::Owned(
+        decode_utf16(self.code_units())
+          .map(|point| point.unwrap_or(char::REPLACEMENT_CHARACTER))
+          .collect(),
+      ),
+    }
+  }
+
+  pub fn to_js_string(&self) -> JsString {
+    JsString(match self.0 {
+      Repr::Latin1(bytes) => Owned::Latin1(bytes.to_vec()),
+      Repr::Utf8(text, length) => Owned::Utf8(text.to_string(), length),
+      Repr::Utf16(units) => Owned::Utf16(units.to_vec()),
+    })
+  }
+}
+
+impl<'a> From<&'a str> for JsStr<'a> {
+  fn from(text: &'a str) -> Self {
+    if text.is_ascii() {
+      JsStr(Repr::Latin1(text.as_bytes()))
+    } else {
+      JsStr(Repr::Utf8(text, text.encode_utf16().count()))
+    }
+  }
+}
+
+impl PartialEq for JsStr<'_> {
+  fn eq(&self, other: &Self) -> bool {
+    match (self.0, other.0) {
+      (Repr::Latin1(a), Repr::Latin1(b)) => a == b,
+      (Repr::Utf8(a, _), Repr::Utf8(b, _)) => a == b,
+      (Repr::Utf16(a), Repr::Utf16(b)) => a == b,
+      _ => self.len() == other.len() && self.code_units().eq(other.code_units()),
+    }
+  }
+}
+
+impl Eq for JsStr<'_> {}
+
+impl PartialEq<str> for JsStr<'_> {
+  fn eq(&self, other: &str) -> bool {
+    match self.as_str() {
+      Some(text) => text == other,
+      None => self.code_units().eq(other.encode_utf16()),
+    }
+  }
+}
+
+impl PartialEq<&str> for JsStr<'_> {
+  fn eq(&self, other: &&str) -> bool {
+    self == *other
+  }
+}
+
+// code unit order, which is how the relational operators compare strings
+impl Ord for JsStr<'_> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self.0, other.0) {
+      (Repr::Latin1(a), Repr::Latin1(b)) => a.cmp(b),
+      (Repr::Utf16(a), Repr::Utf16(b)) => a.cmp(b),
+      _ => self.code_units().cmp(other.code_units()),
+    }
+  }
+}
+
+impl PartialOrd for JsStr<'_> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+// the same code units hash the same whatever form they're held in
+impl Hash for JsStr<'_> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    state.write_usize(self.len());
+    for unit in self.code_units() {
+      state.write_u16(unit);
+    }
+  }
+}
+
+impl fmt::Display for JsStr<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.to_string_lossy())
+  }
+}
+
+// like a `str`, with a lone surrogate as `\u{d800}`
+impl fmt::Debug for JsStr<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.is_well_formed() {
+      return fmt::Debug::fmt(&*self.to_string_lossy(), f);
+    }
+
+    f.write_str("\"")?;
+    for point in decode_utf16(self.code_units()) {
+      match point {
+        Ok(c) => write!(f, "{}", c.escape_debug())?,
+        Err(error) => write!(f, "\\u{{{:x}}}", error.unpaired_surrogate())?,
+      }
+    }
+    f.write_str("\"")
+  }
+}
+
+pub struct CodeUnits<'a>(CodeUnitsRepr<'a>);
+
+enum CodeUnitsRepr<'a> {
+  Latin1(std::slice::Iter<'a, u8>),
+  Utf8(str::EncodeUtf16<'a>),
+  Utf16(std::slice::Iter<'a, u16>),
+}
+
+impl Iterator for CodeUnits<'_> {
+  type Item = u16;
+
+  fn next(&mut self) -> Option<u16> {
+    match &mut self.0 {
+      CodeUnitsRepr::Latin1(bytes) => bytes.next().map(|byte| *byte as u16),
+      CodeUnitsRepr::Utf8(units) => units.next(),
+      CodeUnitsRepr::Utf16(units) => units.next().copied(),
+    }
+  }
+}
+
+// a JavaScript string value that's being built or owned outside an arena. it starts out a byte
+// per code unit, moves to UTF-8 for the first character past 0xFF and to code units for the
+// first surrogate pushed on its own
+#[derive(Clone)]
+pub struct JsString(Owned);
+
+#[derive(Clone)]
+enum Owned {
+  Latin1(Vec<u8>),
+  Utf8(String, usize),
+  Utf16(Vec<u16>),
+}
+
+impl JsString {
+  pub fn new() -> Self {
+    JsString(Owned::Latin1(Vec::new()))
+  }
+
+  // the narrowest form of the code units
+  pub fn from_code_units(units: &[u16]) -> Self {
+    if units.iter().all(|unit| *unit < 0x100) {
+      return JsString(Owned::Latin1(
+        units.iter().map(|unit| *unit as u8).collect(),
+      ));
+    }
+
+    match decode_utf16(units.iter().copied()).collect::<Result<String, _>>() {
+      Ok(text) => JsString(Owned::Utf8(text, units.len())),
+      Err(_) => JsString(Owned::Utf16(units.to_vec())),
+    }
+  }
+
+  pub fn as_js_str(&self) -> JsStr<'_> {
+    JsStr(match &self.0 {
+      Owned::Latin1(bytes) => Repr::Latin1(bytes),
+      Owned::Utf8(text, length) => Repr::Utf8(text, *length),
+      Owned::Utf16(units) => Repr::Utf16(units),
+    })
+  }
+
+  // a copy in the arena, narrowed if the surrogates that were pushed one at a time paired up
+  pub fn alloc_in<'a>(&self, allocator: &'a Bump) -> JsStr<'a> {
+    JsStr(match &self.0 {
+      Owned::Latin1(bytes) => Repr::Latin1(allocator.alloc_slice_copy(bytes)),
+      Owned::Utf8(text, length) => Repr::Utf8(allocator.alloc_str(text), *length),
+      Owned::Utf16(units) => match JsString::from_code_units(units).0 {
+        Owned::Utf16(_) => Repr::Utf16(allocator.alloc_slice_copy(units)),
+        narrowed => return JsString(narrowed).alloc_in(allocator),
+      },
+    })
+  }
+
+  pub fn len(&self) -> usize {
+    self.as_js_str().len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  pub fn push(&mut self, c: char) {
+    match &mut self.0 {
+      Owned::Latin1(bytes) if (c as u32) < 0x100 => bytes.push(c as u8),
+      Owned::Latin1(bytes) => {
+        let mut text = bytes.iter().map(|byte| *byte as char).collect::<String>();
+        let length = bytes.len() + c.len_utf16();
+        text.push(c);
+        self.0 = Owned::Utf8(text, length);
+      }
+      Owned::Utf8(text, length) => {
+        text.push(c);
+        *length += c.len_utf16();
+      }
+      Owned::Utf16(units) => units.extend(c.encode_utf16(&mut [0; 2]).iter()),
+    }
+  }
+
+  pub fn push_code_unit(&mut self, unit: u16) {
+    if let Some(c) = char::from_u32(unit as u32) {
+      return self.push(c);
+    }
+
+    if !matches!(self.0, Owned::Utf16(_)) {
+      self.0 = Owned::Utf16(self.as_js_str().code_units().collect());
+    }
+    if let Owned::Utf16(units) = &mut self.0 {
+      units.push(unit);
+    }
+  }
+
+  // a code point, or a surrogate on its own when it's in the surrogate range
+  pub fn push_code_point(&mut self, point: u32) {
+    match char::from_u32(point) {
+      Some(c) => self.push(c),
+      None => self.push_code_unit(point as u16),
+    }
+  }
+
+  pub fn push_js_str(&mut self, string: JsStr) {
+    match (&mut self.0, string.as_str()) {
+      (Owned::Utf8(text, length), Some(other)) => {
+        text.push_str(other);
+        *length += string.len();
+      }
+      _ => {
+        for point in string.code_points() {
+          self.push_code_point(point);
+        }
+      }
+    }
+  }
+}
+
+impl Default for JsString {
+  fn default() -> Self {
+    JsString::new()
+  }
+}
+
+impl From<&str> for JsString {
+  fn from(text: &str) -> Self {
+    JsStr::from(text).to_js_string()
+  }
+}
+
+impl FromIterator<char> for JsString {
+  fn from_iter<T: IntoIterator<Item = char>>(chars: T) -> Self {
+    let mut string = JsString::new();
+    for c in chars {
+      string.push(c);
+    }
+    string
+  }
+}
+
+impl iter::Extend<char> for JsString {
+  fn extend<T: IntoIterator<Item = char>>(&mut self, chars: T) {
+    for c in chars {
+      self.push(c);
+    }
+  }
+}
+
+impl PartialEq for JsString {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_js_str() == other.as_js_str()
+  }
+}
+
+impl Eq for JsString {}
+
+impl PartialEq<str> for JsString {
+  fn eq(&self, other: &str) -> bool {
+    self.as_js_str() == *other
+  }
+}
+
+impl PartialEq<&str> for JsString {
+  fn eq(&self, other: &&str) -> bool {
+    self.as_js_str() == **other
+  }
+}
+
+impl Hash for JsString {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_js_str().hash(state)
+  }
+}
+
+impl fmt::Display for JsString {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Display::fmt(&self.as_js_str(), f)
+  }
+}
+
+impl fmt::Debug for JsString {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Debug::fmt(&self.as_js_str(), f)
+  }
+}
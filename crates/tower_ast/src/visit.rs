@@ -0,0 +1,970 @@
+use crate::{
+  binding::{
+    ArrayBindingPattern, BindingPattern, BindingPatternInitializer, ObjectBindingPattern,
+    ObjectBindingProperty,
+  },
+  class::{
+    ClassAccessor, ClassDefinition, ClassElement, ClassElementName, ClassField, ClassGetter,
+    ClassMethod, ClassSetter, ClassStaticBlock,
+  },
+  expression::{
+    ArrayElement, CallExpression, ConditionalExpression, Expression, InExpression,
+    MemberExpression, MemberName, NewExpression, TaggedTemplateLiteral, TemplateLiteral,
+    YieldExpression,
+  },
+  function::{
+    Argument, ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition,
+  },
+  jsx::{JsxAttribute, JsxAttributeValue, JsxChild, JsxElement, JsxElementName},
+  object::{
+    ObjectGetter, ObjectMethod, ObjectProperty, ObjectSetter, PropertyDefinition, PropertyName,
+  },
+  op::{AssignmentOpExpression, BinaryOpExpression, UnaryOpExpression},
+  statement::{
+    CatchBlock, DoWhileStatement, ForBinding, ForInStatement, ForInitializer, ForOfStatement,
+    ForStatement, IfStatement, LabelStatement, LexicalDeclaration, Statement, SwitchCase,
+    SwitchStatement, TryStatement, WhileStatement, WithStatement,
+  },
+  Identifier, Program,
+};
+
+// every method walks into the children of its node by default, an implementation overrides the
+// ones it cares about and calls the matching `walk_` function to keep going deeper
+pub trait Visit<'a> {
+  fn visit_program(&mut self, program: &'a Program<'a>) {
+    walk_program(self, program);
+  }
+
+  fn visit_statement_list(&mut self, statements: &'a [Statement<'a>]) {
+    walk_statement_list(self, statements);
+  }
+
+  fn visit_statement(&mut self, statement: &'a Statement<'a>) {
+    walk_statement(self, statement);
+  }
+
+  fn visit_variable_declaration(&mut self, bindings: &'a [BindingPatternInitializer<'a>]) {
+    walk_variable_declaration(self, bindings);
+  }
+
+  fn visit_lexical_declaration(&mut self, declaration: &'a LexicalDeclaration<'a>) {
+    walk_lexical_declaration(self, declaration);
+  }
+
+  fn visit_if_statement(&mut self, statement: &'a IfStatement<'a>) {
+    walk_if_statement(self, statement);
+  }
+
+  fn visit_do_while_statement(&mut self, statement: &'a DoWhileStatement<'a>) {
+    walk_do_while_statement(self, statement);
+  }
+
+  fn visit_while_statement(&mut self, statement: &'a WhileStatement<'a>) {
+    walk_while_statement(self, statement);
+  }
+
+  fn visit_for_statement(&mut self, statement: &'a ForStatement<'a>) {
+    walk_for_statement(self, statement);
+  }
+
+  fn visit_for_initializer(&mut self, initializer: &'a ForInitializer<'a>) {
+    walk_for_initializer(self, initializer);
+  }
+
+  fn visit_for_in_statement(&mut self, statement: &'a ForInStatement<'a>) {
+    walk_for_in_statement(self, statement);
+  }
+
+  fn visit_for_of_statement(&mut self, statement: &'a ForOfStatement<'a>) {
+    walk_for_of_statement(self, statement);
+  }
+
+  fn visit_for_binding(&mut self, binding: &'a ForBinding<'a>) {
+    walk_for_binding(self, binding);
+  }
+
+  fn visit_switch_statement(&mut self, statement: &'a SwitchStatement<'a>) {
+    walk_switch_statement(self, statement);
+  }
+
+  fn visit_switch_case(&mut self, case: &'a SwitchCase<'a>) {
+    walk_switch_case(self, case);
+  }
+
+  fn visit_with_statement(&mut self, statement: &'a WithStatement<'a>) {
+    walk_with_statement(self, statement);
+  }
+
+  fn visit_label_statement(&mut self, statement: &'a LabelStatement<'a>) {
+    walk_label_statement(self, statement);
+  }
+
+  fn visit_try_statement(&mut self, statement: &'a TryStatement<'a>) {
+    walk_try_statement(self, statement);
+  }
+
+  fn visit_catch_block(&mut self, catch: &'a CatchBlock<'a>) {
+    walk_catch_block(self, catch);
+  }
+
+  fn visit_expression(&mut self, expression: &'a Expression<'a>) {
+    walk_expression(self, expression);
+  }
+
+  fn visit_identifier_reference(&mut self, _identifier: &'a Identifier<'a>) {}
+
+  fn visit_binding_identifier(&mut self, _identifier: &'a Identifier<'a>) {}
+
+  fn visit_array_element(&mut self, element: &'a ArrayElement<'a>) {
+    walk_array_element(self, element);
+  }
+
+  fn visit_template_literal(&mut self, literal: &'a TemplateLiteral<'a>) {
+    walk_template_literal(self, literal);
+  }
+
+  fn visit_tagged_template_literal(&mut self, literal: &'a TaggedTemplateLiteral<'a>) {
+    walk_tagged_template_literal(self, literal);
+  }
+
+  fn visit_member_expression(&mut self, expression: &'a MemberExpression<'a>) {
+    walk_member_expression(self, expression);
+  }
+
+  fn visit_member_name(&mut self, name: &'a MemberName<'a>) {
+    walk_member_name(self, name);
+  }
+
+  fn visit_in_expression(&mut self, expression: &'a InExpression<'a>) {
+    walk_in_expression(self, expression);
+  }
+
+  fn visit_call_expression(&mut self, expression: &'a CallExpression<'a>) {
+    walk_call_expression(self, expression);
+  }
+
+  fn visit_new_expression(&mut self, expression: &'a NewExpression<'a>) {
+    walk_new_expression(self, expression);
+  }
+
+  fn visit_argument(&mut self, argument: &'a Argument<'a>) {
+    walk_argument(self, argument);
+  }
+
+  fn visit_conditional_expression(&mut self, expression: &'a ConditionalExpression<'a>) {
+    walk_conditional_expression(self, expression);
+  }
+
+  fn visit_yield_expression(&mut self, expression: &'a YieldExpression<'a>) {
+    walk_yield_expression(self, expression);
+  }
+
+  fn visit_unary_op_expression(&mut self, expression: &'a UnaryOpExpression<'a>) {
+    walk_unary_op_expression(self, expression);
+  }
+
+  fn visit_binary_op_expression(&mut self, expression: &'a BinaryOpExpression<'a>) {
+    walk_binary_op_expression(self, expression);
+  }
+
+  fn visit_assignment_op_expression(&mut self, expression: &'a AssignmentOpExpression<'a>) {
+    walk_assignment_op_expression(self, expression);
+  }
+
+  fn visit_object_property(&mut self, property: &'a ObjectProperty<'a>) {
+    walk_object_property(self, property);
+  }
+
+  fn visit_property_name(&mut self, name: &'a PropertyName<'a>) {
+    walk_property_name(self, name);
+  }
+
+  fn visit_property_definition(&mut self, definition: &'a PropertyDefinition<'a>) {
+    walk_property_definition(self, definition);
+  }
+
+  fn visit_object_method(&mut self, method: &'a ObjectMethod<'a>) {
+    walk_object_method(self, method);
+  }
+
+  fn visit_object_getter(&mut self, getter: &'a ObjectGetter<'a>) {
+    walk_object_getter(self, getter);
+  }
+
+  fn visit_object_setter(&mut self, setter: &'a ObjectSetter<'a>) {
+    walk_object_setter(self, setter);
+  }
+
+  fn visit_function_definition(&mut self, definition: &'a FunctionDefinition<'a>) {
+    walk_function_definition(self, definition);
+  }
+
+  fn visit_arrow_function_definition(&mut self, definition: &'a ArrowFunctionDefinition<'a>) {
+    walk_arrow_function_definition(self, definition);
+  }
+
+  fn visit_formal_parameters(&mut self, parameters: &'a FormalParameters<'a>) {
+    walk_formal_parameters(self, parameters);
+  }
+
+  fn visit_binding_pattern(&mut self, pattern: &'a BindingPattern<'a>) {
+    walk_binding_pattern(self, pattern);
+  }
+
+  fn visit_binding_pattern_initializer(&mut self, binding: &'a BindingPatternInitializer<'a>) {
+    walk_binding_pattern_initializer(self, binding);
+  }
+
+  fn visit_array_binding_pattern(&mut self, pattern: &'a ArrayBindingPattern<'a>) {
+    walk_array_binding_pattern(self, pattern);
+  }
+
+  fn visit_object_binding_pattern(&mut self, pattern: &'a ObjectBindingPattern<'a>) {
+    walk_object_binding_pattern(self, pattern);
+  }
+
+  fn visit_object_binding_property(&mut self, property: &'a ObjectBindingProperty<'a>) {
+    walk_object_binding_property(self, property);
+  }
+
+  fn visit_class_definition(&mut self, definition: &'a ClassDefinition<'a>) {
+    walk_class_definition(self, definition);
+  }
+
+  fn visit_class_element(&mut self, element: &'a ClassElement<'a>) {
+    walk_class_element(self, element);
+  }
+
+  fn visit_class_element_name(&mut self, name: &'a ClassElementName<'a>) {
+    walk_class_element_name(self, name);
+  }
+
+  fn visit_class_accessor(&mut self, accessor: &'a ClassAccessor<'a>) {
+    walk_class_accessor(self, accessor);
+  }
+
+  fn visit_class_field(&mut self, field: &'a ClassField<'a>) {
+    walk_class_field(self, field);
+  }
+
+  fn visit_class_method(&mut self, method: &'a ClassMethod<'a>) {
+    walk_class_method(self, method);
+  }
+
+  fn visit_class_getter(&mut self, getter: &'a ClassGetter<'a>) {
+    walk_class_getter(self, getter);
+  }
+
+  fn visit_class_setter(&mut self, setter: &'a ClassSetter<'a>) {
+    walk_class_setter(self, setter);
+  }
+
+  fn visit_class_static_block(&mut self, block: &'a ClassStaticBlock<'a>) {
+    walk_class_static_block(self, block);
+  }
+
+  fn visit_jsx_element(&mut self, element: &'a JsxElement<'a>) {
+    walk_jsx_element(self, element);
+  }
+
+  fn visit_jsx_element_name(&mut self, _name: &'a JsxElementName<'a>) {}
+
+  fn visit_jsx_attribute(&mut self, attribute: &'a JsxAttribute<'a>) {
+    walk_jsx_attribute(self, attribute);
+  }
+
+  fn visit_jsx_attribute_value(&mut self, value: &'a JsxAttributeValue<'a>) {
+    walk_jsx_attribute_value(self, value);
+  }
+
+  fn visit_jsx_child(&mut self, child: &'a JsxChild<'a>) {
+    walk_jsx_child(self, child);
+  }
+}
+
+pub fn walk_program<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, program: &'a Program<'a>) {
+  visitor.visit_statement_list(&program.statement_list);
+}
+
+pub fn walk_statement_list<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statements: &'a [Statement<'a>],
+) {
+  for statement in statements {
+    visitor.visit_statement(statement);
+  }
+}
+
+pub fn walk_statement<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, statement: &'a Statement<'a>) {
+  match statement {
+    Statement::Block(block) => visitor.visit_statement_list(&block.statements),
+    Statement::Break(_) | Statement::Continue(_) | Statement::Debugger(_) | Statement::Empty(_) => {
+    }
+    Statement::Class(definition) => visitor.visit_class_definition(definition),
+    Statement::DoWhile(statement) => visitor.visit_do_while_statement(statement),
+    Statement::Expression(statement) => visitor.visit_expression(&statement.expression),
+    Statement::For(statement) => visitor.visit_for_statement(statement),
+    Statement::ForIn(statement) => visitor.visit_for_in_statement(statement),
+    Statement::ForOf(statement) => visitor.visit_for_of_statement(statement),
+    Statement::Function(definition) => visitor.visit_function_definition(definition),
+    Statement::If(statement) => visitor.visit_if_statement(statement),
+    Statement::Label(statement) => visitor.visit_label_statement(statement),
+    Statement::Lexical(declaration) => visitor.visit_lexical_declaration(declaration),
+    Statement::Return(statement) => {
+      if let Some(argument) = &statement.argument {
+        visitor.visit_expression(argument);
+      }
+    }
+    Statement::Switch(statement) => visitor.visit_switch_statement(statement),
+    Statement::Throw(statement) => visitor.visit_expression(&statement.argument),
+    Statement::Try(statement) => visitor.visit_try_statement(statement),
+    Statement::Variable(declaration) => visitor.visit_variable_declaration(&declaration.bindings),
+    Statement::With(statement) => visitor.visit_with_statement(statement),
+    Statement::While(statement) => visitor.visit_while_statement(statement),
+  }
+}
+
+pub fn walk_variable_declaration<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  bindings: &'a [BindingPatternInitializer<'a>],
+) {
+  for binding in bindings {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+}
+
+pub fn walk_lexical_declaration<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  declaration: &'a LexicalDeclaration<'a>,
+) {
+  for binding in &declaration.bindings {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+}
+
+pub fn walk_if_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a IfStatement<'a>,
+) {
+  visitor.visit_expression(&statement.condition);
+  visitor.visit_statement(&statement.consequent);
+  if let Some(alternate) = &statement.alternate {
+    visitor.visit_statement(alternate);
+  }
+}
+
+pub fn walk_do_while_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a DoWhileStatement<'a>,
+) {
+  visitor.visit_statement(&statement.body);
+  visitor.visit_expression(&statement.condition);
+}
+
+pub fn walk_while_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a WhileStatement<'a>,
+) {
+  visitor.visit_expression(&statement.condition);
+  visitor.visit_statement(&statement.body);
+}
+
+pub fn walk_for_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a ForStatement<'a>,
+) {
+  if let Some(initializer) = &statement.initializer {
+    visitor.visit_for_initializer(initializer);
+  }
+
+  if let Some(condition) = &statement.condition {
+    visitor.visit_expression(condition);
+  }
+
+  if let Some(update) = &statement.update {
+    visitor.visit_expression(update);
+  }
+
+  visitor.visit_statement(&statement.body);
+}
+
+pub fn walk_for_initializer<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  initializer: &'a ForInitializer<'a>,
+) {
+  match initializer {
+    ForInitializer::Expression(expression) => visitor.visit_expression(expression),
+    ForInitializer::Lexical(declaration) => visitor.visit_lexical_declaration(declaration),
+    ForInitializer::Variable(declaration) => {
+      visitor.visit_variable_declaration(&declaration.bindings)
+    }
+  }
+}
+
+pub fn walk_for_in_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a ForInStatement<'a>,
+) {
+  visitor.visit_for_binding(&statement.binding);
+  visitor.visit_expression(&statement.expression);
+  visitor.visit_statement(&statement.body);
+}
+
+pub fn walk_for_of_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a ForOfStatement<'a>,
+) {
+  visitor.visit_for_binding(&statement.binding);
+  visitor.visit_expression(&statement.expression);
+  visitor.visit_statement(&statement.body);
+}
+
+pub fn walk_for_binding<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, binding: &'a ForBinding<'a>) {
+  match binding {
+    ForBinding::Expression(expression) => visitor.visit_expression(expression),
+    ForBinding::Lexical(_, pattern, _) => visitor.visit_binding_pattern(pattern),
+    ForBinding::Variable(binding, _) => visitor.visit_binding_pattern_initializer(binding),
+  }
+}
+
+pub fn walk_switch_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a SwitchStatement<'a>,
+) {
+  visitor.visit_expression(&statement.expression);
+  for case in &statement.cases {
+    visitor.visit_switch_case(case);
+  }
+}
+
+pub fn walk_switch_case<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, case: &'a SwitchCase<'a>) {
+  if let Some(expression) = &case.expression {
+    visitor.visit_expression(expression);
+  }
+
+  visitor.visit_statement_list(&case.body);
+}
+
+pub fn walk_with_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a WithStatement<'a>,
+) {
+  visitor.visit_expression(&statement.expression);
+  visitor.visit_statement(&statement.body);
+}
+
+pub fn walk_label_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a LabelStatement<'a>,
+) {
+  visitor.visit_statement(&statement.statement);
+}
+
+pub fn walk_try_statement<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &'a TryStatement<'a>,
+) {
+  visitor.visit_statement_list(&statement.body.statements);
+
+  if let Some(catch) = &statement.catch {
+    visitor.visit_catch_block(catch);
+  }
+
+  if let Some(finally) = &statement.finally {
+    visitor.visit_statement_list(&finally.statements);
+  }
+}
+
+pub fn walk_catch_block<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, catch: &'a CatchBlock<'a>) {
+  if let Some(parameter) = &catch.parameter {
+    visitor.visit_binding_pattern(parameter);
+  }
+
+  visitor.visit_statement_list(&catch.body.statements);
+}
+
+pub fn walk_expression<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, expression: &'a Expression<'a>) {
+  match expression {
+    Expression::Array(literal) => {
+      for element in &literal.elements {
+        visitor.visit_array_element(element);
+      }
+    }
+    Expression::ArrowFunction(definition) => visitor.visit_arrow_function_definition(definition),
+    Expression::Assignment(expression) => visitor.visit_assignment_op_expression(expression),
+    Expression::BigInt(_)
+    | Expression::Boolean(_)
+    | Expression::ImportMeta(_)
+    | Expression::NewTarget(_)
+    | Expression::Null(_)
+    | Expression::Number(_)
+    | Expression::RegExp(_)
+    | Expression::String(_)
+    | Expression::Super(_)
+    | Expression::This(_) => {}
+    Expression::BinaryOp(expression) => visitor.visit_binary_op_expression(expression),
+    Expression::Call(expression) => visitor.visit_call_expression(expression),
+    Expression::Chain(expression) => visitor.visit_expression(expression),
+    Expression::Class(definition) => visitor.visit_class_definition(definition),
+    Expression::Conditional(expression) => visitor.visit_conditional_expression(expression),
+    Expression::Function(definition) => visitor.visit_function_definition(definition),
+    Expression::Group(expression) => visitor.visit_expression(&expression.expression),
+    Expression::Identifier(identifier) => visitor.visit_identifier_reference(identifier),
+    Expression::Import(expression) => visitor.visit_expression(&expression.argument),
+    Expression::In(expression) => visitor.visit_in_expression(expression),
+    Expression::JsxElement(element) => visitor.visit_jsx_element(element),
+    Expression::JsxFragment(fragment) => {
+      for child in &fragment.children {
+        visitor.visit_jsx_child(child);
+      }
+    }
+    Expression::List(expression) => {
+      for expression in &expression.expressions {
+        visitor.visit_expression(expression);
+      }
+    }
+    Expression::Member(expression) => visitor.visit_member_expression(expression),
+    Expression::New(expression) => visitor.visit_new_expression(expression),
+    Expression::Object(literal) => {
+      for property in &literal.properties {
+        visitor.visit_object_property(property);
+      }
+    }
+    Expression::TaggedTemplate(literal) => visitor.visit_tagged_template_literal(literal),
+    Expression::Template(literal) => visitor.visit_template_literal(literal),
+    Expression::UnaryOp(expression) => visitor.visit_unary_op_expression(expression),
+    Expression::Yield(expression) => visitor.visit_yield_expression(expression),
+  }
+}
+
+pub fn walk_array_element<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  element: &'a ArrayElement<'a>,
+) {
+  match element {
+    ArrayElement::Elision => {}
+    ArrayElement::Expression(expression) => visitor.visit_expression(expression),
+    ArrayElement::Spread(spread) => visitor.visit_expression(&spread.argument),
+  }
+}
+
+pub fn walk_template_literal<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: &'a TemplateLiteral<'a>,
+) {
+  for substitution in &literal.substitutions {
+    visitor.visit_expression(substitution);
+  }
+}
+
+pub fn walk_tagged_template_literal<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: &'a TaggedTemplateLiteral<'a>,
+) {
+  visitor.visit_expression(&literal.tag);
+  for substitution in &literal.substitutions {
+    visitor.visit_expression(substitution);
+  }
+}
+
+pub fn walk_member_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a MemberExpression<'a>,
+) {
+  visitor.visit_expression(&expression.object);
+  visitor.visit_member_name(&expression.property);
+}
+
+pub fn walk_member_name<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, name: &'a MemberName<'a>) {
+  match name {
+    MemberName::Computed(expression) => visitor.visit_expression(expression),
+    MemberName::Private(_) | MemberName::Static(_) => {}
+  }
+}
+
+pub fn walk_in_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a InExpression<'a>,
+) {
+  visitor.visit_member_name(&expression.name);
+  visitor.visit_expression(&expression.argument);
+}
+
+pub fn walk_call_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a CallExpression<'a>,
+) {
+  visitor.visit_expression(&expression.callee);
+  for argument in &expression.arguments {
+    visitor.visit_argument(argument);
+  }
+}
+
+pub fn walk_new_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a NewExpression<'a>,
+) {
+  visitor.visit_expression(&expression.callee);
+  if let Some(arguments) = &expression.arguments {
+    for argument in arguments {
+      visitor.visit_argument(argument);
+    }
+  }
+}
+
+pub fn walk_argument<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, argument: &'a Argument<'a>) {
+  match argument {
+    Argument::Positional(expression) => visitor.visit_expression(expression),
+    Argument::Spread(spread) => visitor.visit_expression(&spread.argument),
+  }
+}
+
+pub fn walk_conditional_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a ConditionalExpression<'a>,
+) {
+  visitor.visit_expression(&expression.condition);
+  visitor.visit_expression(&expression.consequent);
+  visitor.visit_expression(&expression.alternate);
+}
+
+pub fn walk_yield_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a YieldExpression<'a>,
+) {
+  if let Some(argument) = &expression.argument {
+    visitor.visit_expression(argument);
+  }
+}
+
+pub fn walk_unary_op_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a UnaryOpExpression<'a>,
+) {
+  visitor.visit_expression(&expression.argument);
+}
+
+pub fn walk_binary_op_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a BinaryOpExpression<'a>,
+) {
+  visitor.visit_expression(&expression.left);
+  visitor.visit_expression(&expression.right);
+}
+
+pub fn walk_assignment_op_expression<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &'a AssignmentOpExpression<'a>,
+) {
+  visitor.visit_expression(&expression.left);
+  visitor.visit_expression(&expression.right);
+}
+
+pub fn walk_object_property<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  property: &'a ObjectProperty<'a>,
+) {
+  match property {
+    ObjectProperty::Getter(getter) => visitor.visit_object_getter(getter),
+    ObjectProperty::Method(method) => visitor.visit_object_method(method),
+    ObjectProperty::Property(definition) => visitor.visit_property_definition(definition),
+    ObjectProperty::Setter(setter) => visitor.visit_object_setter(setter),
+    ObjectProperty::Shorthand(identifier) => visitor.visit_identifier_reference(identifier),
+    ObjectProperty::Spread(spread) => visitor.visit_expression(&spread.argument),
+  }
+}
+
+pub fn walk_property_name<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, name: &'a PropertyName<'a>) {
+  match name {
+    PropertyName::Computed(expression) => visitor.visit_expression(expression),
+    PropertyName::Static(_) => {}
+  }
+}
+
+pub fn walk_property_definition<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &'a PropertyDefinition<'a>,
+) {
+  visitor.visit_property_name(&definition.property);
+  visitor.visit_expression(&definition.expression);
+}
+
+pub fn walk_object_method<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  method: &'a ObjectMethod<'a>,
+) {
+  visitor.visit_property_name(&method.property);
+  visitor.visit_formal_parameters(&method.parameters);
+  visitor.visit_statement_list(&method.body.statements);
+}
+
+pub fn walk_object_getter<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  getter: &'a ObjectGetter<'a>,
+) {
+  visitor.visit_property_name(&getter.property);
+  visitor.visit_statement_list(&getter.body.statements);
+}
+
+pub fn walk_object_setter<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  setter: &'a ObjectSetter<'a>,
+) {
+  visitor.visit_property_name(&setter.property);
+  visitor.visit_binding_pattern_initializer(&setter.parameter);
+  visitor.visit_statement_list(&setter.body.statements);
+}
+
+pub fn walk_function_definition<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &'a FunctionDefinition<'a>,
+) {
+  if let Some(identifier) = &definition.identifier {
+    visitor.visit_binding_identifier(identifier);
+  }
+
+  visitor.visit_formal_parameters(&definition.parameters);
+  visitor.visit_statement_list(&definition.body.statements);
+}
+
+pub fn walk_arrow_function_definition<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &'a ArrowFunctionDefinition<'a>,
+) {
+  visitor.visit_formal_parameters(&definition.parameters);
+  match &definition.body {
+    ConciseBody::Block(block) => visitor.visit_statement_list(&block.statements),
+    ConciseBody::Expression(expression) => visitor.visit_expression(expression),
+  }
+}
+
+pub fn walk_formal_parameters<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  parameters: &'a FormalParameters<'a>,
+) {
+  for binding in &parameters.bindings {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+
+  if let Some(rest) = &parameters.rest {
+    visitor.visit_binding_pattern(&rest.pattern);
+  }
+}
+
+pub fn walk_binding_pattern<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &'a BindingPattern<'a>,
+) {
+  match pattern {
+    BindingPattern::Array(pattern) => visitor.visit_array_binding_pattern(pattern),
+    BindingPattern::Identifier(identifier) => visitor.visit_binding_identifier(identifier),
+    BindingPattern::Object(pattern) => visitor.visit_object_binding_pattern(pattern),
+  }
+}
+
+pub fn walk_binding_pattern_initializer<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  binding: &'a BindingPatternInitializer<'a>,
+) {
+  visitor.visit_binding_pattern(&binding.pattern);
+  if let Some(initializer) = &binding.initializer {
+    visitor.visit_expression(initializer);
+  }
+}
+
+pub fn walk_array_binding_pattern<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &'a ArrayBindingPattern<'a>,
+) {
+  for element in pattern.elements.iter().flatten() {
+    visitor.visit_binding_pattern_initializer(element);
+  }
+
+  if let Some(rest) = &pattern.rest {
+    visitor.visit_binding_pattern(&rest.pattern);
+  }
+}
+
+pub fn walk_object_binding_pattern<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &'a ObjectBindingPattern<'a>,
+) {
+  for property in &pattern.properties {
+    visitor.visit_object_binding_property(property);
+  }
+
+  if let Some(rest) = &pattern.rest {
+    visitor.visit_binding_pattern(&rest.pattern);
+  }
+}
+
+pub fn walk_object_binding_property<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  property: &'a ObjectBindingProperty<'a>,
+) {
+  visitor.visit_property_name(&property.property);
+  visitor.visit_binding_pattern_initializer(&property.binding);
+}
+
+pub fn walk_class_definition<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &'a ClassDefinition<'a>,
+) {
+  for decorator in &definition.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  if let Some(identifier) = &definition.identifier {
+    visitor.visit_binding_identifier(identifier);
+  }
+
+  if let Some(heritage) = &definition.heritage {
+    visitor.visit_expression(heritage);
+  }
+
+  for element in &definition.body {
+    visitor.visit_class_element(element);
+  }
+}
+
+pub fn walk_class_element<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  element: &'a ClassElement<'a>,
+) {
+  match element {
+    ClassElement::Accessor(accessor) => visitor.visit_class_accessor(accessor),
+    ClassElement::Field(field) => visitor.visit_class_field(field),
+    ClassElement::Getter(getter) => visitor.visit_class_getter(getter),
+    ClassElement::Method(method) => visitor.visit_class_method(method),
+    ClassElement::Setter(setter) => visitor.visit_class_setter(setter),
+    ClassElement::Static(block) => visitor.visit_class_static_block(block),
+  }
+}
+
+pub fn walk_class_element_name<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  name: &'a ClassElementName<'a>,
+) {
+  match name {
+    ClassElementName::Computed(expression) => visitor.visit_expression(expression),
+    ClassElementName::Private(_) | ClassElementName::Static(_) => {}
+  }
+}
+
+pub fn walk_class_accessor<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  accessor: &'a ClassAccessor<'a>,
+) {
+  for decorator in &accessor.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&accessor.name);
+  if let Some(value) = &accessor.value {
+    visitor.visit_expression(value);
+  }
+}
+
+pub fn walk_class_field<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, field: &'a ClassField<'a>) {
+  for decorator in &field.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&field.name);
+  if let Some(value) = &field.value {
+    visitor.visit_expression(value);
+  }
+}
+
+pub fn walk_class_method<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, method: &'a ClassMethod<'a>) {
+  for decorator in &method.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&method.name);
+  visitor.visit_formal_parameters(&method.parameters);
+  visitor.visit_statement_list(&method.body.statements);
+}
+
+pub fn walk_class_getter<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, getter: &'a ClassGetter<'a>) {
+  for decorator in &getter.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&getter.name);
+  visitor.visit_statement_list(&getter.body.statements);
+}
+
+pub fn walk_class_setter<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, setter: &'a ClassSetter<'a>) {
+  for decorator in &setter.decorators {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&setter.name);
+  visitor.visit_binding_pattern_initializer(&setter.parameter);
+  visitor.visit_statement_list(&setter.body.statements);
+}
+
+pub fn walk_class_static_block<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  block: &'a ClassStaticBlock<'a>,
+) {
+  visitor.visit_statement_list(&block.statements);
+}
+
+pub fn walk_jsx_element<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, element: &'a JsxElement<'a>) {
+  visitor.visit_jsx_element_name(&element.name);
+
+  for attribute in &element.attributes {
+    visitor.visit_jsx_attribute(attribute);
+  }
+
+  for child in &element.children {
+    visitor.visit_jsx_child(child);
+  }
+}
+
+pub fn walk_jsx_attribute<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  attribute: &'a JsxAttribute<'a>,
+) {
+  match attribute {
+    JsxAttribute::Attribute(attribute) => {
+      if let Some(value) = &attribute.value {
+        visitor.visit_jsx_attribute_value(value);
+      }
+    }
+    JsxAttribute::Spread(container) => visitor.visit_expression(&container.expression),
+  }
+}
+
+pub fn walk_jsx_attribute_value<'a, V: Visit<'a> + ?Sized>(
+  visitor: &mut V,
+  value: &'a JsxAttributeValue<'a>,
+) {
+  match value {
+    JsxAttributeValue::Element(element) => visitor.visit_jsx_element(element),
+    JsxAttributeValue::Expression(container) => visitor.visit_expression(&container.expression),
+    JsxAttributeValue::Fragment(fragment) => {
+      for child in &fragment.children {
+        visitor.visit_jsx_child(child);
+      }
+    }
+    JsxAttributeValue::String(_) => {}
+  }
+}
+
+pub fn walk_jsx_child<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, child: &'a JsxChild<'a>) {
+  match child {
+    JsxChild::Element(element) => visitor.visit_jsx_element(element),
+    JsxChild::Empty(_) | JsxChild::Text(_) => {}
+    JsxChild::Expression(container) | JsxChild::Spread(container) => {
+      visitor.visit_expression(&container.expression)
+    }
+    JsxChild::Fragment(fragment) => {
+      for child in &fragment.children {
+        visitor.visit_jsx_child(child);
+      }
+    }
+  }
+}
@@ -0,0 +1,1149 @@
+use bumpalo::{collections::Vec, Bump};
+
+use crate::{
+  binding::{
+    ArrayBindingPattern, BindingPattern, BindingPatternInitializer, ObjectBindingPattern,
+    ObjectBindingProperty,
+  },
+  class::{
+    ClassAccessor, ClassDefinition, ClassElement, ClassElementName, ClassField, ClassGetter,
+    ClassMethod, ClassSetter, ClassStaticBlock,
+  },
+  expression::{
+    ArrayElement, CallExpression, ConditionalExpression, Expression, InExpression,
+    MemberExpression, MemberName, NewExpression, TaggedTemplateLiteral, TemplateLiteral,
+    YieldExpression,
+  },
+  function::{
+    Argument, ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition,
+  },
+  jsx::{JsxAttribute, JsxAttributeValue, JsxChild, JsxElement, JsxElementName, JsxFragment},
+  object::{
+    ObjectGetter, ObjectMethod, ObjectProperty, ObjectSetter, PropertyDefinition, PropertyName,
+  },
+  op::{AssignmentOpExpression, BinaryOpExpression, UnaryOpExpression},
+  statement::{
+    CatchBlock, DoWhileStatement, ForBinding, ForInStatement, ForInitializer, ForOfStatement,
+    ForStatement, IfStatement, LabelStatement, LexicalDeclaration, Statement, SwitchCase,
+    SwitchStatement, TryStatement, WhileStatement, WithStatement,
+  },
+  Identifier, Program,
+};
+
+// nodes behind an arena reference can't be changed in place, so the walk copies each of them into
+// the allocator, visits the copy and points the parent at it. the original tree is left untouched
+//
+// the copies are made whether or not the visitor changes anything, and the lists in them are
+// copied too, so every pass allocates about as much as the tree again and none of it is freed
+// before the arena is. an analysis that only reads should use `Visit`, and transforms that run
+// one after the other are cheaper as one visitor
+pub trait VisitMut<'a> {
+  fn allocator(&self) -> &'a Bump;
+
+  fn visit_program(&mut self, program: &mut Program<'a>) {
+    walk_program(self, program);
+  }
+
+  fn visit_statement_list(&mut self, statements: &mut Vec<'a, Statement<'a>>) {
+    walk_statement_list(self, statements);
+  }
+
+  fn visit_statement(&mut self, statement: &mut Statement<'a>) {
+    walk_statement(self, statement);
+  }
+
+  fn visit_variable_declaration(&mut self, bindings: &mut Vec<'a, BindingPatternInitializer<'a>>) {
+    walk_variable_declaration(self, bindings);
+  }
+
+  fn visit_lexical_declaration(&mut self, declaration: &mut LexicalDeclaration<'a>) {
+    walk_lexical_declaration(self, declaration);
+  }
+
+  fn visit_if_statement(&mut self, statement: &mut IfStatement<'a>) {
+    walk_if_statement(self, statement);
+  }
+
+  fn visit_do_while_statement(&mut self, statement: &mut DoWhileStatement<'a>) {
+    walk_do_while_statement(self, statement);
+  }
+
+  fn visit_while_statement(&mut self, statement: &mut WhileStatement<'a>) {
+    walk_while_statement(self, statement);
+  }
+
+  fn visit_for_statement(&mut self, statement: &mut ForStatement<'a>) {
+    walk_for_statement(self, statement);
+  }
+
+  fn visit_for_initializer(&mut self, initializer: &mut ForInitializer<'a>) {
+    walk_for_initializer(self, initializer);
+  }
+
+  fn visit_for_in_statement(&mut self, statement: &mut ForInStatement<'a>) {
+    walk_for_in_statement(self, statement);
+  }
+
+  fn visit_for_of_statement(&mut self, statement: &mut ForOfStatement<'a>) {
+    walk_for_of_statement(self, statement);
+  }
+
+  fn visit_for_binding(&mut self, binding: &mut ForBinding<'a>) {
+    walk_for_binding(self, binding);
+  }
+
+  fn visit_switch_statement(&mut self, statement: &mut SwitchStatement<'a>) {
+    walk_switch_statement(self, statement);
+  }
+
+  fn visit_switch_case(&mut self, case: &mut SwitchCase<'a>) {
+    walk_switch_case(self, case);
+  }
+
+  fn visit_with_statement(&mut self, statement: &mut WithStatement<'a>) {
+    walk_with_statement(self, statement);
+  }
+
+  fn visit_label_statement(&mut self, statement: &mut LabelStatement<'a>) {
+    walk_label_statement(self, statement);
+  }
+
+  fn visit_try_statement(&mut self, statement: &mut TryStatement<'a>) {
+    walk_try_statement(self, statement);
+  }
+
+  fn visit_catch_block(&mut self, catch: &mut CatchBlock<'a>) {
+    walk_catch_block(self, catch);
+  }
+
+  fn visit_expression(&mut self, expression: &mut Expression<'a>) {
+    walk_expression(self, expression);
+  }
+
+  fn visit_identifier_reference(&mut self, _identifier: &mut Identifier<'a>) {}
+
+  fn visit_binding_identifier(&mut self, _identifier: &mut Identifier<'a>) {}
+
+  fn visit_array_element(&mut self, element: &mut ArrayElement<'a>) {
+    walk_array_element(self, element);
+  }
+
+  fn visit_template_literal(&mut self, literal: &mut TemplateLiteral<'a>) {
+    walk_template_literal(self, literal);
+  }
+
+  fn visit_tagged_template_literal(&mut self, literal: &mut TaggedTemplateLiteral<'a>) {
+    walk_tagged_template_literal(self, literal);
+  }
+
+  fn visit_member_expression(&mut self, expression: &mut MemberExpression<'a>) {
+    walk_member_expression(self, expression);
+  }
+
+  fn visit_member_name(&mut self, name: &mut MemberName<'a>) {
+    walk_member_name(self, name);
+  }
+
+  fn visit_in_expression(&mut self, expression: &mut InExpression<'a>) {
+    walk_in_expression(self, expression);
+  }
+
+  fn visit_call_expression(&mut self, expression: &mut CallExpression<'a>) {
+    walk_call_expression(self, expression);
+  }
+
+  fn visit_new_expression(&mut self, expression: &mut NewExpression<'a>) {
+    walk_new_expression(self, expression);
+  }
+
+  fn visit_argument(&mut self, argument: &mut Argument<'a>) {
+    walk_argument(self, argument);
+  }
+
+  fn visit_conditional_expression(&mut self, expression: &mut ConditionalExpression<'a>) {
+    walk_conditional_expression(self, expression);
+  }
+
+  fn visit_yield_expression(&mut self, expression: &mut YieldExpression<'a>) {
+    walk_yield_expression(self, expression);
+  }
+
+  fn visit_unary_op_expression(&mut self, expression: &mut UnaryOpExpression<'a>) {
+    walk_unary_op_expression(self, expression);
+  }
+
+  fn visit_binary_op_expression(&mut self, expression: &mut BinaryOpExpression<'a>) {
+    walk_binary_op_expression(self, expression);
+  }
+
+  fn visit_assignment_op_expression(&mut self, expression: &mut AssignmentOpExpression<'a>) {
+    walk_assignment_op_expression(self, expression);
+  }
+
+  fn visit_object_property(&mut self, property: &mut ObjectProperty<'a>) {
+    walk_object_property(self, property);
+  }
+
+  fn visit_property_name(&mut self, name: &mut PropertyName<'a>) {
+    walk_property_name(self, name);
+  }
+
+  fn visit_property_definition(&mut self, definition: &mut PropertyDefinition<'a>) {
+    walk_property_definition(self, definition);
+  }
+
+  fn visit_object_method(&mut self, method: &mut ObjectMethod<'a>) {
+    walk_object_method(self, method);
+  }
+
+  fn visit_object_getter(&mut self, getter: &mut ObjectGetter<'a>) {
+    walk_object_getter(self, getter);
+  }
+
+  fn visit_object_setter(&mut self, setter: &mut ObjectSetter<'a>) {
+    walk_object_setter(self, setter);
+  }
+
+  fn visit_function_definition(&mut self, definition: &mut FunctionDefinition<'a>) {
+    walk_function_definition(self, definition);
+  }
+
+  fn visit_arrow_function_definition(&mut self, definition: &mut ArrowFunctionDefinition<'a>) {
+    walk_arrow_function_definition(self, definition);
+  }
+
+  fn visit_formal_parameters(&mut self, parameters: &mut FormalParameters<'a>) {
+    walk_formal_parameters(self, parameters);
+  }
+
+  fn visit_binding_pattern(&mut self, pattern: &mut BindingPattern<'a>) {
+    walk_binding_pattern(self, pattern);
+  }
+
+  fn visit_binding_pattern_initializer(&mut self, binding: &mut BindingPatternInitializer<'a>) {
+    walk_binding_pattern_initializer(self, binding);
+  }
+
+  fn visit_array_binding_pattern(&mut self, pattern: &mut ArrayBindingPattern<'a>) {
+    walk_array_binding_pattern(self, pattern);
+  }
+
+  fn visit_object_binding_pattern(&mut self, pattern: &mut ObjectBindingPattern<'a>) {
+    walk_object_binding_pattern(self, pattern);
+  }
+
+  fn visit_object_binding_property(&mut self, property: &mut ObjectBindingProperty<'a>) {
+    walk_object_binding_property(self, property);
+  }
+
+  fn visit_class_definition(&mut self, definition: &mut ClassDefinition<'a>) {
+    walk_class_definition(self, definition);
+  }
+
+  fn visit_class_element(&mut self, element: &mut ClassElement<'a>) {
+    walk_class_element(self, element);
+  }
+
+  fn visit_class_element_name(&mut self, name: &mut ClassElementName<'a>) {
+    walk_class_element_name(self, name);
+  }
+
+  fn visit_class_accessor(&mut self, accessor: &mut ClassAccessor<'a>) {
+    walk_class_accessor(self, accessor);
+  }
+
+  fn visit_class_field(&mut self, field: &mut ClassField<'a>) {
+    walk_class_field(self, field);
+  }
+
+  fn visit_class_method(&mut self, method: &mut ClassMethod<'a>) {
+    walk_class_method(self, method);
+  }
+
+  fn visit_class_getter(&mut self, getter: &mut ClassGetter<'a>) {
+    walk_class_getter(self, getter);
+  }
+
+  fn visit_class_setter(&mut self, setter: &mut ClassSetter<'a>) {
+    walk_class_setter(self, setter);
+  }
+
+  fn visit_class_static_block(&mut self, block: &mut ClassStaticBlock<'a>) {
+    walk_class_static_block(self, block);
+  }
+
+  fn visit_jsx_element(&mut self, element: &mut JsxElement<'a>) {
+    walk_jsx_element(self, element);
+  }
+
+  fn visit_jsx_element_name(&mut self, _name: &mut JsxElementName<'a>) {}
+
+  fn visit_jsx_attribute(&mut self, attribute: &mut JsxAttribute<'a>) {
+    walk_jsx_attribute(self, attribute);
+  }
+
+  fn visit_jsx_attribute_value(&mut self, value: &mut JsxAttributeValue<'a>) {
+    walk_jsx_attribute_value(self, value);
+  }
+
+  fn visit_jsx_child(&mut self, child: &mut JsxChild<'a>) {
+    walk_jsx_child(self, child);
+  }
+}
+
+// copies the node even when the visit leaves it as it was, see the cost above `VisitMut`
+fn visit_copy<'a, V: VisitMut<'a> + ?Sized, T: Clone>(
+  visitor: &mut V,
+  node: &mut &'a T,
+  visit: impl FnOnce(&mut V, &mut T),
+) {
+  let mut copy = (*node).clone();
+  visit(visitor, &mut copy);
+  *node = visitor.allocator().alloc(copy);
+}
+
+fn visit_jsx_fragment<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  fragment: &mut &'a JsxFragment<'a>,
+) {
+  visit_copy(visitor, fragment, |visitor, fragment| {
+    for child in fragment.children.iter_mut() {
+      visitor.visit_jsx_child(child);
+    }
+  });
+}
+
+pub fn walk_program<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, program: &mut Program<'a>) {
+  visitor.visit_statement_list(&mut program.statement_list);
+}
+
+pub fn walk_statement_list<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statements: &mut Vec<'a, Statement<'a>>,
+) {
+  for statement in statements.iter_mut() {
+    visitor.visit_statement(statement);
+  }
+}
+
+pub fn walk_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut Statement<'a>,
+) {
+  match statement {
+    Statement::Block(block) => visit_copy(visitor, block, |visitor, block| {
+      visitor.visit_statement_list(&mut block.statements)
+    }),
+    Statement::Break(_) | Statement::Continue(_) | Statement::Debugger(_) | Statement::Empty(_) => {
+    }
+    Statement::Class(definition) => visit_copy(visitor, definition, |visitor, definition| {
+      visitor.visit_class_definition(definition)
+    }),
+    Statement::DoWhile(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_do_while_statement(statement)
+    }),
+    Statement::Expression(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_expression(&mut statement.expression)
+    }),
+    Statement::For(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_for_statement(statement)
+    }),
+    Statement::ForIn(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_for_in_statement(statement)
+    }),
+    Statement::ForOf(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_for_of_statement(statement)
+    }),
+    Statement::Function(definition) => visit_copy(visitor, definition, |visitor, definition| {
+      visitor.visit_function_definition(definition)
+    }),
+    Statement::If(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_if_statement(statement)
+    }),
+    Statement::Label(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_label_statement(statement)
+    }),
+    Statement::Lexical(declaration) => visit_copy(visitor, declaration, |visitor, declaration| {
+      visitor.visit_lexical_declaration(declaration)
+    }),
+    Statement::Return(statement) => {
+      if statement.argument.is_some() {
+        visit_copy(visitor, statement, |visitor, statement| {
+          if let Some(argument) = &mut statement.argument {
+            visitor.visit_expression(argument);
+          }
+        });
+      }
+    }
+    Statement::Switch(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_switch_statement(statement)
+    }),
+    Statement::Throw(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_expression(&mut statement.argument)
+    }),
+    Statement::Try(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_try_statement(statement)
+    }),
+    Statement::Variable(declaration) => visit_copy(visitor, declaration, |visitor, declaration| {
+      visitor.visit_variable_declaration(&mut declaration.bindings)
+    }),
+    Statement::With(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_with_statement(statement)
+    }),
+    Statement::While(statement) => visit_copy(visitor, statement, |visitor, statement| {
+      visitor.visit_while_statement(statement)
+    }),
+  }
+}
+
+pub fn walk_variable_declaration<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  bindings: &mut Vec<'a, BindingPatternInitializer<'a>>,
+) {
+  for binding in bindings.iter_mut() {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+}
+
+pub fn walk_lexical_declaration<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  declaration: &mut LexicalDeclaration<'a>,
+) {
+  for binding in declaration.bindings.iter_mut() {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+}
+
+pub fn walk_if_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut IfStatement<'a>,
+) {
+  visitor.visit_expression(&mut statement.condition);
+  visitor.visit_statement(&mut statement.consequent);
+  if let Some(alternate) = &mut statement.alternate {
+    visitor.visit_statement(alternate);
+  }
+}
+
+pub fn walk_do_while_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut DoWhileStatement<'a>,
+) {
+  visitor.visit_statement(&mut statement.body);
+  visitor.visit_expression(&mut statement.condition);
+}
+
+pub fn walk_while_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut WhileStatement<'a>,
+) {
+  visitor.visit_expression(&mut statement.condition);
+  visitor.visit_statement(&mut statement.body);
+}
+
+pub fn walk_for_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut ForStatement<'a>,
+) {
+  if let Some(initializer) = &mut statement.initializer {
+    visitor.visit_for_initializer(initializer);
+  }
+
+  if let Some(condition) = &mut statement.condition {
+    visitor.visit_expression(condition);
+  }
+
+  if let Some(update) = &mut statement.update {
+    visitor.visit_expression(update);
+  }
+
+  visitor.visit_statement(&mut statement.body);
+}
+
+pub fn walk_for_initializer<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  initializer: &mut ForInitializer<'a>,
+) {
+  match initializer {
+    ForInitializer::Expression(expression) => visitor.visit_expression(expression),
+    ForInitializer::Lexical(declaration) => {
+      visit_copy(visitor, declaration, |visitor, declaration| {
+        visitor.visit_lexical_declaration(declaration)
+      })
+    }
+    ForInitializer::Variable(declaration) => {
+      visit_copy(visitor, declaration, |visitor, declaration| {
+        visitor.visit_variable_declaration(&mut declaration.bindings)
+      })
+    }
+  }
+}
+
+pub fn walk_for_in_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut ForInStatement<'a>,
+) {
+  visitor.visit_for_binding(&mut statement.binding);
+  visitor.visit_expression(&mut statement.expression);
+  visitor.visit_statement(&mut statement.body);
+}
+
+pub fn walk_for_of_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut ForOfStatement<'a>,
+) {
+  visitor.visit_for_binding(&mut statement.binding);
+  visitor.visit_expression(&mut statement.expression);
+  visitor.visit_statement(&mut statement.body);
+}
+
+pub fn walk_for_binding<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  binding: &mut ForBinding<'a>,
+) {
+  match binding {
+    ForBinding::Expression(expression) => visitor.visit_expression(expression),
+    ForBinding::Lexical(_, pattern, _) => visitor.visit_binding_pattern(pattern),
+    ForBinding::Variable(binding, _) => visitor.visit_binding_pattern_initializer(binding),
+  }
+}
+
+pub fn walk_switch_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut SwitchStatement<'a>,
+) {
+  visitor.visit_expression(&mut statement.expression);
+  for case in statement.cases.iter_mut() {
+    visitor.visit_switch_case(case);
+  }
+}
+
+pub fn walk_switch_case<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, case: &mut SwitchCase<'a>) {
+  if let Some(expression) = &mut case.expression {
+    visitor.visit_expression(expression);
+  }
+
+  visitor.visit_statement_list(&mut case.body);
+}
+
+pub fn walk_with_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut WithStatement<'a>,
+) {
+  visitor.visit_expression(&mut statement.expression);
+  visitor.visit_statement(&mut statement.body);
+}
+
+pub fn walk_label_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut LabelStatement<'a>,
+) {
+  visitor.visit_statement(&mut statement.statement);
+}
+
+pub fn walk_try_statement<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  statement: &mut TryStatement<'a>,
+) {
+  visitor.visit_statement_list(&mut statement.body.statements);
+
+  if let Some(catch) = &mut statement.catch {
+    visitor.visit_catch_block(catch);
+  }
+
+  if let Some(finally) = &mut statement.finally {
+    visitor.visit_statement_list(&mut finally.statements);
+  }
+}
+
+pub fn walk_catch_block<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, catch: &mut CatchBlock<'a>) {
+  if let Some(parameter) = &mut catch.parameter {
+    visitor.visit_binding_pattern(parameter);
+  }
+
+  visitor.visit_statement_list(&mut catch.body.statements);
+}
+
+pub fn walk_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut Expression<'a>,
+) {
+  match expression {
+    Expression::Array(literal) => visit_copy(visitor, literal, |visitor, literal| {
+      for element in literal.elements.iter_mut() {
+        visitor.visit_array_element(element);
+      }
+    }),
+    Expression::ArrowFunction(definition) => {
+      visit_copy(visitor, definition, |visitor, definition| {
+        visitor.visit_arrow_function_definition(definition)
+      })
+    }
+    Expression::Assignment(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_assignment_op_expression(expression)
+    }),
+    Expression::BigInt(_)
+    | Expression::Boolean(_)
+    | Expression::ImportMeta(_)
+    | Expression::NewTarget(_)
+    | Expression::Null(_)
+    | Expression::Number(_)
+    | Expression::RegExp(_)
+    | Expression::String(_)
+    | Expression::Super(_)
+    | Expression::This(_) => {}
+    Expression::BinaryOp(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_binary_op_expression(expression)
+    }),
+    Expression::Call(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_call_expression(expression)
+    }),
+    Expression::Chain(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_expression(expression)
+    }),
+    Expression::Class(definition) => visit_copy(visitor, definition, |visitor, definition| {
+      visitor.visit_class_definition(definition)
+    }),
+    Expression::Conditional(expression) => {
+      visit_copy(visitor, expression, |visitor, expression| {
+        visitor.visit_conditional_expression(expression)
+      })
+    }
+    Expression::Function(definition) => visit_copy(visitor, definition, |visitor, definition| {
+      visitor.visit_function_definition(definition)
+    }),
+    Expression::Group(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_expression(&mut expression.expression)
+    }),
+    Expression::Identifier(identifier) => visit_copy(visitor, identifier, |visitor, identifier| {
+      visitor.visit_identifier_reference(identifier)
+    }),
+    Expression::Import(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_expression(&mut expression.argument)
+    }),
+    Expression::In(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_in_expression(expression)
+    }),
+    Expression::JsxElement(element) => visit_copy(visitor, element, |visitor, element| {
+      visitor.visit_jsx_element(element)
+    }),
+    Expression::JsxFragment(fragment) => visit_jsx_fragment(visitor, fragment),
+    Expression::List(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      for expression in expression.expressions.iter_mut() {
+        visitor.visit_expression(expression);
+      }
+    }),
+    Expression::Member(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_member_expression(expression)
+    }),
+    Expression::New(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_new_expression(expression)
+    }),
+    Expression::Object(literal) => visit_copy(visitor, literal, |visitor, literal| {
+      for property in literal.properties.iter_mut() {
+        visitor.visit_object_property(property);
+      }
+    }),
+    Expression::TaggedTemplate(literal) => visit_copy(visitor, literal, |visitor, literal| {
+      visitor.visit_tagged_template_literal(literal)
+    }),
+    Expression::Template(literal) => visit_copy(visitor, literal, |visitor, literal| {
+      visitor.visit_template_literal(literal)
+    }),
+    Expression::UnaryOp(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_unary_op_expression(expression)
+    }),
+    Expression::Yield(expression) => visit_copy(visitor, expression, |visitor, expression| {
+      visitor.visit_yield_expression(expression)
+    }),
+  }
+}
+
+pub fn walk_array_element<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  element: &mut ArrayElement<'a>,
+) {
+  match element {
+    ArrayElement::Elision => {}
+    ArrayElement::Expression(expression) => visitor.visit_expression(expression),
+    ArrayElement::Spread(spread) => visitor.visit_expression(&mut spread.argument),
+  }
+}
+
+pub fn walk_template_literal<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: &mut TemplateLiteral<'a>,
+) {
+  for substitution in literal.substitutions.iter_mut() {
+    visitor.visit_expression(substitution);
+  }
+}
+
+pub fn walk_tagged_template_literal<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  literal: &mut TaggedTemplateLiteral<'a>,
+) {
+  visitor.visit_expression(&mut literal.tag);
+  for substitution in literal.substitutions.iter_mut() {
+    visitor.visit_expression(substitution);
+  }
+}
+
+pub fn walk_member_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut MemberExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.object);
+  visitor.visit_member_name(&mut expression.property);
+}
+
+pub fn walk_member_name<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, name: &mut MemberName<'a>) {
+  match name {
+    MemberName::Computed(expression) => visitor.visit_expression(expression),
+    MemberName::Private(_) | MemberName::Static(_) => {}
+  }
+}
+
+pub fn walk_in_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut InExpression<'a>,
+) {
+  visitor.visit_member_name(&mut expression.name);
+  visitor.visit_expression(&mut expression.argument);
+}
+
+pub fn walk_call_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut CallExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.callee);
+  for argument in expression.arguments.iter_mut() {
+    visitor.visit_argument(argument);
+  }
+}
+
+pub fn walk_new_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut NewExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.callee);
+  if let Some(arguments) = &mut expression.arguments {
+    for argument in arguments.iter_mut() {
+      visitor.visit_argument(argument);
+    }
+  }
+}
+
+pub fn walk_argument<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, argument: &mut Argument<'a>) {
+  match argument {
+    Argument::Positional(expression) => visitor.visit_expression(expression),
+    Argument::Spread(spread) => visitor.visit_expression(&mut spread.argument),
+  }
+}
+
+pub fn walk_conditional_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut ConditionalExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.condition);
+  visitor.visit_expression(&mut expression.consequent);
+  visitor.visit_expression(&mut expression.alternate);
+}
+
+pub fn walk_yield_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut YieldExpression<'a>,
+) {
+  if let Some(argument) = &mut expression.argument {
+    visitor.visit_expression(argument);
+  }
+}
+
+pub fn walk_unary_op_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut UnaryOpExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.argument);
+}
+
+pub fn walk_binary_op_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut BinaryOpExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.left);
+  visitor.visit_expression(&mut expression.right);
+}
+
+pub fn walk_assignment_op_expression<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  expression: &mut AssignmentOpExpression<'a>,
+) {
+  visitor.visit_expression(&mut expression.left);
+  visitor.visit_expression(&mut expression.right);
+}
+
+pub fn walk_object_property<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  property: &mut ObjectProperty<'a>,
+) {
+  match property {
+    ObjectProperty::Getter(getter) => visit_copy(visitor, getter, |visitor, getter| {
+      visitor.visit_object_getter(getter)
+    }),
+    ObjectProperty::Method(method) => visit_copy(visitor, method, |visitor, method| {
+      visitor.visit_object_method(method)
+    }),
+    ObjectProperty::Property(definition) => {
+      visit_copy(visitor, definition, |visitor, definition| {
+        visitor.visit_property_definition(definition)
+      })
+    }
+    ObjectProperty::Setter(setter) => visit_copy(visitor, setter, |visitor, setter| {
+      visitor.visit_object_setter(setter)
+    }),
+    ObjectProperty::Shorthand(identifier) => {
+      visit_copy(visitor, identifier, |visitor, identifier| {
+        visitor.visit_identifier_reference(identifier)
+      })
+    }
+    ObjectProperty::Spread(spread) => visitor.visit_expression(&mut spread.argument),
+  }
+}
+
+pub fn walk_property_name<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  name: &mut PropertyName<'a>,
+) {
+  match name {
+    PropertyName::Computed(expression) => visitor.visit_expression(expression),
+    PropertyName::Static(_) => {}
+  }
+}
+
+pub fn walk_property_definition<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &mut PropertyDefinition<'a>,
+) {
+  visitor.visit_property_name(&mut definition.property);
+  visitor.visit_expression(&mut definition.expression);
+}
+
+pub fn walk_object_method<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  method: &mut ObjectMethod<'a>,
+) {
+  visitor.visit_property_name(&mut method.property);
+  visitor.visit_formal_parameters(&mut method.parameters);
+  visitor.visit_statement_list(&mut method.body.statements);
+}
+
+pub fn walk_object_getter<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  getter: &mut ObjectGetter<'a>,
+) {
+  visitor.visit_property_name(&mut getter.property);
+  visitor.visit_statement_list(&mut getter.body.statements);
+}
+
+pub fn walk_object_setter<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  setter: &mut ObjectSetter<'a>,
+) {
+  visitor.visit_property_name(&mut setter.property);
+  visitor.visit_binding_pattern_initializer(&mut setter.parameter);
+  visitor.visit_statement_list(&mut setter.body.statements);
+}
+
+pub fn walk_function_definition<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &mut FunctionDefinition<'a>,
+) {
+  if let Some(identifier) = &mut definition.identifier {
+    visitor.visit_binding_identifier(identifier);
+  }
+
+  visitor.visit_formal_parameters(&mut definition.parameters);
+  visitor.visit_statement_list(&mut definition.body.statements);
+}
+
+pub fn walk_arrow_function_definition<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &mut ArrowFunctionDefinition<'a>,
+) {
+  visitor.visit_formal_parameters(&mut definition.parameters);
+  match &mut definition.body {
+    ConciseBody::Block(block) => visitor.visit_statement_list(&mut block.statements),
+    ConciseBody::Expression(expression) => visitor.visit_expression(expression),
+  }
+}
+
+pub fn walk_formal_parameters<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  parameters: &mut FormalParameters<'a>,
+) {
+  for binding in parameters.bindings.iter_mut() {
+    visitor.visit_binding_pattern_initializer(binding);
+  }
+
+  if let Some(rest) = &mut parameters.rest {
+    visitor.visit_binding_pattern(&mut rest.pattern);
+  }
+}
+
+pub fn walk_binding_pattern<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &mut BindingPattern<'a>,
+) {
+  match pattern {
+    BindingPattern::Array(pattern) => visit_copy(visitor, pattern, |visitor, pattern| {
+      visitor.visit_array_binding_pattern(pattern)
+    }),
+    BindingPattern::Identifier(identifier) => {
+      visit_copy(visitor, identifier, |visitor, identifier| {
+        visitor.visit_binding_identifier(identifier)
+      })
+    }
+    BindingPattern::Object(pattern) => visit_copy(visitor, pattern, |visitor, pattern| {
+      visitor.visit_object_binding_pattern(pattern)
+    }),
+  }
+}
+
+pub fn walk_binding_pattern_initializer<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  binding: &mut BindingPatternInitializer<'a>,
+) {
+  visitor.visit_binding_pattern(&mut binding.pattern);
+  if let Some(initializer) = &mut binding.initializer {
+    visitor.visit_expression(initializer);
+  }
+}
+
+pub fn walk_array_binding_pattern<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &mut ArrayBindingPattern<'a>,
+) {
+  for element in pattern.elements.iter_mut().flatten() {
+    visitor.visit_binding_pattern_initializer(element);
+  }
+
+  if let Some(rest) = &mut pattern.rest {
+    visitor.visit_binding_pattern(&mut rest.pattern);
+  }
+}
+
+pub fn walk_object_binding_pattern<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  pattern: &mut ObjectBindingPattern<'a>,
+) {
+  for property in pattern.properties.iter_mut() {
+    visitor.visit_object_binding_property(property);
+  }
+
+  if let Some(rest) = &mut pattern.rest {
+    visitor.visit_binding_pattern(&mut rest.pattern);
+  }
+}
+
+pub fn walk_object_binding_property<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  property: &mut ObjectBindingProperty<'a>,
+) {
+  visitor.visit_property_name(&mut property.property);
+  visitor.visit_binding_pattern_initializer(&mut property.binding);
+}
+
+pub fn walk_class_definition<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  definition: &mut ClassDefinition<'a>,
+) {
+  for decorator in definition.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  if let Some(identifier) = &mut definition.identifier {
+    visitor.visit_binding_identifier(identifier);
+  }
+
+  if let Some(heritage) = &mut definition.heritage {
+    visitor.visit_expression(heritage);
+  }
+
+  for element in definition.body.iter_mut() {
+    visitor.visit_class_element(element);
+  }
+}
+
+pub fn walk_class_element<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  element: &mut ClassElement<'a>,
+) {
+  match element {
+    ClassElement::Accessor(accessor) => visit_copy(visitor, accessor, |visitor, accessor| {
+      visitor.visit_class_accessor(accessor)
+    }),
+    ClassElement::Field(field) => visit_copy(visitor, field, |visitor, field| {
+      visitor.visit_class_field(field)
+    }),
+    ClassElement::Getter(getter) => visit_copy(visitor, getter, |visitor, getter| {
+      visitor.visit_class_getter(getter)
+    }),
+    ClassElement::Method(method) => visit_copy(visitor, method, |visitor, method| {
+      visitor.visit_class_method(method)
+    }),
+    ClassElement::Setter(setter) => visit_copy(visitor, setter, |visitor, setter| {
+      visitor.visit_class_setter(setter)
+    }),
+    ClassElement::Static(block) => visit_copy(visitor, block, |visitor, block| {
+      visitor.visit_class_static_block(block)
+    }),
+  }
+}
+
+pub fn walk_class_element_name<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  name: &mut ClassElementName<'a>,
+) {
+  match name {
+    ClassElementName::Computed(expression) => visitor.visit_expression(expression),
+    ClassElementName::Private(_) | ClassElementName::Static(_) => {}
+  }
+}
+
+pub fn walk_class_accessor<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  accessor: &mut ClassAccessor<'a>,
+) {
+  for decorator in accessor.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&mut accessor.name);
+  if let Some(value) = &mut accessor.value {
+    visitor.visit_expression(value);
+  }
+}
+
+pub fn walk_class_field<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, field: &mut ClassField<'a>) {
+  for decorator in field.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&mut field.name);
+  if let Some(value) = &mut field.value {
+    visitor.visit_expression(value);
+  }
+}
+
+pub fn walk_class_method<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  method: &mut ClassMethod<'a>,
+) {
+  for decorator in method.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&mut method.name);
+  visitor.visit_formal_parameters(&mut method.parameters);
+  visitor.visit_statement_list(&mut method.body.statements);
+}
+
+pub fn walk_class_getter<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  getter: &mut ClassGetter<'a>,
+) {
+  for decorator in getter.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&mut getter.name);
+  visitor.visit_statement_list(&mut getter.body.statements);
+}
+
+pub fn walk_class_setter<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  setter: &mut ClassSetter<'a>,
+) {
+  for decorator in setter.decorators.iter_mut() {
+    visitor.visit_expression(decorator);
+  }
+
+  visitor.visit_class_element_name(&mut setter.name);
+  visitor.visit_binding_pattern_initializer(&mut setter.parameter);
+  visitor.visit_statement_list(&mut setter.body.statements);
+}
+
+pub fn walk_class_static_block<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  block: &mut ClassStaticBlock<'a>,
+) {
+  visitor.visit_statement_list(&mut block.statements);
+}
+
+pub fn walk_jsx_element<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  element: &mut JsxElement<'a>,
+) {
+  visitor.visit_jsx_element_name(&mut element.name);
+
+  for attribute in element.attributes.iter_mut() {
+    visitor.visit_jsx_attribute(attribute);
+  }
+
+  for child in element.children.iter_mut() {
+    visitor.visit_jsx_child(child);
+  }
+}
+
+pub fn walk_jsx_attribute<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  attribute: &mut JsxAttribute<'a>,
+) {
+  match attribute {
+    JsxAttribute::Attribute(attribute) => {
+      if attribute.value.is_some() {
+        visit_copy(visitor, attribute, |visitor, attribute| {
+          if let Some(value) = &mut attribute.value {
+            visitor.visit_jsx_attribute_value(value);
+          }
+        });
+      }
+    }
+    JsxAttribute::Spread(container) => visit_copy(visitor, container, |visitor, container| {
+      visitor.visit_expression(&mut container.expression)
+    }),
+  }
+}
+
+pub fn walk_jsx_attribute_value<'a, V: VisitMut<'a> + ?Sized>(
+  visitor: &mut V,
+  value: &mut JsxAttributeValue<'a>,
+) {
+  match value {
+    JsxAttributeValue::Element(element) => visit_copy(visitor, element, |visitor, element| {
+      visitor.visit_jsx_element(element)
+    }),
+    JsxAttributeValue::Expression(container) => {
+      visit_copy(visitor, container, |visitor, container| {
+        visitor.visit_expression(&mut container.expression)
+      })
+    }
+    JsxAttributeValue::Fragment(fragment) => visit_jsx_fragment(visitor, fragment),
+    JsxAttributeValue::String(_) => {}
+  }
+}
+
+pub fn walk_jsx_child<'a, V: VisitMut<'a> + ?Sized>(visitor: &mut V, child: &mut JsxChild<'a>) {
+  match child {
+    JsxChild::Element(element) => visit_copy(visitor, element, |visitor, element| {
+      visitor.visit_jsx_element(element)
+    }),
+    JsxChild::Empty(_) | JsxChild::Text(_) => {}
+    JsxChild::Expression(container) | JsxChild::Spread(container) => {
+      visit_copy(visitor, container, |visitor, container| {
+        visitor.visit_expression(&mut container.expression)
+      })
+    }
+    JsxChild::Fragment(fragment) => visit_jsx_fragment(visitor, fragment),
+  }
+}
@@ -0,0 +1,194 @@
+use tower_ast::{
+  class::{ClassDefinition, ClassElement, ClassElementName},
+  expression::{Expression, MemberName},
+};
+
+use crate::{
+  expression::{strip_groups, Precedence},
+  Printer,
+};
+
+impl Printer {
+  pub fn print_class(&mut self, definition: &ClassDefinition) {
+    self.print_decorators(&definition.decorators);
+    self.write("class");
+
+    if let Some(identifier) = &definition.identifier {
+      self.space();
+      self.print_identifier(identifier);
+    }
+
+    if let Some(heritage) = &definition.heritage {
+      self.space();
+      self.write("extends");
+      self.space();
+      self.print_expression(heritage, Precedence::Call);
+    }
+
+    self.space();
+
+    let statement_start = self.statement_start.take();
+    let arrow_body_start = self.arrow_body_start.take();
+    let no_in = std::mem::replace(&mut self.no_in, false);
+
+    if definition.body.is_empty() {
+      self.write("{}");
+    } else {
+      self.write("{");
+      self.indent += 1;
+      for element in definition.body.iter() {
+        self.newline();
+        self.print_class_element(element);
+      }
+      self.indent -= 1;
+      self.newline();
+      self.write("}");
+    }
+
+    self.statement_start = statement_start;
+    self.arrow_body_start = arrow_body_start;
+    self.no_in = no_in;
+  }
+
+  fn print_class_element(&mut self, element: &ClassElement) {
+    self.mark(element.span());
+
+    match element {
+      ClassElement::Accessor(accessor) => {
+        self.print_decorators(&accessor.decorators);
+        self.print_static(accessor.r#static);
+        self.write("accessor");
+        self.space();
+        self.print_class_element_name(&accessor.name);
+        self.print_field_value(accessor.value.as_ref());
+      }
+      ClassElement::Field(field) => {
+        self.print_decorators(&field.decorators);
+        self.print_static(field.r#static);
+        self.print_class_element_name(&field.name);
+        self.print_field_value(field.value.as_ref());
+      }
+      ClassElement::Getter(getter) => {
+        self.print_decorators(&getter.decorators);
+        self.print_static(getter.r#static);
+        self.write("get");
+        self.space();
+        self.print_class_element_name(&getter.name);
+        self.write("()");
+        self.space();
+        self.print_function_body(&getter.body.statements);
+      }
+      ClassElement::Method(method) => {
+        self.print_decorators(&method.decorators);
+        self.print_static(method.r#static);
+
+        if method.r#async {
+          self.write("async");
+          self.space();
+        }
+
+        if method.generator {
+          self.write("*");
+        }
+
+        self.print_class_element_name(&method.name);
+        self.print_formal_parameters(&method.parameters);
+        self.space();
+        self.print_function_body(&method.body.statements);
+      }
+      ClassElement::Setter(setter) => {
+        self.print_decorators(&setter.decorators);
+        self.print_static(setter.r#static);
+        self.write("set");
+        self.space();
+        self.print_class_element_name(&setter.name);
+        self.write("(");
+        self.print_binding_initializer(&setter.parameter);
+        self.write(")");
+        self.space();
+        self.print_function_body(&setter.body.statements);
+      }
+      ClassElement::Static(block) => {
+        self.write("static");
+        self.space();
+        self.print_function_body(&block.statements);
+      }
+    }
+  }
+
+  fn print_static(&mut self, r#static: bool) {
+    if r#static {
+      self.write("static");
+      self.space();
+    }
+  }
+
+  fn print_class_element_name(&mut self, name: &ClassElementName) {
+    match name {
+      ClassElementName::Computed(expression) => {
+        self.write("[");
+        self.with_in(|printer| printer.print_expression(expression, Precedence::Assignment));
+        self.write("]");
+      }
+      ClassElementName::Private(name) => {
+        self.mark_name(name.span, name.name);
+        self.write("#");
+        self.write(name.name);
+      }
+      ClassElementName::Static(name) => {
+        self.mark(name.span);
+        self.print_property_key(name.name);
+      }
+    }
+  }
+
+  // fields always end with a semicolon, so a following `[computed]` or `*generator` name can't be
+  // read as part of them
+  fn print_field_value(&mut self, value: Option<&Expression>) {
+    if let Some(value) = value {
+      self.space();
+      self.write("=");
+      self.space();
+      self.with_in(|printer| printer.print_expression(value, Precedence::Assignment));
+    }
+
+    self.write(";");
+  }
+
+  fn print_decorators(&mut self, decorators: &[Expression]) {
+    for decorator in decorators {
+      let decorator = strip_groups(decorator);
+      self.write("@");
+
+      if is_simple_decorator(decorator) {
+        self.print_expression(decorator, Precedence::Comma);
+      } else {
+        self.write("(");
+        self.with_in(|printer| printer.print_expression(decorator, Precedence::Comma));
+        self.write(")");
+      }
+
+      self.space();
+    }
+  }
+}
+
+// `@a.b.c` and `@a.b.c(...)` are written without parentheses
+fn is_simple_decorator(expression: &Expression) -> bool {
+  fn is_member_chain(expression: &Expression) -> bool {
+    match expression {
+      Expression::Identifier(_) => true,
+      Expression::Member(member) => {
+        !member.optional
+          && matches!(member.property, MemberName::Static(_))
+          && is_member_chain(&member.object)
+      }
+      _ => false,
+    }
+  }
+
+  match expression {
+    Expression::Call(call) => !call.optional && is_member_chain(&call.callee),
+    expression => is_member_chain(expression),
+  }
+}
@@ -0,0 +1,1610 @@
+use tower_ast::{
+  binding::{BindingPattern, BindingPatternInitializer, BindingRestElement},
+  class::{ClassDefinition, ClassElement, ClassElementName},
+  expression::{ArrayElement, Expression, MemberName, SpreadElement},
+  function::{Argument, ConciseBody, FormalParameters, FunctionDefinition},
+  jsx::{
+    JsxAttribute, JsxAttributeName, JsxAttributeValue, JsxChild, JsxElement, JsxElementName,
+    JsxExpressionContainer, JsxFragment,
+  },
+  object::{ObjectProperty, PropertyName},
+  op::{AssignmentOp, BinaryOp, UnaryOp},
+  statement::{Block, ForBinding, ForInitializer, LexicalKind, Statement},
+  string::JsStr,
+  Identifier, Program, PropertyKey, SourceType, Span,
+};
+
+use crate::{json::JsonValue, source_map::LineIndex};
+
+// the program as an ESTree tree with the node types and fields acorn gives it, with `locations` and
+// `ranges` on. offsets and columns count utf-16 code units like they do in javascript, the source
+// has to be the text the program was parsed from
+pub fn program_to_estree(program: &Program, source: &[char]) -> JsonValue {
+  let mut utf16_offsets = Vec::with_capacity(source.len() + 1);
+  let mut offset = 0;
+  for c in source {
+    utf16_offsets.push(offset);
+    offset += c.len_utf16();
+  }
+  utf16_offsets.push(offset);
+
+  let serializer = Serializer {
+    source,
+    lines: LineIndex::new(source),
+    utf16_offsets,
+  };
+
+  let source_type = match program.source_type {
+    SourceType::Script => "script",
+    SourceType::Module => "module",
+  };
+
+  serializer.node(
+    "Program",
+    Span::new(0, source.len()),
+    vec![
+      ("body", serializer.statements(&program.statement_list, true)),
+      ("sourceType", string(source_type)),
+    ],
+  )
+}
+
+struct Serializer<'s> {
+  source: &'s [char],
+  lines: LineIndex<'s>,
+  utf16_offsets: Vec<usize>,
+}
+
+fn string(value: &str) -> JsonValue {
+  JsonValue::String(value.to_string())
+}
+
+// JSON text read back here decodes lone surrogates to U+FFFD, so they're written that way too
+fn js_string(value: JsStr) -> JsonValue {
+  JsonValue::String(value.to_string_lossy().into_owned())
+}
+
+fn boolean(value: bool) -> JsonValue {
+  JsonValue::Boolean(value)
+}
+
+fn array(values: impl IntoIterator<Item = JsonValue>) -> JsonValue {
+  JsonValue::Array(values.into_iter().collect())
+}
+
+fn optional<T>(value: Option<T>, serialize: impl FnOnce(T) -> JsonValue) -> JsonValue {
+  value.map_or(JsonValue::Null, serialize)
+}
+
+impl Serializer<'_> {
+  fn node(&self, kind: &str, span: Span, fields: Vec<(&str, JsonValue)>) -> JsonValue {
+    let start = self.utf16_offsets[span.start as usize];
+    let end = self.utf16_offsets[span.end as usize];
+
+    let mut members = vec![
+      ("type".to_string(), string(kind)),
+      ("start".to_string(), JsonValue::Number(start as f64)),
+      ("end".to_string(), JsonValue::Number(end as f64)),
+      (
+        "loc".to_string(),
+        JsonValue::Object(vec![
+          ("start".to_string(), self.position(span.start)),
+          ("end".to_string(), self.position(span.end)),
+        ]),
+      ),
+      (
+        "range".to_string(),
+        array([
+          JsonValue::Number(start as f64),
+          JsonValue::Number(end as f64),
+        ]),
+      ),
+    ];
+
+    members.extend(
+      fields
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value)),
+    );
+    JsonValue::Object(members)
+  }
+
+  fn position(&self, offset: u32) -> JsonValue {
+    let (line, column) = self.lines.position(offset as usize);
+    JsonValue::Object(vec![
+      ("line".to_string(), JsonValue::Number(line as f64 + 1.0)),
+      ("column".to_string(), JsonValue::Number(column as f64)),
+    ])
+  }
+
+  fn raw(&self, span: Span) -> String {
+    self.source[span.start as usize..span.end as usize]
+      .iter()
+      .collect()
+  }
+
+  // statements at the start of a function or program that are a string literal on their own are
+  // directives, they get the text between the quotes
+  fn statements(&self, statements: &[Statement], directives: bool) -> JsonValue {
+    let mut prologue = directives;
+
+    array(statements.iter().map(|statement| {
+      let mut node = self.statement(statement);
+
+      match statement {
+        Statement::Expression(statement) if prologue => match &statement.expression {
+          Expression::String(literal) => {
+            let raw = self.raw(literal.span);
+            let directive = raw[1..raw.len() - 1].to_string();
+            if let JsonValue::Object(members) = &mut node {
+              members.push(("directive".to_string(), JsonValue::String(directive)));
+            }
+          }
+          _ => prologue = false,
+        },
+        _ => prologue = false,
+      }
+
+      node
+    }))
+  }
+
+  fn block(&self, block: &Block, directives: bool) -> JsonValue {
+    self.node(
+      "BlockStatement",
+      block.span,
+      vec![("body", self.statements(&block.statements, directives))],
+    )
+  }
+
+  fn statement(&self, statement: &Statement) -> JsonValue {
+    let span = statement.span();
+
+    match statement {
+      Statement::Block(block) => self.block(block, false),
+      Statement::Break(statement) => self.node(
+        "BreakStatement",
+        span,
+        vec![(
+          "label",
+          optional(statement.label.as_ref(), |label| self.identifier(label)),
+        )],
+      ),
+      Statement::Class(definition) => self.class("ClassDeclaration", definition),
+      Statement::Continue(statement) => self.node(
+        "ContinueStatement",
+        span,
+        vec![(
+          "label",
+          optional(statement.label.as_ref(), |label| self.identifier(label)),
+        )],
+      ),
+      Statement::Debugger(_) => self.node("DebuggerStatement", span, vec![]),
+      Statement::DoWhile(statement) => self.node(
+        "DoWhileStatement",
+        span,
+        vec![
+          ("body", self.statement(&statement.body)),
+          ("test", self.expression(&statement.condition)),
+        ],
+      ),
+      Statement::Expression(statement) => self.node(
+        "ExpressionStatement",
+        span,
+        vec![("expression", self.expression(&statement.expression))],
+      ),
+      Statement::Empty(_) => self.node("EmptyStatement", span, vec![]),
+      Statement::For(statement) => {
+        let init = optional(
+          statement.initializer.as_ref(),
+          |initializer| match initializer {
+            ForInitializer::Expression(expression) => self.expression(expression),
+            ForInitializer::Lexical(declaration) => self.declaration(
+              lexical_kind(declaration.kind),
+              &declaration.bindings,
+              declaration.span,
+            ),
+            ForInitializer::Variable(declaration) => {
+              self.declaration("var", &declaration.bindings, declaration.span)
+            }
+          },
+        );
+
+        self.node(
+          "ForStatement",
+          span,
+          vec![
+            ("init", init),
+            (
+              "test",
+              optional(statement.condition.as_ref(), |test| self.expression(test)),
+            ),
+            (
+              "update",
+              optional(statement.update.as_ref(), |update| self.expression(update)),
+            ),
+            ("body", self.statement(&statement.body)),
+          ],
+        )
+      }
+      Statement::ForIn(statement) => self.node(
+        "ForInStatement",
+        span,
+        vec![
+          ("left", self.for_binding(&statement.binding)),
+          ("right", self.expression(&statement.expression)),
+          ("body", self.statement(&statement.body)),
+        ],
+      ),
+      Statement::ForOf(statement) => self.node(
+        "ForOfStatement",
+        span,
+        vec![
+          ("await", boolean(statement.r#await)),
+          ("left", self.for_binding(&statement.binding)),
+          ("right", self.expression(&statement.expression)),
+          ("body", self.statement(&statement.body)),
+        ],
+      ),
+      Statement::Function(definition) => self.function("FunctionDeclaration", definition),
+      Statement::If(statement) => self.node(
+        "IfStatement",
+        span,
+        vec![
+          ("test", self.expression(&statement.condition)),
+          ("consequent", self.statement(&statement.consequent)),
+          (
+            "alternate",
+            optional(statement.alternate.as_ref(), |alternate| {
+              self.statement(alternate)
+            }),
+          ),
+        ],
+      ),
+      Statement::Label(statement) => self.node(
+        "LabeledStatement",
+        span,
+        vec![
+          ("body", self.statement(&statement.statement)),
+          ("label", self.identifier(&statement.label)),
+        ],
+      ),
+      Statement::Lexical(declaration) => self.declaration(
+        lexical_kind(declaration.kind),
+        &declaration.bindings,
+        declaration.span,
+      ),
+      Statement::Return(statement) => self.node(
+        "ReturnStatement",
+        span,
+        vec![(
+          "argument",
+          optional(statement.argument.as_ref(), |argument| {
+            self.expression(argument)
+          }),
+        )],
+      ),
+      Statement::Switch(statement) => {
+        let cases = statement.cases.iter().map(|case| {
+          self.node(
+            "SwitchCase",
+            case.span,
+            vec![
+              ("consequent", self.statements(&case.body, false)),
+              (
+                "test",
+                optional(case.expression.as_ref(), |test| self.expression(test)),
+              ),
+            ],
+          )
+        });
+
+        self.node(
+          "SwitchStatement",
+          span,
+          vec![
+            ("discriminant", self.expression(&statement.expression)),
+            ("cases", array(cases)),
+          ],
+        )
+      }
+      Statement::Throw(statement) => self.node(
+        "ThrowStatement",
+        span,
+        vec![("argument", self.expression(&statement.argument))],
+      ),
+      Statement::Try(statement) => {
+        let handler = optional(statement.catch.as_ref(), |catch| {
+          self.node(
+            "CatchClause",
+            catch.span,
+            vec![
+              (
+                "param",
+                optional(catch.parameter.as_ref(), |pattern| self.pattern(pattern)),
+              ),
+              ("body", self.block(&catch.body, false)),
+            ],
+          )
+        });
+
+        self.node(
+          "TryStatement",
+          span,
+          vec![
+            ("block", self.block(&statement.body, false)),
+            ("handler", handler),
+            (
+              "finalizer",
+              optional(statement.finally.as_ref(), |block| self.block(block, false)),
+            ),
+          ],
+        )
+      }
+      Statement::Variable(declaration) => {
+        self.declaration("var", &declaration.bindings, declaration.span)
+      }
+      Statement::With(statement) => self.node(
+        "WithStatement",
+        span,
+        vec![
+          ("object", self.expression(&statement.expression)),
+          ("body", self.statement(&statement.body)),
+        ],
+      ),
+      Statement::While(statement) => self.node(
+        "WhileStatement",
+        span,
+        vec![
+          ("test", self.expression(&statement.condition)),
+          ("body", self.statement(&statement.body)),
+        ],
+      ),
+    }
+  }
+
+  fn declaration(
+    &self,
+    kind: &str,
+    bindings: &[BindingPatternInitializer],
+    span: Span,
+  ) -> JsonValue {
+    let declarations = bindings.iter().map(|binding| {
+      self.node(
+        "VariableDeclarator",
+        binding.span,
+        vec![
+          ("id", self.pattern(&binding.pattern)),
+          (
+            "init",
+            optional(binding.initializer.as_ref(), |init| self.expression(init)),
+          ),
+        ],
+      )
+    });
+
+    self.node(
+      "VariableDeclaration",
+      span,
+      vec![
+        ("declarations", array(declarations)),
+        ("kind", string(kind)),
+      ],
+    )
+  }
+
+  fn for_binding(&self, binding: &ForBinding) -> JsonValue {
+    match binding {
+      ForBinding::Expression(expression) => self.expression_pattern(expression),
+      ForBinding::Lexical(kind, pattern, span) => {
+        let declarator = self.node(
+          "VariableDeclarator",
+          pattern.span(),
+          vec![("id", self.pattern(pattern)), ("init", JsonValue::Null)],
+        );
+
+        self.node(
+          "VariableDeclaration",
+          *span,
+          vec![
+            ("declarations", array([declarator])),
+            ("kind", string(lexical_kind(*kind))),
+          ],
+        )
+      }
+      ForBinding::Variable(binding, span) => self.declaration("var", &[*binding], *span),
+    }
+  }
+
+  fn identifier(&self, identifier: &Identifier) -> JsonValue {
+    self.node(
+      "Identifier",
+      identifier.span,
+      vec![("name", string(identifier.name))],
+    )
+  }
+
+  fn private_identifier(&self, identifier: &Identifier) -> JsonValue {
+    self.node(
+      "PrivateIdentifier",
+      identifier.span,
+      vec![("name", string(identifier.name))],
+    )
+  }
+
+  // a static property key is written as an identifier, a string or a number
+  fn property_key(&self, key: &PropertyKey) -> JsonValue {
+    match self.source.get(key.span.start as usize) {
+      Some('"' | '\'') => self.node(
+        "Literal",
+        key.span,
+        vec![
+          ("value", js_string(key.name)),
+          ("raw", JsonValue::String(self.raw(key.span))),
+        ],
+      ),
+      // a bigint key is stored as its decimal digits, the node has them the way they were written
+      Some('0'..='9') if self.raw(key.span).ends_with('n') => {
+        let raw = self.raw(key.span);
+        let digits = raw[..raw.len() - 1].replace('_', "");
+
+        self.node(
+          "Literal",
+          key.span,
+          vec![
+            ("value", JsonValue::Null),
+            ("raw", JsonValue::String(raw)),
+            ("bigint", JsonValue::String(digits)),
+          ],
+        )
+      }
+      Some('0'..='9' | '.') => self.node(
+        "Literal",
+        key.span,
+        vec![
+          (
+            "value",
+            JsonValue::Number(key.name.to_string_lossy().parse().unwrap_or(f64::NAN)),
+          ),
+          ("raw", JsonValue::String(self.raw(key.span))),
+        ],
+      ),
+      _ => self.node("Identifier", key.span, vec![("name", js_string(key.name))]),
+    }
+  }
+
+  fn property_name(&self, property: &PropertyName) -> (bool, JsonValue) {
+    match property {
+      PropertyName::Computed(expression) => (true, self.expression(expression)),
+      PropertyName::Static(key) => (false, self.property_key(key)),
+    }
+  }
+
+  fn spread(&self, spread: &SpreadElement) -> JsonValue {
+    self.node(
+      "SpreadElement",
+      spread.span,
+      vec![("argument", self.expression(&spread.argument))],
+    )
+  }
+
+  fn arguments(&self, arguments: &[Argument]) -> JsonValue {
+    array(arguments.iter().map(|argument| match argument {
+      Argument::Positional(expression) => self.expression(expression),
+      Argument::Spread(spread) => self.spread(spread),
+    }))
+  }
+
+  fn expression(&self, expression: &Expression) -> JsonValue {
+    let span = expression.span();
+
+    match expression {
+      Expression::Array(literal) => {
+        let elements = literal.elements.iter().map(|element| match element {
+          ArrayElement::Elision => JsonValue::Null,
+          ArrayElement::Expression(expression) => self.expression(expression),
+          ArrayElement::Spread(spread) => self.spread(spread),
+        });
+
+        self.node("ArrayExpression", span, vec![("elements", array(elements))])
+      }
+      Expression::ArrowFunction(definition) => {
+        let (expression, body) = match &definition.body {
+          ConciseBody::Block(block) => (false, self.block(block, true)),
+          ConciseBody::Expression(expression) => (true, self.expression(expression)),
+        };
+
+        self.node(
+          "ArrowFunctionExpression",
+          span,
+          vec![
+            ("id", JsonValue::Null),
+            ("expression", boolean(expression)),
+            ("generator", boolean(false)),
+            ("async", boolean(definition.r#async)),
+            ("params", self.parameters(&definition.parameters)),
+            ("body", body),
+          ],
+        )
+      }
+      Expression::Assignment(expression) => {
+        // the target of a plain assignment is a pattern
+        let left = match expression.op {
+          AssignmentOp::Assignment => self.expression_pattern(&expression.left),
+          _ => self.expression(&expression.left),
+        };
+
+        self.node(
+          "AssignmentExpression",
+          span,
+          vec![
+            ("operator", string(assignment_operator(expression.op))),
+            ("left", left),
+            ("right", self.expression(&expression.right)),
+          ],
+        )
+      }
+      // a bigint can't be a json value, `bigint` has the digits as they were written
+      Expression::BigInt(literal) => {
+        let raw = self.raw(literal.span);
+        let digits = raw[..raw.len() - 1].replace('_', "");
+
+        self.node(
+          "Literal",
+          span,
+          vec![
+            ("value", JsonValue::Null),
+            ("raw", JsonValue::String(raw)),
+            ("bigint", JsonValue::String(digits)),
+          ],
+        )
+      }
+      Expression::BinaryOp(expression) => {
+        let kind = match expression.op {
+          BinaryOp::Coalesce | BinaryOp::LogicalAnd | BinaryOp::LogicalOr => "LogicalExpression",
+          _ => "BinaryExpression",
+        };
+
+        self.node(
+          kind,
+          span,
+          vec![
+            ("left", self.expression(&expression.left)),
+            ("operator", string(binary_operator(expression.op))),
+            ("right", self.expression(&expression.right)),
+          ],
+        )
+      }
+      Expression::Boolean(literal) => self.node(
+        "Literal",
+        span,
+        vec![
+          ("value", boolean(literal.value)),
+          ("raw", JsonValue::String(self.raw(span))),
+        ],
+      ),
+      Expression::Call(expression) => self.node(
+        "CallExpression",
+        span,
+        vec![
+          ("callee", self.expression(&expression.callee)),
+          ("arguments", self.arguments(&expression.arguments)),
+          ("optional", boolean(expression.optional)),
+        ],
+      ),
+      Expression::Chain(expression) => self.node(
+        "ChainExpression",
+        span,
+        vec![("expression", self.expression(expression))],
+      ),
+      Expression::Class(definition) => self.class("ClassExpression", definition),
+      Expression::Conditional(expression) => self.node(
+        "ConditionalExpression",
+        span,
+        vec![
+          ("test", self.expression(&expression.condition)),
+          ("consequent", self.expression(&expression.consequent)),
+          ("alternate", self.expression(&expression.alternate)),
+        ],
+      ),
+      // acorn drops parentheses unless `preserveParens` is on
+      Expression::Group(group) => self.expression(&group.expression),
+      Expression::Function(definition) => self.function("FunctionExpression", definition),
+      Expression::Identifier(identifier) => self.identifier(identifier),
+      Expression::Import(expression) => self.node(
+        "ImportExpression",
+        span,
+        vec![("source", self.expression(&expression.argument))],
+      ),
+      Expression::ImportMeta(_) => self.meta_property(span, "import", "meta"),
+      Expression::In(expression) => {
+        let left = match &expression.name {
+          MemberName::Computed(left) => self.expression(left),
+          MemberName::Private(name) => self.private_identifier(name),
+          MemberName::Static(name) => self.identifier(name),
+        };
+
+        self.node(
+          "BinaryExpression",
+          span,
+          vec![
+            ("left", left),
+            ("operator", string("in")),
+            ("right", self.expression(&expression.argument)),
+          ],
+        )
+      }
+      Expression::JsxElement(element) => self.jsx_element(element),
+      Expression::JsxFragment(fragment) => self.jsx_fragment(fragment),
+      Expression::List(list) => self.node(
+        "SequenceExpression",
+        span,
+        vec![(
+          "expressions",
+          array(
+            list
+              .expressions
+              .iter()
+              .map(|expression| self.expression(expression)),
+          ),
+        )],
+      ),
+      Expression::Member(expression) => {
+        let (computed, property) = match &expression.property {
+          MemberName::Computed(property) => (true, self.expression(property)),
+          MemberName::Private(name) => (false, self.private_identifier(name)),
+          MemberName::Static(name) => (false, self.identifier(name)),
+        };
+
+        self.node(
+          "MemberExpression",
+          span,
+          vec![
+            ("object", self.expression(&expression.object)),
+            ("property", property),
+            ("computed", boolean(computed)),
+            ("optional", boolean(expression.optional)),
+          ],
+        )
+      }
+      Expression::NewTarget(_) => self.meta_property(span, "new", "target"),
+      Expression::New(expression) => self.node(
+        "NewExpression",
+        span,
+        vec![
+          ("callee", self.expression(&expression.callee)),
+          (
+            "arguments",
+            match &expression.arguments {
+              Some(arguments) => self.arguments(arguments),
+              None => array([]),
+            },
+          ),
+        ],
+      ),
+      Expression::Null(_) => self.node(
+        "Literal",
+        span,
+        vec![("value", JsonValue::Null), ("raw", string("null"))],
+      ),
+      Expression::Number(literal) => self.node(
+        "Literal",
+        span,
+        vec![
+          ("value", JsonValue::Number(literal.value)),
+          ("raw", JsonValue::String(self.raw(span))),
+        ],
+      ),
+      Expression::Object(literal) => self.node(
+        "ObjectExpression",
+        span,
+        vec![(
+          "properties",
+          array(
+            literal
+              .properties
+              .iter()
+              .map(|property| self.object_property(property)),
+          ),
+        )],
+      ),
+      // a regexp isn't a json value either
+      Expression::RegExp(literal) => self.node(
+        "Literal",
+        span,
+        vec![
+          ("value", JsonValue::Null),
+          ("raw", JsonValue::String(self.raw(span))),
+          (
+            "regex",
+            JsonValue::Object(vec![
+              ("pattern".to_string(), string(literal.source)),
+              ("flags".to_string(), string(literal.flags)),
+            ]),
+          ),
+        ],
+      ),
+      Expression::String(literal) => self.node(
+        "Literal",
+        span,
+        vec![
+          ("value", js_string(literal.value)),
+          ("raw", JsonValue::String(self.raw(span))),
+        ],
+      ),
+      Expression::Super(_) => self.node("Super", span, vec![]),
+      Expression::TaggedTemplate(literal) => {
+        let quasis = literal
+          .string_spans
+          .iter()
+          .zip(literal.strings.iter())
+          .map(|(span, cooked)| (*span, *cooked));
+        let quasi_start = literal.string_spans[0].start as usize - 1;
+        let quasi = self.template(
+          Span::new(quasi_start, span.end as usize),
+          quasis,
+          &literal.substitutions,
+        );
+
+        self.node(
+          "TaggedTemplateExpression",
+          span,
+          vec![("tag", self.expression(&literal.tag)), ("quasi", quasi)],
+        )
+      }
+      Expression::Template(literal) => {
+        let quasis = literal
+          .string_spans
+          .iter()
+          .zip(literal.strings.iter())
+          .map(|(span, cooked)| (*span, Some(*cooked)));
+
+        self.template(span, quasis, &literal.substitutions)
+      }
+      Expression::This(_) => self.node("ThisExpression", span, vec![]),
+      Expression::UnaryOp(expression) => {
+        let argument = self.expression(&expression.argument);
+
+        match expression.op {
+          UnaryOp::Await => self.node("AwaitExpression", span, vec![("argument", argument)]),
+          UnaryOp::PostfixDecrement
+          | UnaryOp::PostfixIncrement
+          | UnaryOp::PrefixDecrement
+          | UnaryOp::PrefixIncrement => {
+            let prefix = matches!(
+              expression.op,
+              UnaryOp::PrefixDecrement | UnaryOp::PrefixIncrement
+            );
+            let operator = match expression.op {
+              UnaryOp::PostfixDecrement | UnaryOp::PrefixDecrement => "--",
+              _ => "++",
+            };
+
+            self.node(
+              "UpdateExpression",
+              span,
+              vec![
+                ("operator", string(operator)),
+                ("prefix", boolean(prefix)),
+                ("argument", argument),
+              ],
+            )
+          }
+          op => self.node(
+            "UnaryExpression",
+            span,
+            vec![
+              ("operator", string(unary_operator(op))),
+              ("prefix", boolean(true)),
+              ("argument", argument),
+            ],
+          ),
+        }
+      }
+      Expression::Yield(expression) => self.node(
+        "YieldExpression",
+        span,
+        vec![
+          ("delegate", boolean(expression.delegate)),
+          (
+            "argument",
+            optional(expression.argument.as_ref(), |argument| {
+              self.expression(argument)
+            }),
+          ),
+        ],
+      ),
+    }
+  }
+
+  // `import.meta` and `new.target`, the two names are the first and last word of the span
+  fn meta_property(&self, span: Span, meta: &str, property: &str) -> JsonValue {
+    let start = span.start as usize;
+    let end = span.end as usize;
+    let meta = Identifier {
+      name: meta,
+      span: Span::new(start, start + meta.len()),
+    };
+    let property = Identifier {
+      name: property,
+      span: Span::new(end - property.len(), end),
+    };
+
+    self.node(
+      "MetaProperty",
+      span,
+      vec![
+        ("meta", self.identifier(&meta)),
+        ("property", self.identifier(&property)),
+      ],
+    )
+  }
+
+  fn template<'t>(
+    &self,
+    span: Span,
+    quasis: impl ExactSizeIterator<Item = (Span, Option<JsStr<'t>>)>,
+    substitutions: &[Expression],
+  ) -> JsonValue {
+    let count = quasis.len();
+    let quasis = quasis.enumerate().map(|(index, (span, cooked))| {
+      // line terminators in the raw text are normalized to `\n`
+      let raw = self.raw(span).replace("\r\n", "\n").replace('\r', "\n");
+
+      self.node(
+        "TemplateElement",
+        span,
+        vec![
+          (
+            "value",
+            JsonValue::Object(vec![
+              ("raw".to_string(), JsonValue::String(raw)),
+              ("cooked".to_string(), optional(cooked, js_string)),
+            ]),
+          ),
+          ("tail", boolean(index == count - 1)),
+        ],
+      )
+    });
+
+    self.node(
+      "TemplateLiteral",
+      span,
+      vec![
+        (
+          "expressions",
+          array(
+            substitutions
+              .iter()
+              .map(|expression| self.expression(expression)),
+          ),
+        ),
+        ("quasis", array(quasis)),
+      ],
+    )
+  }
+
+  fn object_property(&self, property: &ObjectProperty) -> JsonValue {
+    let span = property.span();
+
+    let property_node = |kind: &str, method: bool, name: &PropertyName, value: JsonValue| {
+      let (computed, key) = self.property_name(name);
+
+      self.node(
+        "Property",
+        span,
+        vec![
+          ("method", boolean(method)),
+          ("shorthand", boolean(false)),
+          ("computed", boolean(computed)),
+          ("key", key),
+          ("value", value),
+          ("kind", string(kind)),
+        ],
+      )
+    };
+
+    match property {
+      ObjectProperty::Getter(getter) => {
+        let parameters = array([]);
+        let value = self.method_function(
+          getter.parameters_span,
+          parameters,
+          &getter.body,
+          false,
+          false,
+        );
+        property_node("get", false, &getter.property, value)
+      }
+      ObjectProperty::Method(method) => {
+        let parameters = self.parameters(&method.parameters);
+        let value = self.method_function(
+          method.parameters.span,
+          parameters,
+          &method.body,
+          method.r#async,
+          method.generator,
+        );
+        property_node("init", true, &method.property, value)
+      }
+      ObjectProperty::Property(definition) => {
+        let value = self.expression(&definition.expression);
+        property_node("init", false, &definition.property, value)
+      }
+      ObjectProperty::Setter(setter) => {
+        let parameters = array([self.binding_initializer(&setter.parameter)]);
+        let value = self.method_function(
+          setter.parameters_span,
+          parameters,
+          &setter.body,
+          false,
+          false,
+        );
+        property_node("set", false, &setter.property, value)
+      }
+      ObjectProperty::Shorthand(identifier) => self.node(
+        "Property",
+        span,
+        vec![
+          ("method", boolean(false)),
+          ("shorthand", boolean(true)),
+          ("computed", boolean(false)),
+          ("key", self.identifier(identifier)),
+          ("value", self.identifier(identifier)),
+          ("kind", string("init")),
+        ],
+      ),
+      ObjectProperty::Spread(spread) => self.spread(spread),
+    }
+  }
+
+  // the left side of an assignment or a for-in/of is parsed as an expression, acorn turns it into
+  // the pattern it stands for
+  fn expression_pattern(&self, expression: &Expression) -> JsonValue {
+    let span = expression.span();
+
+    match expression {
+      Expression::Array(literal) => {
+        let elements = literal.elements.iter().map(|element| match element {
+          ArrayElement::Elision => JsonValue::Null,
+          ArrayElement::Expression(expression) => self.expression_pattern(expression),
+          ArrayElement::Spread(spread) => self.node(
+            "RestElement",
+            spread.span,
+            vec![("argument", self.expression_pattern(&spread.argument))],
+          ),
+        });
+
+        self.node("ArrayPattern", span, vec![("elements", array(elements))])
+      }
+      Expression::Assignment(assignment) if matches!(assignment.op, AssignmentOp::Assignment) => {
+        self.node(
+          "AssignmentPattern",
+          span,
+          vec![
+            ("left", self.expression_pattern(&assignment.left)),
+            ("right", self.expression(&assignment.right)),
+          ],
+        )
+      }
+      Expression::Group(group) => self.expression_pattern(&group.expression),
+      Expression::Object(literal) => {
+        let properties = literal.properties.iter().map(|property| match property {
+          ObjectProperty::Property(definition) => {
+            let (computed, key) = self.property_name(&definition.property);
+
+            self.node(
+              "Property",
+              definition.span,
+              vec![
+                ("method", boolean(false)),
+                ("shorthand", boolean(false)),
+                ("computed", boolean(computed)),
+                ("key", key),
+                ("value", self.expression_pattern(&definition.expression)),
+                ("kind", string("init")),
+              ],
+            )
+          }
+          ObjectProperty::Spread(spread) => self.node(
+            "RestElement",
+            spread.span,
+            vec![("argument", self.expression_pattern(&spread.argument))],
+          ),
+          property => self.object_property(property),
+        });
+
+        self.node(
+          "ObjectPattern",
+          span,
+          vec![("properties", array(properties))],
+        )
+      }
+      expression => self.expression(expression),
+    }
+  }
+
+  fn pattern(&self, pattern: &BindingPattern) -> JsonValue {
+    match pattern {
+      BindingPattern::Array(pattern) => {
+        let mut elements = pattern
+          .elements
+          .iter()
+          .map(|element| {
+            optional(element.as_ref(), |element| {
+              self.binding_initializer(element)
+            })
+          })
+          .collect::<Vec<_>>();
+        elements.extend(pattern.rest.as_ref().map(|rest| self.rest(rest)));
+
+        self.node(
+          "ArrayPattern",
+          pattern.span,
+          vec![("elements", array(elements))],
+        )
+      }
+      BindingPattern::Identifier(identifier) => self.identifier(identifier),
+      BindingPattern::Object(pattern) => {
+        let mut properties = pattern
+          .properties
+          .iter()
+          .map(|property| {
+            let (computed, key) = self.property_name(&property.property);
+            // a shorthand property's key is the same token as its binding
+            let shorthand = matches!(
+              &property.property,
+              PropertyName::Static(key) if key.span.start == property.binding.pattern.span().start
+            );
+
+            self.node(
+              "Property",
+              property.span,
+              vec![
+                ("method", boolean(false)),
+                ("shorthand", boolean(shorthand)),
+                ("computed", boolean(computed)),
+                ("key", key),
+                ("value", self.binding_initializer(&property.binding)),
+                ("kind", string("init")),
+              ],
+            )
+          })
+          .collect::<Vec<_>>();
+        properties.extend(pattern.rest.as_ref().map(|rest| self.rest(rest)));
+
+        self.node(
+          "ObjectPattern",
+          pattern.span,
+          vec![("properties", array(properties))],
+        )
+      }
+    }
+  }
+
+  fn binding_initializer(&self, binding: &BindingPatternInitializer) -> JsonValue {
+    match &binding.initializer {
+      Some(initializer) => self.node(
+        "AssignmentPattern",
+        binding.span,
+        vec![
+          ("left", self.pattern(&binding.pattern)),
+          ("right", self.expression(initializer)),
+        ],
+      ),
+      None => self.pattern(&binding.pattern),
+    }
+  }
+
+  fn rest(&self, rest: &BindingRestElement) -> JsonValue {
+    self.node(
+      "RestElement",
+      rest.span,
+      vec![("argument", self.pattern(&rest.pattern))],
+    )
+  }
+
+  fn parameters(&self, parameters: &FormalParameters) -> JsonValue {
+    let mut params = parameters
+      .bindings
+      .iter()
+      .map(|binding| self.binding_initializer(binding))
+      .collect::<Vec<_>>();
+    params.extend(parameters.rest.as_ref().map(|rest| self.rest(rest)));
+    JsonValue::Array(params)
+  }
+
+  fn function(&self, kind: &str, definition: &FunctionDefinition) -> JsonValue {
+    self.node(
+      kind,
+      definition.span,
+      vec![
+        (
+          "id",
+          optional(definition.identifier.as_ref(), |identifier| {
+            self.identifier(identifier)
+          }),
+        ),
+        ("expression", boolean(false)),
+        ("generator", boolean(definition.generator)),
+        ("async", boolean(definition.r#async)),
+        ("params", self.parameters(&definition.parameters)),
+        ("body", self.block(&definition.body, true)),
+      ],
+    )
+  }
+
+  // the function of a method runs from its parameters to the end of its body
+  fn method_function(
+    &self,
+    parameters_span: Span,
+    parameters: JsonValue,
+    body: &Block,
+    r#async: bool,
+    generator: bool,
+  ) -> JsonValue {
+    self.node(
+      "FunctionExpression",
+      parameters_span.to(body.span),
+      vec![
+        ("id", JsonValue::Null),
+        ("expression", boolean(false)),
+        ("generator", boolean(generator)),
+        ("async", boolean(r#async)),
+        ("params", parameters),
+        ("body", self.block(body, true)),
+      ],
+    )
+  }
+
+  // decorators aren't in acorn, they're written the way the ESTree proposal has them and only
+  // when there are some
+  fn with_decorators(&self, mut node: JsonValue, decorators: &[Expression]) -> JsonValue {
+    if !decorators.is_empty() {
+      let decorators = decorators.iter().map(|decorator| {
+        self.node(
+          "Decorator",
+          decorator.span(),
+          vec![("expression", self.expression(decorator))],
+        )
+      });
+
+      if let JsonValue::Object(members) = &mut node {
+        members.push(("decorators".to_string(), array(decorators)));
+      }
+    }
+
+    node
+  }
+
+  fn class(&self, kind: &str, definition: &ClassDefinition) -> JsonValue {
+    let elements = definition
+      .body
+      .iter()
+      .map(|element| self.class_element(element));
+    let body = self.node(
+      "ClassBody",
+      definition.body_span,
+      vec![("body", array(elements))],
+    );
+
+    let node = self.node(
+      kind,
+      definition.span,
+      vec![
+        (
+          "id",
+          optional(definition.identifier.as_ref(), |identifier| {
+            self.identifier(identifier)
+          }),
+        ),
+        (
+          "superClass",
+          optional(definition.heritage.as_ref(), |heritage| {
+            self.expression(heritage)
+          }),
+        ),
+        ("body", body),
+      ],
+    );
+
+    self.with_decorators(node, &definition.decorators)
+  }
+
+  fn class_element_name(&self, name: &ClassElementName) -> (bool, JsonValue) {
+    match name {
+      ClassElementName::Computed(expression) => (true, self.expression(expression)),
+      ClassElementName::Private(name) => (false, self.private_identifier(name)),
+      ClassElementName::Static(name) => (false, self.property_key(name)),
+    }
+  }
+
+  fn class_element(&self, element: &ClassElement) -> JsonValue {
+    let span = element.span();
+
+    let method = |kind: &str,
+                  r#static: bool,
+                  name: &ClassElementName,
+                  value: JsonValue,
+                  decorators: &[Expression]| {
+      let (computed, key) = self.class_element_name(name);
+      let node = self.node(
+        "MethodDefinition",
+        span,
+        vec![
+          ("static", boolean(r#static)),
+          ("computed", boolean(computed)),
+          ("key", key),
+          ("kind", string(kind)),
+          ("value", value),
+        ],
+      );
+
+      self.with_decorators(node, decorators)
+    };
+
+    let property = |kind: &str,
+                    r#static: bool,
+                    name: &ClassElementName,
+                    value: Option<&Expression>,
+                    decorators: &[Expression]| {
+      let (computed, key) = self.class_element_name(name);
+      let node = self.node(
+        kind,
+        span,
+        vec![
+          ("static", boolean(r#static)),
+          ("computed", boolean(computed)),
+          ("key", key),
+          ("value", optional(value, |value| self.expression(value))),
+        ],
+      );
+
+      self.with_decorators(node, decorators)
+    };
+
+    match element {
+      ClassElement::Accessor(accessor) => property(
+        "AccessorProperty",
+        accessor.r#static,
+        &accessor.name,
+        accessor.value.as_ref(),
+        &accessor.decorators,
+      ),
+      ClassElement::Field(field) => property(
+        "PropertyDefinition",
+        field.r#static,
+        &field.name,
+        field.value.as_ref(),
+        &field.decorators,
+      ),
+      ClassElement::Getter(getter) => {
+        let value = self.method_function(
+          getter.parameters_span,
+          array([]),
+          &getter.body,
+          false,
+          false,
+        );
+        method(
+          "get",
+          getter.r#static,
+          &getter.name,
+          value,
+          &getter.decorators,
+        )
+      }
+      ClassElement::Method(definition) => {
+        let constructor = !definition.r#static
+          && matches!(
+            definition.name,
+            ClassElementName::Static(PropertyKey { name, .. }) if name == "constructor"
+          );
+        let parameters = self.parameters(&definition.parameters);
+        let value = self.method_function(
+          definition.parameters.span,
+          parameters,
+          &definition.body,
+          definition.r#async,
+          definition.generator,
+        );
+        let kind = if constructor { "constructor" } else { "method" };
+        method(
+          kind,
+          definition.r#static,
+          &definition.name,
+          value,
+          &definition.decorators,
+        )
+      }
+      ClassElement::Setter(setter) => {
+        let parameters = array([self.binding_initializer(&setter.parameter)]);
+        let value = self.method_function(
+          setter.parameters_span,
+          parameters,
+          &setter.body,
+          false,
+          false,
+        );
+        method(
+          "set",
+          setter.r#static,
+          &setter.name,
+          value,
+          &setter.decorators,
+        )
+      }
+      ClassElement::Static(block) => self.node(
+        "StaticBlock",
+        span,
+        vec![("body", self.statements(&block.statements, false))],
+      ),
+    }
+  }
+
+  // the shapes acorn-jsx gives jsx
+  fn jsx_element(&self, element: &JsxElement) -> JsonValue {
+    let attributes = element
+      .attributes
+      .iter()
+      .map(|attribute| self.jsx_attribute(attribute));
+
+    let opening = self.node(
+      "JSXOpeningElement",
+      element.opening_span,
+      vec![
+        ("attributes", array(attributes)),
+        ("name", self.jsx_element_name(&element.name, 0)),
+        ("selfClosing", boolean(element.self_closing)),
+      ],
+    );
+
+    // the closing tag repeats the name, it only has the spans of the opening one
+    let closing = optional(element.closing_span, |closing_span| {
+      let mut name_start = closing_span.start as usize + 2;
+      while self
+        .source
+        .get(name_start)
+        .is_some_and(|c| c.is_whitespace())
+      {
+        name_start += 1;
+      }
+
+      let shift = name_start as i64 - element.name.span().start as i64;
+      self.node(
+        "JSXClosingElement",
+        closing_span,
+        vec![("name", self.jsx_element_name(&element.name, shift))],
+      )
+    });
+
+    self.node(
+      "JSXElement",
+      element.span,
+      vec![
+        ("openingElement", opening),
+        ("closingElement", closing),
+        ("children", self.jsx_children(&element.children)),
+      ],
+    )
+  }
+
+  fn jsx_fragment(&self, fragment: &JsxFragment) -> JsonValue {
+    self.node(
+      "JSXFragment",
+      fragment.span,
+      vec![
+        (
+          "openingFragment",
+          self.node(
+            "JSXOpeningFragment",
+            fragment.opening_span,
+            vec![("attributes", array([])), ("selfClosing", boolean(false))],
+          ),
+        ),
+        (
+          "closingFragment",
+          self.node("JSXClosingFragment", fragment.closing_span, vec![]),
+        ),
+        ("children", self.jsx_children(&fragment.children)),
+      ],
+    )
+  }
+
+  fn jsx_identifier(&self, identifier: &Identifier, shift: i64) -> JsonValue {
+    self.node(
+      "JSXIdentifier",
+      shift_span(identifier.span, shift),
+      vec![("name", string(identifier.name))],
+    )
+  }
+
+  fn jsx_namespaced_name(
+    &self,
+    namespace: &Identifier,
+    name: &Identifier,
+    span: Span,
+    shift: i64,
+  ) -> JsonValue {
+    self.node(
+      "JSXNamespacedName",
+      shift_span(span, shift),
+      vec![
+        ("namespace", self.jsx_identifier(namespace, shift)),
+        ("name", self.jsx_identifier(name, shift)),
+      ],
+    )
+  }
+
+  fn jsx_element_name(&self, name: &JsxElementName, shift: i64) -> JsonValue {
+    match name {
+      JsxElementName::Identifier(identifier) => self.jsx_identifier(identifier, shift),
+      JsxElementName::Member(member) => self.node(
+        "JSXMemberExpression",
+        shift_span(member.span, shift),
+        vec![
+          ("object", self.jsx_element_name(&member.object, shift)),
+          ("property", self.jsx_identifier(&member.property, shift)),
+        ],
+      ),
+      JsxElementName::Namespaced(name) => {
+        self.jsx_namespaced_name(&name.namespace, &name.name, name.span, shift)
+      }
+    }
+  }
+
+  fn jsx_attribute(&self, attribute: &JsxAttribute) -> JsonValue {
+    match attribute {
+      JsxAttribute::Attribute(attribute) => {
+        let name = match &attribute.name {
+          JsxAttributeName::Identifier(identifier) => self.jsx_identifier(identifier, 0),
+          JsxAttributeName::Namespaced(name) => {
+            self.jsx_namespaced_name(&name.namespace, &name.name, name.span, 0)
+          }
+        };
+
+        let value = optional(attribute.value.as_ref(), |value| match value {
+          JsxAttributeValue::Element(element) => self.jsx_element(element),
+          JsxAttributeValue::Expression(container) => self.jsx_expression_container(container),
+          JsxAttributeValue::Fragment(fragment) => self.jsx_fragment(fragment),
+          JsxAttributeValue::String(literal) => self.node(
+            "Literal",
+            literal.span,
+            vec![
+              ("value", js_string(literal.value)),
+              ("raw", JsonValue::String(self.raw(literal.span))),
+            ],
+          ),
+        });
+
+        self.node(
+          "JSXAttribute",
+          attribute.span,
+          vec![("name", name), ("value", value)],
+        )
+      }
+      JsxAttribute::Spread(container) => self.node(
+        "JSXSpreadAttribute",
+        container.span,
+        vec![("argument", self.expression(&container.expression))],
+      ),
+    }
+  }
+
+  fn jsx_expression_container(&self, container: &JsxExpressionContainer) -> JsonValue {
+    self.node(
+      "JSXExpressionContainer",
+      container.span,
+      vec![("expression", self.expression(&container.expression))],
+    )
+  }
+
+  fn jsx_children(&self, children: &[JsxChild]) -> JsonValue {
+    array(children.iter().map(|child| match child {
+      JsxChild::Element(element) => self.jsx_element(element),
+      // the empty expression is everything between the braces
+      JsxChild::Empty(span) => {
+        let inner = Span::new(span.start as usize + 1, span.end as usize - 1);
+        let expression = self.node("JSXEmptyExpression", inner, vec![]);
+        self.node(
+          "JSXExpressionContainer",
+          *span,
+          vec![("expression", expression)],
+        )
+      }
+      JsxChild::Expression(container) => self.jsx_expression_container(container),
+      JsxChild::Fragment(fragment) => self.jsx_fragment(fragment),
+      JsxChild::Spread(container) => self.node(
+        "JSXSpreadChild",
+        container.span,
+        vec![("expression", self.expression(&container.expression))],
+      ),
+      JsxChild::Text(text) => self.node(
+        "JSXText",
+        text.span,
+        vec![("value", string(text.value)), ("raw", string(text.raw))],
+      ),
+    }))
+  }
+}
+
+fn shift_span(span: Span, shift: i64) -> Span {
+  Span::new(
+    (span.start as i64 + shift) as usize,
+    (span.end as i64 + shift) as usize,
+  )
+}
+
+fn lexical_kind(kind: LexicalKind) -> &'static str {
+  match kind {
+    LexicalKind::Const => "const",
+    LexicalKind::Let => "let",
+  }
+}
+
+fn unary_operator(op: UnaryOp) -> &'static str {
+  match op {
+    UnaryOp::Absolute => "+",
+    UnaryOp::BitwiseNot => "~",
+    UnaryOp::Delete => "delete",
+    UnaryOp::LogicalNot => "!",
+    UnaryOp::Negate => "-",
+    UnaryOp::Typeof => "typeof",
+    UnaryOp::Void => "void",
+    UnaryOp::Await
+    | UnaryOp::PostfixDecrement
+    | UnaryOp::PostfixIncrement
+    | UnaryOp::PrefixDecrement
+    | UnaryOp::PrefixIncrement => unreachable!(),
+  }
+}
+
+fn binary_operator(op: BinaryOp) -> &'static str {
+  match op {
+    BinaryOp::Addition => "+",
+    BinaryOp::BitwiseAnd => "&",
+    BinaryOp::BitwiseOr => "|",
+    BinaryOp::BitwiseXor => "^",
+    BinaryOp::Coalesce => "??",
+    BinaryOp::Division => "/",
+    BinaryOp::Equality => "==",
+    BinaryOp::Exponentiation => "**",
+    BinaryOp::GreaterThan => ">",
+    BinaryOp::GreaterThanOrEqual => ">=",
+    BinaryOp::Inequality => "!=",
+    BinaryOp::Instanceof => "instanceof",
+    BinaryOp::LeftShift => "<<",
+    BinaryOp::LessThan => "<",
+    BinaryOp::LessThanOrEqual => "<=",
+    BinaryOp::LogicalAnd => "&&",
+    BinaryOp::LogicalOr => "||",
+    BinaryOp::Multiplication => "*",
+    BinaryOp::Remainder => "%",
+    BinaryOp::RightShift => ">>",
+    BinaryOp::StrictEquality => "===",
+    BinaryOp::StrictInequality => "!==",
+    BinaryOp::Subtraction => "-",
+    BinaryOp::UnsignedRightShift => ">>>",
+  }
+}
+
+fn assignment_operator(op: AssignmentOp) -> &'static str {
+  match op {
+    AssignmentOp::Addition => "+=",
+    AssignmentOp::Assignment => "=",
+    AssignmentOp::BitwiseAnd => "&=",
+    AssignmentOp::BitwiseOr => "|=",
+    AssignmentOp::BitwiseXor => "^=",
+    AssignmentOp::Coalesce => "??=",
+    AssignmentOp::Division => "/=",
+    AssignmentOp::Exponentiation => "**=",
+    AssignmentOp::LeftShift => "<<=",
+    AssignmentOp::LogicalAnd => "&&=",
+    AssignmentOp::LogicalOr => "||=",
+    AssignmentOp::Multiplication => "*=",
+    AssignmentOp::Remainder => "%=",
+    AssignmentOp::RightShift => ">>=",
+    AssignmentOp::Subtraction => "-=",
+    AssignmentOp::UnsignedRightShift => ">>>=",
+  }
+}
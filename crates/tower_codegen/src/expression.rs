@@ -0,0 +1,505 @@
+use tower_ast::{
+  expression::{ArrayElement, Expression, MemberName},
+  function::Argument,
+  op::{AssignmentOp, BinaryOp, UnaryOp},
+};
+
+use crate::Printer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+  Comma,
+  Assignment,
+  Conditional,
+  Coalesce,
+  LogicalOr,
+  LogicalAnd,
+  BitwiseOr,
+  BitwiseXor,
+  BitwiseAnd,
+  Equality,
+  Relational,
+  Shift,
+  Additive,
+  Multiplicative,
+  Exponentiation,
+  Unary,
+  Update,
+  // a new expression without arguments, and optional chains
+  New,
+  Call,
+  Member,
+  Primary,
+}
+
+impl Precedence {
+  fn next(self) -> Self {
+    match self {
+      Precedence::Comma => Precedence::Assignment,
+      Precedence::Assignment => Precedence::Conditional,
+      Precedence::Conditional => Precedence::Coalesce,
+      Precedence::Coalesce => Precedence::LogicalOr,
+      Precedence::LogicalOr => Precedence::LogicalAnd,
+      Precedence::LogicalAnd => Precedence::BitwiseOr,
+      Precedence::BitwiseOr => Precedence::BitwiseXor,
+      Precedence::BitwiseXor => Precedence::BitwiseAnd,
+      Precedence::BitwiseAnd => Precedence::Equality,
+      Precedence::Equality => Precedence::Relational,
+      Precedence::Relational => Precedence::Shift,
+      Precedence::Shift => Precedence::Additive,
+      Precedence::Additive => Precedence::Multiplicative,
+      Precedence::Multiplicative => Precedence::Exponentiation,
+      Precedence::Exponentiation => Precedence::Unary,
+      Precedence::Unary => Precedence::Update,
+      Precedence::Update => Precedence::New,
+      Precedence::New => Precedence::Call,
+      Precedence::Call => Precedence::Member,
+      Precedence::Member | Precedence::Primary => Precedence::Primary,
+    }
+  }
+}
+
+pub fn binary_precedence(op: BinaryOp) -> Precedence {
+  match op {
+    BinaryOp::Coalesce => Precedence::Coalesce,
+    BinaryOp::LogicalOr => Precedence::LogicalOr,
+    BinaryOp::LogicalAnd => Precedence::LogicalAnd,
+    BinaryOp::BitwiseOr => Precedence::BitwiseOr,
+    BinaryOp::BitwiseXor => Precedence::BitwiseXor,
+    BinaryOp::BitwiseAnd => Precedence::BitwiseAnd,
+    BinaryOp::Equality
+    | BinaryOp::Inequality
+    | BinaryOp::StrictEquality
+    | BinaryOp::StrictInequality => Precedence::Equality,
+    BinaryOp::GreaterThan
+    | BinaryOp::GreaterThanOrEqual
+    | BinaryOp::Instanceof
+    | BinaryOp::LessThan
+    | BinaryOp::LessThanOrEqual => Precedence::Relational,
+    BinaryOp::LeftShift | BinaryOp::RightShift | BinaryOp::UnsignedRightShift => Precedence::Shift,
+    BinaryOp::Addition | BinaryOp::Subtraction => Precedence::Additive,
+    BinaryOp::Division | BinaryOp::Multiplication | BinaryOp::Remainder => {
+      Precedence::Multiplicative
+    }
+    BinaryOp::Exponentiation => Precedence::Exponentiation,
+  }
+}
+
+pub fn binary_operator(op: BinaryOp) -> &'static str {
+  match op {
+    BinaryOp::Addition => "+",
+    BinaryOp::BitwiseAnd => "&",
+    BinaryOp::BitwiseOr => "|",
+    BinaryOp::BitwiseXor => "^",
+    BinaryOp::Coalesce => "??",
+    BinaryOp::Division => "/",
+    BinaryOp::Equality => "==",
+    BinaryOp::Exponentiation => "**",
+    BinaryOp::GreaterThan => ">",
+    BinaryOp::GreaterThanOrEqual => ">=",
+    BinaryOp::Inequality => "!=",
+    BinaryOp::Instanceof => "instanceof",
+    BinaryOp::LeftShift => "<<",
+    BinaryOp::LessThan => "<",
+    BinaryOp::LessThanOrEqual => "<=",
+    BinaryOp::LogicalAnd => "&&",
+    BinaryOp::LogicalOr => "||",
+    BinaryOp::Multiplication => "*",
+    BinaryOp::Remainder => "%",
+    BinaryOp::RightShift => ">>",
+    BinaryOp::StrictEquality => "===",
+    BinaryOp::StrictInequality => "!==",
+    BinaryOp::Subtraction => "-",
+    BinaryOp::UnsignedRightShift => ">>>",
+  }
+}
+
+pub fn assignment_operator(op: AssignmentOp) -> &'static str {
+  match op {
+    AssignmentOp::Addition => "+=",
+    AssignmentOp::Assignment => "=",
+    AssignmentOp::BitwiseAnd => "&=",
+    AssignmentOp::BitwiseOr => "|=",
+    AssignmentOp::BitwiseXor => "^=",
+    AssignmentOp::Coalesce => "??=",
+    AssignmentOp::Division => "/=",
+    AssignmentOp::Exponentiation => "**=",
+    AssignmentOp::LeftShift => "<<=",
+    AssignmentOp::LogicalAnd => "&&=",
+    AssignmentOp::LogicalOr => "||=",
+    AssignmentOp::Multiplication => "*=",
+    AssignmentOp::Remainder => "%=",
+    AssignmentOp::RightShift => ">>=",
+    AssignmentOp::Subtraction => "-=",
+    AssignmentOp::UnsignedRightShift => ">>>=",
+  }
+}
+
+// parentheses from the source are kept in the tree as groups, the printer looks through them and
+// only puts back the ones the grammar needs
+pub fn strip_groups<'a, 'b>(mut expression: &'b Expression<'a>) -> &'b Expression<'a> {
+  while let Expression::Group(inner) = expression {
+    expression = &inner.expression;
+  }
+
+  expression
+}
+
+pub fn precedence(expression: &Expression) -> Precedence {
+  match expression {
+    Expression::List(_) => Precedence::Comma,
+    Expression::ArrowFunction(_) | Expression::Assignment(_) | Expression::Yield(_) => {
+      Precedence::Assignment
+    }
+    Expression::Conditional(_) => Precedence::Conditional,
+    Expression::BinaryOp(expression) => binary_precedence(expression.op),
+    Expression::In(_) => Precedence::Relational,
+    Expression::UnaryOp(expression) => match expression.op {
+      UnaryOp::PostfixDecrement | UnaryOp::PostfixIncrement => Precedence::Update,
+      _ => Precedence::Unary,
+    },
+    Expression::Number(literal) if literal.value.is_nan() || literal.value.is_infinite() => {
+      Precedence::Multiplicative
+    }
+    Expression::Number(literal) if literal.value.is_sign_negative() => Precedence::Unary,
+    Expression::Chain(_) => Precedence::New,
+    Expression::New(expression) => match expression.arguments {
+      Some(_) => Precedence::Member,
+      None => Precedence::New,
+    },
+    Expression::Call(_) | Expression::Import(_) => Precedence::Call,
+    // a member access on a call is itself a call expression as far as `new` is concerned
+    Expression::Member(expression) => match precedence(&expression.object) {
+      Precedence::Call => Precedence::Call,
+      _ => Precedence::Member,
+    },
+    Expression::TaggedTemplate(expression) => match precedence(&expression.tag) {
+      Precedence::Call => Precedence::Call,
+      _ => Precedence::Member,
+    },
+    Expression::Group(expression) => precedence(&expression.expression),
+    _ => Precedence::Primary,
+  }
+}
+
+impl Printer {
+  pub fn print_expression(&mut self, expression: &Expression, min: Precedence) {
+    let expression = strip_groups(expression);
+    self.mark(expression.span());
+
+    let needs_parens = precedence(expression) < min
+      || (self.no_in && is_in_operator(expression))
+      || (matches!(expression, Expression::Assignment(assignment)
+        if matches!(strip_groups(&assignment.left), Expression::Object(_)))
+        && (self.is_statement_start() || self.is_arrow_body_start()));
+
+    if needs_parens {
+      self.write("(");
+      self.with_in(|printer| printer.print_expression_inner(expression));
+      self.write(")");
+    } else {
+      self.print_expression_inner(expression);
+    }
+  }
+
+  fn print_expression_inner(&mut self, expression: &Expression) {
+    match expression {
+      Expression::Array(literal) => {
+        let elements = &literal.elements;
+        self.write("[");
+        self.with_in(|printer| {
+          for (index, element) in elements.iter().enumerate() {
+            if index > 0 {
+              printer.comma();
+            }
+
+            match element {
+              ArrayElement::Elision => {}
+              ArrayElement::Expression(expression) => {
+                printer.print_expression(expression, Precedence::Assignment)
+              }
+              ArrayElement::Spread(spread) => {
+                printer.mark(spread.span);
+                printer.write("...");
+                printer.print_expression(&spread.argument, Precedence::Assignment);
+              }
+            }
+          }
+
+          // a hole at the end needs its own comma, the last one is otherwise a trailing comma
+          if let Some(ArrayElement::Elision) = elements.last() {
+            printer.write(",");
+          }
+        });
+        self.write("]");
+      }
+      Expression::ArrowFunction(definition) => self.print_arrow_function(definition),
+      Expression::Assignment(expression) => {
+        self.print_expression(&expression.left, Precedence::New);
+        self.space();
+        self.write(assignment_operator(expression.op));
+        self.space();
+        self.print_expression(&expression.right, Precedence::Assignment);
+      }
+      Expression::BigInt(literal) => self.write(&format!("{}n", literal.value)),
+      Expression::BinaryOp(expression) => {
+        let precedence = binary_precedence(expression.op);
+        let (left, right) = match expression.op {
+          // `??` can't be mixed with `||` and `&&` without parentheses
+          BinaryOp::Coalesce => {
+            let left = match strip_groups(&expression.left) {
+              Expression::BinaryOp(left) if matches!(left.op, BinaryOp::Coalesce) => {
+                Precedence::Coalesce
+              }
+              _ => Precedence::BitwiseOr,
+            };
+            (left, Precedence::BitwiseOr)
+          }
+          // the left side of `**` can't be a unary expression
+          BinaryOp::Exponentiation => (Precedence::Update, Precedence::Exponentiation),
+          _ => (precedence, precedence.next()),
+        };
+
+        self.print_expression(&expression.left, left);
+        self.space();
+        self.write(binary_operator(expression.op));
+        self.space();
+        self.print_expression(&expression.right, right);
+      }
+      Expression::Boolean(literal) => self.write(if literal.value { "true" } else { "false" }),
+      Expression::Call(expression) => {
+        self.print_expression(&expression.callee, Precedence::Call);
+        if expression.optional {
+          self.write("?.");
+        }
+        self.print_arguments(&expression.arguments);
+      }
+      Expression::Chain(expression) => self.print_expression(expression, Precedence::New),
+      Expression::Class(definition) => {
+        if self.is_statement_start() {
+          self.write("(");
+          self.print_class(definition);
+          self.write(")");
+        } else {
+          self.print_class(definition);
+        }
+      }
+      Expression::Conditional(expression) => {
+        self.print_expression(&expression.condition, Precedence::Coalesce);
+        self.space();
+        self.write("?");
+        self.space();
+        self.with_in(|printer| {
+          printer.print_expression(&expression.consequent, Precedence::Assignment)
+        });
+        self.space();
+        self.write(":");
+        self.space();
+        self.print_expression(&expression.alternate, Precedence::Assignment);
+      }
+      Expression::Function(definition) => {
+        if self.is_statement_start() {
+          self.write("(");
+          self.print_function(definition);
+          self.write(")");
+        } else {
+          self.print_function(definition);
+        }
+      }
+      Expression::Group(expression) => self.print_expression_inner(&expression.expression),
+      Expression::Identifier(identifier) => {
+        // `let [` at the start of a statement begins a declaration
+        if identifier.name == "let" && self.is_statement_start() {
+          self.write("(let)");
+        } else {
+          self.mark_name(identifier.span, identifier.name);
+          self.write(identifier.name);
+        }
+      }
+      Expression::Import(expression) => {
+        self.write("import(");
+        self.with_in(|printer| {
+          printer.print_expression(&expression.argument, Precedence::Assignment)
+        });
+        self.write(")");
+      }
+      Expression::ImportMeta(_) => self.write("import.meta"),
+      Expression::In(expression) => {
+        match &expression.name {
+          MemberName::Computed(left) => self.print_expression(left, Precedence::Relational),
+          MemberName::Private(name) | MemberName::Static(name) => {
+            self.write("#");
+            self.output.push_str(name.name);
+          }
+        }
+        self.space();
+        self.write("in");
+        self.space();
+        self.print_expression(&expression.argument, Precedence::Shift);
+      }
+      Expression::JsxElement(element) => self.print_jsx_element(element),
+      Expression::JsxFragment(fragment) => self.print_jsx_fragment(fragment),
+      Expression::List(list) => {
+        for (index, expression) in list.expressions.iter().enumerate() {
+          if index > 0 {
+            self.comma();
+          }
+
+          self.print_expression(expression, Precedence::Assignment);
+        }
+      }
+      Expression::Member(expression) => {
+        let start = self.output.len();
+        self.print_expression(&expression.object, Precedence::Call);
+
+        match &expression.property {
+          MemberName::Computed(property) => {
+            if expression.optional {
+              self.write("?.");
+            }
+            self.write("[");
+            self.with_in(|printer| printer.print_expression(property, Precedence::Comma));
+            self.write("]");
+          }
+          MemberName::Private(name) => {
+            self.write(if expression.optional { "?.#" } else { ".#" });
+            self.output.push_str(name.name);
+          }
+          MemberName::Static(name) => {
+            // `1.x` would read the dot as a decimal point
+            let object = self.output[start..].trim_start();
+            if !expression.optional && object.bytes().all(|c| c.is_ascii_digit()) {
+              self.output.push('.');
+            }
+
+            self.write(if expression.optional { "?." } else { "." });
+            self.mark_name(name.span, name.name);
+            self.write(name.name);
+          }
+        }
+      }
+      Expression::NewTarget(_) => self.write("new.target"),
+      Expression::New(expression) => {
+        self.write("new");
+        self.print_expression(&expression.callee, Precedence::Member);
+        if let Some(arguments) = &expression.arguments {
+          self.print_arguments(arguments);
+        }
+      }
+      Expression::Null(_) => self.write("null"),
+      Expression::Number(literal) => self.print_number_literal(literal.value),
+      Expression::Object(literal) => {
+        if self.is_statement_start() || self.is_arrow_body_start() {
+          self.write("(");
+          self.print_object_literal(&literal.properties);
+          self.write(")");
+        } else {
+          self.print_object_literal(&literal.properties);
+        }
+      }
+      Expression::RegExp(literal) => self.print_regexp_literal(literal.source, literal.flags),
+      Expression::String(literal) => self.print_string_literal(literal.value),
+      Expression::Super(_) => self.write("super"),
+      Expression::TaggedTemplate(literal) => {
+        self.print_expression(&literal.tag, Precedence::Call);
+        self.write("`");
+        for (index, raw) in literal.raw_strings.iter().enumerate() {
+          if index > 0 {
+            self.output.push_str("${");
+            self.with_in(|printer| {
+              printer.print_expression(&literal.substitutions[index - 1], Precedence::Comma)
+            });
+            self.output.push('}');
+          }
+
+          self.output.push_str(&raw.to_string_lossy());
+        }
+        self.output.push('`');
+      }
+      Expression::Template(literal) => {
+        self.write("`");
+        for (index, chunk) in literal.strings.iter().enumerate() {
+          if index > 0 {
+            self.output.push_str("${");
+            self.with_in(|printer| {
+              printer.print_expression(&literal.substitutions[index - 1], Precedence::Comma)
+            });
+            self.output.push('}');
+          }
+
+          self.print_template_chunk(*chunk);
+        }
+        self.output.push('`');
+      }
+      Expression::This(_) => self.write("this"),
+      Expression::UnaryOp(expression) => {
+        let prefix = match expression.op {
+          UnaryOp::Absolute => "+",
+          UnaryOp::Await => "await",
+          UnaryOp::BitwiseNot => "~",
+          UnaryOp::Delete => "delete",
+          UnaryOp::LogicalNot => "!",
+          UnaryOp::Negate => "-",
+          UnaryOp::PrefixDecrement => "--",
+          UnaryOp::PrefixIncrement => "++",
+          UnaryOp::Typeof => "typeof",
+          UnaryOp::Void => "void",
+          UnaryOp::PostfixDecrement => {
+            self.print_expression(&expression.argument, Precedence::New);
+            self.write("--");
+            return;
+          }
+          UnaryOp::PostfixIncrement => {
+            self.print_expression(&expression.argument, Precedence::New);
+            self.write("++");
+            return;
+          }
+        };
+
+        self.write(prefix);
+        if prefix.starts_with(char::is_alphabetic) {
+          self.space();
+        }
+        self.print_expression(&expression.argument, Precedence::Unary);
+      }
+      Expression::Yield(expression) => {
+        self.write(if expression.delegate {
+          "yield*"
+        } else {
+          "yield"
+        });
+        if let Some(argument) = &expression.argument {
+          self.space();
+          self.print_expression(argument, Precedence::Assignment);
+        }
+      }
+    }
+  }
+
+  pub fn print_arguments(&mut self, arguments: &[Argument]) {
+    self.write("(");
+    self.with_in(|printer| {
+      for (index, argument) in arguments.iter().enumerate() {
+        if index > 0 {
+          printer.comma();
+        }
+
+        match argument {
+          Argument::Positional(expression) => {
+            printer.print_expression(expression, Precedence::Assignment)
+          }
+          Argument::Spread(spread) => {
+            printer.mark(spread.span);
+            printer.write("...");
+            printer.print_expression(&spread.argument, Precedence::Assignment);
+          }
+        }
+      }
+    });
+    self.write(")");
+  }
+}
+
+fn is_in_operator(expression: &Expression) -> bool {
+  matches!(expression, Expression::In(_))
+}
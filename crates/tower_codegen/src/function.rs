@@ -0,0 +1,186 @@
+use tower_ast::{
+  binding::{BindingPattern, BindingPatternInitializer},
+  function::{ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition},
+  object::PropertyName,
+  statement::Statement,
+  Identifier,
+};
+
+use crate::{expression::Precedence, Printer};
+
+impl Printer {
+  pub fn print_function(&mut self, definition: &FunctionDefinition) {
+    if definition.r#async {
+      self.write("async");
+      self.space();
+    }
+
+    self.write("function");
+    if definition.generator {
+      self.write("*");
+    }
+
+    if let Some(identifier) = &definition.identifier {
+      self.space();
+      self.print_identifier(identifier);
+    }
+
+    self.print_formal_parameters(&definition.parameters);
+    self.space();
+    self.print_function_body(&definition.body.statements);
+  }
+
+  pub fn print_arrow_function(&mut self, definition: &ArrowFunctionDefinition) {
+    if definition.r#async {
+      self.write("async");
+      self.space();
+    }
+
+    self.print_formal_parameters(&definition.parameters);
+    self.space();
+    self.write("=>");
+    self.space();
+
+    match &definition.body {
+      ConciseBody::Block(block) => self.print_function_body(&block.statements),
+      ConciseBody::Expression(expression) => {
+        let arrow_body_start = self.arrow_body_start.replace(self.output.len());
+        self.print_expression(expression, Precedence::Assignment);
+        self.arrow_body_start = arrow_body_start;
+      }
+    }
+  }
+
+  pub fn print_formal_parameters(&mut self, parameters: &FormalParameters) {
+    self.write("(");
+    self.with_in(|printer| {
+      for (index, binding) in parameters.bindings.iter().enumerate() {
+        if index > 0 {
+          printer.comma();
+        }
+
+        printer.print_binding_initializer(binding);
+      }
+
+      if let Some(rest) = &parameters.rest {
+        if !parameters.bindings.is_empty() {
+          printer.comma();
+        }
+
+        printer.mark(rest.span);
+        printer.write("...");
+        printer.print_binding_pattern(&rest.pattern);
+      }
+    });
+    self.write(")");
+  }
+
+  pub fn print_identifier(&mut self, identifier: &Identifier) {
+    self.mark_name(identifier.span, identifier.name);
+    self.write(identifier.name);
+  }
+
+  pub fn print_function_body(&mut self, statements: &[Statement]) {
+    let statement_start = self.statement_start.take();
+    let arrow_body_start = self.arrow_body_start.take();
+    let no_in = std::mem::replace(&mut self.no_in, false);
+
+    self.print_block(statements);
+
+    self.statement_start = statement_start;
+    self.arrow_body_start = arrow_body_start;
+    self.no_in = no_in;
+  }
+
+  pub fn print_binding_initializer(&mut self, binding: &BindingPatternInitializer) {
+    self.print_binding_pattern(&binding.pattern);
+
+    if let Some(initializer) = &binding.initializer {
+      self.space();
+      self.write("=");
+      self.space();
+      self.print_expression(initializer, Precedence::Assignment);
+    }
+  }
+
+  pub fn print_binding_pattern(&mut self, pattern: &BindingPattern) {
+    match pattern {
+      BindingPattern::Array(pattern) => {
+        self.write("[");
+        self.with_in(|printer| {
+          for (index, element) in pattern.elements.iter().enumerate() {
+            if index > 0 {
+              printer.comma();
+            }
+
+            if let Some(element) = element {
+              printer.print_binding_initializer(element);
+            }
+          }
+
+          match &pattern.rest {
+            Some(rest) => {
+              if !pattern.elements.is_empty() {
+                printer.comma();
+              }
+
+              printer.mark(rest.span);
+              printer.write("...");
+              printer.print_binding_pattern(&rest.pattern);
+            }
+            None => {
+              if let Some(None) = pattern.elements.last() {
+                printer.write(",");
+              }
+            }
+          }
+        });
+        self.write("]");
+      }
+      BindingPattern::Identifier(identifier) => self.print_identifier(identifier),
+      BindingPattern::Object(pattern) => {
+        if pattern.properties.is_empty() && pattern.rest.is_none() {
+          self.write("{}");
+          return;
+        }
+
+        self.write("{");
+        self.space();
+        self.with_in(|printer| {
+          for (index, property) in pattern.properties.iter().enumerate() {
+            if index > 0 {
+              printer.comma();
+            }
+
+            let shorthand = matches!(
+              (&property.property, &property.binding.pattern),
+              (PropertyName::Static(key), BindingPattern::Identifier(identifier))
+                if key.name == identifier.name
+            );
+
+            if shorthand {
+              printer.print_binding_initializer(&property.binding);
+            } else {
+              printer.print_property_name(&property.property);
+              printer.write(":");
+              printer.space();
+              printer.print_binding_initializer(&property.binding);
+            }
+          }
+
+          if let Some(rest) = &pattern.rest {
+            if !pattern.properties.is_empty() {
+              printer.comma();
+            }
+
+            printer.mark(rest.span);
+            printer.write("...");
+            printer.print_binding_pattern(&rest.pattern);
+          }
+        });
+        self.space();
+        self.write("}");
+      }
+    }
+  }
+}
@@ -0,0 +1,304 @@
+use std::fmt::Write;
+
+use tower_parser::number::write_number;
+
+// just enough json for source maps and ESTree trees
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+  Array(Vec<JsonValue>),
+  Boolean(bool),
+  Null,
+  Number(f64),
+  Object(Vec<(String, JsonValue)>),
+  String(String),
+}
+
+impl JsonValue {
+  pub fn get(&self, key: &str) -> Option<&JsonValue> {
+    match self {
+      JsonValue::Object(members) => members
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      JsonValue::String(string) => Some(string),
+      _ => None,
+    }
+  }
+
+  pub fn as_array(&self) -> Option<&[JsonValue]> {
+    match self {
+      JsonValue::Array(values) => Some(values),
+      _ => None,
+    }
+  }
+}
+
+pub fn parse_json(text: &str) -> Result<JsonValue, String> {
+  let mut parser = JsonParser {
+    chars: text.chars().collect(),
+    position: 0,
+  };
+
+  let value = parser.read_value()?;
+  parser.skip_whitespace();
+
+  match parser.position == parser.chars.len() {
+    true => Ok(value),
+    false => Err(parser.error("unexpected text after the value")),
+  }
+}
+
+struct JsonParser {
+  chars: Vec<char>,
+  position: usize,
+}
+
+impl JsonParser {
+  fn error(&self, message: &str) -> String {
+    format!("{message} at {}", self.position)
+  }
+
+  fn skip_whitespace(&mut self) {
+    while let Some(' ' | '\t' | '\n' | '\r') = self.chars.get(self.position) {
+      self.position += 1;
+    }
+  }
+
+  fn expect(&mut self, c: char) -> Result<(), String> {
+    self.skip_whitespace();
+    match self.chars.get(self.position) {
+      Some(found) if *found == c => {
+        self.position += 1;
+        Ok(())
+      }
+      _ => Err(self.error(&format!("expected `{c}`"))),
+    }
+  }
+
+  fn read_keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, String> {
+    for c in keyword.chars() {
+      if self.chars.get(self.position) != Some(&c) {
+        return Err(self.error("unexpected character"));
+      }
+
+      self.position += 1;
+    }
+
+    Ok(value)
+  }
+
+  fn read_value(&mut self) -> Result<JsonValue, String> {
+    self.skip_whitespace();
+
+    match self.chars.get(self.position) {
+      Some('{') => {
+        self.position += 1;
+        let mut members = Vec::new();
+
+        self.skip_whitespace();
+        if let Some('}') = self.chars.get(self.position) {
+          self.position += 1;
+          return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+          self.skip_whitespace();
+          let name = self.read_string()?;
+          self.expect(':')?;
+          members.push((name, self.read_value()?));
+
+          self.skip_whitespace();
+          match self.chars.get(self.position) {
+            Some(',') => self.position += 1,
+            Some('}') => {
+              self.position += 1;
+              break Ok(JsonValue::Object(members));
+            }
+            _ => break Err(self.error("expected `,` or `}`")),
+          }
+        }
+      }
+      Some('[') => {
+        self.position += 1;
+        let mut values = Vec::new();
+
+        self.skip_whitespace();
+        if let Some(']') = self.chars.get(self.position) {
+          self.position += 1;
+          return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+          values.push(self.read_value()?);
+
+          self.skip_whitespace();
+          match self.chars.get(self.position) {
+            Some(',') => self.position += 1,
+            Some(']') => {
+              self.position += 1;
+              break Ok(JsonValue::Array(values));
+            }
+            _ => break Err(self.error("expected `,` or `]`")),
+          }
+        }
+      }
+      Some('"') => Ok(JsonValue::String(self.read_string()?)),
+      Some('t') => self.read_keyword("true", JsonValue::Boolean(true)),
+      Some('f') => self.read_keyword("false", JsonValue::Boolean(false)),
+      Some('n') => self.read_keyword("null", JsonValue::Null),
+      Some('-' | '0'..='9') => {
+        let start = self.position;
+        while let Some('-' | '+' | '.' | 'e' | 'E' | '0'..='9') = self.chars.get(self.position) {
+          self.position += 1;
+        }
+
+        let text = self.chars[start..self.position].iter().collect::<String>();
+        match text.parse::<f64>() {
+          Ok(number) => Ok(JsonValue::Number(number)),
+          Err(_) => Err(self.error("invalid number")),
+        }
+      }
+      _ => Err(self.error("expected a value")),
+    }
+  }
+
+  fn read_string(&mut self) -> Result<String, String> {
+    if self.chars.get(self.position) != Some(&'"') {
+      return Err(self.error("expected a string"));
+    }
+
+    self.position += 1;
+    let mut units = Vec::<u16>::new();
+
+    loop {
+      let c = *self
+        .chars
+        .get(self.position)
+        .ok_or(self.error("unterminated string"))?;
+      self.position += 1;
+
+      match c {
+        '"' => break,
+        '\\' => {
+          let escape = *self
+            .chars
+            .get(self.position)
+            .ok_or(self.error("unterminated string"))?;
+          self.position += 1;
+
+          match escape {
+            '"' | '\\' | '/' => units.push(escape as u16),
+            'b' => units.push(0x08),
+            'f' => units.push(0x0c),
+            'n' => units.push(0x0a),
+            'r' => units.push(0x0d),
+            't' => units.push(0x09),
+            'u' => {
+              let digits = self
+                .chars
+                .get(self.position..self.position + 4)
+                .ok_or(self.error("invalid escape"))?
+                .iter()
+                .collect::<String>();
+              let unit =
+                u16::from_str_radix(&digits, 16).map_err(|_| self.error("invalid escape"))?;
+              self.position += 4;
+              units.push(unit);
+            }
+            _ => return Err(self.error("invalid escape")),
+          }
+        }
+        c => {
+          let mut buffer = [0; 2];
+          units.extend_from_slice(c.encode_utf16(&mut buffer));
+        }
+      }
+    }
+
+    // lone surrogates from `\u` escapes can't be kept in a rust string
+    Ok(String::from_utf16_lossy(&units))
+  }
+}
+
+pub fn write_json_string(output: &mut String, string: &str) {
+  output.push('"');
+
+  for c in string.chars() {
+    match c {
+      '"' => output.push_str("\\\""),
+      '\\' => output.push_str("\\\\"),
+      '\n' => output.push_str("\\n"),
+      '\r' => output.push_str("\\r"),
+      '\t' => output.push_str("\\t"),
+      '\u{0}'..='\u{1f}' | '\u{2028}' | '\u{2029}' => {
+        write!(output, "\\u{:04x}", c as u32).unwrap();
+      }
+      c => output.push(c),
+    }
+  }
+
+  output.push('"');
+}
+
+// the same text `JSON.stringify` gives, indented by two spaces when `pretty` is set
+pub fn stringify(value: &JsonValue, pretty: bool) -> String {
+  let mut output = String::new();
+  write_json(&mut output, value, pretty, 0);
+  output
+}
+
+fn write_json(output: &mut String, value: &JsonValue, pretty: bool, depth: usize) {
+  let newline = |output: &mut String, depth: usize| {
+    if pretty {
+      output.push('\n');
+      for _ in 0..depth {
+        output.push_str("  ");
+      }
+    }
+  };
+
+  match value {
+    JsonValue::Array(values) if values.is_empty() => output.push_str("[]"),
+    JsonValue::Array(values) => {
+      output.push('[');
+      for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+        newline(output, depth + 1);
+        write_json(output, value, pretty, depth + 1);
+      }
+      newline(output, depth);
+      output.push(']');
+    }
+    JsonValue::Boolean(value) => output.push_str(if *value { "true" } else { "false" }),
+    JsonValue::Null => output.push_str("null"),
+    JsonValue::Number(value) if value.is_finite() => write_number(*value, 10, output).unwrap(),
+    JsonValue::Number(_) => output.push_str("null"),
+    JsonValue::Object(members) if members.is_empty() => output.push_str("{}"),
+    JsonValue::Object(members) => {
+      output.push('{');
+      for (index, (name, value)) in members.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+        newline(output, depth + 1);
+        write_json_string(output, name);
+        output.push(':');
+        if pretty {
+          output.push(' ');
+        }
+        write_json(output, value, pretty, depth + 1);
+      }
+      newline(output, depth);
+      output.push('}');
+    }
+    JsonValue::String(string) => write_json_string(output, string),
+  }
+}
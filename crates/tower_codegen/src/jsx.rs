@@ -0,0 +1,133 @@
+use tower_ast::jsx::{
+  JsxAttribute, JsxAttributeName, JsxAttributeValue, JsxChild, JsxElement, JsxElementName,
+  JsxExpressionContainer, JsxFragment,
+};
+
+use crate::{expression::Precedence, Printer};
+
+impl Printer {
+  pub fn print_jsx_element(&mut self, element: &JsxElement) {
+    self.write("<");
+    self.print_jsx_element_name(&element.name);
+
+    for attribute in element.attributes.iter() {
+      self.output.push(' ');
+      match attribute {
+        JsxAttribute::Attribute(attribute) => {
+          match &attribute.name {
+            JsxAttributeName::Identifier(name) => self.write(name.name),
+            JsxAttributeName::Namespaced(name) => {
+              self.write(name.namespace.name);
+              self.write(":");
+              self.write(name.name.name);
+            }
+          }
+
+          if let Some(value) = &attribute.value {
+            self.write("=");
+            self.print_jsx_attribute_value(value);
+          }
+        }
+        JsxAttribute::Spread(container) => {
+          self.write("{...");
+          self.print_jsx_expression(container);
+          self.write("}");
+        }
+      }
+    }
+
+    if element.self_closing {
+      self.write("/>");
+      return;
+    }
+
+    self.write(">");
+    self.print_jsx_children(&element.children);
+    self.write("</");
+    self.print_jsx_element_name(&element.name);
+    self.write(">");
+  }
+
+  pub fn print_jsx_fragment(&mut self, fragment: &JsxFragment) {
+    self.write("<>");
+    self.print_jsx_children(&fragment.children);
+    self.write("</>");
+  }
+
+  fn print_jsx_element_name(&mut self, name: &JsxElementName) {
+    match name {
+      JsxElementName::Identifier(identifier) => self.print_identifier(identifier),
+      JsxElementName::Member(member) => {
+        self.print_jsx_element_name(&member.object);
+        self.write(".");
+        self.write(member.property.name);
+      }
+      JsxElementName::Namespaced(name) => {
+        self.write(name.namespace.name);
+        self.write(":");
+        self.write(name.name.name);
+      }
+    }
+  }
+
+  fn print_jsx_attribute_value(&mut self, value: &JsxAttributeValue) {
+    match value {
+      JsxAttributeValue::Element(element) => self.print_jsx_element(element),
+      JsxAttributeValue::Expression(container) => {
+        self.write("{");
+        self.print_jsx_expression(container);
+        self.write("}");
+      }
+      JsxAttributeValue::Fragment(fragment) => self.print_jsx_fragment(fragment),
+      JsxAttributeValue::String(literal) => {
+        let value = literal.value.to_string_lossy();
+        // attribute strings have no escapes, only character references
+        let quote = if value.contains('"') && !value.contains('\'') {
+          '\''
+        } else {
+          '"'
+        };
+
+        self.output.push(quote);
+        for c in value.chars() {
+          match c {
+            '&' => self.output.push_str("&amp;"),
+            '"' if quote == '"' => self.output.push_str("&quot;"),
+            c => self.output.push(c),
+          }
+        }
+        self.output.push(quote);
+      }
+    }
+  }
+
+  // text is written as it was read, it can't be escaped any other way
+  fn print_jsx_children(&mut self, children: &[JsxChild]) {
+    for child in children {
+      match child {
+        JsxChild::Element(element) => self.print_jsx_element(element),
+        JsxChild::Empty(_) => self.output.push_str("{}"),
+        JsxChild::Expression(container) => {
+          self.output.push('{');
+          self.print_jsx_expression(container);
+          self.output.push('}');
+        }
+        JsxChild::Fragment(fragment) => self.print_jsx_fragment(fragment),
+        JsxChild::Spread(container) => {
+          self.output.push_str("{...");
+          self.print_jsx_expression(container);
+          self.output.push('}');
+        }
+        JsxChild::Text(text) => self.output.push_str(text.raw),
+      }
+    }
+  }
+
+  fn print_jsx_expression(&mut self, container: &JsxExpressionContainer) {
+    let statement_start = self.statement_start.take();
+    let arrow_body_start = self.arrow_body_start.take();
+    self.with_in(|printer| printer.print_expression(&container.expression, Precedence::Assignment));
+    self.statement_start = statement_start;
+    self.arrow_body_start = arrow_body_start;
+  }
+}
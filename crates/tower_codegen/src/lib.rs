@@ -0,0 +1,164 @@
+use source_map::{MappingRecorder, SourceFile, SourceMap};
+use tower_ast::{Program, Span};
+use tower_parser::parser::is_id_continue;
+
+mod class;
+pub mod estree;
+mod expression;
+mod function;
+pub mod json;
+mod jsx;
+mod literal;
+mod object;
+pub mod source_map;
+mod statement;
+
+pub fn print_program(program: &Program, options: PrintOptions) -> String {
+  let mut printer = Printer::new(options);
+  printer.print_statement_list(&program.statement_list);
+  printer.finish()
+}
+
+// also maps where each node was printed back to where it was in `source`, the file that was parsed
+// into `program`
+pub fn print_program_with_source_map(
+  program: &Program,
+  options: PrintOptions,
+  source: &SourceFile,
+) -> (String, SourceMap) {
+  let mut printer = Printer::new(options);
+  printer.mappings = Some(MappingRecorder::default());
+  printer.print_statement_list(&program.statement_list);
+
+  let recorder = printer.mappings.take().unwrap();
+  let output = printer.finish();
+  let map = recorder.finish(&output, source);
+  (output, map)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PrintOptions {
+  // leaves out every space and line break that isn't needed to separate tokens
+  pub compact: bool,
+}
+
+impl PrintOptions {
+  pub fn readable() -> Self {
+    Self { compact: false }
+  }
+
+  pub fn compact() -> Self {
+    Self { compact: true }
+  }
+}
+
+pub struct Printer {
+  output: String,
+  compact: bool,
+  indent: usize,
+  // output positions where an expression statement or a concise arrow body begins, an object
+  // literal there would be read as a block so it gets parenthesized
+  statement_start: Option<usize>,
+  arrow_body_start: Option<usize>,
+  // inside the head of a for statement an `in` operator would be read as a for-in
+  no_in: bool,
+  mappings: Option<MappingRecorder>,
+}
+
+impl Printer {
+  pub fn new(options: PrintOptions) -> Self {
+    Self {
+      output: String::new(),
+      compact: options.compact,
+      indent: 0,
+      statement_start: None,
+      arrow_body_start: None,
+      no_in: false,
+      mappings: None,
+    }
+  }
+
+  pub fn finish(mut self) -> String {
+    if !self.compact && !self.output.is_empty() {
+      self.output.push('\n');
+    }
+
+    self.output
+  }
+
+  // writes a token, with a space in front when it would otherwise run into the previous one
+  fn write(&mut self, text: &str) {
+    if let (Some(last), Some(first)) = (self.output.chars().next_back(), text.chars().next()) {
+      let separate = (is_word_char(last) && is_word_char(first))
+        || (last == '+' && first == '+')
+        || (last == '-' && first == '-')
+        || (last == '/' && first == '/')
+        || (last == '<' && first == '<')
+        || (self.output.ends_with("<!") && text.starts_with("--"));
+
+      if separate {
+        self.output.push(' ');
+      }
+    }
+
+    if let Some(mappings) = &mut self.mappings {
+      mappings.flush(self.output.len());
+    }
+
+    self.output.push_str(text);
+  }
+
+  // the next token written starts a node that was at `span` in the source
+  fn mark(&mut self, span: Span) {
+    if let Some(mappings) = &mut self.mappings {
+      mappings.mark(span.start as usize, None);
+    }
+  }
+
+  // the same for identifiers, which also go in the names of the source map
+  fn mark_name(&mut self, span: Span, name: &str) {
+    if let Some(mappings) = &mut self.mappings {
+      mappings.mark(span.start as usize, Some(name));
+    }
+  }
+
+  fn space(&mut self) {
+    if !self.compact {
+      self.output.push(' ');
+    }
+  }
+
+  fn newline(&mut self) {
+    if !self.compact {
+      self.output.push('\n');
+      for _ in 0..self.indent {
+        self.output.push_str("  ");
+      }
+    }
+  }
+
+  fn comma(&mut self) {
+    self.write(",");
+    self.space();
+  }
+
+  fn is_statement_start(&self) -> bool {
+    self.statement_start == Some(self.output.len())
+  }
+
+  fn is_arrow_body_start(&self) -> bool {
+    self.arrow_body_start == Some(self.output.len())
+  }
+
+  // runs the printer for something between brackets, where `in` is allowed again
+  fn with_in(&mut self, print: impl FnOnce(&mut Self)) {
+    let no_in = self.no_in;
+    self.no_in = false;
+    print(self);
+    self.no_in = no_in;
+  }
+}
+
+fn is_word_char(c: char) -> bool {
+  c == '\\' || is_id_continue(c)
+}
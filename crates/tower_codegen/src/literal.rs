@@ -0,0 +1,178 @@
+use std::char::decode_utf16;
+
+use tower_ast::string::JsStr;
+use tower_parser::{
+  number::es_number_to_string,
+  parser::{is_id_continue, is_id_start},
+};
+
+use crate::Printer;
+
+impl Printer {
+  // picks whichever quote needs fewer escapes, double quotes on a tie
+  pub fn print_string_literal(&mut self, value: JsStr) {
+    let doubles = value
+      .code_units()
+      .filter(|unit| *unit == b'"' as u16)
+      .count();
+    let singles = value
+      .code_units()
+      .filter(|unit| *unit == b'\'' as u16)
+      .count();
+    let quote = if singles < doubles { '\'' } else { '"' };
+
+    let mut text = String::with_capacity(value.len() + 2);
+    text.push(quote);
+
+    let mut chars = decode_utf16(value.code_units()).peekable();
+    while let Some(c) = chars.next() {
+      let c = match c {
+        Ok(c) => c,
+        // a lone surrogate only has an escape to stand for it
+        Err(error) => {
+          text.push_str(&format!("\\u{:04X}", error.unpaired_surrogate()));
+          continue;
+        }
+      };
+
+      match c {
+        '\\' => text.push_str("\\\\"),
+        '\n' => text.push_str("\\n"),
+        '\r' => text.push_str("\\r"),
+        '\t' => text.push_str("\\t"),
+        '\u{2028}' => text.push_str("\\u2028"),
+        '\u{2029}' => text.push_str("\\u2029"),
+        // `\0` followed by a digit would be read as a legacy octal escape
+        '\0' if !matches!(chars.peek(), Some(Ok('0'..='9'))) => text.push_str("\\0"),
+        c if c == quote => {
+          text.push('\\');
+          text.push(c);
+        }
+        c if (c as u32) < 0x20 || c == '\u{7F}' => {
+          text.push_str(&format!("\\x{:02X}", c as u32));
+        }
+        c => text.push(c),
+      }
+    }
+
+    text.push(quote);
+    self.write(&text);
+  }
+
+  // the cooked value of a template chunk, escaped so it reads back the same
+  pub fn print_template_chunk(&mut self, value: JsStr) {
+    let mut chars = decode_utf16(value.code_units()).peekable();
+    while let Some(c) = chars.next() {
+      match c {
+        Ok('\\') => self.output.push_str("\\\\"),
+        Ok('`') => self.output.push_str("\\`"),
+        Ok('\r') => self.output.push_str("\\r"),
+        Ok('$') if matches!(chars.peek(), Some(Ok('{'))) => self.output.push_str("\\$"),
+        Ok(c) => self.output.push(c),
+        Err(error) => {
+          let escape = format!("\\u{:04X}", error.unpaired_surrogate());
+          self.output.push_str(&escape);
+        }
+      }
+    }
+  }
+
+  pub fn print_regexp_literal(&mut self, source: &str, flags: &str) {
+    let mut text = String::from("/");
+
+    if source.is_empty() {
+      // an empty pattern would start a line comment
+      text.push_str("(?:)");
+    } else {
+      let mut chars = source.chars();
+      let mut in_class = false;
+
+      while let Some(c) = chars.next() {
+        match c {
+          '\\' => {
+            text.push('\\');
+            match chars.next() {
+              Some(c) => text.push(c),
+              None => text.push('\\'),
+            }
+          }
+          '[' => {
+            in_class = true;
+            text.push(c);
+          }
+          ']' => {
+            in_class = false;
+            text.push(c);
+          }
+          '/' if !in_class => text.push_str("\\/"),
+          '\n' => text.push_str("\\n"),
+          '\r' => text.push_str("\\r"),
+          '\u{2028}' => text.push_str("\\u2028"),
+          '\u{2029}' => text.push_str("\\u2029"),
+          c => text.push(c),
+        }
+      }
+    }
+
+    text.push('/');
+    text.push_str(flags);
+    self.write(&text);
+  }
+
+  // negative and non-finite numbers only come out of transforms, they're printed as the unary and
+  // division expressions that evaluate to them
+  pub fn print_number_literal(&mut self, value: f64) {
+    if value.is_nan() {
+      self.write("0");
+      self.space();
+      self.write("/");
+      self.space();
+      self.write("0");
+      return;
+    }
+
+    if value.is_sign_negative() {
+      self.write("-");
+    }
+
+    if value.is_infinite() {
+      self.write("1");
+      self.space();
+      self.write("/");
+      self.space();
+      self.write("0");
+    } else {
+      self.write(&es_number_to_string(value.abs(), 10));
+    }
+  }
+
+  // object and class keys keep the shortest form that names the same property
+  pub fn print_property_key(&mut self, name: JsStr) {
+    match name.as_str() {
+      Some(text) if is_identifier_name(text) || is_canonical_number(text) => self.write(text),
+      _ => self.print_string_literal(name),
+    }
+  }
+}
+
+pub fn is_identifier_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  match chars.next() {
+    Some(c) if is_id_start(c) => {
+      chars.all(|c| is_id_continue(c) || c == '\u{200C}' || c == '\u{200D}')
+    }
+    _ => false,
+  }
+}
+
+// a key like `1` or `1.5` that prints back the same as the number it reads as
+fn is_canonical_number(name: &str) -> bool {
+  if !name.starts_with(|c: char| c.is_ascii_digit()) {
+    return false;
+  }
+
+  match name.parse::<f64>() {
+    Ok(value) if value.is_finite() => es_number_to_string(value, 10) == name,
+    _ => false,
+  }
+}
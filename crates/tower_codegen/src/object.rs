@@ -0,0 +1,91 @@
+use tower_ast::object::{ObjectProperty, PropertyName};
+
+use crate::{expression::Precedence, Printer};
+
+impl Printer {
+  pub fn print_object_literal(&mut self, properties: &[ObjectProperty]) {
+    if properties.is_empty() {
+      self.write("{}");
+      return;
+    }
+
+    self.write("{");
+    self.space();
+    self.with_in(|printer| {
+      for (index, property) in properties.iter().enumerate() {
+        if index > 0 {
+          printer.comma();
+        }
+
+        printer.print_object_property(property);
+      }
+    });
+    self.space();
+    self.write("}");
+  }
+
+  fn print_object_property(&mut self, property: &ObjectProperty) {
+    self.mark(property.span());
+
+    match property {
+      ObjectProperty::Getter(getter) => {
+        self.write("get");
+        self.space();
+        self.print_property_name(&getter.property);
+        self.write("()");
+        self.space();
+        self.print_function_body(&getter.body.statements);
+      }
+      ObjectProperty::Method(method) => {
+        if method.r#async {
+          self.write("async");
+          self.space();
+        }
+
+        if method.generator {
+          self.write("*");
+        }
+
+        self.print_property_name(&method.property);
+        self.print_formal_parameters(&method.parameters);
+        self.space();
+        self.print_function_body(&method.body.statements);
+      }
+      ObjectProperty::Property(definition) => {
+        self.print_property_name(&definition.property);
+        self.write(":");
+        self.space();
+        self.print_expression(&definition.expression, Precedence::Assignment);
+      }
+      ObjectProperty::Setter(setter) => {
+        self.write("set");
+        self.space();
+        self.print_property_name(&setter.property);
+        self.write("(");
+        self.print_binding_initializer(&setter.parameter);
+        self.write(")");
+        self.space();
+        self.print_function_body(&setter.body.statements);
+      }
+      ObjectProperty::Shorthand(identifier) => self.print_identifier(identifier),
+      ObjectProperty::Spread(spread) => {
+        self.write("...");
+        self.print_expression(&spread.argument, Precedence::Assignment);
+      }
+    }
+  }
+
+  pub fn print_property_name(&mut self, property: &PropertyName) {
+    match property {
+      PropertyName::Computed(expression) => {
+        self.write("[");
+        self.with_in(|printer| printer.print_expression(expression, Precedence::Assignment));
+        self.write("]");
+      }
+      PropertyName::Static(name) => {
+        self.mark(name.span);
+        self.print_property_key(name.name);
+      }
+    }
+  }
+}
@@ -0,0 +1,552 @@
+use std::collections::HashMap;
+
+use crate::json::{parse_json, write_json_string, JsonValue};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// a version 3 source map, lines and columns start at 0 and columns count utf-16 code units
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceMap {
+  pub file: Option<String>,
+  pub source_root: Option<String>,
+  pub sources: Vec<String>,
+  pub sources_content: Vec<Option<String>>,
+  pub names: Vec<String>,
+  // the mappings of each generated line, sorted by column
+  pub lines: Vec<Vec<Mapping>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+  pub generated_column: u32,
+  // the segments without one mark generated code that comes from nowhere
+  pub original: Option<OriginalPosition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalPosition {
+  pub source: u32,
+  pub line: u32,
+  pub column: u32,
+  pub name: Option<u32>,
+}
+
+impl SourceMap {
+  pub fn from_json(text: &str) -> Result<SourceMap, String> {
+    let json = parse_json(text)?;
+
+    if json.get("sections").is_some() {
+      return Err("index source maps aren't supported".to_string());
+    }
+
+    match json.get("version") {
+      Some(JsonValue::Number(version)) if *version == 3.0 => {}
+      _ => return Err("not a version 3 source map".to_string()),
+    }
+
+    let strings = |key: &str| -> Result<Vec<Option<String>>, String> {
+      match json.get(key) {
+        None => Ok(Vec::new()),
+        Some(value) => value
+          .as_array()
+          .ok_or(format!("`{key}` isn't an array"))?
+          .iter()
+          .map(|value| match value {
+            JsonValue::Null => Ok(None),
+            JsonValue::String(string) => Ok(Some(string.clone())),
+            _ => Err(format!("`{key}` should only have strings")),
+          })
+          .collect(),
+      }
+    };
+
+    let sources = strings("sources")?
+      .into_iter()
+      .map(Option::unwrap_or_default)
+      .collect::<Vec<_>>();
+    let names = strings("names")?
+      .into_iter()
+      .map(Option::unwrap_or_default)
+      .collect::<Vec<_>>();
+
+    let mut sources_content = strings("sourcesContent")?;
+    sources_content.resize(sources.len(), None);
+
+    let mappings = json
+      .get("mappings")
+      .and_then(JsonValue::as_str)
+      .ok_or("`mappings` is missing")?;
+    let lines = decode_mappings(mappings, sources.len(), names.len())?;
+
+    Ok(SourceMap {
+      file: json
+        .get("file")
+        .and_then(JsonValue::as_str)
+        .map(String::from),
+      source_root: json
+        .get("sourceRoot")
+        .and_then(JsonValue::as_str)
+        .map(String::from),
+      sources,
+      sources_content,
+      names,
+      lines,
+    })
+  }
+
+  pub fn to_json(&self) -> String {
+    let mut output = String::from("{\"version\":3");
+
+    if let Some(file) = &self.file {
+      output.push_str(",\"file\":");
+      write_json_string(&mut output, file);
+    }
+
+    if let Some(source_root) = &self.source_root {
+      output.push_str(",\"sourceRoot\":");
+      write_json_string(&mut output, source_root);
+    }
+
+    output.push_str(",\"sources\":[");
+    for (index, source) in self.sources.iter().enumerate() {
+      if index > 0 {
+        output.push(',');
+      }
+      write_json_string(&mut output, source);
+    }
+    output.push(']');
+
+    if self.sources_content.iter().any(Option::is_some) {
+      output.push_str(",\"sourcesContent\":[");
+      for (index, content) in self.sources_content.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+        match content {
+          Some(content) => write_json_string(&mut output, content),
+          None => output.push_str("null"),
+        }
+      }
+      output.push(']');
+    }
+
+    output.push_str(",\"names\":[");
+    for (index, name) in self.names.iter().enumerate() {
+      if index > 0 {
+        output.push(',');
+      }
+      write_json_string(&mut output, name);
+    }
+    output.push(']');
+
+    output.push_str(",\"mappings\":");
+    write_json_string(&mut output, &self.encode_mappings());
+    output.push('}');
+    output
+  }
+
+  pub fn encode_mappings(&self) -> String {
+    let mut output = String::new();
+    // everything but the generated column is relative to the previous segment of the whole map
+    let mut previous = OriginalPosition {
+      source: 0,
+      line: 0,
+      column: 0,
+      name: Some(0),
+    };
+
+    for (index, line) in self.lines.iter().enumerate() {
+      if index > 0 {
+        output.push(';');
+      }
+
+      let mut previous_column = 0;
+      for (index, mapping) in line.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+
+        write_vlq(
+          &mut output,
+          delta(mapping.generated_column, previous_column),
+        );
+        previous_column = mapping.generated_column;
+
+        let Some(original) = mapping.original else {
+          continue;
+        };
+
+        write_vlq(&mut output, delta(original.source, previous.source));
+        write_vlq(&mut output, delta(original.line, previous.line));
+        write_vlq(&mut output, delta(original.column, previous.column));
+
+        if let Some(name) = original.name {
+          write_vlq(&mut output, delta(name, previous.name.unwrap_or(0)));
+          previous.name = Some(name);
+        }
+
+        previous = OriginalPosition {
+          name: previous.name,
+          ..original
+        };
+      }
+    }
+
+    output
+  }
+
+  // the original position of the closest mapping at or before a generated position
+  pub fn lookup(&self, line: u32, column: u32) -> Option<OriginalPosition> {
+    let mappings = self.lines.get(line as usize)?;
+    let index = mappings.partition_point(|mapping| mapping.generated_column <= column);
+    mappings.get(index.checked_sub(1)?)?.original
+  }
+
+  // maps the original positions of this map through `input`, the map of the file that was
+  // transformed into this map's source, so they point to where that file came from
+  pub fn compose(&self, input: &SourceMap) -> SourceMap {
+    let mut result = SourceMap {
+      file: self.file.clone(),
+      source_root: input.source_root.clone(),
+      ..SourceMap::default()
+    };
+
+    let mut sources = HashMap::<u32, u32>::new();
+    let mut names = HashMap::<String, u32>::new();
+
+    for line in &self.lines {
+      let mut mappings = Vec::with_capacity(line.len());
+
+      for mapping in line {
+        let traced = mapping.original.and_then(|original| {
+          let traced = input.lookup(original.line, original.column)?;
+
+          // only segments that are names keep one, the name of the earlier file is the one that
+          // was written by hand
+          let name = original
+            .name
+            .and_then(|name| self.names.get(name as usize))
+            .map(|name| {
+              traced
+                .name
+                .and_then(|name| input.names.get(name as usize))
+                .unwrap_or(name)
+            })
+            .map(|name| {
+              *names.entry(name.clone()).or_insert_with(|| {
+                result.names.push(name.clone());
+                result.names.len() as u32 - 1
+              })
+            });
+
+          let source = *sources.entry(traced.source).or_insert_with(|| {
+            let index = traced.source as usize;
+            result
+              .sources
+              .push(input.sources.get(index).cloned().unwrap_or_default());
+            result
+              .sources_content
+              .push(input.sources_content.get(index).cloned().flatten());
+            result.sources.len() as u32 - 1
+          });
+
+          Some(OriginalPosition {
+            source,
+            name,
+            ..traced
+          })
+        });
+
+        mappings.push(Mapping {
+          generated_column: mapping.generated_column,
+          original: traced,
+        });
+      }
+
+      result.lines.push(mappings);
+    }
+
+    result
+  }
+}
+
+fn delta(value: u32, previous: u32) -> i64 {
+  value as i64 - previous as i64
+}
+
+pub fn write_vlq(output: &mut String, value: i64) {
+  // the sign goes in the lowest bit, then 5 bits per digit with a continuation bit
+  let mut vlq = match value < 0 {
+    true => (value.unsigned_abs() << 1) | 1,
+    false => (value as u64) << 1,
+  };
+
+  loop {
+    let mut digit = vlq & 0b11111;
+    vlq >>= 5;
+    if vlq > 0 {
+      digit |= 0b100000;
+    }
+
+    output.push(BASE64[digit as usize] as char);
+
+    if vlq == 0 {
+      break;
+    }
+  }
+}
+
+pub fn read_vlq(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<i64, String> {
+  let mut vlq = 0u64;
+  let mut shift = 0;
+
+  loop {
+    let c = chars.next().ok_or("unterminated vlq")?;
+    let digit = BASE64
+      .iter()
+      .position(|byte| *byte as char == c)
+      .ok_or(format!("`{c}` isn't a base64 digit"))? as u64;
+
+    if shift > 60 {
+      return Err("vlq value out of range".to_string());
+    }
+
+    vlq |= (digit & 0b11111) << shift;
+    shift += 5;
+
+    if digit & 0b100000 == 0 {
+      break;
+    }
+  }
+
+  let value = (vlq >> 1) as i64;
+  Ok(if vlq & 1 == 1 { -value } else { value })
+}
+
+pub fn decode_mappings(
+  text: &str,
+  sources: usize,
+  names: usize,
+) -> Result<Vec<Vec<Mapping>>, String> {
+  let mut lines = vec![Vec::new()];
+  let mut chars = text.chars().peekable();
+  let mut column = 0i64;
+  let mut source = 0i64;
+  let mut line = 0i64;
+  let mut original_column = 0i64;
+  let mut name = 0i64;
+
+  let as_u32 = |value: i64| u32::try_from(value).map_err(|_| "negative position in mappings");
+
+  while let Some(c) = chars.peek() {
+    match c {
+      ';' => {
+        chars.next();
+        lines.push(Vec::new());
+        column = 0;
+        continue;
+      }
+      ',' => {
+        chars.next();
+        continue;
+      }
+      _ => {}
+    }
+
+    column += read_vlq(&mut chars)?;
+    let mut fields = Vec::new();
+    while !matches!(chars.peek(), None | Some(',' | ';')) {
+      fields.push(read_vlq(&mut chars)?);
+    }
+
+    let original = match fields.len() {
+      0 => None,
+      3 | 4 => {
+        source += fields[0];
+        line += fields[1];
+        original_column += fields[2];
+
+        if as_u32(source)? as usize >= sources {
+          return Err(format!("source {source} is out of range"));
+        }
+
+        let name = match fields.get(3) {
+          Some(delta) => {
+            name += delta;
+            if as_u32(name)? as usize >= names {
+              return Err(format!("name {name} is out of range"));
+            }
+            Some(as_u32(name)?)
+          }
+          None => None,
+        };
+
+        Some(OriginalPosition {
+          source: as_u32(source)?,
+          line: as_u32(line)?,
+          column: as_u32(original_column)?,
+          name,
+        })
+      }
+      count => return Err(format!("a segment can't have {} fields", count + 1)),
+    };
+
+    lines.last_mut().unwrap().push(Mapping {
+      generated_column: as_u32(column)?,
+      original,
+    });
+  }
+
+  for line in &mut lines {
+    line.sort_by_key(|mapping| mapping.generated_column);
+  }
+
+  Ok(lines)
+}
+
+// the start of each line of a text, to turn char offsets into lines and utf-16 columns
+pub struct LineIndex<'s> {
+  text: &'s [char],
+  line_starts: Vec<usize>,
+}
+
+impl<'s> LineIndex<'s> {
+  pub fn new(text: &'s [char]) -> Self {
+    let mut line_starts = vec![0];
+
+    for (index, c) in text.iter().enumerate() {
+      match c {
+        '\r' if text.get(index + 1) == Some(&'\n') => {}
+        '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(index + 1),
+        _ => {}
+      }
+    }
+
+    Self { text, line_starts }
+  }
+
+  pub fn position(&self, offset: usize) -> (u32, u32) {
+    let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+    let start = self.line_starts[line];
+    let end = offset.min(self.text.len()).max(start);
+    let column = self.text[start..end]
+      .iter()
+      .map(|c| c.len_utf16())
+      .sum::<usize>();
+
+    (line as u32, column as u32)
+  }
+
+  // the char offset of a line and utf-16 column, the other way around
+  pub fn offset(&self, line: u32, column: u32) -> Option<usize> {
+    let mut offset = *self.line_starts.get(line as usize)?;
+    let mut remaining = column as usize;
+
+    while remaining > 0 {
+      remaining = remaining.checked_sub(self.text.get(offset)?.len_utf16())?;
+      offset += 1;
+    }
+
+    Some(offset)
+  }
+}
+
+// the nodes the printer wrote, with where they start in the output and in the source
+#[derive(Default)]
+pub(crate) struct MappingRecorder {
+  mappings: Vec<(usize, usize, Option<u32>)>,
+  names: Vec<String>,
+  name_indices: HashMap<String, u32>,
+  // a node starts with the next token that gets written
+  pending: Option<(usize, Option<u32>)>,
+}
+
+impl MappingRecorder {
+  pub fn mark(&mut self, source_offset: usize, name: Option<&str>) {
+    let name = name.map(|name| match self.name_indices.get(name) {
+      Some(index) => *index,
+      None => {
+        self.names.push(name.to_string());
+        let index = self.names.len() as u32 - 1;
+        self.name_indices.insert(name.to_string(), index);
+        index
+      }
+    });
+
+    self.pending = Some((source_offset, name));
+  }
+
+  pub fn flush(&mut self, output_offset: usize) {
+    if let Some((source_offset, name)) = self.pending.take() {
+      // a node that starts where another one did gives the more precise position
+      if let Some(last) = self
+        .mappings
+        .last_mut()
+        .filter(|last| last.0 == output_offset)
+      {
+        *last = (output_offset, source_offset, name);
+      } else {
+        self.mappings.push((output_offset, source_offset, name));
+      }
+    }
+  }
+
+  pub fn finish(self, output: &str, source: &SourceFile) -> SourceMap {
+    let source_lines = LineIndex::new(source.text);
+    let mut lines = vec![Vec::new()];
+    let mut mappings = self.mappings.iter().peekable();
+    let mut column = 0;
+    let mut chars = output.char_indices().peekable();
+
+    loop {
+      let offset = chars.peek().map_or(output.len(), |(offset, _)| *offset);
+
+      while let Some((_, source_offset, name)) =
+        mappings.next_if(|(output_offset, ..)| *output_offset <= offset)
+      {
+        let (line, original_column) = source_lines.position(*source_offset);
+        lines.last_mut().unwrap().push(Mapping {
+          generated_column: column,
+          original: Some(OriginalPosition {
+            source: 0,
+            line,
+            column: original_column,
+            name: *name,
+          }),
+        });
+      }
+
+      let Some((_, c)) = chars.next() else {
+        break;
+      };
+
+      match c {
+        '\r' if matches!(chars.peek(), Some((_, '\n'))) => column += 1,
+        '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+          lines.push(Vec::new());
+          column = 0;
+        }
+        c => column += c.len_utf16() as u32,
+      }
+    }
+
+    SourceMap {
+      file: source.output_name.clone(),
+      source_root: None,
+      sources: vec![source.name.clone()],
+      sources_content: vec![source.include_content.then(|| source.text.iter().collect())],
+      names: self.names,
+      lines,
+    }
+  }
+}
+
+// what a source map needs to know about the file that was parsed
+pub struct SourceFile<'s> {
+  pub name: String,
+  pub text: &'s [char],
+  // whether the text is embedded in the map as `sourcesContent`
+  pub include_content: bool,
+  // the name of the file the output is written to
+  pub output_name: Option<String>,
+}
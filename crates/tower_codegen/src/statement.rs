@@ -0,0 +1,369 @@
+use tower_ast::{
+  binding::BindingPatternInitializer,
+  expression::Expression,
+  statement::{
+    ForBinding, ForInitializer, IfStatement, JumpStatement, LexicalDeclaration, LexicalKind,
+    Statement, SwitchStatement, TryStatement,
+  },
+};
+
+use crate::{expression::Precedence, Printer};
+
+impl Printer {
+  pub fn print_statement_list(&mut self, statements: &[Statement]) {
+    for (index, statement) in statements.iter().enumerate() {
+      if index > 0 {
+        self.newline();
+      }
+
+      self.print_statement(statement);
+    }
+  }
+
+  pub fn print_block(&mut self, statements: &[Statement]) {
+    if statements.is_empty() {
+      self.write("{}");
+      return;
+    }
+
+    self.write("{");
+    self.indent += 1;
+    for statement in statements {
+      self.newline();
+      self.print_statement(statement);
+    }
+    self.indent -= 1;
+    self.newline();
+    self.write("}");
+  }
+
+  pub fn print_statement(&mut self, statement: &Statement) {
+    self.mark(statement.span());
+
+    match statement {
+      Statement::Block(block) => self.print_block(&block.statements),
+      Statement::Break(statement) => self.print_jump_statement("break", statement),
+      Statement::Class(definition) => self.print_class(definition),
+      Statement::Continue(statement) => self.print_jump_statement("continue", statement),
+      Statement::Debugger(_) => self.write("debugger;"),
+      Statement::DoWhile(statement) => {
+        self.write("do");
+        self.print_body(&statement.body);
+        self.space();
+        self.write("while");
+        self.space();
+        self.print_condition(&statement.condition);
+        self.write(";");
+      }
+      // `"use strict"` written with an escape is a directive that doesn't make the code strict, it
+      // has to keep an escape to stay that way
+      Statement::Expression(statement)
+        if matches!(statement.expression, Expression::String(literal)
+          if literal.value == "use strict" && literal.span.end - literal.span.start != 12) =>
+      {
+        self.write("\"use\\x20strict\";");
+      }
+      Statement::Expression(statement) => {
+        self.statement_start = Some(self.output.len());
+        self.print_expression(&statement.expression, Precedence::Comma);
+        self.statement_start = None;
+        self.write(";");
+      }
+      Statement::Empty(_) => self.write(";"),
+      Statement::For(statement) => {
+        self.write("for");
+        self.space();
+        self.write("(");
+
+        if let Some(initializer) = &statement.initializer {
+          self.no_in = true;
+          match initializer {
+            ForInitializer::Expression(expression) => {
+              self.statement_start = Some(self.output.len());
+              self.print_expression(expression, Precedence::Comma);
+              self.statement_start = None;
+            }
+            ForInitializer::Lexical(declaration) => self.print_lexical_declaration(declaration),
+            ForInitializer::Variable(declaration) => {
+              self.print_variable_declaration(&declaration.bindings)
+            }
+          }
+          self.no_in = false;
+        }
+
+        self.write(";");
+        if let Some(condition) = &statement.condition {
+          self.space();
+          self.print_expression(condition, Precedence::Comma);
+        }
+
+        self.write(";");
+        if let Some(update) = &statement.update {
+          self.space();
+          self.print_expression(update, Precedence::Comma);
+        }
+
+        self.write(")");
+        self.print_body(&statement.body);
+      }
+      Statement::ForIn(statement) => {
+        self.write("for");
+        self.space();
+        self.write("(");
+        self.print_for_binding(&statement.binding);
+        self.space();
+        self.write("in");
+        self.space();
+        self.print_expression(&statement.expression, Precedence::Comma);
+        self.write(")");
+        self.print_body(&statement.body);
+      }
+      Statement::ForOf(statement) => {
+        self.write("for");
+        if statement.r#await {
+          self.space();
+          self.write("await");
+        }
+        self.space();
+        self.write("(");
+        self.print_for_binding(&statement.binding);
+        self.space();
+        self.write("of");
+        self.space();
+        self.print_expression(&statement.expression, Precedence::Assignment);
+        self.write(")");
+        self.print_body(&statement.body);
+      }
+      Statement::Function(definition) => self.print_function(definition),
+      Statement::If(statement) => self.print_if_statement(statement),
+      Statement::Label(statement) => {
+        self.mark_name(statement.label.span, statement.label.name);
+        self.write(statement.label.name);
+        self.write(":");
+        self.space();
+        self.print_statement(&statement.statement);
+      }
+      Statement::Lexical(declaration) => {
+        self.print_lexical_declaration(declaration);
+        self.write(";");
+      }
+      Statement::Return(statement) => {
+        self.write("return");
+        if let Some(argument) = &statement.argument {
+          self.space();
+          self.print_expression(argument, Precedence::Comma);
+        }
+        self.write(";");
+      }
+      Statement::Switch(statement) => self.print_switch_statement(statement),
+      Statement::Throw(statement) => {
+        self.write("throw");
+        self.space();
+        self.print_expression(&statement.argument, Precedence::Comma);
+        self.write(";");
+      }
+      Statement::Try(statement) => self.print_try_statement(statement),
+      Statement::Variable(declaration) => {
+        self.print_variable_declaration(&declaration.bindings);
+        self.write(";");
+      }
+      Statement::With(statement) => {
+        self.write("with");
+        self.space();
+        self.print_condition(&statement.expression);
+        self.print_body(&statement.body);
+      }
+      Statement::While(statement) => {
+        self.write("while");
+        self.space();
+        self.print_condition(&statement.condition);
+        self.print_body(&statement.body);
+      }
+    }
+  }
+
+  fn print_jump_statement(&mut self, keyword: &str, statement: &JumpStatement) {
+    self.write(keyword);
+    if let Some(label) = &statement.label {
+      self.space();
+      self.mark_name(label.span, label.name);
+      self.write(label.name);
+    }
+    self.write(";");
+  }
+
+  // the body of a loop or an if, on the same line as its head
+  fn print_body(&mut self, statement: &Statement) {
+    if !matches!(statement, Statement::Empty(_)) {
+      self.space();
+    }
+
+    self.print_statement(statement);
+  }
+
+  fn print_condition(&mut self, condition: &Expression) {
+    self.write("(");
+    self.print_expression(condition, Precedence::Comma);
+    self.write(")");
+  }
+
+  fn print_if_statement(&mut self, statement: &IfStatement) {
+    self.write("if");
+    self.space();
+    self.print_condition(&statement.condition);
+
+    let Some(alternate) = &statement.alternate else {
+      self.print_body(&statement.consequent);
+      return;
+    };
+
+    // an `else` after an if without one would attach to the inner if
+    if has_dangling_if(&statement.consequent) {
+      self.space();
+      self.print_block(&[statement.consequent]);
+    } else {
+      self.print_body(&statement.consequent);
+    }
+
+    if matches!(statement.consequent, Statement::Block(_)) || has_dangling_if(&statement.consequent)
+    {
+      self.space();
+    } else {
+      self.newline();
+    }
+
+    self.write("else");
+    self.print_body(alternate);
+  }
+
+  fn print_switch_statement(&mut self, statement: &SwitchStatement) {
+    self.write("switch");
+    self.space();
+    self.print_condition(&statement.expression);
+    self.space();
+
+    if statement.cases.is_empty() {
+      self.write("{}");
+      return;
+    }
+
+    self.write("{");
+    self.indent += 1;
+    for case in statement.cases.iter() {
+      self.newline();
+      match &case.expression {
+        Some(expression) => {
+          self.write("case");
+          self.space();
+          self.print_expression(expression, Precedence::Comma);
+        }
+        None => self.write("default"),
+      }
+      self.write(":");
+
+      self.indent += 1;
+      for statement in case.body.iter() {
+        self.newline();
+        self.print_statement(statement);
+      }
+      self.indent -= 1;
+    }
+    self.indent -= 1;
+    self.newline();
+    self.write("}");
+  }
+
+  fn print_try_statement(&mut self, statement: &TryStatement) {
+    self.write("try");
+    self.space();
+    self.print_block(&statement.body.statements);
+
+    if let Some(catch) = &statement.catch {
+      self.space();
+      self.write("catch");
+      self.space();
+      if let Some(parameter) = &catch.parameter {
+        self.write("(");
+        self.print_binding_pattern(parameter);
+        self.write(")");
+        self.space();
+      }
+      self.print_block(&catch.body.statements);
+    }
+
+    if let Some(finally) = &statement.finally {
+      self.space();
+      self.write("finally");
+      self.space();
+      self.print_block(&finally.statements);
+    }
+  }
+
+  fn print_lexical_declaration(&mut self, declaration: &LexicalDeclaration) {
+    self.write(lexical_keyword(declaration.kind));
+    self.space();
+    self.print_declarations(&declaration.bindings);
+  }
+
+  fn print_variable_declaration(&mut self, bindings: &[BindingPatternInitializer]) {
+    self.write("var");
+    self.space();
+    self.print_declarations(bindings);
+  }
+
+  fn print_declarations(&mut self, bindings: &[BindingPatternInitializer]) {
+    for (index, binding) in bindings.iter().enumerate() {
+      if index > 0 {
+        self.comma();
+      }
+
+      self.print_binding_initializer(binding);
+    }
+  }
+
+  fn print_for_binding(&mut self, binding: &ForBinding) {
+    self.no_in = true;
+    match binding {
+      ForBinding::Expression(expression) => {
+        self.statement_start = Some(self.output.len());
+        self.print_expression(expression, Precedence::New);
+        self.statement_start = None;
+      }
+      ForBinding::Lexical(kind, pattern, _) => {
+        self.write(lexical_keyword(*kind));
+        self.space();
+        self.print_binding_pattern(pattern);
+      }
+      ForBinding::Variable(binding, _) => {
+        self.write("var");
+        self.space();
+        self.print_binding_initializer(binding);
+      }
+    }
+    self.no_in = false;
+  }
+}
+
+fn lexical_keyword(kind: LexicalKind) -> &'static str {
+  match kind {
+    LexicalKind::Const => "const",
+    LexicalKind::Let => "let",
+  }
+}
+
+// whether the statement ends in an if that has no else
+fn has_dangling_if(statement: &Statement) -> bool {
+  match statement {
+    Statement::If(statement) => match &statement.alternate {
+      Some(alternate) => has_dangling_if(alternate),
+      None => true,
+    },
+    Statement::For(statement) => has_dangling_if(&statement.body),
+    Statement::ForIn(statement) => has_dangling_if(&statement.body),
+    Statement::ForOf(statement) => has_dangling_if(&statement.body),
+    Statement::Label(statement) => has_dangling_if(&statement.statement),
+    Statement::While(statement) => has_dangling_if(&statement.body),
+    Statement::With(statement) => has_dangling_if(&statement.body),
+    _ => false,
+  }
+}
@@ -0,0 +1,73 @@
+use crate::parser::{ParseError, ParseErrorCode};
+
+// where each line starts, with the line terminators javascript has
+pub struct Lines {
+  starts: Vec<usize>,
+}
+
+impl Lines {
+  pub fn new(source: &[char]) -> Self {
+    let mut starts = vec![0];
+
+    for (index, c) in source.iter().enumerate() {
+      match c {
+        '\r' if source.get(index + 1) == Some(&'\n') => {}
+        '\n' | '\r' | '\u{2028}' | '\u{2029}' => starts.push(index + 1),
+        _ => {}
+      }
+    }
+
+    Self { starts }
+  }
+
+  // the line and column of an offset, both counted from 1
+  pub fn position(&self, offset: usize) -> (usize, usize) {
+    let line = self.starts.partition_point(|start| *start <= offset);
+    (line, offset - self.starts[line - 1] + 1)
+  }
+
+  // the characters of a line counted from 1, without its terminator
+  fn text<'s>(&self, source: &'s [char], line: usize) -> &'s [char] {
+    let start = self.starts[line - 1];
+    let end = self.starts.get(line).copied().unwrap_or(source.len());
+    let line = &source[start..end];
+    let terminator = line
+      .iter()
+      .rev()
+      .take_while(|c| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+      .count();
+
+    &line[..line.len() - terminator]
+  }
+}
+
+// the error with the line it's on and a marker under the token it's at
+//
+// error: unexpected token
+//  --> file.js:1:9
+//   |
+// 1 | let x = ;
+//   |         ^
+pub fn render_error(name: &str, source: &[char], lines: &Lines, error: &ParseError) -> String {
+  let start = error.span.start as usize;
+  let end = error.span.end as usize;
+
+  let message = match error.code {
+    ParseErrorCode::SyntaxError if start >= source.len() => "unexpected end of input",
+    ParseErrorCode::SyntaxError => "unexpected token",
+    code => code.message(),
+  };
+
+  let (line, column) = lines.position(start);
+  let text = lines.text(source, line);
+  let remaining = text.len().saturating_sub(column - 1);
+  let width = end.saturating_sub(start).min(remaining).max(1);
+  let gutter = " ".repeat(line.to_string().len());
+
+  format!(
+    "error: {message}\n{gutter}--> {name}:{line}:{column}\n{gutter} |\n{line} | {}\n{gutter} | {}{}\n",
+    text.iter().collect::<String>(),
+    " ".repeat(column - 1),
+    "^".repeat(width)
+  )
+}
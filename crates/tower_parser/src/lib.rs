@@ -1,5 +1,5 @@
-#![feature(bigint_helper_methods)]
-
-pub mod bigint;
+pub mod diagnostic;
 pub mod number;
 pub mod parser;
+
+pub use tower_ast::bigint;
@@ -0,0 +1,61 @@
+// binary16, what Float16Array and DataView's getFloat16 and setFloat16 store. the bits are
+// 1 sign, 5 exponent and 10 fraction, with the exponent biased by 15
+
+// Math.f16round, the nearest binary16 value as a Number
+pub fn f16_round(value: f64) -> f64 {
+  f16_bits_to_f64(f64_to_f16_bits(value))
+}
+
+// the nearest binary16 value, ties to even, straight from the double so nothing rounds twice.
+// too large is ±Infinity and too small ±0
+pub fn f64_to_f16_bits(value: f64) -> u16 {
+  let bits = value.to_bits();
+  let sign = ((bits >> 48) & 0x8000) as u16;
+  let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+  let fraction = bits & ((1 << 52) - 1);
+
+  if biased_exponent == 0x7ff {
+    return if fraction == 0 { sign | 0x7c00 } else { 0x7e00 };
+  }
+  // a subnormal double is far below half the smallest binary16 value
+  if biased_exponent == 0 {
+    return sign;
+  }
+  if biased_exponent - 1023 > 15 {
+    return sign | 0x7c00;
+  }
+
+  // the value is significand·2^exponent, and the result a count of 2^quantum, the spacing of
+  // binary16 values around it
+  let significand = fraction | 1 << 52;
+  let exponent = biased_exponent - 1075;
+  let quantum = (biased_exponent - 1023 - 10).max(-24);
+  let shift = (quantum - exponent) as u32;
+  if shift > 53 {
+    return sign;
+  }
+
+  let mut count = significand >> shift;
+  let remainder = significand & ((1 << shift) - 1);
+  let half = 1 << (shift - 1);
+  if remainder > half || (remainder == half && count & 1 == 1) {
+    count += 1;
+  }
+
+  // a count past 2^11 carries into the exponent, and past the largest exponent into Infinity
+  let biased_quantum = (quantum + 24) as u16;
+  sign | ((biased_quantum << 10) + count as u16)
+}
+
+pub fn f16_bits_to_f64(bits: u16) -> f64 {
+  let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+  let biased_exponent = ((bits >> 10) & 0x1f) as i32;
+  let fraction = (bits & 0x3ff) as f64;
+
+  match biased_exponent {
+    0 => sign * fraction * 2f64.powi(-24),
+    0x1f if fraction == 0.0 => sign * f64::INFINITY,
+    0x1f => f64::NAN,
+    _ => sign * (fraction + 1024.0) * 2f64.powi(biased_exponent - 25),
+  }
+}
@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use crate::bigint::BigInt;
+
+use super::es_number_to_string;
+
+// the largest fractionDigits and precision Number.prototype allows
+const MAX_DIGITS: f64 = 100.0;
+
+// Number.prototype.toFixed, the digits argument already through ToIntegerOrInfinity. None when
+// it's out of range, a RangeError
+pub fn to_fixed(value: f64, fraction_digits: f64) -> Option<String> {
+  if !(0.0..=MAX_DIGITS).contains(&fraction_digits) {
+    return None;
+  }
+  if !value.is_finite() {
+    return Some(es_number_to_string(value, 10));
+  }
+
+  let fraction_digits = fraction_digits as usize;
+  let sign = if value < 0.0 { "-" } else { "" };
+  let value = value.abs();
+
+  if value >= 1e21 {
+    return Some(format!("{sign}{}", es_number_to_string(value, 10)));
+  }
+
+  // n / 10^f - x as close to zero as possible
+  let mut digits = round_scaled(value, -(fraction_digits as i32)).to_string();
+  if fraction_digits == 0 {
+    return Some(format!("{sign}{digits}"));
+  }
+
+  if digits.len() <= fraction_digits {
+    digits = "0".repeat(fraction_digits + 1 - digits.len()) + &digits;
+  }
+
+  let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+  Some(format!("{sign}{integer}.{fraction}"))
+}
+
+// Number.prototype.toExponential, None for the digits is undefined and gives as many digits as
+// it takes to tell the value apart from any other
+pub fn to_exponential(value: f64, fraction_digits: Option<f64>) -> Option<String> {
+  if !value.is_finite() {
+    return Some(es_number_to_string(value, 10));
+  }
+  if fraction_digits.is_some_and(|digits| !(0.0..=MAX_DIGITS).contains(&digits)) {
+    return None;
+  }
+
+  let sign = if value < 0.0 { "-" } else { "" };
+  let value = value.abs();
+
+  let (digits, exponent) = match fraction_digits {
+    _ if value == 0.0 => {
+      let digits = fraction_digits.unwrap_or(0.0) as usize + 1;
+      ("0".repeat(digits), 0)
+    }
+    Some(fraction_digits) => significant_digits(value, fraction_digits as usize + 1),
+    None => {
+      let (significand, exponent, _) = port_dragonbox::to_decimal(value);
+      let digits = significand.to_string();
+      let exponent = exponent + digits.len() as i32 - 1;
+      (digits, exponent)
+    }
+  };
+
+  Some(format!("{sign}{}", exponential(&digits, exponent)))
+}
+
+// Number.prototype.toPrecision, None for the precision is undefined and is ToString
+pub fn to_precision(value: f64, precision: Option<f64>) -> Option<String> {
+  let Some(precision) = precision else {
+    return Some(es_number_to_string(value, 10));
+  };
+  if !value.is_finite() {
+    return Some(es_number_to_string(value, 10));
+  }
+  if !(1.0..=MAX_DIGITS).contains(&precision) {
+    return None;
+  }
+
+  let precision = precision as usize;
+  let sign = if value < 0.0 { "-" } else { "" };
+  let value = value.abs();
+
+  let (digits, exponent) = if value == 0.0 {
+    ("0".repeat(precision), 0)
+  } else {
+    significant_digits(value, precision)
+  };
+
+  if exponent < -6 || exponent >= precision as i32 {
+    return Some(format!("{sign}{}", exponential(&digits, exponent)));
+  }
+
+  let text = if exponent >= 0 {
+    let (integer, fraction) = digits.split_at(exponent as usize + 1);
+    if fraction.is_empty() {
+      integer.to_string()
+    } else {
+      format!("{integer}.{fraction}")
+    }
+  } else {
+    format!("0.{}{digits}", "0".repeat((-exponent - 1) as usize))
+  };
+
+  Some(format!("{sign}{text}"))
+}
+
+// `d.ddde+x`, the first digit before the point
+fn exponential(digits: &str, exponent: i32) -> String {
+  let (first, rest) = digits.split_at(1);
+  let point = if rest.is_empty() { "" } else { "." };
+  let exponent_sign = if exponent < 0 { "-" } else { "+" };
+
+  format!(
+    "{first}{point}{rest}e{exponent_sign}{}",
+    exponent.unsigned_abs()
+  )
+}
+
+// the n with `count` digits and the e for which n·10^(e - count + 1) - x is as close to zero as
+// possible, the larger n on a tie. x is positive
+fn significant_digits(value: f64, count: usize) -> (String, i32) {
+  // the estimate is off by at most one either way, the digit count says which
+  let mut exponent = value.log10().floor() as i32;
+
+  loop {
+    let digits = round_scaled(value, exponent - count as i32 + 1).to_string();
+    match digits.len().cmp(&count) {
+      Ordering::Greater => exponent += 1,
+      Ordering::Less => exponent -= 1,
+      Ordering::Equal => return (digits, exponent),
+    }
+  }
+}
+
+// x / 10^scale rounded to the nearest integer with halves rounding up, exactly. x is finite and
+// not negative, so it's a significand times a power of two
+fn round_scaled(value: f64, scale: i32) -> BigInt {
+  let bits = value.to_bits();
+  let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+  let fraction = bits & ((1 << 52) - 1);
+  let (significand, exponent) = match biased_exponent {
+    0 => (fraction, -1074),
+    _ => (fraction | 1 << 52, biased_exponent - 1075),
+  };
+
+  let power_of_ten = |exponent: i32| {
+    BigInt::from(10u64)
+      .pow(&BigInt::from(exponent as u64))
+      .unwrap()
+  };
+
+  // x / 10^scale = numerator / denominator
+  let mut numerator = BigInt::from(significand);
+  let mut denominator = BigInt::from(1u64);
+  if exponent >= 0 {
+    numerator = &numerator << exponent as usize;
+  } else {
+    denominator = &denominator << -exponent as usize;
+  }
+  if scale >= 0 {
+    denominator = &denominator * &power_of_ten(scale);
+  } else {
+    numerator = &numerator * &power_of_ten(-scale);
+  }
+
+  // floor((2·numerator + denominator) / (2·denominator))
+  let twice = &denominator << 1;
+  &(&(&numerator << 1) + &denominator) / &twice
+}
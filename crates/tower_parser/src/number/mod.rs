@@ -1,114 +1,182 @@
-use std::num::FpCategory;
+use std::{fmt, num::FpCategory, str};
 
+mod float16;
+mod format;
+mod parse;
+mod shortest;
+
+pub use float16::{f16_bits_to_f64, f16_round, f64_to_f16_bits};
+pub use format::{to_exponential, to_fixed, to_precision};
+pub use parse::{parse_float, parse_int, string_to_number};
+pub use shortest::{float16_to_string, float32_to_string, write_float16, write_float32};
+
+// Number::toString
 pub fn es_number_to_string(value: f64, radix: u8) -> String {
-  assert!(radix >= 2 && radix <= 36);
+  let mut output = String::new();
+  write_number(value, radix, &mut output).unwrap();
+  output
+}
+
+// Number::toString written straight to the output, without allocating
+pub fn write_number(value: f64, radix: u8, output: &mut impl fmt::Write) -> fmt::Result {
+  assert!((2..=36).contains(&radix));
 
   match value.classify() {
-    FpCategory::Nan => String::from("NaN"),
-    FpCategory::Zero => String::from("0"),
-    FpCategory::Infinite => {
-      if value.is_sign_positive() {
-        String::from("Infinity")
-      } else {
-        String::from("-Infinity")
-      }
+    FpCategory::Nan | FpCategory::Zero | FpCategory::Infinite => write_special(value, output),
+    // every safe integer is written the same in any radix, and never with an exponent
+    _ if value.trunc() == value && value.abs() < SAFE_INTEGER_LIMIT => {
+      write_integer(value, radix, output)
     }
+    _ if radix != 10 => port_v8_double_to_string_radix::write_double_radix(value, radix, output),
     _ => {
-      if radix != 10 {
-        return port_v8_double_to_string_radix::double_to_string_radix(value, radix);
-      } else {
-        let (mut significant, rep_exponent, sign) = port_dragonbox::to_decimal(value);
-
-        let mut significant_digits = significant.ilog10() + 1;
-        let mut exponent = significant_digits as i32 + rep_exponent;
-
-        let mut buf = [0u8; 64];
-        let mut cursor = 0usize;
-
-        if exponent >= -5 && exponent <= 21 {
-          if exponent >= significant_digits as i32 {
-            while exponent > 0 {
-              buf[cursor] = b'0';
-              cursor += 1;
-              exponent -= 1;
-            }
-            while significant_digits > 0 {
-              buf[cursor] = b'0' + (significant % 10) as u8;
-              cursor += 1;
-              significant /= 10;
-              significant_digits -= 1;
-            }
-          } else if exponent > 0 {
-            while significant_digits > exponent as u32 {
-              buf[cursor] = b'0' + (significant % 10) as u8;
-              cursor += 1;
-              significant /= 10;
-              significant_digits -= 1;
-            }
-            buf[cursor] = b'.';
-            cursor += 1;
-            while significant_digits > 0 {
-              buf[cursor] = b'0' + (significant % 10) as u8;
-              cursor += 1;
-              significant /= 10;
-              significant_digits -= 1;
-            }
-          } else {
-            while significant_digits > 0 {
-              buf[cursor] = b'0' + (significant % 10) as u8;
-              cursor += 1;
-              significant /= 10;
-              significant_digits -= 1;
-            }
-            while exponent < 0 {
-              buf[cursor] = b'0';
-              cursor += 1;
-              exponent += 1;
-            }
-            buf[cursor] = b'.';
-            buf[cursor + 1] = b'0';
-            cursor += 2;
-          }
-        } else {
-          let mut exponent_abs = (exponent - 1).unsigned_abs();
-          loop {
-            buf[cursor] = b'0' + (exponent_abs % 10) as u8;
-            cursor += 1;
-            if exponent_abs < 10 {
-              break;
-            }
-            exponent_abs /= 10;
-          }
-
-          buf[cursor] = if exponent < 0 { b'-' } else { b'+' };
-          buf[cursor + 1] = b'e';
-          cursor += 2;
-
-          if significant_digits > 1 {
-            loop {
-              buf[cursor] = b'0' + (significant % 10) as u8;
-              cursor += 1;
-              significant /= 10;
-              if significant < 10 {
-                break;
-              }
-            }
-
-            buf[cursor] = b'.';
-            cursor += 1;
-          }
-
-          buf[cursor] = b'0' + significant as u8;
-          cursor += 1;
-        }
+      let (significand, exponent, sign) = port_dragonbox::to_decimal(value);
+      write_decimal(significand, exponent, sign, output)
+    }
+  }
+}
 
-        if sign {
-          buf[cursor] = b'-';
-          cursor += 1;
-        }
+// NaN, zero and the infinities, which have no digits to find
+fn write_special(value: f64, output: &mut impl fmt::Write) -> fmt::Result {
+  output.write_str(if value.is_nan() {
+    "NaN"
+  } else if value == 0.0 {
+    "0"
+  } else if value > 0.0 {
+    "Infinity"
+  } else {
+    "-Infinity"
+  })
+}
+
+// 2^53
+const SAFE_INTEGER_LIMIT: f64 = 9007199254740992.0;
+
+fn write_integer(value: f64, radix: u8, output: &mut impl fmt::Write) -> fmt::Result {
+  const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+  // the standard library's decimal is quicker than a digit at a time
+  if radix == 10 {
+    return write!(output, "{}", value as i64);
+  }
+
+  let mut buf = [0u8; 64];
+  let mut cursor = buf.len();
+  let mut magnitude = value.abs() as u64;
+
+  loop {
+    cursor -= 1;
+    buf[cursor] = DIGITS[(magnitude % radix as u64) as usize];
+    magnitude /= radix as u64;
+    if magnitude == 0 {
+      break;
+    }
+  }
+
+  if value < 0.0 {
+    cursor -= 1;
+    buf[cursor] = b'-';
+  }
 
-        String::from_iter(buf[0..cursor].iter().rev().map(|c| *c as char))
+  output.write_str(str::from_utf8(&buf[cursor..]).unwrap())
+}
+
+// the digits significant·10^rep_exponent laid out the way Number::toString does, built backwards
+// from the last one
+fn write_decimal(
+  mut significant: u64,
+  rep_exponent: i32,
+  sign: bool,
+  output: &mut impl fmt::Write,
+) -> fmt::Result {
+  let mut significant_digits = significant.ilog10() + 1;
+  let mut exponent = significant_digits as i32 + rep_exponent;
+
+  let mut buf = [0u8; 64];
+  let mut cursor = 0usize;
+
+  if (-5..=21).contains(&exponent) {
+    if exponent >= significant_digits as i32 {
+      while exponent > significant_digits as i32 {
+        buf[cursor] = b'0';
+        cursor += 1;
+        exponent -= 1;
+      }
+      while significant_digits > 0 {
+        buf[cursor] = b'0' + (significant % 10) as u8;
+        cursor += 1;
+        significant /= 10;
+        significant_digits -= 1;
+      }
+    } else if exponent > 0 {
+      while significant_digits > exponent as u32 {
+        buf[cursor] = b'0' + (significant % 10) as u8;
+        cursor += 1;
+        significant /= 10;
+        significant_digits -= 1;
+      }
+      buf[cursor] = b'.';
+      cursor += 1;
+      while significant_digits > 0 {
+        buf[cursor] = b'0' + (significant % 10) as u8;
+        cursor += 1;
+        significant /= 10;
+        significant_digits -= 1;
+      }
+    } else {
+      while significant_digits > 0 {
+        buf[cursor] = b'0' + (significant % 10) as u8;
+        cursor += 1;
+        significant /= 10;
+        significant_digits -= 1;
+      }
+      while exponent < 0 {
+        buf[cursor] = b'0';
+        cursor += 1;
+        exponent += 1;
+      }
+      buf[cursor] = b'.';
+      buf[cursor + 1] = b'0';
+      cursor += 2;
+    }
+  } else {
+    let mut exponent_abs = (exponent - 1).unsigned_abs();
+    loop {
+      buf[cursor] = b'0' + (exponent_abs % 10) as u8;
+      cursor += 1;
+      if exponent_abs < 10 {
+        break;
       }
+      exponent_abs /= 10;
     }
+
+    buf[cursor] = if exponent < 0 { b'-' } else { b'+' };
+    buf[cursor + 1] = b'e';
+    cursor += 2;
+
+    if significant_digits > 1 {
+      loop {
+        buf[cursor] = b'0' + (significant % 10) as u8;
+        cursor += 1;
+        significant /= 10;
+        if significant < 10 {
+          break;
+        }
+      }
+
+      buf[cursor] = b'.';
+      cursor += 1;
+    }
+
+    buf[cursor] = b'0' + significant as u8;
+    cursor += 1;
   }
+
+  if sign {
+    buf[cursor] = b'-';
+    cursor += 1;
+  }
+
+  let text = &mut buf[..cursor];
+  text.reverse();
+  output.write_str(str::from_utf8(text).unwrap())
 }
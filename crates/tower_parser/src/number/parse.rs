@@ -0,0 +1,163 @@
+use port_oxc_number_parsers::{
+  parse_binary, parse_decimal, parse_decimal_float, parse_hexadecimal, parse_octal,
+};
+
+use crate::bigint::{is_string_whitespace, BigInt};
+
+// StringToNumber, what ToNumber, Number() and loose equality make of a string. unlike a literal
+// in source there are no separators and no legacy octals, and the whole text has to be a number
+// apart from the whitespace around it, or it's NaN
+pub fn string_to_number(text: &str) -> f64 {
+  let text = text.trim_matches(is_string_whitespace);
+  if text.is_empty() {
+    return 0.0;
+  }
+
+  let chars = text.chars().collect::<Vec<_>>();
+  let (radix, digits) = match chars[..] {
+    ['0', 'x' | 'X', ..] => (16, &chars[2..]),
+    ['0', 'o' | 'O', ..] => (8, &chars[2..]),
+    ['0', 'b' | 'B', ..] => (2, &chars[2..]),
+    _ => {
+      return match decimal_prefix(&chars) {
+        Some((value, length)) if length == chars.len() => value,
+        _ => f64::NAN,
+      }
+    }
+  };
+
+  if digits.is_empty() || !digits.iter().all(|digit| digit.is_digit(radix)) {
+    return f64::NAN;
+  }
+
+  parse_digits(digits, radix)
+}
+
+// parseFloat, the longest prefix after the leading whitespace that's a decimal number, NaN
+// when there isn't one
+pub fn parse_float(text: &str) -> f64 {
+  let text = text.trim_start_matches(is_string_whitespace);
+  let chars = text.chars().collect::<Vec<_>>();
+
+  decimal_prefix(&chars).map_or(f64::NAN, |(value, _)| value)
+}
+
+// parseInt, with the radix already converted by ToInt32. 0 is radix 10, or 16 when the digits
+// start with `0x`, and anything else outside 2 to 36 is NaN
+pub fn parse_int(text: &str, radix: i32) -> f64 {
+  let text = text.trim_start_matches(is_string_whitespace);
+  let chars = text.chars().collect::<Vec<_>>();
+
+  let (negative, mut digits) = match chars[..] {
+    ['-', ..] => (true, &chars[1..]),
+    ['+', ..] => (false, &chars[1..]),
+    _ => (false, &chars[..]),
+  };
+
+  // without a radix, or with 16, a `0x` prefix is skipped
+  let strip_prefix = radix == 0 || radix == 16;
+  let mut radix = match radix {
+    0 => 10,
+    2..=36 => radix as u32,
+    _ => return f64::NAN,
+  };
+
+  if strip_prefix {
+    if let ['0', 'x' | 'X', rest @ ..] = digits {
+      digits = rest;
+      radix = 16;
+    }
+  }
+
+  let length = digits
+    .iter()
+    .position(|digit| !digit.is_digit(radix))
+    .unwrap_or(digits.len());
+  if length == 0 {
+    return f64::NAN;
+  }
+
+  let value = parse_digits(&digits[..length], radix);
+  if negative {
+    -value
+  } else {
+    value
+  }
+}
+
+// the digits of an integer in any radix, the ones a literal can have through the parsers the
+// lexer uses and the rest exactly through a BigInt
+fn parse_digits(digits: &[char], radix: u32) -> f64 {
+  match radix {
+    2 => parse_binary(digits),
+    8 => parse_octal(digits),
+    10 => parse_decimal(digits),
+    16 => parse_hexadecimal(digits),
+    _ => {
+      let radix = BigInt::from(radix as u64);
+      digits
+        .iter()
+        .fold(BigInt::zero(), |value, digit| {
+          &(&value * &radix) + &BigInt::from(digit.to_digit(36).unwrap() as u64)
+        })
+        .to_f64()
+    }
+  }
+}
+
+// StrDecimalLiteral at the start of the chars, its value and how many chars it takes
+//
+//   [+-] (Infinity | digits [. [digits]] [exponent] | . digits [exponent])
+fn decimal_prefix(chars: &[char]) -> Option<(f64, usize)> {
+  let sign_length = matches!(chars.first(), Some('+' | '-')) as usize;
+  let negative = chars.first() == Some(&'-');
+  let rest = &chars[sign_length..];
+
+  if rest.starts_with(&['I', 'n', 'f', 'i', 'n', 'i', 't', 'y']) {
+    let value = if negative {
+      f64::NEG_INFINITY
+    } else {
+      f64::INFINITY
+    };
+    return Some((value, sign_length + 8));
+  }
+
+  let count_digits = |from: usize| {
+    rest[from.min(rest.len())..]
+      .iter()
+      .take_while(|c| c.is_ascii_digit())
+      .count()
+  };
+
+  let integer = count_digits(0);
+  let mut length = integer;
+  let mut fraction = 0;
+  if rest.get(length) == Some(&'.') {
+    fraction = count_digits(length + 1);
+    if integer > 0 || fraction > 0 {
+      length += 1 + fraction;
+    }
+  }
+
+  if integer == 0 && fraction == 0 {
+    return None;
+  }
+
+  // the exponent only counts when it has digits
+  if matches!(rest.get(length), Some('e' | 'E')) {
+    let exponent_sign = matches!(rest.get(length + 1), Some('+' | '-')) as usize;
+    let exponent = count_digits(length + 1 + exponent_sign);
+    if exponent > 0 {
+      length += 1 + exponent_sign + exponent;
+    }
+  }
+
+  let number = &rest[..length];
+  let value = if integer == length {
+    parse_decimal(number)
+  } else {
+    parse_decimal_float(number)
+  };
+
+  Some((if negative { -value } else { value }, sign_length + length))
+}
@@ -0,0 +1,130 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::bigint::BigInt;
+
+use super::{write_decimal, write_special};
+
+// binary16 laid out the way Number::toString lays out a double, with the fewest digits that read
+// back as the same binary16 value rather than the same double
+pub fn float16_to_string(bits: u16) -> String {
+  let mut output = String::new();
+  write_float16(bits, &mut output).unwrap();
+  output
+}
+
+pub fn write_float16(bits: u16, output: &mut impl fmt::Write) -> fmt::Result {
+  let value = super::f16_bits_to_f64(bits);
+  if !value.is_finite() || value == 0.0 {
+    return write_special(value, output);
+  }
+
+  let biased_exponent = ((bits >> 10) & 0x1f) as i32;
+  let fraction = (bits & 0x3ff) as u64;
+  let (significand, exponent) = match biased_exponent {
+    0 => (fraction, -24),
+    _ => (fraction | 1 << 10, biased_exponent - 25),
+  };
+
+  let (digits, exponent) = shortest_digits(significand, exponent, 11, -24);
+  write_decimal(digits, exponent, value < 0.0, output)
+}
+
+// binary32 the same way, with the fewest digits that read back as the same f32
+pub fn float32_to_string(value: f32) -> String {
+  let mut output = String::new();
+  write_float32(value, &mut output).unwrap();
+  output
+}
+
+pub fn write_float32(value: f32, output: &mut impl fmt::Write) -> fmt::Result {
+  if !value.is_finite() || value == 0.0 {
+    return write_special(value as f64, output);
+  }
+
+  let bits = value.to_bits();
+  let biased_exponent = ((bits >> 23) & 0xff) as i32;
+  let fraction = (bits & ((1 << 23) - 1)) as u64;
+  let (significand, exponent) = match biased_exponent {
+    0 => (fraction, -149),
+    _ => (fraction | 1 << 23, biased_exponent - 150),
+  };
+
+  let (digits, exponent) = shortest_digits(significand, exponent, 24, -149);
+  write_decimal(digits, exponent, value < 0.0, output)
+}
+
+// the n and k with the fewest digits in n for which n·10^k reads back as significand·2^exponent,
+// in a format with `precision` significand bits whose subnormals have `min_exponent`. of those
+// the closest, the even one on a tie. it's what dragonbox gives for doubles, done exactly with
+// big integers where the speed matters less
+fn shortest_digits(
+  significand: u64,
+  exponent: i32,
+  precision: u32,
+  min_exponent: i32,
+) -> (u64, i32) {
+  // the value and the bounds of what rounds to it in quarters of the spacing, 2^quarter. the
+  // spacing below a power of two is half of the one above, apart from the smallest exponent
+  let value = BigInt::from(significand << 2);
+  let above = BigInt::from((significand << 2) + 2);
+  let below = if significand == 1 << (precision - 1) && exponent > min_exponent {
+    BigInt::from((significand << 2) - 1)
+  } else {
+    BigInt::from((significand << 2) - 2)
+  };
+  let quarter = exponent - 2;
+  // the bounds themselves round to an even significand
+  let inclusive = significand & 1 == 0;
+
+  // from a power of ten above the value down until there's a multiple of it between the bounds,
+  // and when there is the one below the value or the one above is
+  let mut k =
+    ((significand as f64).log10() + exponent as f64 * std::f64::consts::LOG10_2).ceil() as i32 + 1;
+
+  loop {
+    // the value over 10^k is numerator / denominator, and n·10^k against x quarters is n times
+    // the denominator against x times the scale
+    let scale = &power(2, quarter) * &power(10, -k);
+    let denominator = &power(2, -quarter) * &power(10, k);
+    let numerator = &value * &scale;
+
+    let between = |n: &BigInt| {
+      let n = n * &denominator;
+      match (n.cmp(&(&below * &scale)), n.cmp(&(&above * &scale))) {
+        (Ordering::Greater, Ordering::Less) => true,
+        (Ordering::Equal, _) | (_, Ordering::Equal) => inclusive,
+        _ => false,
+      }
+    };
+
+    let floor = &numerator / &denominator;
+    let ceiling = &floor + &BigInt::from(1u64);
+    let digits = match (!floor.is_zero() && between(&floor), between(&ceiling)) {
+      (true, true) => {
+        // twice the value against the midpoint of the two
+        let midpoint = &(&(&floor << 1) + &BigInt::from(1u64)) * &denominator;
+        match (&numerator << 1).cmp(&midpoint) {
+          Ordering::Less => floor,
+          Ordering::Greater => ceiling,
+          Ordering::Equal if floor.to_u64().unwrap() & 1 == 0 => floor,
+          Ordering::Equal => ceiling,
+        }
+      }
+      (true, false) => floor,
+      (false, true) => ceiling,
+      (false, false) => {
+        k -= 1;
+        continue;
+      }
+    };
+
+    return (digits.to_u64().unwrap(), k);
+  }
+}
+
+// base^exponent, 1 when the exponent is negative so the pairs above only scale one side
+fn power(base: u64, exponent: i32) -> BigInt {
+  BigInt::from(base)
+    .pow(&BigInt::from(exponent.max(0) as u64))
+    .unwrap()
+}
@@ -5,11 +5,13 @@ use crate::number::es_number_to_string;
 use super::{
   ast::{
     binding::{
-      ArrayBindingPattern, BindingPattern, BindingPatternInitializer, ObjectBindingPattern,
-      ObjectBindingProperty,
+      ArrayBindingPattern, BindingPattern, BindingPatternInitializer, BindingRestElement,
+      ObjectBindingPattern, ObjectBindingProperty,
     },
+    expression::Expression,
     object::PropertyName,
-    SourceType,
+    string::JsStr,
+    Identifier, PropertyKey, SourceType,
   },
   error::{syntax_err, ParseError, ParseErrorCode},
   lexer::token::{Name, Token},
@@ -20,28 +22,69 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_binding_pattern_with_initializer(
     &mut self,
   ) -> Result<BindingPatternInitializer<'a>, ParseError> {
+    let start = self.context.token_start;
     let pattern = self.read_binding_pattern()?;
-    let initializer = match &self.context.token {
-      Token::Equals => {
-        self.next_token()?;
-        Some(self.read_assignment_expression()?.ok_or(syntax_err!())?)
+    let initializer = self.read_binding_initializer()?;
+
+    let pattern_with_initializer = BindingPatternInitializer {
+      pattern,
+      initializer,
+      span: self.span_from(start),
+    };
+
+    Ok(pattern_with_initializer)
+  }
+
+  // a binding of a declaration or a parameter list, where typescript allows a type annotation
+  // after the pattern, with `?` for optional parameters or `!` for definitely assigned variables
+  pub fn read_declared_binding(&mut self) -> Result<BindingPatternInitializer<'a>, ParseError> {
+    let pattern = self.read_binding_pattern()?;
+
+    if self.typescript {
+      match &self.context.token {
+        Token::QuestionMark if self.context.flags.formal_parameters => self.next_token()?,
+        Token::Exclamation if !self.context.flags.formal_parameters => self.next_token()?,
+        _ => {}
       }
-      _ => None,
+
+      self.skip_type_annotation()?;
+    }
+
+    let initializer = self.read_binding_initializer()?;
+
+    // the span of a typed binding leaves out its type annotation
+    let span = match initializer {
+      Some(initializer) => pattern.span().to(initializer.span()),
+      None => pattern.span(),
     };
 
     let pattern_with_initializer = BindingPatternInitializer {
       pattern,
       initializer,
+      span,
     };
 
     Ok(pattern_with_initializer)
   }
 
+  fn read_binding_initializer(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let initializer = match &self.context.token {
+      Token::Equals => {
+        self.next_token()?;
+        Some(self.read_assignment_expression()?.ok_or(syntax_err!())?)
+      }
+      _ => None,
+    };
+
+    Ok(initializer)
+  }
+
   pub fn read_binding_pattern(&mut self) -> Result<BindingPattern<'a>, ParseError> {
+    let start = self.context.token_start;
     let pattern = match &self.context.token {
       Token::LeftSquareBracket => {
         self.next_token()?;
-        let mut elements = Vec::<Option<BindingPatternInitializer<'a>>>::new_in(&self.allocator);
+        let mut elements = Vec::<Option<BindingPatternInitializer<'a>>>::new_in(self.allocator);
         let rest = loop {
           match &self.context.token {
             Token::Comma => {
@@ -49,8 +92,13 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
               elements.push(None);
             }
             Token::TripleStop => {
+              let rest_start = self.context.token_start;
               self.next_token()?;
-              break Some(self.read_binding_pattern()?);
+              let pattern = self.read_binding_pattern()?;
+              break Some(BindingRestElement {
+                pattern,
+                span: self.span_from(rest_start),
+              });
             }
             Token::RightSquareBracket => {
               break None;
@@ -69,7 +117,13 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         };
 
         required_token!(self, Token::RightSquareBracket);
-        BindingPattern::Array(self.allocator.alloc(ArrayBindingPattern { elements, rest }))
+        let pattern = ArrayBindingPattern {
+          elements,
+          rest,
+          span: self.span_from(start),
+        };
+
+        BindingPattern::Array(self.allocator.alloc(pattern))
       }
       Token::LeftCurlyBracket => {
         self.next_token()?;
@@ -78,17 +132,20 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           self.next_token()?;
           return Ok(BindingPattern::Object(self.allocator.alloc(
             ObjectBindingPattern {
-              properties: Vec::new_in(&self.allocator),
+              properties: Vec::new_in(self.allocator),
               rest: None,
+              span: self.span_from(start),
             },
           )));
         }
 
-        let mut properties = Vec::<ObjectBindingProperty<'a>>::new_in(&self.allocator);
+        let mut properties = Vec::<ObjectBindingProperty<'a>>::new_in(self.allocator);
         let rest = loop {
+          let property_start = self.context.token_start;
           match &self.context.token {
             Token::Name(name) => {
               let name = name.clone();
+              let key_span = self.token_span();
               self.next_token()?;
 
               match &self.context.token {
@@ -97,28 +154,43 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
 
                   let binding = self.read_binding_pattern_with_initializer()?;
                   let property = ObjectBindingProperty {
-                    property: PropertyName::Static(self.allocator.alloc_str(name.as_string())),
+                    property: PropertyName::Static(PropertyKey {
+                      name: JsStr::from(&*self.allocator.alloc_str(name.as_string())),
+                      span: key_span,
+                    }),
                     binding,
+                    span: self.span_from(property_start),
                   };
 
                   properties.push(property);
                 }
                 token => match self.name_as_binding_identifier(&name)? {
-                  Some(identifier) => {
+                  Some(name) => {
                     let initializer = match token {
                       Token::Equals => {
                         self.next_token()?;
-                        Some(self.read_expression()?.ok_or(syntax_err!())?)
+                        Some(self.read_assignment_expression()?.ok_or(syntax_err!())?)
                       }
                       _ => None,
                     };
 
+                    let identifier = Identifier {
+                      name,
+                      span: key_span,
+                    };
+
+                    let span = self.span_from(property_start);
                     let property = ObjectBindingProperty {
-                      property: PropertyName::Static(identifier),
+                      property: PropertyName::Static(PropertyKey {
+                        name: JsStr::from(name),
+                        span: key_span,
+                      }),
                       binding: BindingPatternInitializer {
-                        pattern: BindingPattern::Identifier(identifier),
+                        pattern: BindingPattern::Identifier(self.allocator.alloc(identifier)),
                         initializer,
+                        span,
                       },
+                      span,
                     };
 
                     properties.push(property);
@@ -128,38 +200,70 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
               }
             }
             Token::StringLiteral(string_literal) => {
-              let name = self.allocator.alloc_str(&string_literal);
+              let name = PropertyKey {
+                name: string_literal.alloc_in(self.allocator),
+                span: self.token_span(),
+              };
+
               self.next_token()?;
               required_token!(self, Token::Colon);
 
+              let binding = self.read_binding_pattern_with_initializer()?;
               let property = ObjectBindingProperty {
                 property: PropertyName::Static(name),
-                binding: self.read_binding_pattern_with_initializer()?,
+                binding,
+                span: self.span_from(property_start),
               };
 
               properties.push(property);
             }
             Token::NumberLiteral(number_literal) => {
-              let name = self
-                .allocator
-                .alloc_str(&es_number_to_string(*number_literal, 10));
+              let name = PropertyKey {
+                name: JsStr::from(
+                  &*self
+                    .allocator
+                    .alloc_str(&es_number_to_string(*number_literal, 10)),
+                ),
+                span: self.token_span(),
+              };
 
               self.next_token()?;
               required_token!(self, Token::Colon);
 
+              let binding = self.read_binding_pattern_with_initializer()?;
               let property = ObjectBindingProperty {
                 property: PropertyName::Static(name),
-                binding: self.read_binding_pattern_with_initializer()?,
+                binding,
+                span: self.span_from(property_start),
               };
 
               properties.push(property);
             }
-            Token::BigIntLiteral(_) => {
-              todo!()
+            Token::BigIntLiteral(bigint_literal) => {
+              let name = PropertyKey {
+                name: JsStr::from(&*self.allocator.alloc_str(&bigint_literal.to_string())),
+                span: self.token_span(),
+              };
+
+              self.next_token()?;
+              required_token!(self, Token::Colon);
+
+              let binding = self.read_binding_pattern_with_initializer()?;
+              let property = ObjectBindingProperty {
+                property: PropertyName::Static(name),
+                binding,
+                span: self.span_from(property_start),
+              };
+
+              properties.push(property);
             }
             Token::TripleStop => {
               self.next_token()?;
-              break Some(BindingPattern::Identifier(self.read_binding_identifier()?));
+              let identifier = self.read_binding_identifier()?;
+              break Some(BindingRestElement {
+                pattern: BindingPattern::Identifier(self.allocator.alloc(identifier)),
+                span: self.span_from(property_start),
+              });
             }
             _ => return Err(syntax_err!()),
           }
@@ -172,32 +276,38 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         };
 
         required_token!(self, Token::RightCurlyBracket);
-        BindingPattern::Object(
-          self
-            .allocator
-            .alloc(ObjectBindingPattern { properties, rest }),
-        )
+        let pattern = ObjectBindingPattern {
+          properties,
+          rest,
+          span: self.span_from(start),
+        };
+
+        BindingPattern::Object(self.allocator.alloc(pattern))
+      }
+      Token::Name(_) => {
+        let identifier = self.read_binding_identifier()?;
+        BindingPattern::Identifier(self.allocator.alloc(identifier))
       }
-      Token::Name(_) => BindingPattern::Identifier(self.read_binding_identifier()?),
       _ => return Err(syntax_err!()),
     };
 
     Ok(pattern)
   }
 
-  pub fn read_binding_identifier(&mut self) -> Result<&'a str, ParseError> {
-    let pattern = match &self.context.token {
+  pub fn read_binding_identifier(&mut self) -> Result<Identifier<'a>, ParseError> {
+    let identifier = match &self.context.token {
       Token::Name(name) => match self.name_as_binding_identifier(name)? {
-        Some(string) => {
-          self.next_token()?;
-          string
-        }
+        Some(name) => Identifier {
+          name,
+          span: self.token_span(),
+        },
         _ => return Err(syntax_err!()),
       },
       _ => return Err(syntax_err!()),
     };
 
-    Ok(pattern)
+    self.next_token()?;
+    Ok(identifier)
   }
 
   pub fn name_as_binding_identifier(&self, name: &Name) -> Result<Option<&'a str>, ParseError> {
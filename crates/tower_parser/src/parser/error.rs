@@ -3,9 +3,12 @@ use std::{
   fmt::{Debug, Display},
 };
 
+use tower_ast::Span;
+
 #[derive(Debug, Clone, Copy)]
 pub enum ParseErrorCode {
   InvalidEscape,
+  InvalidRegExp,
   InvalidTemplateString,
   InvalidUnicode,
   StrictOctalLiteral,
@@ -13,11 +16,27 @@ pub enum ParseErrorCode {
   SyntaxError,
 }
 
+impl ParseErrorCode {
+  pub fn message(self) -> &'static str {
+    match self {
+      ParseErrorCode::InvalidEscape => "invalid escape sequence",
+      ParseErrorCode::InvalidRegExp => "invalid regular expression",
+      ParseErrorCode::InvalidTemplateString => "invalid template string",
+      ParseErrorCode::InvalidUnicode => "invalid unicode escape",
+      ParseErrorCode::StrictOctalLiteral => "octal literals aren't allowed in strict mode",
+      ParseErrorCode::StrictOctalEscape => "octal escapes aren't allowed in strict mode",
+      ParseErrorCode::SyntaxError => "syntax error",
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ParseError {
   pub code: ParseErrorCode,
   pub module_path: &'static str,
   pub line: u32,
+  // the token the parser was at, filled in once the error gets out of the parser
+  pub span: Span,
 }
 
 impl Display for ParseError {
@@ -34,6 +53,7 @@ macro_rules! parse_err {
       code: $code,
       module_path: module_path!(),
       line: line!(),
+      span: $crate::parser::ast::Span::new(0, 0),
     }
   };
 }
@@ -44,6 +64,7 @@ macro_rules! syntax_err {
       code: ParseErrorCode::SyntaxError,
       module_path: module_path!(),
       line: line!(),
+      span: $crate::parser::ast::Span::new(0, 0),
     }
   };
 }
@@ -57,6 +78,7 @@ macro_rules! required_token {
         code: ParseErrorCode::SyntaxError,
         module_path: module_path!(),
         line: line!(),
+        span: $crate::parser::ast::Span::new(0, 0),
       })?;
     }
   };
@@ -6,9 +6,14 @@ use crate::{
     ast::{
       class::{
         ClassDefinition, ClassElement, ClassElementName, ClassField, ClassGetter, ClassMethod,
-        ClassSetter,
+        ClassSetter, ClassStaticBlock,
       },
-      expression::Expression,
+      expression::{Expression, MemberExpression, MemberName},
+      function::FormalParameters,
+      op::{AssignmentOp, AssignmentOpExpression},
+      statement::{Block, ExpressionStatement, Statement},
+      string::JsStr,
+      Identifier, PropertyKey,
     },
     error::{ParseError, ParseErrorCode},
     lexer::token::{Name, Token},
@@ -16,106 +21,163 @@ use crate::{
   },
 };
 
+#[cfg(feature = "decorators")]
+use crate::parser::ast::class::ClassAccessor;
+
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_class_expression(&mut self) -> Result<Expression<'a>, ParseError> {
+    let definition = self.read_class_definition(true)?;
+    Ok(Expression::Class(definition))
+  }
+
+  pub fn read_class_declaration(&mut self) -> Result<Statement<'a>, ParseError> {
+    let definition = self.read_class_definition(false)?;
+    Ok(Statement::Class(definition))
+  }
+
+  fn read_class_definition(
+    &mut self,
+    expression: bool,
+  ) -> Result<&'a ClassDefinition<'a>, ParseError> {
+    let start = self.context.token_start;
+    let decorators = self.read_decorators()?;
+    required_token!(self, Token::Name(Name::Class));
+
+    // all of a class is strict code, from its name on. the token after the body is read with the
+    // strictness around the class again
+    let strict_mode = self.context.flags.strict_mode;
+    self.context.flags.strict_mode = true;
+
     let identifier = match &self.context.token {
-      Token::Name(Name::Extends) | Token::LeftCurlyBracket => None,
+      Token::Name(Name::Extends) | Token::LeftCurlyBracket if expression => None,
+      Token::LessThan if expression => None,
+      _ if expression && self.typescript && self.is_contextual_name("implements") => None,
       _ => Some(self.read_binding_identifier()?),
     };
 
+    self.skip_type_parameters()?;
+
     let heritage = match &self.context.token {
-      Token::LeftCurlyBracket => None,
       Token::Name(Name::Extends) => {
         self.next_token()?;
-        Some(
-          self
-            .read_left_hand_side_expression()?
-            .ok_or(syntax_err!())?,
-        )
+        let heritage = self
+          .read_left_hand_side_expression()?
+          .ok_or(syntax_err!())?;
+
+        if self.typescript && matches!(self.context.token, Token::LessThan) {
+          self.skip_type_arguments()?;
+        }
+
+        Some(heritage)
       }
-      _ => return Err(syntax_err!()),
+      _ => None,
     };
 
+    if self.typescript && self.is_contextual_name("implements") {
+      self.next_token()?;
+      self.skip_type()?;
+
+      while let Token::Comma = &self.context.token {
+        self.next_token()?;
+        self.skip_type()?;
+      }
+    }
+
+    let body_start = self.context.token_start;
     required_token!(self, Token::LeftCurlyBracket);
 
-    let mut body = Vec::<ClassElement<'a>>::new_in(&self.allocator);
+    let mut body = Vec::<ClassElement<'a>>::new_in(self.allocator);
 
     loop {
       match &self.context.token {
         Token::RightCurlyBracket => {
+          self.context.flags.strict_mode = strict_mode;
           self.next_token()?;
           break;
         }
         Token::Semicolon => {
           self.next_token()?;
         }
-        Token::Name(Name::Static) => {
-          self.next_token()?;
-          match &self.context.token {
-            Token::LeftCurlyBracket => {
-              todo!()
-            }
-            Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket => {
-              body.push(self.read_field_definition(ClassElementName::Static("static"), false)?)
-            }
-            _ => body.push(self.read_field_or_method_definition(true)?),
+        _ => {
+          if let Some(element) = self.read_field_or_method_definition()? {
+            body.push(element);
           }
         }
-        _ => body.push(self.read_field_or_method_definition(false)?),
       }
     }
 
     let definition = ClassDefinition {
       body,
+      body_span: self.span_from(body_start),
+      decorators,
       heritage,
       identifier,
+      span: self.span_from(start),
     };
 
-    Ok(Expression::Class(self.allocator.alloc(definition)))
+    Ok(self.allocator.alloc(definition))
   }
 
-  fn read_field_or_method_definition(
-    &mut self,
-    r#static: bool,
-  ) -> Result<ClassElement<'a>, ParseError> {
+  // typescript members that only exist for the type checker, like abstract and overloaded
+  // methods or index signatures, are read and left out
+  fn read_field_or_method_definition(&mut self) -> Result<Option<ClassElement<'a>>, ParseError> {
+    let start = self.context.token_start;
+    let decorators = self.read_decorators()?;
+    let mut erased = self.read_member_modifiers()?;
+
+    let r#static = match &self.context.token {
+      Token::Name(Name::Static) => {
+        let span = self.token_span();
+        self.next_token()?;
+        match &self.context.token {
+          Token::LeftCurlyBracket => {
+            if !decorators.is_empty() {
+              return Err(syntax_err!());
+            }
+
+            return Ok(Some(self.read_class_static_block(start)?));
+          }
+          _ if self.is_element_name_end() => {
+            let name = ClassElementName::Static(PropertyKey {
+              name: JsStr::from("static"),
+              span,
+            });
+            return self.read_field_definition(name, false, decorators, erased, start);
+          }
+          _ => true,
+        }
+      }
+      _ => false,
+    };
+
+    erased |= self.read_member_modifiers()?;
+
+    if self.typescript && self.try_skip_index_signature()? {
+      self.auto_semicolon()?;
+      return Ok(None);
+    }
+
     let element = match &self.context.token {
       Token::Asterisk => {
         self.next_token()?;
         let name = self.read_element_name()?;
-        let parameters = self.read_formal_parameters()?;
-        let body = self.read_function_body()?;
-        let definition = ClassMethod {
-          r#async: false,
-          body,
-          generator: true,
-          name,
-          parameters,
-          r#static,
-        };
-
-        ClassElement::Method(self.allocator.alloc(definition))
+        self.read_method_definition(name, false, true, r#static, decorators, start)?
       }
       Token::Name(Name::Async) => {
+        let span = self.token_span();
         self.next_token()?;
         match &self.context.token {
-          Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket => {
-            self.read_field_definition(ClassElementName::Static("async"), r#static)?
+          _ if self.is_element_name_end() => {
+            let name = ClassElementName::Static(PropertyKey {
+              name: JsStr::from("async"),
+              span,
+            });
+            return self.read_field_definition(name, r#static, decorators, erased, start);
           }
           Token::Asterisk if !self.context.line_terminator => {
             self.next_token()?;
             let name = self.read_element_name()?;
-            let parameters = self.read_formal_parameters()?;
-            let body = self.read_function_body()?;
-            let definition = ClassMethod {
-              r#async: true,
-              body,
-              generator: true,
-              name,
-              parameters,
-              r#static,
-            };
-
-            ClassElement::Method(self.allocator.alloc(definition))
+            self.read_method_definition(name, true, true, r#static, decorators, start)?
           }
           _ => {
             if self.context.line_terminator {
@@ -123,75 +185,299 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             }
 
             let name = self.read_element_name()?;
-            let parameters = self.read_formal_parameters()?;
-            let body = self.read_function_body()?;
-            let definition = ClassMethod {
-              r#async: true,
-              body,
-              generator: false,
-              name,
-              parameters,
-              r#static,
-            };
-
-            ClassElement::Method(self.allocator.alloc(definition))
+            self.read_method_definition(name, true, false, r#static, decorators, start)?
           }
         }
       }
       Token::Name(Name::Get) => {
+        let span = self.token_span();
         self.next_token()?;
         match &self.context.token {
-          Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket => {
-            self.read_field_definition(ClassElementName::Static("get"), r#static)?
+          _ if self.is_element_name_end() => {
+            let name = ClassElementName::Static(PropertyKey {
+              name: JsStr::from("get"),
+              span,
+            });
+            return self.read_field_definition(name, r#static, decorators, erased, start);
           }
           _ => {
             let name = self.read_element_name()?;
-            required_token!(self, Token::LeftParenthesis);
-            required_token!(self, Token::RightParenthesis);
-            let body = self.read_function_body()?;
-            let definition = ClassGetter {
-              body,
-              name,
-              r#static,
-            };
-
-            ClassElement::Getter(self.allocator.alloc(definition))
+            let (parameters_span, body) = self.read_getter_signature()?;
+            body.map(|body| {
+              let definition = ClassGetter {
+                body,
+                decorators,
+                name,
+                parameters_span,
+                span: self.span_from(start),
+                r#static,
+              };
+
+              ClassElement::Getter(self.allocator.alloc(definition))
+            })
           }
         }
       }
       Token::Name(Name::Set) => {
+        let span = self.token_span();
         self.next_token()?;
         match &self.context.token {
-          Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket => {
-            self.read_field_definition(ClassElementName::Static("set"), r#static)?
+          _ if self.is_element_name_end() => {
+            let name = ClassElementName::Static(PropertyKey {
+              name: JsStr::from("set"),
+              span,
+            });
+            return self.read_field_definition(name, r#static, decorators, erased, start);
           }
           _ => {
             let name = self.read_element_name()?;
-            required_token!(self, Token::LeftParenthesis);
-            let parameter = self.read_binding_pattern_with_initializer()?;
-            required_token!(self, Token::RightParenthesis);
-            let body = self.read_function_body()?;
-            let definition = ClassSetter {
-              body,
-              name,
-              parameter,
-              r#static,
-            };
-
-            ClassElement::Setter(self.allocator.alloc(definition))
+            let (parameter, parameters_span, body) = self.read_setter_signature()?;
+            body.map(|body| {
+              let definition = ClassSetter {
+                body,
+                decorators,
+                name,
+                parameter,
+                parameters_span,
+                span: self.span_from(start),
+                r#static,
+              };
+
+              ClassElement::Setter(self.allocator.alloc(definition))
+            })
           }
         }
       }
+      #[cfg(feature = "decorators")]
+      Token::Name(Name::Unclassified(name)) if name == "accessor" => {
+        let span = self.token_span();
+        self.next_token()?;
+        let is_field = self.context.line_terminator
+          || matches!(
+            self.context.token,
+            Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket | Token::Semicolon
+          );
+
+        if is_field {
+          let name = ClassElementName::Static(PropertyKey {
+            name: JsStr::from("accessor"),
+            span,
+          });
+          return self.read_field_definition(name, r#static, decorators, erased, start);
+        }
+
+        let name = self.read_element_name()?;
+        self.skip_type_annotation()?;
+        let value = self.read_class_field_initializer()?;
+
+        self.auto_semicolon()?;
+        let definition = ClassAccessor {
+          decorators,
+          name,
+          span: self.span_from(start),
+          r#static,
+          value,
+        };
+
+        Some(ClassElement::Accessor(self.allocator.alloc(definition)))
+      }
       _ => {
         let name = self.read_element_name()?;
-        self.read_field_definition(name, r#static)?
+        return self.read_field_definition(name, r#static, decorators, erased, start);
+      }
+    };
+
+    Ok(element.filter(|_| !erased))
+  }
+
+  // an initializer runs like a method of its own. `await` and `yield` stay reserved where they were,
+  // but there's no function around it to await or yield in
+  fn read_class_field_initializer(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    if !matches!(self.context.token, Token::Equals) {
+      return Ok(None);
+    }
+
+    let flags = self.enter_function(
+      self.context.flags.param_await,
+      self.context.flags.param_yield,
+    );
+    self.context.flags.param_return = false;
+    self.context.flags.field_initializer = true;
+    self.next_token()?;
+    let value = self.read_assignment_expression();
+    self.exit_function(flags);
+
+    Ok(Some(value?.ok_or(syntax_err!())?))
+  }
+
+  // the current token is the `{` after `static`
+  fn read_class_static_block(&mut self, start: usize) -> Result<ClassElement<'a>, ParseError> {
+    // a static block is like a function body where `return` isn't allowed, and where `await` is
+    // reserved but can't be used, the same as in parameters
+    let flags = self.enter_function(true, false);
+    self.context.flags.param_return = false;
+    self.context.flags.formal_parameters = true;
+    let block = self.read_block_statement();
+    self.exit_function(flags);
+
+    let block = ClassStaticBlock {
+      span: self.span_from(start),
+      statements: block?.statements,
+    };
+
+    Ok(ClassElement::Static(self.allocator.alloc(block)))
+  }
+
+  // typescript's member modifiers, tells if one of them removes the member from the program
+  fn read_member_modifiers(&mut self) -> Result<bool, ParseError> {
+    let mut erased = false;
+
+    while self.typescript {
+      let modifier = match &self.context.token {
+        Token::Name(Name::Unclassified(name)) => match name.as_str() {
+          "public" | "private" | "protected" | "readonly" | "override" => false,
+          "abstract" | "declare" => true,
+          _ => break,
+        },
+        _ => break,
+      };
+
+      let snapshot = self.context.clone();
+      self.next_token()?;
+
+      // it was the name of the element instead
+      if self.context.line_terminator || self.is_element_name_end() {
+        self.context = snapshot;
+        break;
       }
+
+      erased |= modifier;
+    }
+
+    Ok(erased)
+  }
+
+  // after `static`, `async`, `get`, `set` and the modifiers, tells if the word was the name of the
+  // element
+  fn is_element_name_end(&self) -> bool {
+    match &self.context.token {
+      Token::Equals | Token::LeftParenthesis | Token::RightCurlyBracket => true,
+      Token::Colon
+      | Token::QuestionMark
+      | Token::Exclamation
+      | Token::LessThan
+      | Token::Semicolon => self.typescript,
+      _ => false,
+    }
+  }
+
+  fn read_method_definition(
+    &mut self,
+    name: ClassElementName<'a>,
+    r#async: bool,
+    generator: bool,
+    r#static: bool,
+    decorators: Vec<'a, Expression<'a>>,
+    start: usize,
+  ) -> Result<Option<ClassElement<'a>>, ParseError> {
+    let (parameters, body) = match name {
+      ClassElementName::Static(PropertyKey { name, .. })
+        if name == "constructor" && !r#static && self.typescript =>
+      {
+        self.read_constructor_parameters_and_body()?
+      }
+      _ => self.read_method_signature(r#async, generator)?,
+    };
+
+    let Some(body) = body else {
+      return Ok(None);
+    };
+
+    let definition = ClassMethod {
+      r#async,
+      body,
+      decorators,
+      generator,
+      name,
+      parameters,
+      span: self.span_from(start),
+      r#static,
+    };
+
+    Ok(Some(ClassElement::Method(self.allocator.alloc(definition))))
+  }
+
+  // typescript parameter properties like `constructor(private x)` become `this.x = x;` at the start
+  // of the body, or right after the `super()` call if there is one
+  fn read_constructor_parameters_and_body(
+    &mut self,
+  ) -> Result<(FormalParameters<'a>, Option<Block<'a>>), ParseError> {
+    let flags = self.enter_function(false, false);
+    let mut properties = std::vec::Vec::new();
+    let parameters = self.read_formal_parameter_list(Some(&mut properties))?;
+    let body = self.read_optional_function_body()?;
+    self.exit_function(flags);
+
+    if let Some(body) = &body {
+      self.check_use_strict_function(
+        flags.strict_mode,
+        None,
+        &parameters.bindings,
+        parameters.rest.is_some(),
+        body,
+      )?;
+    }
+
+    let Some(mut body) = body else {
+      return Ok((parameters, None));
     };
 
-    Ok(element)
+    let position = body
+      .statements
+      .iter()
+      .position(|statement| match statement {
+        Statement::Expression(statement) => match statement.expression {
+          Expression::Call(call) => matches!(call.callee, Expression::Super(_)),
+          _ => false,
+        },
+        _ => false,
+      })
+      .map_or(0, |index| index + 1);
+
+    // the added statements take the span of their parameter
+    for (index, property) in properties.into_iter().enumerate() {
+      let span = property.span;
+      let member = MemberExpression {
+        object: Expression::This(span),
+        optional: false,
+        property: MemberName::Static(property),
+        span,
+      };
+
+      let assignment = AssignmentOpExpression {
+        left: Expression::Member(self.allocator.alloc(member)),
+        op: AssignmentOp::Assignment,
+        right: Expression::Identifier(self.allocator.alloc(property)),
+        span,
+      };
+
+      let statement = ExpressionStatement {
+        expression: Expression::Assignment(self.allocator.alloc(assignment)),
+        span,
+      };
+
+      body.statements.insert(
+        position + index,
+        Statement::Expression(self.allocator.alloc(statement)),
+      );
+    }
+
+    Ok((parameters, Some(body)))
   }
 
   fn read_element_name(&mut self) -> Result<ClassElementName<'a>, ParseError> {
+    let start = self.context.token_start;
+    let span = self.token_span();
     let name = match &self.context.token {
       Token::NumberSign => {
         self.next_token()?;
@@ -199,30 +485,39 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           Token::Name(name) => {
             let name = self.allocator.alloc_str(name.as_string());
             self.next_token()?;
-            ClassElementName::Private(name)
+            let span = self.span_from(start);
+            ClassElementName::Private(Identifier { name, span })
           }
           _ => return Err(syntax_err!()),
         }
       }
       Token::Name(name) => {
-        let name = self.allocator.alloc_str(name.as_string());
+        let name = JsStr::from(&*self.allocator.alloc_str(name.as_string()));
         self.next_token()?;
-        ClassElementName::Static(name)
+        ClassElementName::Static(PropertyKey { name, span })
       }
       Token::StringLiteral(string_literal) => {
-        let name = self.allocator.alloc_str(&string_literal);
+        let name = string_literal.alloc_in(self.allocator);
         self.next_token()?;
-        ClassElementName::Static(name)
+        ClassElementName::Static(PropertyKey { name, span })
       }
       Token::NumberLiteral(number_literal) => {
         let name = self
           .allocator
           .alloc_str(&es_number_to_string(*number_literal, 10));
         self.next_token()?;
-        ClassElementName::Static(name)
+        ClassElementName::Static(PropertyKey {
+          name: JsStr::from(&*name),
+          span,
+        })
       }
-      Token::BigIntLiteral(_) => {
-        todo!()
+      Token::BigIntLiteral(bigint_literal) => {
+        let name = self.allocator.alloc_str(&bigint_literal.to_string());
+        self.next_token()?;
+        ClassElementName::Static(PropertyKey {
+          name: JsStr::from(&*name),
+          span,
+        })
       }
       Token::LeftSquareBracket => {
         self.next_token()?;
@@ -240,48 +535,50 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     &mut self,
     name: ClassElementName<'a>,
     r#static: bool,
-  ) -> Result<ClassElement<'a>, ParseError> {
-    let element = match &self.context.token {
-      Token::Equals => {
+    decorators: Vec<'a, Expression<'a>>,
+    erased: bool,
+    start: usize,
+  ) -> Result<Option<ClassElement<'a>>, ParseError> {
+    // optional and definitely assigned members, like `x?: T` and `x!: T`
+    if self.typescript {
+      if let Token::QuestionMark | Token::Exclamation = &self.context.token {
         self.next_token()?;
-        let value = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-        let definition = ClassField {
-          name,
-          r#static,
-          value: Some(value),
-        };
-
-        self.auto_semicolon()?;
-
-        ClassElement::Field(self.allocator.alloc(definition))
       }
-      Token::LeftParenthesis => {
-        self.next_token()?;
-        let parameters = self.read_formal_parameters()?;
-        let body = self.read_function_body()?;
-        let definition = ClassMethod {
-          r#async: false,
-          body,
-          generator: false,
-          name,
-          parameters,
-          r#static,
-        };
+    }
 
-        ClassElement::Method(self.allocator.alloc(definition))
+    let element = match &self.context.token {
+      Token::LeftParenthesis | Token::LessThan => {
+        // the constructor can't be decorated
+        if !r#static
+          && !decorators.is_empty()
+          && matches!(
+            name,
+            ClassElementName::Static(PropertyKey { name, .. }) if name == "constructor"
+          )
+        {
+          return Err(syntax_err!());
+        }
+
+        self.read_method_definition(name, false, false, r#static, decorators, start)?
       }
       _ => {
+        self.skip_type_annotation()?;
+
+        let value = self.read_class_field_initializer()?;
+
         self.auto_semicolon()?;
         let definition = ClassField {
+          decorators,
           name,
+          span: self.span_from(start),
           r#static,
-          value: None,
+          value,
         };
 
-        ClassElement::Field(self.allocator.alloc(definition))
+        Some(ClassElement::Field(self.allocator.alloc(definition)))
       }
     };
 
-    Ok(element)
+    Ok(element.filter(|_| !erased))
   }
 }
@@ -0,0 +1,74 @@
+use bumpalo::collections::Vec;
+
+use crate::parser::{ast::expression::Expression, error::ParseError, Parser};
+
+#[cfg(feature = "decorators")]
+use crate::parser::{
+  ast::expression::{CallExpression, GroupExpression},
+  error::ParseErrorCode,
+  lexer::token::Token,
+  required_token, syntax_err,
+};
+
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  #[cfg(feature = "decorators")]
+  pub fn read_decorators(&mut self) -> Result<Vec<'a, Expression<'a>>, ParseError> {
+    let mut decorators = Vec::<Expression<'a>>::new_in(self.allocator);
+    if !self.decorators {
+      return Ok(decorators);
+    }
+
+    while let Token::CommercialAt = &self.context.token {
+      self.next_token()?;
+      decorators.push(self.read_decorator()?);
+    }
+
+    Ok(decorators)
+  }
+
+  #[cfg(not(feature = "decorators"))]
+  pub fn read_decorators(&mut self) -> Result<Vec<'a, Expression<'a>>, ParseError> {
+    Ok(Vec::new_in(self.allocator))
+  }
+
+  // decorators only take a parenthesized expression, or a chain of static member accesses that can
+  // end with a call
+  #[cfg(feature = "decorators")]
+  fn read_decorator(&mut self) -> Result<Expression<'a>, ParseError> {
+    let start = self.context.token_start;
+    if let Token::LeftParenthesis = &self.context.token {
+      self.next_token()?;
+      let param_in = self.context.flags.param_in;
+      self.context.flags.param_in = true;
+      let expression = self.read_expression()?.ok_or(syntax_err!())?;
+      self.context.flags.param_in = param_in;
+      required_token!(self, Token::RightParenthesis);
+      let group = GroupExpression {
+        expression,
+        span: self.span_from(start),
+      };
+
+      return Ok(Expression::Group(self.allocator.alloc(group)));
+    }
+
+    let mut expression = self.read_identifier_reference()?.ok_or(syntax_err!())?;
+
+    while let Token::FullStop = &self.context.token {
+      self.next_token()?;
+      expression = self.read_static_member(expression, false)?;
+    }
+
+    if let Token::LeftParenthesis = &self.context.token {
+      let arguments = self.read_arguments()?;
+      let call = CallExpression {
+        arguments,
+        callee: expression,
+        optional: false,
+        span: self.span_from(start),
+      };
+      expression = Expression::Call(self.allocator.alloc(call));
+    }
+
+    Ok(expression)
+  }
+}
@@ -2,18 +2,49 @@ use bumpalo::{collections::Vec, vec};
 
 use crate::parser::{
   ast::{
-    binding::{BindingPattern, BindingPatternInitializer},
-    expression::Expression,
-    function::{Argument, ArrowFunctionDefinition, FormalParameters, FunctionDefinition},
-    statement::Statement,
+    binding::{BindingPattern, BindingPatternInitializer, BindingRestElement},
+    expression::{Expression, SpreadElement},
+    function::{
+      Argument, ArrowFunctionDefinition, ConciseBody, FormalParameters, FunctionDefinition,
+    },
+    statement::{Block, Statement},
+    Identifier, Span,
   },
   error::{ParseError, ParseErrorCode},
-  lexer::token::Token,
+  lexer::token::{Name, Token},
   required_token, syntax_err, Parser,
 };
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
-  pub fn read_function_expression(&mut self, r#async: bool) -> Result<Expression<'a>, ParseError> {
+  // the `function` keyword has been read already, `start` is where it or the `async` before it
+  // starts
+  pub fn read_function_expression(
+    &mut self,
+    r#async: bool,
+    start: usize,
+  ) -> Result<Expression<'a>, ParseError> {
+    let definition = self.read_function_definition(r#async, true, start)?;
+    Ok(Expression::Function(definition.ok_or(syntax_err!())?))
+  }
+
+  pub fn read_function_declaration(
+    &mut self,
+    r#async: bool,
+    start: usize,
+  ) -> Result<Statement<'a>, ParseError> {
+    match self.read_function_definition(r#async, false, start)? {
+      Some(definition) => Ok(Statement::Function(definition)),
+      // typescript overload signatures have no body and leave nothing behind
+      None => Ok(Statement::Empty(self.span_from(start))),
+    }
+  }
+
+  fn read_function_definition(
+    &mut self,
+    r#async: bool,
+    expression: bool,
+    start: usize,
+  ) -> Result<Option<&'a FunctionDefinition<'a>>, ParseError> {
     let generator = match &self.context.token {
       Token::Asterisk => {
         self.next_token()?;
@@ -23,10 +54,21 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     };
 
     let identifier = match &self.context.token {
-      Token::LeftParenthesis => None,
+      Token::LeftParenthesis | Token::LessThan if expression => None,
       _ => {
-        let identifier = self.read_binding_identifier()?;
-        if self.context.flags.strict_mode && matches!(identifier, "eval" | "arguments") {
+        // the name of a function expression is bound inside of it, so it follows the function's
+        // own await/yield parameters, while a declaration's name follows the enclosing ones
+        let flags = self.context.flags;
+        if expression {
+          self.context.flags.param_await = r#async;
+          self.context.flags.param_yield = generator;
+        }
+
+        let identifier = self.read_binding_identifier();
+        self.exit_function(flags);
+        let identifier = identifier?;
+
+        if self.context.flags.strict_mode && matches!(identifier.name, "eval" | "arguments") {
           return Err(syntax_err!());
         }
 
@@ -34,23 +76,57 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
     };
 
+    let flags = self.enter_function(r#async, generator);
+    self.skip_type_parameters()?;
     let parameters = self.read_formal_parameters()?;
-    let body = self.read_function_body()?;
+    self.skip_return_type_annotation()?;
+    let body = match expression {
+      true => Some(self.read_function_body()?),
+      false => self.read_optional_function_body()?,
+    };
+    self.exit_function(flags);
+
+    let Some(body) = body else {
+      return Ok(None);
+    };
+
+    self.check_use_strict_function(
+      flags.strict_mode,
+      identifier.as_ref(),
+      &parameters.bindings,
+      parameters.rest.is_some(),
+      &body,
+    )?;
+
     let definition = FunctionDefinition {
       r#async,
       body,
       generator,
       identifier,
       parameters,
+      span: self.span_from(start),
     };
 
-    Ok(Expression::Function(self.allocator.alloc(definition)))
+    Ok(Some(self.allocator.alloc(definition)))
   }
 
   pub fn read_formal_parameters(&mut self) -> Result<FormalParameters<'a>, ParseError> {
+    self.read_formal_parameter_list(None)
+  }
+
+  // typescript parameter properties, like the `private x` of `constructor(private x)`, are only
+  // allowed when there is somewhere to put their names
+  pub fn read_formal_parameter_list(
+    &mut self,
+    mut parameter_properties: Option<&mut std::vec::Vec<Identifier<'a>>>,
+  ) -> Result<FormalParameters<'a>, ParseError> {
+    let start = self.context.token_start;
     required_token!(self, Token::LeftParenthesis);
 
-    let mut bindings = Vec::<BindingPatternInitializer<'a>>::new_in(&self.allocator);
+    let formal_parameters = self.context.flags.formal_parameters;
+    self.context.flags.formal_parameters = true;
+
+    let mut bindings = Vec::<BindingPatternInitializer<'a>>::new_in(self.allocator);
     let rest = loop {
       match &self.context.token {
         Token::RightParenthesis => {
@@ -58,13 +134,47 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           break None;
         }
         Token::TripleStop => {
+          let rest_start = self.context.token_start;
           self.next_token()?;
           let pattern = self.read_binding_pattern()?;
+          let rest = BindingRestElement {
+            pattern,
+            span: Span::new(rest_start, pattern.span().end as usize),
+          };
+
+          self.skip_type_annotation()?;
           required_token!(self, Token::RightParenthesis);
-          break Some(pattern);
+          break Some(rest);
+        }
+        // typescript's `this` parameter only declares the type of `this`
+        Token::Name(Name::This) if self.typescript && bindings.is_empty() => {
+          self.next_token()?;
+          self.skip_type_annotation()?;
+
+          match &self.context.token {
+            Token::Comma => self.next_token()?,
+            Token::RightParenthesis => {}
+            _ => return Err(syntax_err!()),
+          }
         }
         _ => {
-          let pattern = self.read_binding_pattern_with_initializer()?;
+          let mut is_property = false;
+          while self.typescript && self.is_parameter_property_modifier()? {
+            is_property = true;
+            self.next_token()?;
+          }
+
+          let pattern = self.read_declared_binding()?;
+
+          if is_property {
+            match (&mut parameter_properties, pattern.pattern) {
+              (Some(properties), BindingPattern::Identifier(identifier)) => {
+                properties.push(*identifier)
+              }
+              _ => return Err(syntax_err!()),
+            }
+          }
+
           bindings.push(pattern);
 
           match &self.context.token {
@@ -79,16 +189,150 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
     };
 
-    Ok(FormalParameters { bindings, rest })
+    self.context.flags.formal_parameters = formal_parameters;
+    Ok(FormalParameters {
+      bindings,
+      rest,
+      span: self.span_from(start),
+    })
+  }
+
+  // `public`, `private`, `protected`, `readonly` and `override` are modifiers when a binding
+  // follows them
+  fn is_parameter_property_modifier(&mut self) -> Result<bool, ParseError> {
+    let is_modifier = match &self.context.token {
+      Token::Name(Name::Unclassified(name)) => {
+        matches!(
+          name.as_str(),
+          "public" | "private" | "protected" | "readonly" | "override"
+        ) && matches!(
+          self.peek_token()?,
+          Token::Name(_) | Token::LeftCurlyBracket | Token::LeftSquareBracket
+        )
+      }
+      _ => false,
+    };
+
+    Ok(is_modifier)
+  }
+
+  pub fn read_method_parameters_and_body(
+    &mut self,
+    r#async: bool,
+    generator: bool,
+  ) -> Result<(FormalParameters<'a>, Block<'a>), ParseError> {
+    let (parameters, body) = self.read_method_signature(r#async, generator)?;
+    Ok((parameters, body.ok_or(syntax_err!())?))
+  }
+
+  // typescript class members can leave out the body, for overloads and abstract members
+  pub fn read_method_signature(
+    &mut self,
+    r#async: bool,
+    generator: bool,
+  ) -> Result<(FormalParameters<'a>, Option<Block<'a>>), ParseError> {
+    let flags = self.enter_function(r#async, generator);
+    self.skip_type_parameters()?;
+    let parameters = self.read_formal_parameters()?;
+    self.skip_return_type_annotation()?;
+    let body = self.read_optional_function_body()?;
+    self.exit_function(flags);
+
+    if let Some(body) = &body {
+      self.check_use_strict_function(
+        flags.strict_mode,
+        None,
+        &parameters.bindings,
+        parameters.rest.is_some(),
+        body,
+      )?;
+    }
+
+    Ok((parameters, body))
+  }
+
+  // getters and setters also return the span of their parentheses
+  pub fn read_getter_body(&mut self) -> Result<(Span, Block<'a>), ParseError> {
+    let (parameters_span, body) = self.read_getter_signature()?;
+    Ok((parameters_span, body.ok_or(syntax_err!())?))
+  }
+
+  pub fn read_getter_signature(&mut self) -> Result<(Span, Option<Block<'a>>), ParseError> {
+    let flags = self.enter_function(false, false);
+    let start = self.context.token_start;
+    required_token!(self, Token::LeftParenthesis);
+    required_token!(self, Token::RightParenthesis);
+    let parameters_span = self.span_from(start);
+    self.skip_return_type_annotation()?;
+    let body = self.read_optional_function_body()?;
+    self.exit_function(flags);
+    Ok((parameters_span, body))
   }
 
-  pub fn read_function_body(&mut self) -> Result<Vec<'a, Statement<'a>>, ParseError> {
-    self.read_block_statement()
+  pub fn read_setter_parameter_and_body(
+    &mut self,
+  ) -> Result<(BindingPatternInitializer<'a>, Span, Block<'a>), ParseError> {
+    let (parameter, parameters_span, body) = self.read_setter_signature()?;
+    Ok((parameter, parameters_span, body.ok_or(syntax_err!())?))
+  }
+
+  pub fn read_setter_signature(
+    &mut self,
+  ) -> Result<(BindingPatternInitializer<'a>, Span, Option<Block<'a>>), ParseError> {
+    let flags = self.enter_function(false, false);
+    let start = self.context.token_start;
+    required_token!(self, Token::LeftParenthesis);
+    self.context.flags.formal_parameters = true;
+    let parameter = self.read_declared_binding()?;
+    self.context.flags.formal_parameters = false;
+    required_token!(self, Token::RightParenthesis);
+    let parameters_span = self.span_from(start);
+    let body = self.read_optional_function_body()?;
+    self.exit_function(flags);
+
+    if let Some(body) = &body {
+      let bindings = [parameter];
+      self.check_use_strict_function(flags.strict_mode, None, &bindings, false, body)?;
+    }
+
+    Ok((parameter, parameters_span, body))
+  }
+
+  // the body's own directives can make it strict, the token after it is read with the strictness
+  // around the function again
+  pub fn read_function_body(&mut self) -> Result<Block<'a>, ParseError> {
+    let start = self.context.token_start;
+    required_token!(self, Token::LeftCurlyBracket);
+    let strict_mode = self.context.flags.strict_mode;
+    let mut statements = Vec::<Statement<'a>>::new_in(self.allocator);
+    self.read_directives(&mut statements)?;
+
+    while !matches!(self.context.token, Token::RightCurlyBracket) {
+      let statement = self.read_statement_list_item()?.ok_or(syntax_err!())?;
+      statements.push(statement);
+    }
+
+    self.context.flags.strict_mode = strict_mode;
+    self.next_token()?;
+
+    Ok(Block {
+      span: self.span_from(start),
+      statements,
+    })
+  }
+
+  pub fn read_optional_function_body(&mut self) -> Result<Option<Block<'a>>, ParseError> {
+    if self.typescript && !matches!(self.context.token, Token::LeftCurlyBracket) {
+      self.auto_semicolon()?;
+      return Ok(None);
+    }
+
+    Ok(Some(self.read_function_body()?))
   }
 
   pub fn read_arguments(&mut self) -> Result<Vec<'a, Argument<'a>>, ParseError> {
     required_token!(self, Token::LeftParenthesis);
-    let mut arguments = Vec::<Argument<'a>>::new_in(&self.allocator);
+    let mut arguments = Vec::<Argument<'a>>::new_in(self.allocator);
 
     loop {
       let argument = match &self.context.token {
@@ -96,11 +340,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           self.next_token()?;
           break;
         }
-        Token::TripleStop => {
-          self.next_token()?;
-          let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-          Argument::Spread(expression)
-        }
+        Token::TripleStop => Argument::Spread(self.read_spread_element()?),
         _ => {
           let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
           Argument::Positional(expression)
@@ -124,52 +364,112 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     Ok(arguments)
   }
 
+  // the current token is the `...`
+  pub fn read_spread_element(&mut self) -> Result<SpreadElement<'a>, ParseError> {
+    let start = self.context.token_start;
+    self.next_token()?;
+    let argument = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+
+    Ok(SpreadElement {
+      argument,
+      span: self.span_from(start),
+    })
+  }
+
+  // `start` is where the parameters or the `async` before them start
   pub fn read_arrow_function_expression(
     &mut self,
     r#async: bool,
+    start: usize,
   ) -> Result<Option<Expression<'a>>, ParseError> {
     let snapshot = self.context.clone();
 
+    // async arrow parameters are parsed as if they were already inside the function, so `await`
+    // is reserved in them, while plain arrow parameters keep the enclosing parameters
+    let flags = self.context.flags;
+    if r#async {
+      self.context.flags.param_await = true;
+    }
+
     let parameters = match &self.context.token {
-      Token::LeftParenthesis => match self.read_formal_parameters() {
-        Ok(parameters) => parameters,
-        Err(_) => {
-          self.context = snapshot;
-          return Ok(None);
+      Token::LeftParenthesis | Token::LessThan => {
+        match self
+          .skip_type_parameters()
+          .and_then(|_| self.read_formal_parameters())
+        {
+          Ok(parameters) => parameters,
+          Err(_) => {
+            self.context = snapshot;
+            return Ok(None);
+          }
         }
-      },
-      Token::Name(name) => match self.name_as_binding_identifier(name)? {
-        Some(identifier) => {
+      }
+      Token::Name(name) => match self.name_as_binding_identifier(name) {
+        Ok(Some(name)) => {
+          let span = self.token_span();
+          let identifier = self.allocator.alloc(Identifier { name, span });
           self.next_token()?;
+          let binding = BindingPatternInitializer {
+            initializer: None,
+            pattern: BindingPattern::Identifier(identifier),
+            span,
+          };
+
           FormalParameters {
-            bindings: vec![in &self.allocator; BindingPatternInitializer { initializer: None, pattern: BindingPattern::Identifier(identifier) }],
+            bindings: vec![in &self.allocator; binding],
             rest: None,
+            span,
           }
         }
-        None => return Ok(None),
+        _ => {
+          self.context = snapshot;
+          return Ok(None);
+        }
       },
-      _ => return Ok(None),
+      _ => {
+        self.context = snapshot;
+        return Ok(None);
+      }
     };
 
-    if self.context.line_terminator || !matches!(self.context.token, Token::FatArrow) {
+    self.exit_function(flags);
+
+    if (self.context.flags.arrow_return_type && self.skip_return_type_annotation().is_err())
+      || self.context.line_terminator
+      || !matches!(self.context.token, Token::FatArrow)
+    {
       self.context = snapshot;
       return Ok(None);
     }
 
     self.next_token()?;
 
+    let flags = self.enter_function(r#async, false);
     let body = match &self.context.token {
-      Token::LeftSquareBracket => self.read_function_body()?,
+      Token::LeftCurlyBracket => ConciseBody::Block(self.read_function_body()?),
       _ => {
-        let _expression = self.read_assignment_expression()?;
-        todo!()
+        self.context.flags.param_in = flags.param_in;
+        let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+        ConciseBody::Expression(expression)
       }
     };
+    self.exit_function(flags);
+
+    if let ConciseBody::Block(body) = &body {
+      self.check_use_strict_function(
+        flags.strict_mode,
+        None,
+        &parameters.bindings,
+        parameters.rest.is_some(),
+        body,
+      )?;
+    }
 
     let definition = ArrowFunctionDefinition {
       r#async,
       body,
       parameters,
+      span: self.span_from(start),
     };
 
     Ok(Some(Expression::ArrowFunction(
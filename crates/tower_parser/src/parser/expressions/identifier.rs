@@ -1,5 +1,5 @@
 use crate::parser::{
-  ast::{expression::Expression, SourceType},
+  ast::{expression::Expression, Identifier, SourceType},
   error::{ParseError, ParseErrorCode},
   lexer::token::{Name, Token},
   syntax_err, Parser,
@@ -9,9 +9,16 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_identifier_reference(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
     match &self.context.token {
       Token::Name(name) => match self.name_as_identifier_reference(name)? {
-        Some(string) => {
+        Some(name) => {
+          let identifier = Identifier {
+            name,
+            span: self.token_span(),
+          };
+
           self.next_token()?;
-          Ok(Some(Expression::Identifier(string)))
+          Ok(Some(Expression::Identifier(
+            self.allocator.alloc(identifier),
+          )))
         }
         None => Ok(None),
       },
@@ -79,13 +86,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         | "return" | "super" | "switch" | "this" | "throw" | "true" | "try" | "typeof" | "var"
         | "void" | "while" | "with" => None,
         string => {
-          if self.context.flags.strict_mode
-            && match string {
-              "implements" | "interface" | "let" | "package" | "private" | "protected"
-              | "public" | "static" | "yield" => true,
-              _ => false,
-            }
-          {
+          if self.context.flags.strict_mode && is_strict_mode_reserved_word(string) {
             return Err(syntax_err!());
           }
 
@@ -103,3 +104,19 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     Ok(string)
   }
 }
+
+// the words that are only reserved in strict mode code
+pub fn is_strict_mode_reserved_word(name: &str) -> bool {
+  matches!(
+    name,
+    "implements"
+      | "interface"
+      | "let"
+      | "package"
+      | "private"
+      | "protected"
+      | "public"
+      | "static"
+      | "yield"
+  )
+}
@@ -0,0 +1,341 @@
+use bumpalo::collections::Vec;
+
+use crate::parser::{
+  ast::{
+    expression::{Expression, StringLiteral},
+    jsx::{
+      JsxAttribute, JsxAttributeName, JsxAttributeValue, JsxChild, JsxElement, JsxElementName,
+      JsxExpressionContainer, JsxFragment, JsxMemberExpression, JsxNamedAttribute,
+      JsxNamespacedName, JsxText,
+    },
+    Identifier,
+  },
+  error::{ParseError, ParseErrorCode},
+  lexer::{jsx::JsxGoal, token::Token},
+  syntax_err, Parser,
+};
+
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  // the current token is the `<` that opens the element
+  pub fn read_jsx_expression(&mut self) -> Result<Expression<'a>, ParseError> {
+    let start = self.context.token_start;
+    self.next_jsx_token(Some(JsxGoal::Tag))?;
+
+    let expression = match self.read_jsx_element(start, None)? {
+      JsxChild::Element(element) => Expression::JsxElement(element),
+      JsxChild::Fragment(fragment) => Expression::JsxFragment(fragment),
+      _ => unreachable!(),
+    };
+
+    Ok(expression)
+  }
+
+  // reads the token after the current one with the given goal, the lexer goes back to the previous
+  // goal afterwards
+  fn next_jsx_token(&mut self, goal: Option<JsxGoal>) -> Result<(), ParseError> {
+    let previous_goal = self.context.flags.goal_jsx;
+    self.context.flags.goal_jsx = goal;
+    let result = self.next_token();
+    self.context.flags.goal_jsx = previous_goal;
+    result
+  }
+
+  // reads an element or fragment after its `<`, which is at `start`, `goal_after` is used for the
+  // token that follows it
+  fn read_jsx_element(
+    &mut self,
+    start: usize,
+    goal_after: Option<JsxGoal>,
+  ) -> Result<JsxChild<'a>, ParseError> {
+    if let Token::GreaterThan = &self.context.token {
+      self.next_jsx_token(Some(JsxGoal::Text))?;
+      let opening_span = self.span_from(start);
+      let (children, closing_start) = self.read_jsx_children()?;
+
+      if !matches!(self.context.token, Token::GreaterThan) {
+        return Err(syntax_err!());
+      }
+
+      self.next_jsx_token(goal_after)?;
+      let fragment = JsxFragment {
+        children,
+        closing_span: self.span_from(closing_start),
+        opening_span,
+        span: self.span_from(start),
+      };
+
+      return Ok(JsxChild::Fragment(self.allocator.alloc(fragment)));
+    }
+
+    let name = self.read_jsx_element_name()?;
+    let mut attributes = Vec::<JsxAttribute<'a>>::new_in(self.allocator);
+
+    loop {
+      match &self.context.token {
+        Token::JsxIdentifier(_) => attributes.push(self.read_jsx_attribute()?),
+        Token::LeftCurlyBracket => {
+          let container_start = self.context.token_start;
+          self.next_token()?;
+          if !matches!(self.context.token, Token::TripleStop) {
+            return Err(syntax_err!());
+          }
+
+          self.next_token()?;
+          let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+          let container = self.read_jsx_container_end(expression, container_start, JsxGoal::Tag)?;
+          attributes.push(JsxAttribute::Spread(container));
+        }
+        _ => break,
+      }
+    }
+
+    let (self_closing, opening_span, children, closing_span) = match &self.context.token {
+      Token::Solidus => {
+        self.next_jsx_token(Some(JsxGoal::Tag))?;
+        if !matches!(self.context.token, Token::GreaterThan) {
+          return Err(syntax_err!());
+        }
+
+        self.next_jsx_token(goal_after)?;
+        (
+          true,
+          self.span_from(start),
+          Vec::new_in(self.allocator),
+          None,
+        )
+      }
+      Token::GreaterThan => {
+        self.next_jsx_token(Some(JsxGoal::Text))?;
+        let opening_span = self.span_from(start);
+        let (children, closing_start) = self.read_jsx_children()?;
+        let closing_name = self.read_jsx_element_name()?;
+
+        if !jsx_element_names_equal(&name, &closing_name)
+          || !matches!(self.context.token, Token::GreaterThan)
+        {
+          return Err(syntax_err!());
+        }
+
+        self.next_jsx_token(goal_after)?;
+        let closing_span = self.span_from(closing_start);
+        (false, opening_span, children, Some(closing_span))
+      }
+      _ => return Err(syntax_err!()),
+    };
+
+    let element = JsxElement {
+      attributes,
+      children,
+      closing_span,
+      name,
+      opening_span,
+      self_closing,
+      span: self.span_from(start),
+    };
+
+    Ok(JsxChild::Element(self.allocator.alloc(element)))
+  }
+
+  // reads children up to and including the `</` of the closing tag, and tells where it starts
+  fn read_jsx_children(&mut self) -> Result<(Vec<'a, JsxChild<'a>>, usize), ParseError> {
+    let mut children = Vec::<JsxChild<'a>>::new_in(self.allocator);
+
+    let closing_start = loop {
+      let start = self.context.token_start;
+      let child = match &self.context.token {
+        Token::JsxText(raw, value) => {
+          let text = JsxText {
+            raw: self.allocator.alloc_str(raw),
+            span: self.token_span(),
+            value: self.allocator.alloc_str(value),
+          };
+
+          self.next_jsx_token(Some(JsxGoal::Text))?;
+          JsxChild::Text(self.allocator.alloc(text))
+        }
+        Token::LeftCurlyBracket => {
+          self.next_token()?;
+
+          match &self.context.token {
+            Token::RightCurlyBracket => {
+              self.next_jsx_token(Some(JsxGoal::Text))?;
+              JsxChild::Empty(self.span_from(start))
+            }
+            Token::TripleStop => {
+              self.next_token()?;
+              let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+              JsxChild::Spread(self.read_jsx_container_end(expression, start, JsxGoal::Text)?)
+            }
+            _ => {
+              let expression = self.read_expression()?.ok_or(syntax_err!())?;
+              JsxChild::Expression(self.read_jsx_container_end(expression, start, JsxGoal::Text)?)
+            }
+          }
+        }
+        Token::LessThan => {
+          self.next_jsx_token(Some(JsxGoal::Tag))?;
+
+          if let Token::Solidus = &self.context.token {
+            self.next_jsx_token(Some(JsxGoal::Tag))?;
+            break start;
+          }
+
+          self.read_jsx_element(start, Some(JsxGoal::Text))?
+        }
+        _ => return Err(syntax_err!()),
+      };
+
+      children.push(child);
+    };
+
+    Ok((children, closing_start))
+  }
+
+  // reads the `}` after the expression of a container that starts at `start`
+  fn read_jsx_container_end(
+    &mut self,
+    expression: Expression<'a>,
+    start: usize,
+    goal_after: JsxGoal,
+  ) -> Result<&'a JsxExpressionContainer<'a>, ParseError> {
+    if !matches!(self.context.token, Token::RightCurlyBracket) {
+      return Err(syntax_err!());
+    }
+
+    self.next_jsx_token(Some(goal_after))?;
+    let container = JsxExpressionContainer {
+      expression,
+      span: self.span_from(start),
+    };
+
+    Ok(self.allocator.alloc(container))
+  }
+
+  fn read_jsx_element_name(&mut self) -> Result<JsxElementName<'a>, ParseError> {
+    let identifier = self.read_jsx_identifier()?;
+
+    let name = match &self.context.token {
+      Token::Colon => {
+        self.next_jsx_token(Some(JsxGoal::Tag))?;
+        let name = self.read_jsx_identifier()?;
+        let name = JsxNamespacedName {
+          name,
+          namespace: identifier,
+          span: identifier.span.to(name.span),
+        };
+
+        JsxElementName::Namespaced(self.allocator.alloc(name))
+      }
+      _ => {
+        let mut name = JsxElementName::Identifier(identifier);
+
+        while let Token::FullStop = &self.context.token {
+          self.next_jsx_token(Some(JsxGoal::Tag))?;
+          let property = self.read_jsx_identifier()?;
+          let member = JsxMemberExpression {
+            object: name,
+            property,
+            span: name.span().to(property.span),
+          };
+
+          name = JsxElementName::Member(self.allocator.alloc(member));
+        }
+
+        name
+      }
+    };
+
+    Ok(name)
+  }
+
+  fn read_jsx_identifier(&mut self) -> Result<Identifier<'a>, ParseError> {
+    let name = match &self.context.token {
+      Token::JsxIdentifier(identifier) => self.allocator.alloc_str(identifier),
+      _ => return Err(syntax_err!()),
+    };
+
+    let span = self.token_span();
+    self.next_jsx_token(Some(JsxGoal::Tag))?;
+    Ok(Identifier { name, span })
+  }
+
+  fn read_jsx_attribute(&mut self) -> Result<JsxAttribute<'a>, ParseError> {
+    let start = self.context.token_start;
+    let identifier = self.read_jsx_identifier()?;
+
+    let name = match &self.context.token {
+      Token::Colon => {
+        self.next_jsx_token(Some(JsxGoal::Tag))?;
+        let name = self.read_jsx_identifier()?;
+        let name = JsxNamespacedName {
+          name,
+          namespace: identifier,
+          span: identifier.span.to(name.span),
+        };
+
+        JsxAttributeName::Namespaced(self.allocator.alloc(name))
+      }
+      _ => JsxAttributeName::Identifier(identifier),
+    };
+
+    let value = match &self.context.token {
+      Token::Equals => {
+        self.next_jsx_token(Some(JsxGoal::Tag))?;
+
+        let value_start = self.context.token_start;
+        let value = match &self.context.token {
+          Token::StringLiteral(string) => {
+            let literal = StringLiteral {
+              span: self.token_span(),
+              value: string.alloc_in(self.allocator),
+            };
+
+            self.next_jsx_token(Some(JsxGoal::Tag))?;
+            JsxAttributeValue::String(self.allocator.alloc(literal))
+          }
+          Token::LeftCurlyBracket => {
+            self.next_token()?;
+            let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+            let container = self.read_jsx_container_end(expression, value_start, JsxGoal::Tag)?;
+            JsxAttributeValue::Expression(container)
+          }
+          Token::LessThan => {
+            self.next_jsx_token(Some(JsxGoal::Tag))?;
+            match self.read_jsx_element(value_start, Some(JsxGoal::Tag))? {
+              JsxChild::Element(element) => JsxAttributeValue::Element(element),
+              JsxChild::Fragment(fragment) => JsxAttributeValue::Fragment(fragment),
+              _ => unreachable!(),
+            }
+          }
+          _ => return Err(syntax_err!()),
+        };
+
+        Some(value)
+      }
+      _ => None,
+    };
+
+    let attribute = JsxNamedAttribute {
+      name,
+      span: self.span_from(start),
+      value,
+    };
+    Ok(JsxAttribute::Attribute(self.allocator.alloc(attribute)))
+  }
+}
+
+fn jsx_element_names_equal(left: &JsxElementName, right: &JsxElementName) -> bool {
+  match (left, right) {
+    (JsxElementName::Identifier(left), JsxElementName::Identifier(right)) => {
+      left.name == right.name
+    }
+    (JsxElementName::Namespaced(left), JsxElementName::Namespaced(right)) => {
+      left.namespace.name == right.namespace.name && left.name.name == right.name.name
+    }
+    (JsxElementName::Member(left), JsxElementName::Member(right)) => {
+      left.property.name == right.property.name
+        && jsx_element_names_equal(&left.object, &right.object)
+    }
+    _ => false,
+  }
+}
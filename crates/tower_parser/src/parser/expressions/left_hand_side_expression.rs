@@ -1,5 +1,10 @@
 use crate::parser::{
-  ast::expression::{CallExpression, Expression, MemberExpression, MemberName, NewExpression},
+  ast::{
+    expression::{
+      CallExpression, Expression, ImportCall, MemberExpression, MemberName, NewExpression,
+    },
+    Identifier,
+  },
   error::{ParseError, ParseErrorCode},
   lexer::token::{Name, Token},
   required_token, syntax_err, Parser,
@@ -9,12 +14,16 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_left_hand_side_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
     // the idea here is to pick an expression to start from, then loop trough calls, optional chains
     // and member accesses until its over
+    let start = self.context.token_start;
     let mut expression = match &self.context.token {
       Token::Name(Name::Super) => {
+        let span = self.token_span();
         self.next_token()?;
         match &self.context.token {
           // The actual expression will be figured out in the second part of this function
-          Token::LeftParenthesis | Token::LeftSquareBracket | Token::FullStop => Expression::Super,
+          Token::LeftParenthesis | Token::LeftSquareBracket | Token::FullStop => {
+            Expression::Super(span)
+          }
           _ => return Err(syntax_err!()),
         }
       }
@@ -25,12 +34,17 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             self.next_token()?;
             let argument = self.read_assignment_expression()?.ok_or(syntax_err!())?;
             required_token!(self, Token::RightParenthesis);
-            Expression::Import(self.allocator.alloc(argument))
+            let expression = ImportCall {
+              argument,
+              span: self.span_from(start),
+            };
+
+            Expression::Import(self.allocator.alloc(expression))
           }
           Token::FullStop => {
             self.next_token()?;
             required_token!(self, Token::Name(Name::Meta));
-            Expression::ImportMeta
+            Expression::ImportMeta(self.span_from(start))
           }
           _ => return Err(syntax_err!()),
         }
@@ -41,7 +55,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           Token::FullStop => {
             self.next_token()?;
             required_token!(self, Token::Name(Name::Target));
-            Expression::NewTarget
+            Expression::NewTarget(self.span_from(start))
           }
           // new expressions need special treatment
           // if it has parenthesis, its a MemberExpression production, and the
@@ -50,7 +64,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           // is not accepted for CallExpression/OptionalExpression/MemberExpression,
           // so the second part of this function cant use it
           _ => {
-            let (expr, is_new_expr) = self.recurse_new_expression()?;
+            let (expr, is_new_expr) = self.recurse_new_expression(start)?;
             if is_new_expr {
               return Ok(Some(expr));
             } else {
@@ -65,10 +79,23 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       },
     };
 
+    // once an optional chain starts, everything until the end of this expression is part of it and
+    // gets short-circuited together, so the whole chain is wrapped to mark where it ends
+    let mut chain = false;
+
     loop {
       expression = match &self.context.token {
         Token::QuestionMarkStop => {
           self.next_token()?;
+          chain = true;
+
+          if self.typescript && matches!(self.context.token, Token::LessThan) {
+            self.skip_type_arguments()?;
+            if !matches!(self.context.token, Token::LeftParenthesis) {
+              return Err(syntax_err!());
+            }
+          }
+
           match &self.context.token {
             Token::LeftParenthesis => {
               let arguments = self.read_arguments()?;
@@ -76,13 +103,16 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
                 arguments,
                 callee: expression,
                 optional: true,
+                span: self.span_from(start),
               };
               Expression::Call(self.allocator.alloc(expression))
             }
-            _ => match self.read_member_access(expression, true)? {
-              Some(expr) => expr,
-              None => return Err(syntax_err!()),
-            },
+            Token::LeftSquareBracket => {
+              self.next_token()?;
+              self.read_computed_member(expression, true)?
+            }
+            Token::NumberSign | Token::Name(_) => self.read_static_member(expression, true)?,
+            _ => return Err(syntax_err!()),
           }
         }
         Token::LeftParenthesis => {
@@ -91,77 +121,117 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             arguments,
             callee: expression,
             optional: false,
+            span: self.span_from(start),
           };
           Expression::Call(self.allocator.alloc(expression))
         }
-        _ => match self.read_member_access(expression, false)? {
+        // typescript's type arguments, like `f<T>()`, and non-null assertions, like `x!`
+        Token::LessThan if self.typescript => match self.try_skip_type_arguments()? {
+          true => continue,
+          false => break,
+        },
+        Token::Exclamation if self.typescript && !self.context.line_terminator => {
+          self.next_token()?;
+          continue;
+        }
+        // a tagged template can't be part of an optional chain
+        Token::TemplateHead(_, _) | Token::NoSubstitutionTemplate(_, _) if chain => {
+          return Err(syntax_err!());
+        }
+        _ => match self.read_member_access(expression)? {
           Some(expr) => expr,
           None => break,
         },
       }
     }
 
+    if chain {
+      expression = Expression::Chain(self.allocator.alloc(expression));
+    }
+
     Ok(Some(expression))
   }
 
-  fn recurse_new_expression(&mut self) -> Result<(Expression<'a>, bool), ParseError> {
-    let result = match &self.context.token {
+  // reads what comes after a `new` token, and tells if it was a NewExpression (without arguments)
+  // instead of a MemberExpression, `start` is where the `new` starts
+  fn recurse_new_expression(&mut self, start: usize) -> Result<(Expression<'a>, bool), ParseError> {
+    let callee_start = self.context.token_start;
+    let mut callee = match &self.context.token {
       // If the token is `new`, we dont know if its a MemberExpression or NewExpression
       Token::Name(Name::New) => {
-        let (expr, is_new_expr) = self.recurse_new_expression()?;
-        // if the next expression is a NewExpression, this one is also a NewExpression
-        // and cant have parenthesis
-        if is_new_expr {
-          (expr, true)
-        } else {
-          match &self.context.token {
-            Token::LeftParenthesis => {
-              let arguments = self.read_arguments()?;
-              let expression = NewExpression {
-                arguments: Some(arguments),
-                callee: expr,
-              };
-
-              // If there are parenthesis, this is a MemberExpression
-              (Expression::New(self.allocator.alloc(expression)), false)
-            }
-            _ => {
+        self.next_token()?;
+        match &self.context.token {
+          Token::FullStop => {
+            self.next_token()?;
+            required_token!(self, Token::Name(Name::Target));
+            Expression::NewTarget(self.span_from(callee_start))
+          }
+          _ => {
+            let (expr, is_new_expr) = self.recurse_new_expression(callee_start)?;
+            // if the next expression is a NewExpression, this one is also a NewExpression
+            // and cant have parenthesis
+            if is_new_expr {
               let expression = NewExpression {
                 arguments: None,
                 callee: expr,
+                span: self.span_from(start),
               };
 
-              // if there arent parenthesis, this is a NewExpression
-              (Expression::New(self.allocator.alloc(expression)), true)
+              return Ok((Expression::New(self.allocator.alloc(expression)), true));
             }
+
+            expr
           }
         }
       }
       // but otherwise, its a member expression
       // this works like `read_left_hand_side_expression`, but only matches the MemberExpression production
-      _ => {
-        let mut expression = match &self.context.token {
-          Token::Name(Name::Import) => {
-            self.next_token()?;
-            required_token!(self, Token::FullStop);
-            required_token!(self, Token::Name(Name::Meta));
-            Expression::ImportMeta
-          }
-          Token::Name(Name::Super) => {
-            self.next_token()?;
-            match &self.context.token {
-              Token::LeftSquareBracket | Token::FullStop => Expression::Super,
-              _ => return Err(syntax_err!()),
-            }
-          }
-          _ => self.read_primary_expression()?.ok_or(syntax_err!())?,
+      Token::Name(Name::Import) => {
+        self.next_token()?;
+        required_token!(self, Token::FullStop);
+        required_token!(self, Token::Name(Name::Meta));
+        Expression::ImportMeta(self.span_from(callee_start))
+      }
+      Token::Name(Name::Super) => {
+        let span = self.token_span();
+        self.next_token()?;
+        match &self.context.token {
+          Token::LeftSquareBracket | Token::FullStop => Expression::Super(span),
+          _ => return Err(syntax_err!()),
+        }
+      }
+      _ => self.read_primary_expression()?.ok_or(syntax_err!())?,
+    };
+
+    while let Some(expr) = self.read_member_access(callee)? {
+      callee = expr;
+    }
+
+    if self.typescript && matches!(self.context.token, Token::LessThan) {
+      self.try_skip_type_arguments()?;
+    }
+
+    let result = match &self.context.token {
+      Token::LeftParenthesis => {
+        let arguments = self.read_arguments()?;
+        let expression = NewExpression {
+          arguments: Some(arguments),
+          callee,
+          span: self.span_from(start),
         };
 
-        while let Some(expr) = self.read_member_access(expression, false)? {
-          expression = expr;
-        }
+        // If there are parenthesis, this is a MemberExpression
+        (Expression::New(self.allocator.alloc(expression)), false)
+      }
+      _ => {
+        let expression = NewExpression {
+          arguments: None,
+          callee,
+          span: self.span_from(start),
+        };
 
-        (expression, false)
+        // if there arent parenthesis, this is a NewExpression
+        (Expression::New(self.allocator.alloc(expression)), true)
       }
     };
 
@@ -171,54 +241,77 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   fn read_member_access(
     &mut self,
     object: Expression<'a>,
-    optional: bool,
   ) -> Result<Option<Expression<'a>>, ParseError> {
     let expression = match &self.context.token {
       Token::LeftSquareBracket => {
         self.next_token()?;
-        let property_expr = self.read_expression()?.ok_or(syntax_err!())?;
-        let property = MemberName::Computed(property_expr);
-        required_token!(self, Token::RightSquareBracket);
-        let expression = MemberExpression {
-          object,
-          optional,
-          property,
-        };
-        Expression::Member(self.allocator.alloc(expression))
+        self.read_computed_member(object, false)?
       }
       Token::FullStop => {
         self.next_token()?;
+        self.read_static_member(object, false)?
+      }
+      Token::TemplateHead(_, _) | Token::NoSubstitutionTemplate(_, _) => {
+        self.read_tagged_template_literal(object)?
+      }
+      _ => return Ok(None),
+    };
 
-        let property = match &self.context.token {
-          Token::NumberSign => {
-            self.next_token()?;
-            let identifier = match &self.context.token {
-              Token::Name(name) => self.allocator.alloc_str(name.as_string()),
-              _ => return Err(syntax_err!()),
-            };
-            self.next_token()?;
-            MemberName::Private(identifier)
-          }
-          Token::Name(name) => {
-            let identifier = self.allocator.alloc_str(name.as_string());
-            self.next_token()?;
-            MemberName::Static(identifier)
-          }
-          _ => return Err(syntax_err!()),
-        };
+    Ok(Some(expression))
+  }
+
+  fn read_computed_member(
+    &mut self,
+    object: Expression<'a>,
+    optional: bool,
+  ) -> Result<Expression<'a>, ParseError> {
+    let property_expr = self.read_expression()?.ok_or(syntax_err!())?;
+    let property = MemberName::Computed(property_expr);
+    required_token!(self, Token::RightSquareBracket);
+    let expression = MemberExpression {
+      object,
+      optional,
+      property,
+      span: self.span_from(object.span().start as usize),
+    };
 
-        let expression = MemberExpression {
-          object,
-          optional,
-          property,
+    Ok(Expression::Member(self.allocator.alloc(expression)))
+  }
+
+  pub fn read_static_member(
+    &mut self,
+    object: Expression<'a>,
+    optional: bool,
+  ) -> Result<Expression<'a>, ParseError> {
+    let start = self.context.token_start;
+    let property = match &self.context.token {
+      Token::NumberSign => {
+        self.next_token()?;
+        let name = match &self.context.token {
+          Token::Name(name) => self.allocator.alloc_str(name.as_string()),
+          _ => return Err(syntax_err!()),
         };
-        Expression::Member(self.allocator.alloc(expression))
+        self.next_token()?;
+        let span = self.span_from(start);
+        MemberName::Private(Identifier { name, span })
       }
-      Token::TemplateHead(_, _) => self.read_tagged_template_literal(object, optional)?,
-      _ => return Ok(None),
+      Token::Name(name) => {
+        let name = self.allocator.alloc_str(name.as_string());
+        let span = self.token_span();
+        self.next_token()?;
+        MemberName::Static(Identifier { name, span })
+      }
+      _ => return Err(syntax_err!()),
     };
 
-    Ok(Some(expression))
+    let expression = MemberExpression {
+      object,
+      optional,
+      property,
+      span: self.span_from(object.span().start as usize),
+    };
+
+    Ok(Expression::Member(self.allocator.alloc(expression)))
   }
 }
 
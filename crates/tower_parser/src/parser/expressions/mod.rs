@@ -2,7 +2,8 @@ use bumpalo::collections::Vec;
 
 use super::{
   ast::{
-    expression::{Expression, YieldExpression},
+    expression::{ArrayElement, Expression, ListExpression, YieldExpression},
+    object::ObjectProperty,
     op::{AssignmentOp, AssignmentOpExpression},
   },
   error::{ParseError, ParseErrorCode},
@@ -11,8 +12,10 @@ use super::{
 };
 
 mod class;
+mod decorator;
 mod function;
-mod identifier;
+pub mod identifier;
+mod jsx;
 mod left_hand_side_expression;
 mod object_literal;
 mod operator_expressions;
@@ -21,10 +24,15 @@ mod template;
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     match self.read_assignment_expression()? {
       Some(expr) => match &self.context.token {
         Token::Comma => {
-          let list = self.read_expression_list(expr)?;
+          let list = ListExpression {
+            expressions: self.read_expression_list(expr)?,
+            span: self.span_from(start),
+          };
+
           Ok(Some(Expression::List(self.allocator.alloc(list))))
         }
         _ => Ok(Some(expr)),
@@ -37,65 +45,81 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     &mut self,
     init: Expression<'a>,
   ) -> Result<Vec<'a, Expression<'a>>, ParseError> {
-    let mut list = Vec::<Expression<'a>>::new_in(&self.allocator);
+    let mut list = Vec::<Expression<'a>>::new_in(self.allocator);
     list.push(init);
 
-    loop {
-      match &self.context.token {
-        Token::Comma => {
-          self.next_token()?;
-          let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-          list.push(expression);
-        }
-        _ => break,
-      }
+    while let Token::Comma = &self.context.token {
+      self.next_token()?;
+      let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+      list.push(expression);
     }
 
     Ok(list)
   }
 
   pub fn read_assignment_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     let higher_expr = match &self.context.token {
-      Token::LeftParenthesis => self.read_arrow_function_expression(false)?,
+      Token::LeftParenthesis => self.read_arrow_function_expression(false, start)?,
+      Token::LessThan if self.typescript => match self.is_generic_arrow_start()? {
+        true => self.read_arrow_function_expression(false, start)?,
+        false => None,
+      },
       Token::Name(Name::Async) => {
         let snapshot = self.context.clone();
         self.next_token()?;
-        if self.context.line_terminator {
-          return Err(syntax_err!());
-        }
 
-        match self.read_arrow_function_expression(true)? {
-          Some(expr) => Some(expr),
-          None => {
-            self.context = snapshot;
-            None
-          }
+        let expression = if self.context.line_terminator {
+          None
+        } else {
+          self.read_arrow_function_expression(true, start)?
+        };
+
+        if expression.is_none() {
+          self.context = snapshot;
         }
+
+        expression
       }
       Token::Name(Name::Yield) if self.context.flags.param_yield => {
+        if self.context.flags.formal_parameters || self.context.flags.field_initializer {
+          return Err(syntax_err!());
+        }
+
         self.next_token()?;
 
-        let expr = if self.context.line_terminator {
-          Expression::Yield(&YieldExpression::Empty)
+        let (argument, delegate) = if self.context.line_terminator {
+          (None, false)
         } else {
           match &self.context.token {
             Token::Asterisk => {
               self.next_token()?;
               let argument = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-              let expression = self.allocator.alloc(YieldExpression::All(argument));
-              Expression::Yield(expression)
+              (Some(argument), true)
             }
-            _ => match self.read_assignment_expression()? {
-              Some(expr) => {
-                let expression = self.allocator.alloc(YieldExpression::Argument(expr));
-                Expression::Yield(expression)
-              }
-              None => Expression::Yield(&YieldExpression::Empty),
-            },
+            _ => (self.read_assignment_expression()?, false),
           }
         };
 
-        Some(expr)
+        let expression = YieldExpression {
+          argument,
+          delegate,
+          span: self.span_from(start),
+        };
+
+        Some(Expression::Yield(self.allocator.alloc(expression)))
+      }
+      Token::Name(_) => {
+        let snapshot = self.context.clone();
+        self.next_token()?;
+        let is_arrow = matches!(self.context.token, Token::FatArrow);
+        self.context = snapshot;
+
+        if is_arrow {
+          self.read_arrow_function_expression(false, start)?
+        } else {
+          None
+        }
       }
       _ => None,
     };
@@ -135,6 +159,8 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       None => return Ok(None),
     };
 
+    check_assignment_target(&left)?;
+
     let op = match &self.context.token {
       Token::Equals => AssignmentOp::Assignment,
       Token::AsteriskEquals => AssignmentOp::Multiplication,
@@ -154,11 +180,50 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       _ => unreachable!(),
     };
 
+    self.next_token()?;
     let right = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-    let expression = AssignmentOpExpression { left, op, right };
+    let expression = AssignmentOpExpression {
+      left,
+      op,
+      right,
+      span: self.span_from(start),
+    };
 
     Ok(Some(Expression::Assignment(
       self.allocator.alloc(expression),
     )))
   }
 }
+
+// an optional chain can't be assigned to, also not in parentheses or as a target inside an array
+// or object literal that's read as a destructuring pattern. defaults were checked when their own
+// assignment was read
+pub fn check_assignment_target(target: &Expression) -> Result<(), ParseError> {
+  match target {
+    Expression::Chain(_) => Err(syntax_err!()),
+    Expression::Group(group) => check_assignment_target(&group.expression),
+    Expression::Array(literal) => {
+      for element in &literal.elements {
+        match element {
+          ArrayElement::Elision => {}
+          ArrayElement::Expression(expression) => check_assignment_target(expression)?,
+          ArrayElement::Spread(spread) => check_assignment_target(&spread.argument)?,
+        }
+      }
+
+      Ok(())
+    }
+    Expression::Object(literal) => {
+      for property in &literal.properties {
+        match property {
+          ObjectProperty::Property(property) => check_assignment_target(&property.expression)?,
+          ObjectProperty::Spread(spread) => check_assignment_target(&spread.argument)?,
+          _ => {}
+        }
+      }
+
+      Ok(())
+    }
+    _ => Ok(()),
+  }
+}
@@ -4,10 +4,12 @@ use crate::{
   number::es_number_to_string,
   parser::{
     ast::{
-      expression::Expression,
+      expression::{Expression, ObjectLiteral},
       object::{
         ObjectGetter, ObjectMethod, ObjectProperty, ObjectSetter, PropertyDefinition, PropertyName,
       },
+      string::JsStr,
+      Identifier, PropertyKey,
     },
     error::{ParseError, ParseErrorCode},
     lexer::token::{Name, Token},
@@ -16,10 +18,12 @@ use crate::{
 };
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
-  pub fn read_object_literal(&mut self) -> Result<Expression<'a>, ParseError> {
-    let mut elements = Vec::<ObjectProperty<'a>>::new_in(&self.allocator);
+  // the `{` has been read already
+  pub fn read_object_literal(&mut self, start: usize) -> Result<Expression<'a>, ParseError> {
+    let mut elements = Vec::<ObjectProperty<'a>>::new_in(self.allocator);
 
     loop {
+      let element_start = self.context.token_start;
       let element = match &self.context.token {
         Token::RightCurlyBracket => {
           self.next_token()?;
@@ -28,36 +32,41 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         Token::Asterisk => {
           self.next_token()?;
           let property = self.read_property_name()?;
-          let parameters = self.read_formal_parameters()?;
-          let body = self.read_function_body()?;
+          let (parameters, body) = self.read_method_parameters_and_body(false, true)?;
           let definition = ObjectMethod {
             r#async: false,
             body,
             generator: true,
             parameters,
             property,
+            span: self.span_from(element_start),
           };
 
           ObjectProperty::Method(self.allocator.alloc(definition))
         }
         Token::Name(Name::Async) => {
+          let span = self.token_span();
           self.next_token()?;
           match &self.context.token {
             Token::Colon | Token::LeftParenthesis => {
-              self.read_property_definition(PropertyName::Static("async"))?
+              let name = PropertyKey {
+                name: JsStr::from("async"),
+                span,
+              };
+              self.read_property_definition(PropertyName::Static(name), element_start)?
             }
             Token::Asterisk if !self.context.line_terminator => {
               self.next_token()?;
 
               let property = self.read_property_name()?;
-              let parameters = self.read_formal_parameters()?;
-              let body = self.read_function_body()?;
+              let (parameters, body) = self.read_method_parameters_and_body(true, true)?;
               let definition = ObjectMethod {
                 r#async: true,
                 body,
                 generator: true,
                 parameters,
                 property,
+                span: self.span_from(element_start),
               };
 
               ObjectProperty::Method(self.allocator.alloc(definition))
@@ -68,14 +77,14 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
               }
 
               let property = self.read_property_name()?;
-              let parameters = self.read_formal_parameters()?;
-              let body = self.read_function_body()?;
+              let (parameters, body) = self.read_method_parameters_and_body(true, false)?;
               let definition = ObjectMethod {
                 r#async: true,
                 body,
                 generator: false,
                 parameters,
                 property,
+                span: self.span_from(element_start),
               };
 
               ObjectProperty::Method(self.allocator.alloc(definition))
@@ -83,38 +92,50 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           }
         }
         Token::Name(Name::Get) => {
+          let span = self.token_span();
           self.next_token()?;
           match &self.context.token {
             Token::Colon | Token::LeftParenthesis => {
-              self.read_property_definition(PropertyName::Static("get"))?
+              let name = PropertyKey {
+                name: JsStr::from("get"),
+                span,
+              };
+              self.read_property_definition(PropertyName::Static(name), element_start)?
             }
             _ => {
               let property = self.read_property_name()?;
-              required_token!(self, Token::LeftParenthesis);
-              required_token!(self, Token::RightParenthesis);
-              let body = self.read_function_body()?;
-              let definition = ObjectGetter { property, body };
+              let (parameters_span, body) = self.read_getter_body()?;
+              let definition = ObjectGetter {
+                body,
+                parameters_span,
+                property,
+                span: self.span_from(element_start),
+              };
 
               ObjectProperty::Getter(self.allocator.alloc(definition))
             }
           }
         }
         Token::Name(Name::Set) => {
+          let span = self.token_span();
           self.next_token()?;
           match &self.context.token {
             Token::Colon | Token::LeftParenthesis => {
-              self.read_property_definition(PropertyName::Static("set"))?
+              let name = PropertyKey {
+                name: JsStr::from("set"),
+                span,
+              };
+              self.read_property_definition(PropertyName::Static(name), element_start)?
             }
             _ => {
               let property = self.read_property_name()?;
-              required_token!(self, Token::LeftParenthesis);
-              let parameter = self.read_binding_pattern_with_initializer()?;
-              required_token!(self, Token::RightParenthesis);
-              let body = self.read_function_body()?;
+              let (parameter, parameters_span, body) = self.read_setter_parameter_and_body()?;
               let definition = ObjectSetter {
                 body,
                 parameter,
+                parameters_span,
                 property,
+                span: self.span_from(element_start),
               };
 
               ObjectProperty::Setter(self.allocator.alloc(definition))
@@ -123,14 +144,23 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         }
         Token::Name(name) => {
           let name = name.clone();
+          let span = self.token_span();
           self.next_token()?;
           match &self.context.token {
             Token::Colon | Token::LeftParenthesis => {
-              let property = PropertyName::Static(self.allocator.alloc_str(name.as_string()));
-              self.read_property_definition(property)?
+              let name = JsStr::from(&*self.allocator.alloc_str(name.as_string()));
+              let property = PropertyName::Static(PropertyKey { name, span });
+              self.read_property_definition(property, element_start)?
+            }
+            Token::LessThan if self.typescript => {
+              let name = JsStr::from(&*self.allocator.alloc_str(name.as_string()));
+              let property = PropertyName::Static(PropertyKey { name, span });
+              self.read_property_definition(property, element_start)?
             }
             _ => match self.name_as_identifier_reference(&name)? {
-              Some(expr) => ObjectProperty::Shorthand(expr),
+              Some(name) => {
+                ObjectProperty::Shorthand(self.allocator.alloc(Identifier { name, span }))
+              }
               None => return Err(syntax_err!()),
             },
           }
@@ -141,6 +171,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           let definition = PropertyDefinition {
             property,
             expression: self.read_assignment_expression()?.ok_or(syntax_err!())?,
+            span: self.span_from(element_start),
           };
 
           ObjectProperty::Property(self.allocator.alloc(definition))
@@ -159,30 +190,44 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
     }
 
-    todo!()
+    let literal = ObjectLiteral {
+      properties: elements,
+      span: self.span_from(start),
+    };
+
+    Ok(Expression::Object(self.allocator.alloc(literal)))
   }
 
   fn read_property_name(&mut self) -> Result<PropertyName<'a>, ParseError> {
+    let span = self.token_span();
     let property = match &self.context.token {
       Token::Name(name) => {
-        let name = self.allocator.alloc_str(name.as_string());
+        let name = JsStr::from(&*self.allocator.alloc_str(name.as_string()));
         self.next_token()?;
-        PropertyName::Static(name)
+        PropertyName::Static(PropertyKey { name, span })
       }
       Token::StringLiteral(string_literal) => {
-        let name = self.allocator.alloc_str(&string_literal);
+        let name = string_literal.alloc_in(self.allocator);
         self.next_token()?;
-        PropertyName::Static(name)
+        PropertyName::Static(PropertyKey { name, span })
       }
       Token::NumberLiteral(number_literal) => {
         let name = self
           .allocator
           .alloc_str(&es_number_to_string(*number_literal, 10));
         self.next_token()?;
-        PropertyName::Static(name)
+        PropertyName::Static(PropertyKey {
+          name: JsStr::from(&*name),
+          span,
+        })
       }
-      Token::BigIntLiteral(_) => {
-        todo!()
+      Token::BigIntLiteral(bigint_literal) => {
+        let name = self.allocator.alloc_str(&bigint_literal.to_string());
+        self.next_token()?;
+        PropertyName::Static(PropertyKey {
+          name: JsStr::from(&*name),
+          span,
+        })
       }
       Token::LeftSquareBracket => {
         self.next_token()?;
@@ -199,6 +244,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   fn read_property_definition(
     &mut self,
     property: PropertyName<'a>,
+    start: usize,
   ) -> Result<ObjectProperty<'a>, ParseError> {
     let element = match &self.context.token {
       Token::Colon => {
@@ -206,19 +252,20 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         let definition = PropertyDefinition {
           property,
           expression: self.read_assignment_expression()?.ok_or(syntax_err!())?,
+          span: self.span_from(start),
         };
 
         ObjectProperty::Property(self.allocator.alloc(definition))
       }
-      Token::LeftParenthesis => {
-        let parameters = self.read_formal_parameters()?;
-        let body = self.read_function_body()?;
+      Token::LeftParenthesis | Token::LessThan => {
+        let (parameters, body) = self.read_method_parameters_and_body(false, false)?;
         let definition = ObjectMethod {
           r#async: false,
           body,
           generator: false,
           parameters,
           property,
+          span: self.span_from(start),
         };
 
         ObjectProperty::Method(self.allocator.alloc(definition))
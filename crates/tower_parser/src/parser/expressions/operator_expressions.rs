@@ -2,14 +2,17 @@ use crate::parser::{
   ast::{
     expression::{ConditionalExpression, Expression, InExpression, MemberName},
     op::{BinaryOp, BinaryOpExpression, UnaryOp, UnaryOpExpression},
+    Identifier,
   },
   error::{ParseError, ParseErrorCode},
+  expressions::check_assignment_target,
   lexer::token::{Name, Token},
   required_token, syntax_err, Parser,
 };
 
 macro_rules! parse_binary_op_expression {
-  ($self:ident.$method:ident, $($a:path => $b:path),*) => {
+  ($self:ident.$method:ident, $($a:path => $b:path),*) => {{
+    let start = $self.context.token_start;
     match $self.$method()? {
       Some(mut expression) => {
         loop {
@@ -25,6 +28,7 @@ macro_rules! parse_binary_op_expression {
             left: expression,
             op,
             right: argument,
+            span: $self.span_from(start),
           };
 
           expression = Expression::BinaryOp($self.allocator.alloc(new_expr));
@@ -34,11 +38,12 @@ macro_rules! parse_binary_op_expression {
       },
       None => Ok(None),
     }
-  }
+  }}
 }
 
 macro_rules! parse_single_binary_op_expression {
-  ($self:ident.$method:ident, $a:path => $b:path) => {
+  ($self:ident.$method:ident, $a:path => $b:path) => {{
+    let start = $self.context.token_start;
     match $self.$method()? {
       Some(mut expression) => {
         while matches!($self.context.token, $a) {
@@ -49,6 +54,7 @@ macro_rules! parse_single_binary_op_expression {
             left: expression,
             op: $b,
             right: argument,
+            span: $self.span_from(start),
           };
 
           expression = Expression::BinaryOp($self.allocator.alloc(new_expr));
@@ -58,22 +64,37 @@ macro_rules! parse_single_binary_op_expression {
       }
       None => Ok(None),
     }
-  };
+  }};
 }
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_conditional_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     match self.read_short_circuit_expression()? {
       Some(condition) => match &self.context.token {
         Token::QuestionMark => {
           self.next_token()?;
-          let consequent = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+          let snapshot = self.typescript.then(|| self.context.clone());
+          let mut consequent = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+
+          // in typescript the colon can be taken as the return type of an arrow, if none is left
+          // for the alternate the consequent is read again without it, like in `a ? (b) : c => d`
+          if let (Some(snapshot), false) = (snapshot, matches!(self.context.token, Token::Colon)) {
+            let flags = snapshot.flags;
+            self.context = snapshot;
+            self.context.flags.arrow_return_type = false;
+            let expression = self.read_assignment_expression();
+            self.context.flags.arrow_return_type = flags.arrow_return_type;
+            consequent = expression?.ok_or(syntax_err!())?;
+          }
+
           required_token!(self, Token::Colon);
           let alternate = self.read_assignment_expression()?.ok_or(syntax_err!())?;
           let expression = ConditionalExpression {
             alternate,
             condition,
             consequent,
+            span: self.span_from(start),
           };
 
           Ok(Some(Expression::Conditional(
@@ -94,6 +115,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   }
 
   fn read_coalesce_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     let snapshot = self.context.clone();
 
     match self.read_bitwise_or_expression()? {
@@ -111,6 +133,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             left: expression,
             op: BinaryOp::Coalesce,
             right: argument,
+            span: self.span_from(start),
           };
 
           expression = Expression::BinaryOp(self.allocator.alloc(new_expr));
@@ -168,6 +191,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   }
 
   fn read_relational_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     let mut expression = match self.read_shift_expression()? {
       Some(expr) => expr,
       None => match &self.context.token {
@@ -180,12 +204,18 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           };
 
           self.next_token()?;
+          let name = Identifier {
+            name,
+            span: self.span_from(start),
+          };
+
           required_token!(self, Token::Name(Name::In));
 
           let argument = self.read_shift_expression()?.ok_or(syntax_err!())?;
           let expression = InExpression {
             argument,
             name: MemberName::Private(name),
+            span: self.span_from(start),
           };
 
           Expression::In(self.allocator.alloc(expression))
@@ -201,12 +231,27 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         Token::LessThanEquals => BinaryOp::LessThanOrEqual,
         Token::GreaterThanEquals => BinaryOp::GreaterThanOrEqual,
         Token::Name(Name::Instanceof) => BinaryOp::Instanceof,
+        // typescript's `x as T`, `x as const` and `x satisfies T` leave only `x`
+        Token::Name(Name::Unclassified(name))
+          if self.typescript
+            && !self.context.line_terminator
+            && matches!(name.as_str(), "as" | "satisfies") =>
+        {
+          self.next_token()?;
+          match &self.context.token {
+            Token::Name(Name::Const) => self.next_token()?,
+            _ => self.skip_type()?,
+          }
+
+          continue;
+        }
         Token::Name(Name::In) if self.context.flags.param_in => {
           self.next_token()?;
           let argument = self.read_shift_expression()?.ok_or(syntax_err!())?;
           let new_expr = InExpression {
             argument,
             name: MemberName::Computed(expression),
+            span: self.span_from(start),
           };
 
           expression = Expression::In(self.allocator.alloc(new_expr));
@@ -222,6 +267,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         left: expression,
         op,
         right: argument,
+        span: self.span_from(start),
       };
 
       expression = Expression::BinaryOp(self.allocator.alloc(new_expr));
@@ -257,6 +303,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   }
 
   fn read_exponentiation_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     match self.read_update_expression()? {
       Some(left) => match &self.context.token {
         Token::DoubleAsterisk => {
@@ -269,6 +316,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             left,
             op: BinaryOp::Exponentiation,
             right,
+            span: self.span_from(start),
           };
 
           Ok(Some(Expression::BinaryOp(self.allocator.alloc(expression))))
@@ -280,6 +328,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   }
 
   fn read_unary_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     let op = match &self.context.token {
       Token::Name(Name::Delete) => UnaryOp::Delete,
       Token::Name(Name::Void) => UnaryOp::Void,
@@ -288,25 +337,56 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       Token::Minus => UnaryOp::Negate,
       Token::Tilde => UnaryOp::BitwiseNot,
       Token::Exclamation => UnaryOp::LogicalNot,
-      Token::Name(Name::Await) if self.context.flags.param_await => UnaryOp::Await,
+      Token::Name(Name::Await) if self.context.flags.param_await => {
+        if self.context.flags.formal_parameters || self.context.flags.field_initializer {
+          return Err(syntax_err!());
+        }
+
+        if !self.context.flags.param_return {
+          self.context.top_level_await = true;
+        }
+
+        UnaryOp::Await
+      }
       _ => return self.read_update_expression(),
     };
 
     self.next_token()?;
     let argument = self.read_unary_expression()?.ok_or(syntax_err!())?;
-    let expression = self.allocator.alloc(UnaryOpExpression { argument, op });
 
-    Ok(Some(Expression::UnaryOp(expression)))
+    // strict code can't delete a plain name, parenthesized or not
+    if matches!(op, UnaryOp::Delete) && self.context.flags.strict_mode {
+      let mut target = &argument;
+      while let Expression::Group(group) = target {
+        target = &group.expression;
+      }
+
+      if let Expression::Identifier(_) = target {
+        return Err(syntax_err!());
+      }
+    }
+
+    let expression = UnaryOpExpression {
+      argument,
+      op,
+      span: self.span_from(start),
+    };
+
+    Ok(Some(Expression::UnaryOp(self.allocator.alloc(expression))))
   }
 
   fn read_update_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    let start = self.context.token_start;
     let expression = match &self.context.token {
       Token::DoublePlus => {
         self.next_token()?;
         let argument = self.read_unary_expression()?.ok_or(syntax_err!())?;
+        check_assignment_target(&argument)?;
+
         let expression = UnaryOpExpression {
           argument,
           op: UnaryOp::PrefixIncrement,
+          span: self.span_from(start),
         };
 
         Expression::UnaryOp(self.allocator.alloc(expression))
@@ -314,9 +394,12 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       Token::DoubleMinus => {
         self.next_token()?;
         let argument = self.read_unary_expression()?.ok_or(syntax_err!())?;
+        check_assignment_target(&argument)?;
+
         let expression = UnaryOpExpression {
           argument,
           op: UnaryOp::PrefixDecrement,
+          span: self.span_from(start),
         };
 
         Expression::UnaryOp(self.allocator.alloc(expression))
@@ -328,19 +411,23 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           } else {
             match &self.context.token {
               Token::DoublePlus => {
+                check_assignment_target(&argument)?;
                 self.next_token()?;
                 let expression = UnaryOpExpression {
                   argument,
                   op: UnaryOp::PostfixIncrement,
+                  span: self.span_from(start),
                 };
 
                 Expression::UnaryOp(self.allocator.alloc(expression))
               }
               Token::DoubleMinus => {
+                check_assignment_target(&argument)?;
                 self.next_token()?;
                 let expression = UnaryOpExpression {
                   argument,
                   op: UnaryOp::PostfixDecrement,
+                  span: self.span_from(start),
                 };
 
                 Expression::UnaryOp(self.allocator.alloc(expression))
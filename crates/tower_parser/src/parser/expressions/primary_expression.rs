@@ -3,100 +3,108 @@ use bumpalo::collections::Vec;
 use crate::{
   bigint::BigInt,
   parser::{
-    ast::expression::{ArrayElement, Expression, RegExpLiteral},
+    ast::expression::{
+      ArrayElement, ArrayLiteral, BigIntLiteral, BooleanLiteral, Expression, GroupExpression,
+      NumberLiteral, RegExpLiteral, StringLiteral,
+    },
     error::{ParseError, ParseErrorCode},
     lexer::token::{Name, Token},
-    parse_err, required_token, syntax_err, Parser,
+    required_token, syntax_err, Parser,
   },
 };
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_primary_expression(&mut self) -> Result<Option<Expression<'a>>, ParseError> {
+    if matches!(self.context.token, Token::Solidus | Token::SolidusEquals) {
+      self.rescan_regexp_literal()?;
+    }
+
+    let start = self.context.token_start;
+    let span = self.token_span();
     let token = match &self.context.token {
       Token::Name(Name::This) => {
         self.next_token()?;
-        Expression::This
+        Expression::This(span)
       }
       Token::Name(Name::Null) => {
         self.next_token()?;
-        Expression::Null
+        Expression::Null(span)
       }
       Token::Name(Name::True) => {
         self.next_token()?;
-        Expression::Boolean(true)
+        Expression::Boolean(self.allocator.alloc(BooleanLiteral { span, value: true }))
       }
       Token::Name(Name::False) => {
         self.next_token()?;
-        Expression::Boolean(false)
+        Expression::Boolean(self.allocator.alloc(BooleanLiteral { span, value: false }))
       }
       Token::NumberLiteral(numeric_literal) => {
-        let value: &'a f64 = self.allocator.alloc(*numeric_literal);
+        let value = *numeric_literal;
         self.next_token()?;
-        Expression::Number(value)
+        Expression::Number(self.allocator.alloc(NumberLiteral { span, value }))
       }
       Token::BigIntLiteral(bigint_literal) => {
-        let value: &'a BigInt = self.allocator.alloc(bigint_literal.clone());
+        let value: BigInt = bigint_literal.clone();
         self.next_token()?;
-        Expression::BigInt(value)
+        Expression::BigInt(self.allocator.alloc(BigIntLiteral { span, value }))
       }
       Token::StringLiteral(string_literal) => {
-        let value: &'a str = self.allocator.alloc_str(string_literal.as_str());
+        let value = string_literal.alloc_in(self.allocator);
         self.next_token()?;
-        Expression::String(value)
+        Expression::String(self.allocator.alloc(StringLiteral { span, value }))
       }
       Token::LeftSquareBracket => {
         self.next_token()?;
-        self.read_array_literal()?
+        self.read_array_literal(start)?
       }
       Token::LeftCurlyBracket => {
         self.next_token()?;
-        self.read_object_literal()?
+        self.read_object_literal(start)?
       }
       Token::Name(Name::Function) => {
         self.next_token()?;
-        self.read_function_expression(false)?
-      }
-      Token::Name(Name::Class) => {
-        self.next_token()?;
-        self.read_class_expression()?
-      }
-      Token::Asterisk => {
-        self.next_token()?;
-        todo!()
+        self.read_function_expression(false, start)?
       }
+      Token::Name(Name::Class) | Token::CommercialAt => self.read_class_expression()?,
+      Token::LessThan if self.jsx => self.read_jsx_expression()?,
       Token::Name(Name::Async) => {
+        let snapshot = self.context.clone();
         self.next_token()?;
         if self.context.line_terminator
           || !matches!(self.context.token, Token::Name(Name::Function))
         {
-          return Err(syntax_err!());
+          self.context = snapshot;
+          return self.read_identifier_reference();
         }
         self.next_token()?;
-        self.read_function_expression(true)?
+        self.read_function_expression(true, start)?
       }
       Token::RegExp(source, flags) => {
         let literal = RegExpLiteral {
-          source: self.allocator.alloc_str(&source),
-          flags: self.allocator.alloc_str(&flags),
+          source: self.allocator.alloc_str(source),
+          flags: self.allocator.alloc_str(flags),
+          span,
         };
 
         self.next_token()?;
         Expression::RegExp(self.allocator.alloc(literal))
       }
-      Token::NoSubstitutionTemplate(_, baked_string) => match baked_string {
-        Some(string) => Expression::String(self.allocator.alloc_str(string)),
-        None => return Err(parse_err!(ParseErrorCode::InvalidTemplateString)),
-      },
-      Token::TemplateHead(_, baked_string) => {
-        let baked_string = baked_string.clone();
-        self.next_token()?;
-        self.read_template_literal(baked_string)?
+      Token::NoSubstitutionTemplate(..) | Token::TemplateHead(..) => {
+        self.read_template_literal()?
       }
       Token::LeftParenthesis => {
         self.next_token()?;
+        let param_in = self.context.flags.param_in;
+        self.context.flags.param_in = true;
         let expression = self.read_expression()?.ok_or(syntax_err!())?;
+        self.context.flags.param_in = param_in;
         required_token!(self, Token::RightParenthesis);
-        Expression::Group(self.allocator.alloc(expression))
+        let group = GroupExpression {
+          expression,
+          span: self.span_from(start),
+        };
+
+        Expression::Group(self.allocator.alloc(group))
       }
       _ => return self.read_identifier_reference(),
     };
@@ -104,8 +112,8 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     Ok(Some(token))
   }
 
-  fn read_array_literal(&mut self) -> Result<Expression<'a>, ParseError> {
-    let mut elements = Vec::new_in(&self.allocator);
+  fn read_array_literal(&mut self, start: usize) -> Result<Expression<'a>, ParseError> {
+    let mut elements = Vec::new_in(self.allocator);
 
     loop {
       match &self.context.token {
@@ -118,21 +126,32 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           elements.push(ArrayElement::Elision);
         }
         Token::TripleStop => {
-          self.next_token()?;
-          let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-          required_token!(self, Token::Comma);
-          elements.push(ArrayElement::Spread(expression));
+          let spread = self.read_spread_element()?;
+          elements.push(ArrayElement::Spread(spread));
+          self.read_array_element_end()?;
         }
         _ => {
           let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
-          required_token!(self, Token::Comma);
           elements.push(ArrayElement::Expression(expression));
+          self.read_array_element_end()?;
         }
       }
     }
 
-    let token = Expression::Array(self.allocator.alloc(elements));
+    let literal = ArrayLiteral {
+      elements,
+      span: self.span_from(start),
+    };
 
-    Ok(token)
+    Ok(Expression::Array(self.allocator.alloc(literal)))
+  }
+
+  // an element is followed by a comma, or by the end of the array which is left for the loop
+  fn read_array_element_end(&mut self) -> Result<(), ParseError> {
+    match &self.context.token {
+      Token::Comma => self.next_token(),
+      Token::RightSquareBracket => Ok(()),
+      _ => Err(syntax_err!()),
+    }
   }
 }
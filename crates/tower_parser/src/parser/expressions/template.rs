@@ -1,46 +1,87 @@
 use bumpalo::collections::Vec;
 
 use crate::parser::{
-  ast::expression::{Expression, TaggedTemplateLiteral, TemplateLiteral},
+  ast::{
+    expression::{Expression, TaggedTemplateLiteral, TemplateLiteral},
+    string::JsStr,
+    Span,
+  },
   error::{ParseError, ParseErrorCode},
   lexer::token::Token,
   parse_err, syntax_err, Parser,
 };
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
-  pub fn read_template_literal(
-    &mut self,
-    baked_string: Option<String>,
-  ) -> Result<Expression<'a>, ParseError> {
-    let mut strings = Vec::<&'a str>::new_in(&self.allocator);
-    let mut substitutions = Vec::<Expression<'a>>::new_in(&self.allocator);
-
-    let head_string = baked_string.ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
-    strings.push(self.allocator.alloc_str(&head_string));
-
-    loop {
-      let expression = self.read_expression()?.ok_or(syntax_err!())?;
-      substitutions.push(expression);
-
-      match &self.context.token {
-        Token::TemplateMiddle(_, baked_string) => {
-          let baked_string = baked_string.clone();
-          self.next_token()?;
-          let string = baked_string.ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
-          strings.push(self.allocator.alloc_str(&string));
-        }
-        Token::TemplateTail(_, baked_string) => {
-          let baked_string = baked_string.clone();
-          self.next_token()?;
-          let string = baked_string.ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
-          strings.push(self.allocator.alloc_str(&string));
-          break;
+  // the span of the characters of the current template token, without its delimiters
+  fn template_string_span(&self) -> Span {
+    let end = match &self.context.token {
+      Token::TemplateHead(..) | Token::TemplateMiddle(..) => self.context.position - 2,
+      _ => self.context.position - 1,
+    };
+
+    Span::new(self.context.token_start + 1, end)
+  }
+
+  // the current token is the template head or a template without substitutions
+  pub fn read_template_literal(&mut self) -> Result<Expression<'a>, ParseError> {
+    let start = self.context.token_start;
+    let mut strings = Vec::<JsStr<'a>>::new_in(self.allocator);
+    let mut string_spans = Vec::<Span>::new_in(self.allocator);
+    let mut substitutions = Vec::<Expression<'a>>::new_in(self.allocator);
+
+    let reached_end = match &self.context.token {
+      Token::TemplateHead(_, baked_string) => {
+        let string = baked_string
+          .as_ref()
+          .ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
+        strings.push(string.alloc_in(self.allocator));
+        false
+      }
+      Token::NoSubstitutionTemplate(_, baked_string) => {
+        let string = baked_string
+          .as_ref()
+          .ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
+        strings.push(string.alloc_in(self.allocator));
+        true
+      }
+      _ => return Err(syntax_err!()),
+    };
+
+    string_spans.push(self.template_string_span());
+    self.next_token()?;
+
+    if !reached_end {
+      loop {
+        let expression = self.read_expression()?.ok_or(syntax_err!())?;
+        substitutions.push(expression);
+        self.rescan_template_continuation()?;
+
+        match &self.context.token {
+          Token::TemplateMiddle(_, baked_string) => {
+            let string = baked_string
+              .as_ref()
+              .ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
+            strings.push(string.alloc_in(self.allocator));
+            string_spans.push(self.template_string_span());
+            self.next_token()?;
+          }
+          Token::TemplateTail(_, baked_string) => {
+            let string = baked_string
+              .as_ref()
+              .ok_or(parse_err!(ParseErrorCode::InvalidTemplateString))?;
+            strings.push(string.alloc_in(self.allocator));
+            string_spans.push(self.template_string_span());
+            self.next_token()?;
+            break;
+          }
+          _ => return Err(syntax_err!()),
         }
-        _ => return Err(syntax_err!()),
       }
     }
 
     let literal = TemplateLiteral {
+      span: self.span_from(start),
+      string_spans,
       strings,
       substitutions,
     };
@@ -51,62 +92,79 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_tagged_template_literal(
     &mut self,
     tag: Expression<'a>,
-    optional: bool,
   ) -> Result<Expression<'a>, ParseError> {
-    let mut strings = Vec::<Option<&'a str>>::new_in(&self.allocator);
-    let mut substitutions = Vec::<Expression<'a>>::new_in(&self.allocator);
-    let mut raw_strings = Vec::<&'a str>::new_in(&self.allocator);
+    let mut strings = Vec::<Option<JsStr<'a>>>::new_in(self.allocator);
+    let mut substitutions = Vec::<Expression<'a>>::new_in(self.allocator);
+    let mut raw_strings = Vec::<JsStr<'a>>::new_in(self.allocator);
+    let mut string_spans = Vec::<Span>::new_in(self.allocator);
 
-    match &self.context.token {
+    let reached_end = match &self.context.token {
       Token::TemplateHead(raw_string, baked_string) => {
-        let raw_string = self.allocator.alloc_str(&raw_string);
-        let baked_string = match baked_string {
-          Some(string) => Some(self.allocator.alloc_str(&string) as &'a str),
-          None => None,
-        };
+        let raw_string = JsStr::from(&*self.allocator.alloc_str(raw_string));
+        let baked_string = baked_string
+          .as_ref()
+          .map(|string| string.alloc_in(self.allocator));
 
+        string_spans.push(self.template_string_span());
         self.next_token()?;
         strings.push(baked_string);
         raw_strings.push(raw_string);
+        false
+      }
+      Token::NoSubstitutionTemplate(raw_string, baked_string) => {
+        let raw_string = JsStr::from(&*self.allocator.alloc_str(raw_string));
+        let baked_string = baked_string
+          .as_ref()
+          .map(|string| string.alloc_in(self.allocator));
+
+        string_spans.push(self.template_string_span());
+        self.next_token()?;
+        strings.push(baked_string);
+        raw_strings.push(raw_string);
+        true
       }
       _ => return Err(syntax_err!()),
     };
 
-    loop {
-      let expression = self.read_expression()?.ok_or(syntax_err!())?;
-      substitutions.push(expression);
-
-      match &self.context.token {
-        Token::TemplateMiddle(raw_string, baked_string) => {
-          let raw_string = self.allocator.alloc_str(&raw_string);
-          let baked_string = match baked_string {
-            Some(string) => Some(self.allocator.alloc_str(&string) as &'a str),
-            None => None,
-          };
-
-          self.next_token()?;
-          strings.push(baked_string);
-          raw_strings.push(raw_string);
-        }
-        Token::TemplateTail(raw_string, baked_string) => {
-          let raw_string = self.allocator.alloc_str(&raw_string);
-          let baked_string = match baked_string {
-            Some(string) => Some(self.allocator.alloc_str(&string) as &'a str),
-            None => None,
-          };
-
-          self.next_token()?;
-          strings.push(baked_string);
-          raw_strings.push(raw_string);
-          break;
+    if !reached_end {
+      loop {
+        let expression = self.read_expression()?.ok_or(syntax_err!())?;
+        substitutions.push(expression);
+        self.rescan_template_continuation()?;
+
+        match &self.context.token {
+          Token::TemplateMiddle(raw_string, baked_string) => {
+            let raw_string = JsStr::from(&*self.allocator.alloc_str(raw_string));
+            let baked_string = baked_string
+              .as_ref()
+              .map(|string| string.alloc_in(self.allocator));
+
+            string_spans.push(self.template_string_span());
+            self.next_token()?;
+            strings.push(baked_string);
+            raw_strings.push(raw_string);
+          }
+          Token::TemplateTail(raw_string, baked_string) => {
+            let raw_string = JsStr::from(&*self.allocator.alloc_str(raw_string));
+            let baked_string = baked_string
+              .as_ref()
+              .map(|string| string.alloc_in(self.allocator));
+
+            string_spans.push(self.template_string_span());
+            self.next_token()?;
+            strings.push(baked_string);
+            raw_strings.push(raw_string);
+            break;
+          }
+          _ => return Err(syntax_err!()),
         }
-        _ => return Err(syntax_err!()),
       }
     }
 
     let literal = TaggedTemplateLiteral {
-      optional,
       raw_strings,
+      span: self.span_from(tag.span().start as usize),
+      string_spans,
       strings,
       substitutions,
       tag,
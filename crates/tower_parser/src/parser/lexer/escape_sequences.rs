@@ -6,11 +6,14 @@ use crate::parser::{
 };
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
-  pub fn read_string_escape_sequence(&mut self) -> Result<Option<char>, ParseError> {
+  // the code point an escape in a string or template stands for, or a surrogate on its own, which
+  // a string can hold. None for a line continuation, which stands for nothing
+  pub fn read_string_escape_sequence(&mut self) -> Result<Option<u32>, ParseError> {
     macro_rules! simple_escape {
       ($character:expr) => {{
+        let character = $character;
         self.context.position += 1;
-        Some($character)
+        Some(character as u32)
       }};
     }
 
@@ -28,32 +31,22 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
       '0' => match self.required_char(self.context.position + 1)? {
         '0'..='7' => Some(self.read_legacy_octal_escape_sequence()?),
-        '8' | '9' => {
-          if self.context.flags.strict_mode {
-            return Err(parse_err!(ParseErrorCode::StrictOctalEscape));
-          } else {
-            self.context.position += 1;
-            Some('\0')
-          }
-        }
-        _ => {
-          self.context.position += 1;
-          Some('\0')
+        '8' | '9' if self.context.flags.strict_mode => {
+          return Err(parse_err!(ParseErrorCode::StrictOctalEscape));
         }
+        _ => simple_escape!('\0'),
       },
       '1'..='7' => Some(self.read_legacy_octal_escape_sequence()?),
       c @ ('8' | '9') => {
         if self.context.flags.strict_mode {
           return Err(parse_err!(ParseErrorCode::StrictOctalEscape));
         } else {
-          let c = *c;
-          self.context.position += 1;
-          Some(c)
+          simple_escape!(*c)
         }
       }
       'x' => {
         self.context.position += 1;
-        Some(self.read_double_digit_hex_escape_sequence()?)
+        Some(self.read_hex_digits(2)?)
       }
       'u' => {
         self.context.position += 1;
@@ -65,78 +58,61 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       'v' => simple_escape!('\u{000B}'),
       'f' => simple_escape!('\u{000C}'),
       'r' => simple_escape!('\u{000D}'),
-      c => {
-        let c = *c;
-        self.context.position += 1;
-        Some(c)
-      }
+      c => simple_escape!(*c),
     };
 
     Ok(escape_char)
   }
 
-  pub fn read_unicode_escape_sequence(&mut self) -> Result<char, ParseError> {
-    match self.required_char(self.context.position)? {
-      '{' => {
-        self.context.position += 1;
-        let start_index = self.context.position;
-
-        loop {
-          match self.required_char(self.context.position)? {
-            '}' => {
-              self.context.position += 1;
-              break;
-            }
-            '0'..='9' | 'a'..='z' | 'A'..='Z' => self.context.position += 1,
-            _ => return Err(parse_err!(ParseErrorCode::InvalidEscape)),
-          }
-        }
-
-        let digits = &self.source[start_index..(self.context.position - 1)];
-
-        if digits.len() > 6 {
-          return Err(parse_err!(ParseErrorCode::InvalidEscape));
-        }
+  // `\u` followed by four hex digits, a code unit which can be a surrogate, or by up to 10FFFF in
+  // braces, a code point which also can be
+  pub fn read_unicode_escape_sequence(&mut self) -> Result<u32, ParseError> {
+    if self.required_char(self.context.position)? != &'{' {
+      return self.read_hex_digits(4);
+    }
 
-        let mut codepoint = 0u64;
-        for digit in digits {
-          codepoint <<= 4;
-          codepoint |= hex_digit_value(*digit);
-        }
+    self.context.position += 1;
+    let mut codepoint = 0u32;
+    let mut has_digits = false;
 
-        if codepoint > (u32::MAX as u64) {
-          return Err(parse_err!(ParseErrorCode::InvalidUnicode));
+    loop {
+      match *self.required_char(self.context.position)? {
+        '}' if has_digits => {
+          self.context.position += 1;
+          break;
         }
+        digit if is_hex_digit(digit) => {
+          self.context.position += 1;
+          codepoint = codepoint << 4 | hex_digit_value(digit) as u32;
+          has_digits = true;
 
-        match char::from_u32(codepoint as u32) {
-          None => Err(parse_err!(ParseErrorCode::InvalidUnicode)),
-          Some(c) => Ok(c),
+          if codepoint > 0x10FFFF {
+            return Err(parse_err!(ParseErrorCode::InvalidUnicode));
+          }
         }
+        _ => return Err(parse_err!(ParseErrorCode::InvalidEscape)),
       }
-      _ => self.read_double_digit_hex_escape_sequence(),
     }
-  }
-
-  fn read_double_digit_hex_escape_sequence(&mut self) -> Result<char, ParseError> {
-    let first_digit = *self.required_char(self.context.position)?;
-    let second_digit = *self.required_char(self.context.position)?;
 
-    if !(is_hex_digit(first_digit) && is_hex_digit(second_digit)) {
-      return Err(parse_err!(ParseErrorCode::InvalidEscape));
-    }
+    Ok(codepoint)
+  }
 
-    let codepoint = (hex_digit_value(first_digit) << 4) | hex_digit_value(second_digit);
+  fn read_hex_digits(&mut self, count: usize) -> Result<u32, ParseError> {
+    let mut value = 0u32;
 
-    match char::from_u32(codepoint as u32) {
-      None => Err(parse_err!(ParseErrorCode::InvalidUnicode)),
-      Some(c) => {
-        self.context.position += 2;
-        Ok(c)
+    for offset in 0..count {
+      let digit = *self.required_char(self.context.position + offset)?;
+      if !is_hex_digit(digit) {
+        return Err(parse_err!(ParseErrorCode::InvalidEscape));
       }
+      value = value << 4 | hex_digit_value(digit) as u32;
     }
+
+    self.context.position += count;
+    Ok(value)
   }
 
-  fn read_legacy_octal_escape_sequence(&mut self) -> Result<char, ParseError> {
+  fn read_legacy_octal_escape_sequence(&mut self) -> Result<u32, ParseError> {
     if self.context.flags.strict_mode {
       return Err(parse_err!(ParseErrorCode::StrictOctalEscape));
     }
@@ -165,13 +141,10 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       codepoint |= *digit as u32 & 0xF;
     }
 
-    match char::from_u32(codepoint) {
-      None => Err(parse_err!(ParseErrorCode::InvalidUnicode)),
-      Some(c) => Ok(c),
-    }
+    Ok(codepoint)
   }
 }
 
 fn is_hex_digit(c: char) -> bool {
-  matches!(c, '0'..='9' | 'a'..='f' | 'A'..='F')
+  c.is_ascii_hexdigit()
 }
@@ -0,0 +1,294 @@
+use tower_ast::string::JsString;
+
+use crate::parser::{
+  error::{syntax_err, ParseError, ParseErrorCode},
+  Parser,
+};
+
+use super::{
+  identifier_utils::{is_id_continue, is_id_start},
+  token::Token,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsxGoal {
+  // inside of a tag, names can contain `-` and strings have no escapes
+  Tag,
+  // between tags, everything up to a `{` or `<` is text
+  Text,
+}
+
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  pub fn read_jsx_text_token(&mut self) -> Result<Token, ParseError> {
+    let token = match self.source.get(self.context.position) {
+      None => Token::EndOfInput,
+      Some('{') => {
+        self.context.position += 1;
+        Token::LeftCurlyBracket
+      }
+      Some('<') => {
+        self.context.position += 1;
+        Token::LessThan
+      }
+      Some(_) => {
+        let start = self.context.position;
+        let mut value = String::new();
+
+        loop {
+          match self.source.get(self.context.position) {
+            None | Some('{' | '<') => break,
+            Some('>' | '}') => return Err(syntax_err!()),
+            Some('&') => value.push(self.read_jsx_character_reference()),
+            Some(c) => {
+              value.push(*c);
+              self.context.position += 1;
+            }
+          }
+        }
+
+        let raw = self.source[start..self.context.position].iter().collect();
+        Token::JsxText(raw, value)
+      }
+    };
+
+    Ok(token)
+  }
+
+  pub fn read_jsx_tag_token(&mut self) -> Result<Option<Token>, ParseError> {
+    let token = match self.source[self.context.position] {
+      start_char if is_id_start(start_char) => {
+        let mut name = String::new();
+
+        while let Some(c) = self.source.get(self.context.position) {
+          if !is_id_continue(*c) && *c != '-' {
+            break;
+          }
+
+          name.push(*c);
+          self.context.position += 1;
+        }
+
+        Token::JsxIdentifier(name)
+      }
+      quote_type @ ('"' | '\'') => {
+        self.context.position += 1;
+        let mut value = String::new();
+
+        loop {
+          match self.required_char(self.context.position)? {
+            '&' => value.push(self.read_jsx_character_reference()),
+            c if *c == quote_type => {
+              self.context.position += 1;
+              break;
+            }
+            c => {
+              value.push(*c);
+              self.context.position += 1;
+            }
+          }
+        }
+
+        Token::StringLiteral(JsString::from(value.as_str()))
+      }
+      // `>=` and `>>` are never tokens inside of a tag
+      '>' => {
+        self.context.position += 1;
+        Token::GreaterThan
+      }
+      _ => return Ok(None),
+    };
+
+    Ok(Some(token))
+  }
+
+  // reads `&name;`, `&#123;` or `&#x7B;`, anything else leaves the `&` as a plain character
+  fn read_jsx_character_reference(&mut self) -> char {
+    let start = self.context.position + 1;
+    let end = self.source[start..]
+      .iter()
+      .take(10)
+      .position(|c| *c == ';')
+      .map(|length| start + length);
+
+    let character = end.and_then(|end| {
+      let reference = &self.source[start..end];
+
+      match reference {
+        ['#', 'x' | 'X', digits @ ..] if !digits.is_empty() => digits
+          .iter()
+          .try_fold(0u32, |value, c| Some(value * 16 + c.to_digit(16)?))
+          .and_then(char::from_u32),
+        ['#', digits @ ..] if !digits.is_empty() => digits
+          .iter()
+          .try_fold(0u32, |value, c| Some(value * 10 + c.to_digit(10)?))
+          .and_then(char::from_u32),
+        _ => html_entity(&reference.iter().collect::<String>()),
+      }
+    });
+
+    match (character, end) {
+      (Some(character), Some(end)) => {
+        self.context.position = end + 1;
+        character
+      }
+      _ => {
+        self.context.position += 1;
+        '&'
+      }
+    }
+  }
+}
+
+// the character entities from XHTML 1.0
+fn html_entity(name: &str) -> Option<char> {
+  const LATIN_1: [&str; 96] = [
+    "nbsp", "iexcl", "cent", "pound", "curren", "yen", "brvbar", "sect", "uml", "copy", "ordf",
+    "laquo", "not", "shy", "reg", "macr", "deg", "plusmn", "sup2", "sup3", "acute", "micro",
+    "para", "middot", "cedil", "sup1", "ordm", "raquo", "frac14", "frac12", "frac34", "iquest",
+    "Agrave", "Aacute", "Acirc", "Atilde", "Auml", "Aring", "AElig", "Ccedil", "Egrave", "Eacute",
+    "Ecirc", "Euml", "Igrave", "Iacute", "Icirc", "Iuml", "ETH", "Ntilde", "Ograve", "Oacute",
+    "Ocirc", "Otilde", "Ouml", "times", "Oslash", "Ugrave", "Uacute", "Ucirc", "Uuml", "Yacute",
+    "THORN", "szlig", "agrave", "aacute", "acirc", "atilde", "auml", "aring", "aelig", "ccedil",
+    "egrave", "eacute", "ecirc", "euml", "igrave", "iacute", "icirc", "iuml", "eth", "ntilde",
+    "ograve", "oacute", "ocirc", "otilde", "ouml", "divide", "oslash", "ugrave", "uacute", "ucirc",
+    "uuml", "yacute", "thorn", "yuml",
+  ];
+
+  // in code point order, the small letters start at U+03B1 and the capital ones at U+0391
+  const GREEK: [&str; 25] = [
+    "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+    "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigmaf", "sigma", "tau", "upsilon", "phi",
+    "chi", "psi", "omega",
+  ];
+
+  if let Some(index) = LATIN_1.iter().position(|entity| *entity == name) {
+    return char::from_u32(0xA0 + index as u32);
+  }
+
+  if let Some(index) = GREEK.iter().position(|entity| *entity == name) {
+    return char::from_u32(0x3B1 + index as u32);
+  }
+
+  // there is no capital final sigma, so U+03A2 is unassigned
+  if let Some(index) = GREEK.iter().position(|entity| {
+    *entity != "sigmaf"
+      && name.get(1..) == entity.get(1..)
+      && name.starts_with(&entity[..1].to_ascii_uppercase())
+  }) {
+    return char::from_u32(0x391 + index as u32);
+  }
+
+  let code_point = match name {
+    "quot" => 0x22,
+    "amp" => 0x26,
+    "apos" => 0x27,
+    "lt" => 0x3C,
+    "gt" => 0x3E,
+    "OElig" => 0x152,
+    "oelig" => 0x153,
+    "Scaron" => 0x160,
+    "scaron" => 0x161,
+    "Yuml" => 0x178,
+    "fnof" => 0x192,
+    "circ" => 0x2C6,
+    "tilde" => 0x2DC,
+    "thetasym" => 0x3D1,
+    "upsih" => 0x3D2,
+    "piv" => 0x3D6,
+    "ensp" => 0x2002,
+    "emsp" => 0x2003,
+    "thinsp" => 0x2009,
+    "zwnj" => 0x200C,
+    "zwj" => 0x200D,
+    "lrm" => 0x200E,
+    "rlm" => 0x200F,
+    "ndash" => 0x2013,
+    "mdash" => 0x2014,
+    "lsquo" => 0x2018,
+    "rsquo" => 0x2019,
+    "sbquo" => 0x201A,
+    "ldquo" => 0x201C,
+    "rdquo" => 0x201D,
+    "bdquo" => 0x201E,
+    "dagger" => 0x2020,
+    "Dagger" => 0x2021,
+    "bull" => 0x2022,
+    "hellip" => 0x2026,
+    "permil" => 0x2030,
+    "prime" => 0x2032,
+    "Prime" => 0x2033,
+    "lsaquo" => 0x2039,
+    "rsaquo" => 0x203A,
+    "oline" => 0x203E,
+    "frasl" => 0x2044,
+    "euro" => 0x20AC,
+    "image" => 0x2111,
+    "weierp" => 0x2118,
+    "real" => 0x211C,
+    "trade" => 0x2122,
+    "alefsym" => 0x2135,
+    "larr" => 0x2190,
+    "uarr" => 0x2191,
+    "rarr" => 0x2192,
+    "darr" => 0x2193,
+    "harr" => 0x2194,
+    "crarr" => 0x21B5,
+    "lArr" => 0x21D0,
+    "uArr" => 0x21D1,
+    "rArr" => 0x21D2,
+    "dArr" => 0x21D3,
+    "hArr" => 0x21D4,
+    "forall" => 0x2200,
+    "part" => 0x2202,
+    "exist" => 0x2203,
+    "empty" => 0x2205,
+    "nabla" => 0x2207,
+    "isin" => 0x2208,
+    "notin" => 0x2209,
+    "ni" => 0x220B,
+    "prod" => 0x220F,
+    "sum" => 0x2211,
+    "minus" => 0x2212,
+    "lowast" => 0x2217,
+    "radic" => 0x221A,
+    "prop" => 0x221D,
+    "infin" => 0x221E,
+    "ang" => 0x2220,
+    "and" => 0x2227,
+    "or" => 0x2228,
+    "cap" => 0x2229,
+    "cup" => 0x222A,
+    "int" => 0x222B,
+    "there4" => 0x2234,
+    "sim" => 0x223C,
+    "cong" => 0x2245,
+    "asymp" => 0x2248,
+    "ne" => 0x2260,
+    "equiv" => 0x2261,
+    "le" => 0x2264,
+    "ge" => 0x2265,
+    "sub" => 0x2282,
+    "sup" => 0x2283,
+    "nsub" => 0x2284,
+    "sube" => 0x2286,
+    "supe" => 0x2287,
+    "oplus" => 0x2295,
+    "otimes" => 0x2297,
+    "perp" => 0x22A5,
+    "sdot" => 0x22C5,
+    "lceil" => 0x2308,
+    "rceil" => 0x2309,
+    "lfloor" => 0x230A,
+    "rfloor" => 0x230B,
+    "lang" => 0x2329,
+    "rang" => 0x232A,
+    "loz" => 0x25CA,
+    "spades" => 0x2660,
+    "clubs" => 0x2663,
+    "hearts" => 0x2665,
+    "diams" => 0x2666,
+    _ => return None,
+  };
+
+  char::from_u32(code_point)
+}
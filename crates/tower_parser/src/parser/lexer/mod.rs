@@ -1,7 +1,9 @@
 use std::slice::SliceIndex;
 
 use identifier_utils::{is_id_continue, is_id_start};
+use jsx::JsxGoal;
 use token::{Name, Token};
+use tower_ast::Span;
 
 use crate::parser::{parse_err, syntax_err};
 
@@ -11,20 +13,51 @@ use super::{
 };
 
 mod escape_sequences;
-mod identifier_utils;
+pub mod identifier_utils;
+pub mod jsx;
 mod numeric;
 mod regexp;
+mod regexp_pattern;
 mod strings;
 
 pub mod token;
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn next_token(&mut self) -> Result<(), ParseError> {
+    self.read_token()?;
+
+    if let Some(tokens) = &mut self.tokens {
+      // going back to a snapshot reads the tokens after it again
+      while tokens
+        .last()
+        .is_some_and(|(_, span)| span.start as usize >= self.context.token_start)
+      {
+        tokens.pop();
+      }
+
+      tokens.push((
+        self.context.token.clone(),
+        Span::new(self.context.token_start, self.context.position),
+      ));
+    }
+
+    Ok(())
+  }
+
+  fn read_token(&mut self) -> Result<(), ParseError> {
     self.context.line_terminator = false;
+    self.context.previous_end = self.context.position;
+
+    if let Some(JsxGoal::Text) = self.context.flags.goal_jsx {
+      self.context.token_start = self.context.position;
+      self.context.token = self.read_jsx_text_token()?;
+      return Ok(());
+    }
 
     loop {
       match self.source.get(self.context.position) {
         None => {
+          self.context.token_start = self.context.position;
           self.context.token = Token::EndOfInput;
           return Ok(());
         }
@@ -42,17 +75,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           '/' => match self.source.get(self.context.position + 1) {
             Some('/') => {
               self.context.position += 2;
-              loop {
-                match self.source.get(self.context.position) {
-                  None => break,
-                  Some('\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}') => {
-                    self.context.line_terminator = true;
-                    self.context.position += 1;
-                    break;
-                  }
-                  _ => self.context.position += 1,
-                }
-              }
+              self.skip_single_line_comment();
             }
             Some('*') => {
               self.context.position += 2;
@@ -80,25 +103,37 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           '#' if self.context.position == 0 => match self.source.get(self.context.position + 1) {
             Some('!') => {
               self.context.position += 2;
-              loop {
-                match self.source.get(self.context.position) {
-                  None => break,
-                  Some('\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}') => {
-                    self.context.line_terminator = true;
-                    self.context.position += 1;
-                    break;
-                  }
-                  _ => self.context.position += 1,
-                }
-              }
+              self.skip_single_line_comment();
             }
             _ => break,
           },
+          // annex B HTML-like comments, `<!--` anywhere and `-->` at the start of a line
+          '<' if self.annex_b && self.source_starts_with(self.context.position, "<!--") => {
+            self.context.position += 4;
+            self.skip_single_line_comment();
+          }
+          '-'
+            if self.annex_b
+              && self.context.line_terminator
+              && self.source_starts_with(self.context.position, "-->") =>
+          {
+            self.context.position += 3;
+            self.skip_single_line_comment();
+          }
           _ => break,
         },
       }
     }
 
+    self.context.token_start = self.context.position;
+
+    if let Some(JsxGoal::Tag) = self.context.flags.goal_jsx {
+      if let Some(token) = self.read_jsx_tag_token()? {
+        self.context.token = token;
+        return Ok(());
+      }
+    }
+
     macro_rules! simple_punctuator {
       ($token:path) => {{
         self.context.position += 1;
@@ -114,7 +149,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       '\\' => match self.required_char(self.context.position + 1)? {
         'u' => {
           self.context.position += 2;
-          let character = self.read_unicode_escape_sequence()?;
+          let character = self.read_identifier_escape()?;
 
           if is_id_start(character) {
             self.read_word_token(character)?
@@ -415,6 +450,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       ';' => simple_punctuator!(Token::Semicolon),
       ',' => simple_punctuator!(Token::Comma),
       '~' => simple_punctuator!(Token::Tilde),
+      '@' => simple_punctuator!(Token::CommercialAt),
       ':' => simple_punctuator!(Token::Colon),
       '#' => simple_punctuator!(Token::NumberSign),
       _ => return Err(syntax_err!()),
@@ -424,6 +460,27 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     Ok(())
   }
 
+  fn skip_single_line_comment(&mut self) {
+    loop {
+      match self.source.get(self.context.position) {
+        None => break,
+        Some('\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}') => {
+          self.context.line_terminator = true;
+          self.context.position += 1;
+          break;
+        }
+        _ => self.context.position += 1,
+      }
+    }
+  }
+
+  fn source_starts_with(&self, position: usize, pattern: &str) -> bool {
+    pattern
+      .chars()
+      .enumerate()
+      .all(|(offset, c)| self.source.get(position + offset) == Some(&c))
+  }
+
   fn required_char<I: SliceIndex<[char]>>(&self, position: I) -> Result<&I::Output, ParseError> {
     match self.source.get(position) {
       Some(c) => Ok(c),
@@ -431,6 +488,12 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     }
   }
 
+  // an escape in an identifier has to be a whole code point, surrogates aren't identifier chars
+  fn read_identifier_escape(&mut self) -> Result<char, ParseError> {
+    let point = self.read_unicode_escape_sequence()?;
+    char::from_u32(point).ok_or(syntax_err!())
+  }
+
   fn read_word_token(&mut self, start_char: char) -> Result<Token, ParseError> {
     let mut characters = String::new();
     let mut has_unicode_escape = false;
@@ -441,7 +504,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         Some('\\') => match self.required_char(self.context.position + 1)? {
           'u' => {
             self.context.position += 2;
-            let character = self.read_unicode_escape_sequence()?;
+            let character = self.read_identifier_escape()?;
 
             if is_id_continue(character) {
               has_unicode_escape = true;
@@ -100,15 +100,10 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           digits.push(*digit);
         }
 
-        loop {
-          match self.source.get(self.context.position) {
-            Some(digit @ '0'..='9') => {
-              self.context.position += 1;
-              if *digit != '0' || digits.len() > 0 {
-                digits.push(*digit);
-              }
-            }
-            _ => break,
+        while let Some(digit @ '0'..='9') = self.source.get(self.context.position) {
+          self.context.position += 1;
+          if *digit != '0' || !digits.is_empty() {
+            digits.push(*digit);
           }
         }
 
@@ -118,6 +113,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           self.read_decimal_literal(Some(digits))?
         }
       }
+      Some('.' | 'e' | 'E') => self.read_decimal_literal(Some(vec!['0']))?,
       _ => Token::NumberLiteral(0f64),
     };
 
@@ -138,7 +134,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         }
         Some(digit @ '0'..='9') => {
           self.context.position += 1;
-          if *digit != '0' || digits.len() > 0 {
+          if *digit != '0' || !digits.is_empty() {
             digits.push(*digit);
           }
         }
@@ -164,22 +160,29 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     &mut self,
     integer_digits: Option<Vec<char>>,
   ) -> Result<Token, ParseError> {
-    let numeric_value = match self.source.get(self.context.position) {
+    // the literal without separators, converted once it's all read so a fraction with an
+    // exponent is only rounded once
+    let has_integer_digits = integer_digits.is_some();
+    let mut literal = match &integer_digits {
+      Some(digits) => String::from_iter(digits),
+      None => String::new(),
+    };
+    let mut is_integer = true;
+
+    match self.source.get(self.context.position) {
       Some('.') => {
         self.context.position += 1;
-
-        let mut digits_str = match integer_digits {
-          Some(digits) => String::from_iter(digits),
-          None => String::new(),
-        };
-
-        digits_str.push('.');
+        literal.push('.');
+        is_integer = false;
 
         match self.source.get(self.context.position) {
           Some(digit @ '0'..='9') => {
             self.context.position += 1;
-            digits_str.push(*digit);
+            literal.push(*digit);
           }
+          // `1.` is a whole literal, a lone `.` is not
+          Some('_') => return Err(syntax_err!()),
+          _ if has_integer_digits => {}
           _ => return Err(syntax_err!()),
         }
 
@@ -187,7 +190,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           match self.source.get(self.context.position) {
             Some(digit @ '0'..='9') => {
               self.context.position += 1;
-              digits_str.push(*digit);
+              literal.push(*digit);
             }
             Some('_') => {
               self.context.position += 1;
@@ -199,72 +202,62 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         if matches!(self.source.get(self.context.position - 1), Some('_')) {
           return Err(syntax_err!());
         }
-
-        digits_str.parse::<f64>().unwrap()
       }
-      _ => match integer_digits {
-        Some(digits) => parse_decimal(&digits),
-        None => return Err(syntax_err!()),
-      },
-    };
+      _ if !has_integer_digits => return Err(syntax_err!()),
+      _ => {}
+    }
 
-    let token = match self.source.get(self.context.position) {
-      Some('e' | 'E') => {
-        self.context.position += 1;
+    if let Some('e' | 'E') = self.source.get(self.context.position) {
+      self.context.position += 1;
+      literal.push('e');
+      is_integer = false;
 
-        let sign: i8 = match self.source.get(self.context.position) {
-          Some('+') => {
-            self.context.position += 1;
-            1
-          }
-          Some('-') => {
-            self.context.position += 1;
-            -1
-          }
-          Some('0'..='9') => 1,
-          _ => return Err(syntax_err!()),
-        };
+      match self.source.get(self.context.position) {
+        Some('+') => {
+          self.context.position += 1;
+        }
+        Some('-') => {
+          self.context.position += 1;
+          literal.push('-');
+        }
+        Some('0'..='9') => {}
+        _ => return Err(syntax_err!()),
+      };
 
-        let mut exponent_digits = Vec::<char>::new();
+      match self.source.get(self.context.position) {
+        Some(digit @ ('0'..='9')) => {
+          self.context.position += 1;
+          literal.push(*digit);
+        }
+        _ => return Err(syntax_err!()),
+      };
 
+      loop {
         match self.source.get(self.context.position) {
           Some(digit @ ('0'..='9')) => {
             self.context.position += 1;
-            if *digit != '0' {
-              exponent_digits.push(*digit)
-            }
+            literal.push(*digit);
           }
-          _ => return Err(syntax_err!()),
-        };
-
-        loop {
-          match self.source.get(self.context.position) {
-            Some(digit @ ('0'..='9')) => {
-              self.context.position += 1;
-              if *digit != '0' || exponent_digits.len() > 0 {
-                exponent_digits.push(*digit);
-              }
-            }
-            Some('_') => {
-              self.context.position += 1;
-            }
-            _ => break,
+          Some('_') => {
+            self.context.position += 1;
           }
+          _ => break,
         }
+      }
 
-        if matches!(self.source.get(self.context.position - 1), Some('_')) {
-          return Err(syntax_err!());
-        }
-
-        let exponent_value = parse_decimal(&exponent_digits) * sign as f64;
-
-        Token::NumberLiteral(numeric_value * 10f64.powf(exponent_value))
+      if matches!(self.source.get(self.context.position - 1), Some('_')) {
+        return Err(syntax_err!());
       }
-      _ => Token::NumberLiteral(numeric_value),
+    }
+
+    // rust's parser rounds correctly however many digits there are
+    let value = match integer_digits {
+      Some(digits) if is_integer => parse_decimal(&digits),
+      _ => literal.parse::<f64>().unwrap(),
     };
 
     self.check_end_of_numeric_literal()?;
-    Ok(token)
+    Ok(Token::NumberLiteral(value))
   }
 
   fn check_end_of_numeric_literal(&mut self) -> Result<(), ParseError> {
@@ -3,10 +3,34 @@ use crate::parser::{
   Parser,
 };
 
-use super::{identifier_utils::is_id_continue, token::Token};
+use super::{
+  identifier_utils::is_id_continue,
+  regexp_pattern::{validate_regexp_flags, validate_regexp_pattern},
+  token::Token,
+};
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
+  // the lexer reads `/` as a division, so where an expression is expected the parser goes back and
+  // reads it again with the regexp goal
+  pub fn rescan_regexp_literal(&mut self) -> Result<(), ParseError> {
+    self.context.position -= match self.context.token {
+      Token::Solidus => 1,
+      Token::SolidusEquals => 2,
+      _ => return Ok(()),
+    };
+
+    let line_terminator = self.context.line_terminator;
+    let previous_end = self.context.previous_end;
+    self.context.flags.goal_regexp = true;
+    let result = self.next_token();
+    self.context.flags.goal_regexp = false;
+    self.context.line_terminator = line_terminator;
+    self.context.previous_end = previous_end;
+    result
+  }
+
   pub fn read_regexp_literal(&mut self) -> Result<Token, ParseError> {
+    let start = self.context.position;
     let mut source = String::new();
 
     loop {
@@ -66,11 +90,18 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       match self.source.get(self.context.position) {
         Some(c) if is_id_continue(*c) => {
           flags.push(*c);
+          self.context.position += 1;
         }
+        Some('\\') => return Err(syntax_err!()),
         _ => break,
       }
     }
 
+    validate_regexp_flags(&flags)?;
+
+    let pattern = &self.source[start..self.context.position - flags.chars().count() - 1];
+    validate_regexp_pattern(pattern, &flags, self.annex_b)?;
+
     Ok(Token::RegExp(source, flags))
   }
 }
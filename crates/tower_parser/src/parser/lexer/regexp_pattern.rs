@@ -0,0 +1,982 @@
+use port_oxc_number_parsers::hex_digit_value;
+use unicode_id::properties::Property;
+
+use crate::parser::{
+  error::{ParseError, ParseErrorCode},
+  parse_err,
+};
+
+use super::identifier_utils::{is_id_continue, is_id_start};
+
+macro_rules! regexp_err {
+  () => {
+    parse_err!(ParseErrorCode::InvalidRegExp)
+  };
+}
+
+// the value of a class atom, classes like `\d` don't have one and can't be used in ranges, and
+// properties of strings like `\p{RGI_Emoji}` may match more than one character
+#[derive(Clone, Copy)]
+enum ClassAtom {
+  Char(u32),
+  Class,
+  Strings,
+}
+
+// a v-mode class operand, and if it may match strings instead of single characters
+#[derive(Clone, Copy)]
+enum ClassSetOperand {
+  Char(u32),
+  Class(bool),
+}
+
+pub fn validate_regexp_flags(flags: &str) -> Result<(), ParseError> {
+  let mut seen = [false; 8];
+
+  for flag in flags.chars() {
+    let index = match flag {
+      'd' => 0,
+      'g' => 1,
+      'i' => 2,
+      'm' => 3,
+      's' => 4,
+      'u' => 5,
+      'v' => 6,
+      'y' => 7,
+      _ => return Err(regexp_err!()),
+    };
+
+    if seen[index] {
+      return Err(regexp_err!());
+    }
+
+    seen[index] = true;
+  }
+
+  if seen[5] && seen[6] {
+    return Err(regexp_err!());
+  }
+
+  Ok(())
+}
+
+// checks the early errors of a regular expression literal's body, following the grammar from
+// section 22.2.1, or the one from annex B.1.2 when `annex_b` is set and the pattern isn't unicode
+pub fn validate_regexp_pattern(
+  pattern: &[char],
+  flags: &str,
+  annex_b: bool,
+) -> Result<(), ParseError> {
+  let unicode_sets_mode = flags.contains('v');
+  let unicode_mode = unicode_sets_mode || flags.contains('u');
+  let (group_count, has_group_names) = count_capturing_groups(pattern, unicode_sets_mode);
+
+  let mut validator = PatternValidator {
+    pattern,
+    position: 0,
+    unicode_mode,
+    unicode_sets_mode,
+    // annex B only parses group names when the pattern has one, otherwise `\k` is an identity escape
+    named_groups: unicode_mode || !annex_b || has_group_names,
+    annex_b: annex_b && !unicode_mode,
+    group_count,
+    group_names: Vec::new(),
+    active_group_names: Vec::new(),
+    backreference_names: Vec::new(),
+    max_backreference: 0,
+  };
+
+  validator.read_disjunction()?;
+
+  if validator.position < pattern.len() {
+    return Err(regexp_err!());
+  }
+
+  if validator.max_backreference > validator.group_count {
+    return Err(regexp_err!());
+  }
+
+  for name in &validator.backreference_names {
+    if !validator.group_names.contains(name) {
+      return Err(regexp_err!());
+    }
+  }
+
+  Ok(())
+}
+
+fn count_capturing_groups(pattern: &[char], unicode_sets_mode: bool) -> (u32, bool) {
+  let mut count = 0;
+  let mut has_group_names = false;
+  let mut class_depth = 0;
+  let mut index = 0;
+
+  while index < pattern.len() {
+    match pattern[index] {
+      '\\' => index += 1,
+      '[' if class_depth == 0 || unicode_sets_mode => class_depth += 1,
+      ']' if class_depth > 0 => class_depth -= 1,
+      '(' if class_depth == 0 => match pattern.get(index + 1) {
+        Some('?') => {
+          if pattern.get(index + 2) == Some(&'<')
+            && !matches!(pattern.get(index + 3), Some('=' | '!'))
+          {
+            count += 1;
+            has_group_names = true;
+          }
+        }
+        _ => count += 1,
+      },
+      _ => {}
+    }
+
+    index += 1;
+  }
+
+  (count, has_group_names)
+}
+
+struct PatternValidator<'s> {
+  pattern: &'s [char],
+  position: usize,
+  unicode_mode: bool,
+  unicode_sets_mode: bool,
+  named_groups: bool,
+  annex_b: bool,
+  group_count: u32,
+  group_names: Vec<String>,
+  // names that could be matched together with a new group, names can only be repeated in
+  // different alternatives
+  active_group_names: Vec<String>,
+  backreference_names: Vec<String>,
+  max_backreference: u32,
+}
+
+impl PatternValidator<'_> {
+  fn peek(&self) -> Option<char> {
+    self.pattern.get(self.position).copied()
+  }
+
+  fn peek_at(&self, offset: usize) -> Option<char> {
+    self.pattern.get(self.position + offset).copied()
+  }
+
+  fn eat(&mut self, c: char) -> bool {
+    if self.peek() == Some(c) {
+      self.position += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn read_disjunction(&mut self) -> Result<(), ParseError> {
+    let names_start = self.active_group_names.len();
+    let mut alternative_names = Vec::new();
+
+    loop {
+      self.read_alternative()?;
+
+      if !self.eat('|') {
+        break;
+      }
+
+      alternative_names.extend(self.active_group_names.drain(names_start..));
+    }
+
+    self.active_group_names.extend(alternative_names);
+    Ok(())
+  }
+
+  fn read_alternative(&mut self) -> Result<(), ParseError> {
+    while let Some(c) = self.peek() {
+      if c == '|' || c == ')' {
+        break;
+      }
+
+      self.read_term()?;
+    }
+
+    Ok(())
+  }
+
+  fn read_term(&mut self) -> Result<(), ParseError> {
+    match self.peek() {
+      Some('^' | '$') => {
+        self.position += 1;
+        return Ok(());
+      }
+      Some('\\') if matches!(self.peek_at(1), Some('b' | 'B')) => {
+        self.position += 2;
+        return Ok(());
+      }
+      Some('(') if self.peek_at(1) == Some('?') => match self.peek_at(2) {
+        Some('=' | '!') => {
+          self.position += 3;
+          self.read_disjunction()?;
+          if !self.eat(')') {
+            return Err(regexp_err!());
+          }
+
+          // annex B allows quantifying lookaheads
+          if self.annex_b {
+            self.read_quantifier()?;
+          }
+
+          return Ok(());
+        }
+        Some('<') if matches!(self.peek_at(3), Some('=' | '!')) => {
+          self.position += 4;
+          self.read_disjunction()?;
+          if !self.eat(')') {
+            return Err(regexp_err!());
+          }
+
+          return Ok(());
+        }
+        _ => {}
+      },
+      _ => {}
+    }
+
+    self.read_atom()?;
+    self.read_quantifier()?;
+    Ok(())
+  }
+
+  fn read_quantifier(&mut self) -> Result<(), ParseError> {
+    match self.peek() {
+      Some('*' | '+' | '?') => self.position += 1,
+      Some('{') => match self.read_braced_quantifier()? {
+        true => {}
+        false if self.annex_b => return Ok(()),
+        false => return Err(regexp_err!()),
+      },
+      _ => return Ok(()),
+    }
+
+    self.eat('?');
+    Ok(())
+  }
+
+  // reads `{n}`, `{n,}` or `{n,m}` if there is one at the current position
+  fn read_braced_quantifier(&mut self) -> Result<bool, ParseError> {
+    let start = self.position;
+    self.position += 1;
+
+    let min = match self.read_decimal_digits() {
+      Some(min) => min,
+      None => {
+        self.position = start;
+        return Ok(false);
+      }
+    };
+
+    let max = if self.eat(',') {
+      self.read_decimal_digits()
+    } else {
+      Some(min)
+    };
+
+    if !self.eat('}') {
+      self.position = start;
+      return Ok(false);
+    }
+
+    match max {
+      Some(max) if max < min => Err(regexp_err!()),
+      _ => Ok(true),
+    }
+  }
+
+  fn read_decimal_digits(&mut self) -> Option<u64> {
+    let mut value: Option<u64> = None;
+
+    while let Some(digit @ '0'..='9') = self.peek() {
+      self.position += 1;
+      let digit = digit as u64 - '0' as u64;
+      value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+    }
+
+    value
+  }
+
+  fn read_atom(&mut self) -> Result<(), ParseError> {
+    match self.peek() {
+      None => Err(regexp_err!()),
+      Some('(') => self.read_group(),
+      Some('[') => {
+        self.position += 1;
+        if self.unicode_sets_mode {
+          self.read_class_set()
+        } else {
+          self.read_class_ranges()
+        }
+      }
+      Some('\\') => {
+        self.position += 1;
+        self.read_atom_escape()
+      }
+      Some('*' | '+' | '?' | ')' | '|') => Err(regexp_err!()),
+      Some('{') => {
+        if !self.annex_b || self.read_braced_quantifier()? {
+          return Err(regexp_err!());
+        }
+
+        self.position += 1;
+        Ok(())
+      }
+      Some(']' | '}') if !self.annex_b => Err(regexp_err!()),
+      Some(_) => {
+        self.position += 1;
+        Ok(())
+      }
+    }
+  }
+
+  fn read_group(&mut self) -> Result<(), ParseError> {
+    self.position += 1;
+
+    if self.eat('?') {
+      match self.peek() {
+        Some(':') => self.position += 1,
+        Some('<') => {
+          self.position += 1;
+          let name = self.read_group_name()?;
+
+          if self.active_group_names.contains(&name) {
+            return Err(regexp_err!());
+          }
+
+          self.active_group_names.push(name.clone());
+          self.group_names.push(name);
+        }
+        Some('i' | 'm' | 's' | '-') => self.read_modifiers()?,
+        _ => return Err(regexp_err!()),
+      }
+    }
+
+    self.read_disjunction()?;
+
+    if !self.eat(')') {
+      return Err(regexp_err!());
+    }
+
+    Ok(())
+  }
+
+  // `(?ims-ims:`, a flag can't be repeated, and at least one has to be present
+  fn read_modifiers(&mut self) -> Result<(), ParseError> {
+    let mut seen = [false; 3];
+    let mut has_modifiers = false;
+    let mut removing = false;
+
+    loop {
+      let index = match self.peek() {
+        Some('i') => 0,
+        Some('m') => 1,
+        Some('s') => 2,
+        Some('-') if !removing => {
+          self.position += 1;
+          removing = true;
+          continue;
+        }
+        Some(':') => {
+          self.position += 1;
+          break;
+        }
+        _ => return Err(regexp_err!()),
+      };
+
+      if seen[index] {
+        return Err(regexp_err!());
+      }
+
+      seen[index] = true;
+      has_modifiers = true;
+      self.position += 1;
+    }
+
+    if removing && !has_modifiers {
+      return Err(regexp_err!());
+    }
+
+    Ok(())
+  }
+
+  // reads a group name up to and including the closing `>`
+  fn read_group_name(&mut self) -> Result<String, ParseError> {
+    let mut name = String::new();
+
+    loop {
+      let c = match self.peek() {
+        Some('>') if !name.is_empty() => {
+          self.position += 1;
+          break;
+        }
+        Some('\\') => {
+          self.position += 1;
+          if !self.eat('u') {
+            return Err(regexp_err!());
+          }
+
+          let value = self.read_unicode_escape(true)?;
+          char::from_u32(value).ok_or(regexp_err!())?
+        }
+        Some(c) => {
+          self.position += 1;
+          c
+        }
+        None => return Err(regexp_err!()),
+      };
+
+      let valid = if name.is_empty() {
+        is_id_start(c)
+      } else {
+        is_id_continue(c) || c == '\u{200C}' || c == '\u{200D}'
+      };
+
+      if !valid {
+        return Err(regexp_err!());
+      }
+
+      name.push(c);
+    }
+
+    Ok(name)
+  }
+
+  fn read_atom_escape(&mut self) -> Result<(), ParseError> {
+    match self.peek() {
+      None => Err(regexp_err!()),
+      Some('1'..='9') => {
+        let start = self.position;
+        let value = self.read_decimal_digits().unwrap_or(0);
+
+        if self.unicode_mode {
+          self.max_backreference = self
+            .max_backreference
+            .max(value.min(u32::MAX as u64) as u32);
+          return Ok(());
+        }
+
+        if value <= self.group_count as u64 {
+          return Ok(());
+        }
+
+        // annex B reads a backreference to a group that doesn't exist as a legacy octal escape
+        if !self.annex_b {
+          return Err(regexp_err!());
+        }
+
+        self.position = start;
+        self.read_character_escape(false)?;
+        Ok(())
+      }
+      Some('k') if self.named_groups => {
+        self.position += 1;
+        if !self.eat('<') {
+          return Err(regexp_err!());
+        }
+
+        let name = self.read_group_name()?;
+        self.backreference_names.push(name);
+        Ok(())
+      }
+      Some(_) => {
+        self.read_class_escape(false)?;
+        Ok(())
+      }
+    }
+  }
+
+  // the escapes that can appear both inside and outside of a class, `\` was already read
+  fn read_class_escape(&mut self, in_class: bool) -> Result<ClassAtom, ParseError> {
+    let atom = match self.peek() {
+      Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+        self.position += 1;
+        ClassAtom::Class
+      }
+      Some(c @ ('p' | 'P')) if self.unicode_mode => {
+        self.position += 1;
+        match self.read_property_escape(c == 'P')? {
+          true => ClassAtom::Strings,
+          false => ClassAtom::Class,
+        }
+      }
+      _ => ClassAtom::Char(self.read_character_escape(in_class)?),
+    };
+
+    Ok(atom)
+  }
+
+  // `\p{Name}` or `\p{Name=Value}`, after the `p`, returning if it's a property of strings. those
+  // are only allowed with the `v` flag, and not in `\P`
+  fn read_property_escape(&mut self, negated: bool) -> Result<bool, ParseError> {
+    if !self.eat('{') {
+      return Err(regexp_err!());
+    }
+
+    let name_start = self.position;
+    while let Some('a'..='z' | 'A'..='Z' | '_') = self.peek() {
+      self.position += 1;
+    }
+
+    if self.position == name_start {
+      return Err(regexp_err!());
+    }
+
+    let name = self.pattern[name_start..self.position]
+      .iter()
+      .collect::<String>();
+    let mut value = None;
+
+    if self.eat('=') {
+      let value_start = self.position;
+      while let Some('a'..='z' | 'A'..='Z' | '0'..='9' | '_') = self.peek() {
+        self.position += 1;
+      }
+
+      if self.position == value_start {
+        return Err(regexp_err!());
+      }
+
+      value = Some(
+        self.pattern[value_start..self.position]
+          .iter()
+          .collect::<String>(),
+      );
+    }
+
+    if !self.eat('}') {
+      return Err(regexp_err!());
+    }
+
+    let property = Property::lookup(&name, value.as_deref()).ok_or(regexp_err!())?;
+    if property.has_strings() && (negated || !self.unicode_sets_mode) {
+      return Err(regexp_err!());
+    }
+
+    Ok(property.has_strings())
+  }
+
+  // reads a CharacterEscape (and the annex B extensions) returning the escaped code point
+  fn read_character_escape(&mut self, in_class: bool) -> Result<u32, ParseError> {
+    let c = self.peek().ok_or(regexp_err!())?;
+
+    let value = match c {
+      'f' => 0x0C,
+      'n' => 0x0A,
+      'r' => 0x0D,
+      't' => 0x09,
+      'v' => 0x0B,
+      'b' if in_class => 0x08,
+      '-' if in_class && self.unicode_mode => '-' as u32,
+      'c' => match self.peek_at(1) {
+        Some(letter @ ('a'..='z' | 'A'..='Z')) => {
+          self.position += 2;
+          return Ok(letter as u32 % 32);
+        }
+        Some(letter @ ('0'..='9' | '_')) if in_class && self.annex_b => {
+          self.position += 2;
+          return Ok(letter as u32 % 32);
+        }
+        // annex B reads the backslash as a literal character, and the `c` is read on its own
+        _ if self.annex_b => return Ok('\\' as u32),
+        _ => return Err(regexp_err!()),
+      },
+      '0' if !matches!(self.peek_at(1), Some('0'..='9')) => 0,
+      '0'..='7' if self.annex_b => return Ok(self.read_legacy_octal_escape()),
+      'x' => {
+        self.position += 1;
+        match (self.peek(), self.peek_at(1)) {
+          (Some(a), Some(b)) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+            self.position += 2;
+            return Ok(((hex_digit_value(a) << 4) | hex_digit_value(b)) as u32);
+          }
+          _ if self.annex_b => return Ok('x' as u32),
+          _ => return Err(regexp_err!()),
+        }
+      }
+      'u' => {
+        self.position += 1;
+        let start = self.position;
+        return match self.read_unicode_escape(self.unicode_mode) {
+          Ok(value) => Ok(value),
+          Err(_) if self.annex_b => {
+            self.position = start;
+            Ok('u' as u32)
+          }
+          Err(error) => Err(error),
+        };
+      }
+      'k' if self.annex_b && self.named_groups => return Err(regexp_err!()),
+      c if self.unicode_mode => {
+        if !matches!(
+          c,
+          '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '/'
+        ) {
+          return Err(regexp_err!());
+        }
+
+        c as u32
+      }
+      c if self.annex_b => c as u32,
+      c => {
+        if c != '$' && is_id_continue(c) {
+          return Err(regexp_err!());
+        }
+
+        c as u32
+      }
+    };
+
+    self.position += 1;
+    Ok(value)
+  }
+
+  fn read_legacy_octal_escape(&mut self) -> u32 {
+    let max_digits = match self.peek() {
+      Some('0'..='3') => 3,
+      _ => 2,
+    };
+
+    let mut value = 0;
+    let mut digits = 0;
+
+    while let Some(digit @ '0'..='7') = self.peek() {
+      if digits == max_digits {
+        break;
+      }
+
+      value = value * 8 + (digit as u32 - '0' as u32);
+      digits += 1;
+      self.position += 1;
+    }
+
+    value
+  }
+
+  // `XXXX` or `{X...}` after a `\u`, in unicode mode surrogate pairs are read as a single code point
+  fn read_unicode_escape(&mut self, unicode_mode: bool) -> Result<u32, ParseError> {
+    if unicode_mode && self.eat('{') {
+      let mut value = 0u32;
+      let mut digits = 0;
+
+      while let Some(digit) = self.peek().filter(char::is_ascii_hexdigit) {
+        self.position += 1;
+        digits += 1;
+        value = (value << 4) | hex_digit_value(digit) as u32;
+
+        if value > 0x10FFFF {
+          return Err(regexp_err!());
+        }
+      }
+
+      if digits == 0 || !self.eat('}') {
+        return Err(regexp_err!());
+      }
+
+      return Ok(value);
+    }
+
+    let value = self.read_hex4()?;
+
+    if unicode_mode
+      && (0xD800..=0xDBFF).contains(&value)
+      && self.peek() == Some('\\')
+      && self.peek_at(1) == Some('u')
+    {
+      let start = self.position;
+      self.position += 2;
+
+      match self.read_hex4() {
+        Ok(trail @ 0xDC00..=0xDFFF) => {
+          return Ok(0x10000 + ((value - 0xD800) << 10) + (trail - 0xDC00));
+        }
+        _ => self.position = start,
+      }
+    }
+
+    Ok(value)
+  }
+
+  fn read_hex4(&mut self) -> Result<u32, ParseError> {
+    let mut value = 0u32;
+
+    for _ in 0..4 {
+      match self.peek() {
+        Some(digit) if digit.is_ascii_hexdigit() => {
+          self.position += 1;
+          value = (value << 4) | hex_digit_value(digit) as u32;
+        }
+        _ => return Err(regexp_err!()),
+      }
+    }
+
+    Ok(value)
+  }
+
+  // a class without the `v` flag, after the `[`
+  fn read_class_ranges(&mut self) -> Result<(), ParseError> {
+    self.eat('^');
+
+    loop {
+      if self.eat(']') {
+        break;
+      }
+
+      let from = self.read_class_atom()?;
+
+      if self.peek() == Some('-') && !matches!(self.peek_at(1), Some(']') | None) {
+        self.position += 1;
+        let to = self.read_class_atom()?;
+
+        match (from, to) {
+          (ClassAtom::Char(from), ClassAtom::Char(to)) => {
+            if from > to {
+              return Err(regexp_err!());
+            }
+          }
+          // annex B reads ranges with a class on either side as a union with `-`
+          _ if self.annex_b => {}
+          _ => return Err(regexp_err!()),
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn read_class_atom(&mut self) -> Result<ClassAtom, ParseError> {
+    match self.peek() {
+      None => Err(regexp_err!()),
+      Some('\\') => {
+        self.position += 1;
+        self.read_class_escape(true)
+      }
+      Some(c) => {
+        self.position += 1;
+        Ok(ClassAtom::Char(c as u32))
+      }
+    }
+  }
+
+  // a class with the `v` flag, after the `[`
+  fn read_class_set(&mut self) -> Result<(), ParseError> {
+    let negated = self.eat('^');
+    let may_contain_strings = self.read_class_set_expression()?;
+
+    if negated && may_contain_strings {
+      return Err(regexp_err!());
+    }
+
+    Ok(())
+  }
+
+  // reads the contents of a v-mode class up to and including the `]`, and tells if it may match
+  // strings
+  fn read_class_set_expression(&mut self) -> Result<bool, ParseError> {
+    if self.eat(']') {
+      return Ok(false);
+    }
+
+    let first = self.read_class_set_operand()?;
+
+    let operator = match (self.peek(), self.peek_at(1)) {
+      (Some('&'), Some('&')) => Some('&'),
+      (Some('-'), Some('-')) => Some('-'),
+      _ => None,
+    };
+
+    if let Some(operator) = operator {
+      let mut may_contain_strings = operand_may_contain_strings(first);
+
+      loop {
+        if self.eat(']') {
+          return Ok(may_contain_strings);
+        }
+
+        if self.peek() != Some(operator) || self.peek_at(1) != Some(operator) {
+          return Err(regexp_err!());
+        }
+
+        self.position += 2;
+
+        if operator == '&' && self.peek() == Some('&') {
+          return Err(regexp_err!());
+        }
+
+        let operand = self.read_class_set_operand()?;
+        if operator == '&' {
+          may_contain_strings &= operand_may_contain_strings(operand);
+        }
+      }
+    }
+
+    let mut may_contain_strings = false;
+    let mut operand = first;
+
+    loop {
+      match operand {
+        ClassSetOperand::Char(from) if self.peek() == Some('-') => {
+          self.position += 1;
+          match self.read_class_set_operand()? {
+            ClassSetOperand::Char(to) if from <= to => {}
+            _ => return Err(regexp_err!()),
+          }
+        }
+        operand => may_contain_strings |= operand_may_contain_strings(operand),
+      }
+
+      if self.eat(']') {
+        return Ok(may_contain_strings);
+      }
+
+      if matches!(
+        (self.peek(), self.peek_at(1)),
+        (Some('&'), Some('&')) | (Some('-'), Some('-'))
+      ) {
+        return Err(regexp_err!());
+      }
+
+      operand = self.read_class_set_operand()?;
+    }
+  }
+
+  fn read_class_set_operand(&mut self) -> Result<ClassSetOperand, ParseError> {
+    let c = self.peek().ok_or(regexp_err!())?;
+
+    let operand = match c {
+      '[' => {
+        self.position += 1;
+        let negated = self.eat('^');
+        let may_contain_strings = self.read_class_set_expression()?;
+
+        if negated && may_contain_strings {
+          return Err(regexp_err!());
+        }
+
+        ClassSetOperand::Class(may_contain_strings)
+      }
+      '\\' => {
+        self.position += 1;
+        match self.peek() {
+          Some('q') => {
+            self.position += 1;
+            ClassSetOperand::Class(self.read_class_string_disjunction()?)
+          }
+          Some(
+            '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~',
+          ) => {
+            self.position += 1;
+            ClassSetOperand::Char(c as u32)
+          }
+          _ => match self.read_class_escape(true)? {
+            ClassAtom::Char(value) => ClassSetOperand::Char(value),
+            ClassAtom::Class => ClassSetOperand::Class(false),
+            ClassAtom::Strings => ClassSetOperand::Class(true),
+          },
+        }
+      }
+      _ => ClassSetOperand::Char(self.read_class_set_character()?),
+    };
+
+    Ok(operand)
+  }
+
+  // a literal character inside of a v-mode class
+  fn read_class_set_character(&mut self) -> Result<u32, ParseError> {
+    let c = self.peek().ok_or(regexp_err!())?;
+
+    if matches!(
+      c,
+      '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|'
+    ) {
+      return Err(regexp_err!());
+    }
+
+    if self.peek_at(1) == Some(c)
+      && matches!(
+        c,
+        '&'
+          | '!'
+          | '#'
+          | '$'
+          | '%'
+          | '*'
+          | '+'
+          | ','
+          | '.'
+          | ':'
+          | ';'
+          | '<'
+          | '='
+          | '>'
+          | '?'
+          | '@'
+          | '^'
+          | '`'
+          | '~'
+      )
+    {
+      return Err(regexp_err!());
+    }
+
+    self.position += 1;
+    Ok(c as u32)
+  }
+
+  // `\q{abc|def}`, after the `q`, tells if any of the strings isn't a single character
+  fn read_class_string_disjunction(&mut self) -> Result<bool, ParseError> {
+    if !self.eat('{') {
+      return Err(regexp_err!());
+    }
+
+    let mut may_contain_strings = false;
+    let mut length = 0;
+
+    loop {
+      match self.peek() {
+        Some('}') => {
+          self.position += 1;
+          break;
+        }
+        Some('|') => {
+          self.position += 1;
+          may_contain_strings |= length != 1;
+          length = 0;
+          continue;
+        }
+        Some('\\') => {
+          self.position += 1;
+          if !self.eat('b') {
+            match self.peek() {
+              Some(
+                '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~',
+              ) => self.position += 1,
+              _ => {
+                self.read_character_escape(true)?;
+              }
+            }
+          }
+        }
+        Some(_) => {
+          self.read_class_set_character()?;
+        }
+        None => return Err(regexp_err!()),
+      }
+
+      length += 1;
+    }
+
+    Ok(may_contain_strings || length != 1)
+  }
+}
+
+fn operand_may_contain_strings(operand: ClassSetOperand) -> bool {
+  match operand {
+    ClassSetOperand::Char(_) => false,
+    ClassSetOperand::Class(may_contain_strings) => may_contain_strings,
+  }
+}
@@ -1,3 +1,5 @@
+use tower_ast::string::JsString;
+
 use crate::parser::{
   error::{syntax_err, ParseError, ParseErrorCode},
   Parser,
@@ -8,7 +10,7 @@ use super::token::Token;
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_string_literal(&mut self, quote_type: char) -> Result<Token, ParseError> {
     self.context.position += 1;
-    let mut characters = String::new();
+    let mut characters = JsString::new();
 
     loop {
       match self.required_char(self.context.position)? {
@@ -17,9 +19,8 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         }
         '\\' => {
           self.context.position += 1;
-          match self.read_string_escape_sequence()? {
-            None => {}
-            Some(c) => characters.push(c),
+          if let Some(point) = self.read_string_escape_sequence()? {
+            characters.push_code_point(point);
           }
         }
         c => {
@@ -37,8 +38,26 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
     Ok(Token::StringLiteral(characters))
   }
 
-  pub fn read_template_characters(&mut self) -> Result<(bool, String, Option<String>), ParseError> {
-    let mut characters = String::new();
+  // the lexer reads `}` as a punctuator, so after a substitution the parser goes back and reads it
+  // again as the start of a template middle or tail
+  pub fn rescan_template_continuation(&mut self) -> Result<(), ParseError> {
+    if !matches!(self.context.token, Token::RightCurlyBracket) {
+      return Err(syntax_err!());
+    }
+
+    self.context.position -= 1;
+    let previous_end = self.context.previous_end;
+    self.context.flags.goal_template = true;
+    let result = self.next_token();
+    self.context.flags.goal_template = false;
+    self.context.previous_end = previous_end;
+    result
+  }
+
+  pub fn read_template_characters(
+    &mut self,
+  ) -> Result<(bool, String, Option<JsString>), ParseError> {
+    let mut characters = JsString::new();
     let mut has_invalid_character = false;
     let start_index = self.context.position;
 
@@ -62,7 +81,7 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           self.context.position += 1;
           let original_position = self.context.position;
           match self.read_string_escape_sequence() {
-            Ok(Some(c)) => characters.push(c),
+            Ok(Some(point)) => characters.push_code_point(point),
             Ok(None) => {}
             Err(_) => {
               self.context.position = original_position;
@@ -70,6 +89,14 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
             }
           }
         }
+        // both the cooked and the raw strings read `\r\n` and `\r` as `\n`
+        '\r' => {
+          characters.push('\n');
+          self.context.position += 1;
+          if self.source.get(self.context.position) == Some(&'\n') {
+            self.context.position += 1;
+          }
+        }
         c => {
           characters.push(*c);
           self.context.position += 1;
@@ -77,7 +104,10 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
     };
 
-    let raw_characters = String::from_iter(&self.source[start_index..(self.context.position - 1)]);
+    let end_index = self.context.position - if is_tail { 1 } else { 2 };
+    let raw_characters = String::from_iter(&self.source[start_index..end_index])
+      .replace("\r\n", "\n")
+      .replace('\r', "\n");
 
     if has_invalid_character {
       Ok((is_tail, raw_characters, None))
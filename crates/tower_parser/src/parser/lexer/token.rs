@@ -1,3 +1,5 @@
+use tower_ast::string::JsString;
+
 use crate::bigint::BigInt;
 
 #[derive(Debug, Clone)]
@@ -11,6 +13,7 @@ pub enum Token {
   CircumflexEquals,
   Comma,
   Colon,
+  CommercialAt,
   DoubleAmpersand,
   DoubleAmpersandEquals,
   DoubleAsterisk,
@@ -35,6 +38,8 @@ pub enum Token {
   FullStop,
   GreaterThan,
   GreaterThanEquals,
+  JsxIdentifier(String),
+  JsxText(String, String),
   LeftCurlyBracket,
   LeftParenthesis,
   LeftSquareBracket,
@@ -42,7 +47,7 @@ pub enum Token {
   LessThanEquals,
   Minus,
   MinusEquals,
-  NoSubstitutionTemplate(String, Option<String>),
+  NoSubstitutionTemplate(String, Option<JsString>),
   Name(Name),
   NumberSign,
   NumberLiteral(f64),
@@ -59,10 +64,10 @@ pub enum Token {
   Semicolon,
   Solidus,
   SolidusEquals,
-  StringLiteral(String),
-  TemplateHead(String, Option<String>),
-  TemplateMiddle(String, Option<String>),
-  TemplateTail(String, Option<String>),
+  StringLiteral(JsString),
+  TemplateHead(String, Option<JsString>),
+  TemplateMiddle(String, Option<JsString>),
+  TemplateTail(String, Option<JsString>),
   Tilde,
   TripleEquals,
   TripleGreaterThan,
@@ -1,36 +1,99 @@
-use ast::{statement::Statement, Program, SourceType};
+use ast::{statement::Statement, Program, SourceType, Span};
 use bumpalo::{collections::Vec, Bump};
-use error::{parse_err, required_token, syntax_err, ParseError, ParseErrorCode};
-use lexer::token::Token;
+use error::{parse_err, required_token, syntax_err};
+use lexer::jsx::JsxGoal;
 
-pub mod ast;
 mod binding;
 mod error;
 mod expressions;
 mod lexer;
 mod statements;
+mod typescript;
+
+pub use error::{ParseError, ParseErrorCode};
+pub use lexer::identifier_utils::{is_id_continue, is_id_start};
+pub use lexer::token::Token;
+pub use tower_ast as ast;
 
 pub fn parse_source<'a>(
   allocator: &'a Bump,
   source: &'a [char],
   source_type: SourceType,
 ) -> Result<&'a Program<'a>, ParseError> {
-  Parser::new(&allocator, &source, source_type).parse_source()
+  parse_source_with_options(allocator, source, ParseOptions::new(source_type))
+}
+
+pub fn parse_source_with_options<'a>(
+  allocator: &'a Bump,
+  source: &'a [char],
+  options: ParseOptions,
+) -> Result<&'a Program<'a>, ParseError> {
+  Parser::new(allocator, source, options).parse_source()
+}
+
+// the tokens of the source as the parser reads them, the parser decides which of `/` and regular
+// expressions or `}` and template middles it wants. that takes a full parse, so with a syntax error
+// the tokens are the ones read up to it
+pub fn tokenize<'a>(
+  allocator: &'a Bump,
+  source: &'a [char],
+  options: ParseOptions,
+) -> (std::vec::Vec<(Token, Span)>, Result<(), ParseError>) {
+  let mut parser = Parser::new(allocator, source, options);
+  parser.tokens = Some(std::vec::Vec::new());
+  let parsed = parser.parse_source().map(|_| ());
+  (parser.tokens.unwrap_or_default(), parsed)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ParseOptions {
+  // the web compatibility grammar from annex B, it never applies to modules and the parts of it
+  // for sloppy code are off in strict code
+  pub annex_b: bool,
+  // decorators on classes and their elements, only parsed with the decorators feature
+  pub decorators: bool,
+  pub jsx: bool,
+  pub source_type: SourceType,
+  // accepts typescript syntax and leaves the types out of the program
+  pub typescript: bool,
+}
+
+impl ParseOptions {
+  pub fn new(source_type: SourceType) -> Self {
+    Self {
+      annex_b: matches!(source_type, SourceType::Script),
+      decorators: false,
+      jsx: false,
+      source_type,
+      typescript: false,
+    }
+  }
 }
 
 pub struct Parser<'r, 'a: 'r> {
   source: &'r [char],
   allocator: &'a Bump,
   source_type: SourceType,
+  annex_b: bool,
+  #[cfg(feature = "decorators")]
+  decorators: bool,
+  jsx: bool,
+  typescript: bool,
   context: ParsingContext,
+  // every token read, when tokenizing
+  tokens: Option<std::vec::Vec<(Token, Span)>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ParsingContext {
   pub position: usize,
   pub token: Token,
+  // where the current token starts and where the one before it ended, for node spans
+  pub token_start: usize,
+  pub previous_end: usize,
   pub line_terminator: bool,
   pub flags: ParserFlags,
+  pub top_level_await: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -38,42 +101,73 @@ pub struct ParserFlags {
   pub strict_mode: bool,
   pub goal_regexp: bool,
   pub goal_template: bool,
+  pub goal_jsx: Option<JsxGoal>,
   pub param_await: bool,
   pub param_yield: bool,
   pub param_in: bool,
+  pub param_return: bool,
+  pub formal_parameters: bool,
+  // in a class field's initializer, where await and yield expressions can't be used
+  pub field_initializer: bool,
+  // typescript, whether a colon after the parameters of an arrow starts its return type
+  pub arrow_return_type: bool,
 }
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
-  pub fn new(allocator: &'a Bump, source: &'a [char], source_type: SourceType) -> Self {
+  pub fn new(allocator: &'a Bump, source: &'a [char], options: ParseOptions) -> Self {
+    let source_type = options.source_type;
+
     Self {
       allocator,
       source_type,
+      annex_b: options.annex_b && matches!(source_type, SourceType::Script),
+      #[cfg(feature = "decorators")]
+      decorators: options.decorators,
+      jsx: options.jsx,
+      typescript: options.typescript,
       source,
+      tokens: None,
       context: ParsingContext {
         position: 0,
         token: Token::EndOfInput,
+        token_start: 0,
+        previous_end: 0,
         line_terminator: false,
         flags: ParserFlags {
           strict_mode: matches!(source_type, SourceType::Module),
-          goal_regexp: true,
+          goal_regexp: false,
           goal_template: false,
+          goal_jsx: None,
           param_await: matches!(source_type, SourceType::Module),
           param_yield: false,
-          param_in: false,
+          param_in: true,
+          param_return: false,
+          formal_parameters: false,
+          field_initializer: false,
+          arrow_return_type: true,
         },
+        top_level_await: false,
       },
     }
   }
 
   pub fn parse_source(&mut self) -> Result<&'a Program<'a>, ParseError> {
+    self.read_program().map_err(|error| ParseError {
+      span: self.token_span(),
+      ..error
+    })
+  }
+
+  fn read_program(&mut self) -> Result<&'a Program<'a>, ParseError> {
     self.next_token()?;
     let mut list = Vec::<Statement<'a>>::new_in(self.allocator);
+    self.read_directives(&mut list)?;
 
     loop {
       match &self.context.token {
         Token::EndOfInput => break,
         _ => {
-          let statement = self.read_statement()?.ok_or(syntax_err!())?;
+          let statement = self.read_statement_list_item()?.ok_or(syntax_err!())?;
           list.push(statement);
         }
       }
@@ -81,17 +175,50 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
 
     let program = Program {
       source_type: self.source_type,
+      span: Span::new(0, self.source.len()),
       statement_list: list,
+      top_level_await: self.context.top_level_await,
     };
 
     Ok(self.allocator.alloc(program))
   }
 
+  // the span from `start` to the end of the last token read
+  pub fn span_from(&self, start: usize) -> Span {
+    Span::new(start, self.context.previous_end)
+  }
+
+  pub fn token_span(&self) -> Span {
+    Span::new(self.context.token_start, self.context.position)
+  }
+
   pub fn auto_semicolon(&mut self) -> Result<(), ParseError> {
     match &self.context.token {
       Token::Semicolon => self.next_token(),
-      Token::RightCurlyBracket | Token::EndOfInput if self.context.line_terminator => Ok(()),
+      Token::RightCurlyBracket | Token::EndOfInput => Ok(()),
+      _ if self.context.line_terminator => Ok(()),
       _ => Err(syntax_err!()),
     }
   }
+
+  pub fn enter_function(&mut self, r#async: bool, generator: bool) -> ParserFlags {
+    let flags = self.context.flags;
+    self.context.flags.param_await = r#async;
+    self.context.flags.param_yield = generator;
+    self.context.flags.param_in = true;
+    self.context.flags.param_return = true;
+    self.context.flags.formal_parameters = false;
+    self.context.flags.field_initializer = false;
+    self.context.flags.arrow_return_type = true;
+    flags
+  }
+
+  pub fn exit_function(&mut self, flags: ParserFlags) {
+    self.context.flags = ParserFlags {
+      goal_regexp: self.context.flags.goal_regexp,
+      goal_template: self.context.flags.goal_template,
+      goal_jsx: self.context.flags.goal_jsx,
+      ..flags
+    };
+  }
 }
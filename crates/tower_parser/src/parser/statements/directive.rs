@@ -0,0 +1,143 @@
+use bumpalo::collections::Vec;
+
+use crate::parser::{
+  ast::{
+    binding::{BindingPattern, BindingPatternInitializer},
+    expression::{Expression, StringLiteral},
+    statement::{Block, Statement},
+    Identifier,
+  },
+  error::{ParseError, ParseErrorCode},
+  expressions::identifier::is_strict_mode_reserved_word,
+  lexer::token::Token,
+  parse_err, syntax_err, Parser,
+};
+
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  // the directive prologue at the start of a script or a function body, the statements that are
+  // only a string literal. `"use strict"` makes the code after it strict, so the token after the
+  // directive is read again now that it's known, and a legacy octal escape in a directive before
+  // it is an error that couldn't be seen when that string was read
+  pub fn read_directives(&mut self, list: &mut Vec<'a, Statement<'a>>) -> Result<(), ParseError> {
+    let mut octal_escape = false;
+
+    while let Token::StringLiteral(_) = &self.context.token {
+      let statement = self.read_statement_list_item()?.ok_or(syntax_err!())?;
+      let directive = directive(&statement);
+      list.push(statement);
+
+      let Some(literal) = directive else {
+        break;
+      };
+
+      octal_escape |= self.has_legacy_octal_escape(literal);
+      if is_use_strict(literal) && !self.context.flags.strict_mode {
+        if octal_escape {
+          return Err(parse_err!(ParseErrorCode::StrictOctalEscape));
+        }
+
+        self.context.flags.strict_mode = true;
+        self.reread_token()?;
+      }
+    }
+
+    Ok(())
+  }
+
+  // a body with `"use strict"` can't have parameters other than plain identifiers, and when the
+  // directive is what makes the function strict, its name and parameters are checked again the way
+  // strict code checks them. `strict_mode` is whether the code around the function is strict
+  pub fn check_use_strict_function(
+    &self,
+    strict_mode: bool,
+    identifier: Option<&Identifier>,
+    bindings: &[BindingPatternInitializer<'a>],
+    rest: bool,
+    body: &Block<'a>,
+  ) -> Result<(), ParseError> {
+    if !body
+      .statements
+      .iter()
+      .map_while(directive)
+      .any(is_use_strict)
+    {
+      return Ok(());
+    }
+
+    let mut names = std::vec::Vec::new();
+    for binding in bindings {
+      match binding {
+        BindingPatternInitializer {
+          initializer: None,
+          pattern: BindingPattern::Identifier(identifier),
+          ..
+        } => names.push(identifier.name),
+        _ => return Err(syntax_err!()),
+      }
+    }
+
+    if rest {
+      return Err(syntax_err!());
+    }
+
+    if !strict_mode
+      && identifier
+        .map(|identifier| identifier.name)
+        .into_iter()
+        .chain(names)
+        .any(|name| matches!(name, "eval" | "arguments") || is_strict_mode_reserved_word(name))
+    {
+      return Err(syntax_err!());
+    }
+
+    Ok(())
+  }
+
+  // reads the current token again, after the strictness it was read with changed
+  fn reread_token(&mut self) -> Result<(), ParseError> {
+    let previous_end = self.context.previous_end;
+    let line_terminator = self.context.line_terminator;
+
+    self.context.position = self.context.token_start;
+    self.next_token()?;
+
+    self.context.previous_end = previous_end;
+    self.context.line_terminator = line_terminator;
+    Ok(())
+  }
+
+  // an escape in the literal as written that's a legacy octal one, `\0` only when a digit follows
+  fn has_legacy_octal_escape(&self, literal: &StringLiteral) -> bool {
+    let raw = &self.source[literal.span.start as usize..literal.span.end as usize];
+    let mut index = 0;
+
+    while index < raw.len() {
+      if raw[index] == '\\' {
+        match raw.get(index + 1) {
+          Some('1'..='9') => return true,
+          Some('0') if matches!(raw.get(index + 2), Some('0'..='9')) => return true,
+          _ => index += 1,
+        }
+      }
+
+      index += 1;
+    }
+
+    false
+  }
+}
+
+fn directive<'s, 'a>(statement: &'s Statement<'a>) -> Option<&'s StringLiteral<'a>> {
+  match statement {
+    Statement::Expression(statement) => match &statement.expression {
+      Expression::String(literal) => Some(literal),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+// only the exact text counts, `"use\x20strict"` is a directive that does nothing
+fn is_use_strict(literal: &StringLiteral) -> bool {
+  literal.value == "use strict" && literal.span.end - literal.span.start == 12
+}
@@ -1,5 +1,8 @@
 use crate::parser::{
-  ast::{statement::Statement, SourceType},
+  ast::{
+    statement::{JumpStatement, Statement},
+    Identifier, SourceType,
+  },
   lexer::token::{Name, Token},
   Parser,
 };
@@ -8,28 +11,48 @@ use super::{syntax_err, ParseError, ParseErrorCode};
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
   pub fn read_continue_statement(&mut self) -> Result<Statement<'a>, ParseError> {
-    self.next_token()?;
-    let label = self.read_label_identifier()?;
-    self.auto_semicolon()?;
-    Ok(Statement::Continue(self.allocator.alloc(label)))
+    let statement = self.read_jump_statement()?;
+    Ok(Statement::Continue(self.allocator.alloc(statement)))
   }
 
   pub fn read_break_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let statement = self.read_jump_statement()?;
+    Ok(Statement::Break(self.allocator.alloc(statement)))
+  }
+
+  fn read_jump_statement(&mut self) -> Result<JumpStatement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     let label = self.read_label_identifier()?;
     self.auto_semicolon()?;
-    Ok(Statement::Break(self.allocator.alloc(label)))
+
+    Ok(JumpStatement {
+      label,
+      span: self.span_from(start),
+    })
   }
 
-  fn read_label_identifier(&mut self) -> Result<Option<&'a str>, ParseError> {
+  fn read_label_identifier(&mut self) -> Result<Option<Identifier<'a>>, ParseError> {
     if self.context.line_terminator {
-      Ok(None)
-    } else {
-      match &self.context.token {
-        Token::Name(name) => self.name_as_label_identifier(name),
-        _ => Ok(None),
-      }
+      return Ok(None);
     }
+
+    let name = match &self.context.token {
+      Token::Name(name) => self.name_as_label_identifier(name)?,
+      _ => None,
+    };
+
+    let Some(name) = name else {
+      return Ok(None);
+    };
+
+    let identifier = Identifier {
+      name,
+      span: self.token_span(),
+    };
+
+    self.next_token()?;
+    Ok(Some(identifier))
   }
 
   pub fn name_as_label_identifier(&self, name: &Name) -> Result<Option<&'a str>, ParseError> {
@@ -1,33 +1,88 @@
 use bumpalo::collections::Vec;
 
 use crate::parser::ast::statement::{
-  CatchBlock, DoWhileStatement, IfStatement, SwitchCase, SwitchStatement, WhileStatement,
-  WithStatement,
+  Block, CatchBlock, DoWhileStatement, ExpressionStatement, ForBinding, ForInStatement,
+  ForInitializer, ForOfStatement, ForStatement, IfStatement, LexicalDeclaration, LexicalKind,
+  ReturnStatement, SwitchCase, SwitchStatement, ThrowStatement, VariableDeclaration,
+  WhileStatement, WithStatement,
 };
 
 use super::{
   ast::{
     binding::{BindingPattern, BindingPatternInitializer},
+    expression::Expression,
     statement::{LabelStatement, Statement, TryStatement},
+    Identifier,
   },
   error::{ParseError, ParseErrorCode},
+  expressions::check_assignment_target,
   lexer::token::{Name, Token},
   required_token, syntax_err, Parser,
 };
 
+mod directive;
 mod label;
+mod typescript;
 
 impl<'r, 'a: 'r> Parser<'r, 'a> {
+  pub fn read_statement_list_item(&mut self) -> Result<Option<Statement<'a>>, ParseError> {
+    let start = self.context.token_start;
+    let statement = match &self.context.token {
+      Token::Name(Name::Function) => {
+        self.next_token()?;
+        self.read_function_declaration(false, start)?
+      }
+      Token::Name(Name::Class) | Token::CommercialAt => self.read_class_declaration()?,
+      Token::Name(Name::Async) => {
+        let snapshot = self.context.clone();
+        self.next_token()?;
+        if !self.context.line_terminator
+          && matches!(self.context.token, Token::Name(Name::Function))
+        {
+          self.next_token()?;
+          self.read_function_declaration(true, start)?
+        } else {
+          self.context = snapshot;
+          return self.read_statement();
+        }
+      }
+      Token::Name(Name::Const) => {
+        self.next_token()?;
+        self.read_lexical_declaration(LexicalKind::Const, start)?
+      }
+      Token::Name(Name::Let) => {
+        let snapshot = self.context.clone();
+        self.next_token()?;
+        if self.is_lexical_binding_start() {
+          self.read_lexical_declaration(LexicalKind::Let, start)?
+        } else {
+          self.context = snapshot;
+          return self.read_statement();
+        }
+      }
+      Token::Name(Name::Unclassified(_)) if self.typescript => {
+        match self.read_typescript_declaration()? {
+          Some(statement) => statement,
+          None => return self.read_statement(),
+        }
+      }
+      _ => return self.read_statement(),
+    };
+
+    Ok(Some(statement))
+  }
+
   pub fn read_statement(&mut self) -> Result<Option<Statement<'a>>, ParseError> {
+    let start = self.context.token_start;
     let statement = match &self.context.token {
       Token::LeftCurlyBracket => {
-        let list = self.read_block_statement()?;
-        Statement::Block(self.allocator.alloc(list))
+        let block = self.read_block_statement()?;
+        Statement::Block(self.allocator.alloc(block))
       }
       Token::Name(Name::Var) => self.read_variable_statement()?,
       Token::Semicolon => {
         self.next_token()?;
-        Statement::Empty
+        Statement::Empty(self.span_from(start))
       }
       Token::Name(Name::If) => self.read_if_statement()?,
       Token::Name(Name::Do) => self.read_do_while_statement()?,
@@ -43,9 +98,9 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       Token::Name(Name::Debugger) => {
         self.next_token()?;
         self.auto_semicolon()?;
-        Statement::Debugger
+        Statement::Debugger(self.span_from(start))
       }
-      Token::Name(Name::Function | Name::Class) => return Ok(None),
+      Token::Name(Name::Function | Name::Class) | Token::CommercialAt => return Ok(None),
       Token::Name(Name::Async) => {
         let snapshot = self.context.clone();
         self.next_token()?;
@@ -71,7 +126,11 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         }
       }
       Token::Name(name) => match self.name_as_label_identifier(name)? {
-        Some(label) => {
+        Some(name) => {
+          let label = Identifier {
+            name,
+            span: self.token_span(),
+          };
           let snapshot = self.context.clone();
           self.next_token()?;
           match &self.context.token {
@@ -79,10 +138,16 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
               self.next_token()?;
               let statement = match self.read_statement()? {
                 Some(st) => st,
-                None => todo!(),
+                None => self.read_annex_b_function_declaration()?,
               };
 
-              Statement::Label(self.allocator.alloc(LabelStatement { label, statement }))
+              let statement = LabelStatement {
+                label,
+                span: self.span_from(start),
+                statement,
+              };
+
+              Statement::Label(self.allocator.alloc(statement))
             }
             _ => {
               self.context = snapshot;
@@ -99,15 +164,25 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
   }
 
   fn read_expression_statement(&mut self) -> Result<Option<Statement<'a>>, ParseError> {
+    let start = self.context.token_start;
     match self.read_expression()? {
-      Some(expr) => Ok(Some(Statement::Expression(self.allocator.alloc(expr)))),
+      Some(expression) => {
+        self.auto_semicolon()?;
+        let statement = ExpressionStatement {
+          expression,
+          span: self.span_from(start),
+        };
+
+        Ok(Some(Statement::Expression(self.allocator.alloc(statement))))
+      }
       None => Ok(None),
     }
   }
 
-  pub fn read_block_statement(&mut self) -> Result<Vec<'a, Statement<'a>>, ParseError> {
+  pub fn read_block_statement(&mut self) -> Result<Block<'a>, ParseError> {
+    let start = self.context.token_start;
     required_token!(self, Token::LeftCurlyBracket);
-    let mut list = Vec::<Statement<'a>>::new_in(&self.allocator);
+    let mut list = Vec::<Statement<'a>>::new_in(self.allocator);
 
     loop {
       match &self.context.token {
@@ -116,27 +191,55 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
           break;
         }
         _ => {
-          let statement = self.read_statement()?.ok_or(syntax_err!())?;
+          let statement = self.read_statement_list_item()?.ok_or(syntax_err!())?;
           list.push(statement);
         }
       }
     }
 
-    Ok(list)
+    Ok(Block {
+      span: self.span_from(start),
+      statements: list,
+    })
   }
 
   fn read_variable_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
-    let mut declarations = Vec::<BindingPatternInitializer<'a>>::new_in(&self.allocator);
+    let bindings = self.read_binding_list()?;
+    check_binding_initializers(&bindings, false)?;
+    self.auto_semicolon()?;
+    let declaration = VariableDeclaration {
+      bindings,
+      span: self.span_from(start),
+    };
 
-    loop {
-      let pattern = self.read_binding_pattern_with_initializer()?;
+    Ok(Statement::Variable(self.allocator.alloc(declaration)))
+  }
 
-      if !matches!(pattern.pattern, BindingPattern::Identifier(_)) && pattern.initializer.is_none()
-      {
-        return Err(syntax_err!());
-      }
+  // the keyword has been read already and starts at `start`
+  fn read_lexical_declaration(
+    &mut self,
+    kind: LexicalKind,
+    start: usize,
+  ) -> Result<Statement<'a>, ParseError> {
+    let bindings = self.read_binding_list()?;
+    check_binding_initializers(&bindings, matches!(kind, LexicalKind::Const))?;
+    self.auto_semicolon()?;
+    let declaration = LexicalDeclaration {
+      bindings,
+      kind,
+      span: self.span_from(start),
+    };
 
+    Ok(Statement::Lexical(self.allocator.alloc(declaration)))
+  }
+
+  fn read_binding_list(&mut self) -> Result<Vec<'a, BindingPatternInitializer<'a>>, ParseError> {
+    let mut declarations = Vec::<BindingPatternInitializer<'a>>::new_in(self.allocator);
+
+    loop {
+      let pattern = self.read_declared_binding()?;
       declarations.push(pattern);
 
       match &self.context.token {
@@ -145,21 +248,31 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       }
     }
 
-    self.auto_semicolon()?;
-    Ok(Statement::Variable(self.allocator.alloc(declarations)))
+    Ok(declarations)
+  }
+
+  // after a `let`, tells if it starts a lexical declaration instead of being an identifier
+  fn is_lexical_binding_start(&self) -> bool {
+    match &self.context.token {
+      Token::LeftSquareBracket | Token::LeftCurlyBracket => true,
+      Token::Name(Name::In | Name::Instanceof) => false,
+      Token::Name(_) => true,
+      _ => false,
+    }
   }
 
   fn read_if_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     required_token!(self, Token::LeftParenthesis);
     let condition = self.read_expression()?.ok_or(syntax_err!())?;
     required_token!(self, Token::RightParenthesis);
-    let consequent = self.read_statement()?.ok_or(syntax_err!())?;
+    let consequent = self.read_body_statement(true)?;
 
     let alternate = match &self.context.token {
       Token::Name(Name::Else) => {
         self.next_token()?;
-        Some(self.read_statement()?.ok_or(syntax_err!())?)
+        Some(self.read_body_statement(true)?)
       }
       _ => None,
     };
@@ -168,52 +281,306 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       alternate,
       condition,
       consequent,
+      span: self.span_from(start),
     };
 
     Ok(Statement::If(self.allocator.alloc(statement)))
   }
 
+  // the body of a loop, if or with statement, where a function declaration is only allowed by
+  // annex B for if statements and never behind a label, IsLabelledFunction
+  fn read_body_statement(&mut self, function: bool) -> Result<Statement<'a>, ParseError> {
+    let statement = match self.read_statement()? {
+      Some(statement) => statement,
+      None if function => self.read_annex_b_function_declaration()?,
+      None => return Err(syntax_err!()),
+    };
+
+    let mut item = &statement;
+    while let Statement::Label(label) = item {
+      item = &label.statement;
+    }
+    if matches!(statement, Statement::Label(_)) && matches!(item, Statement::Function(_)) {
+      return Err(syntax_err!());
+    }
+
+    Ok(statement)
+  }
+
+  // annex B allows plain function declarations as the body of labels and if statements in
+  // non-strict code
+  fn read_annex_b_function_declaration(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
+    if !self.annex_b
+      || self.context.flags.strict_mode
+      || !matches!(self.context.token, Token::Name(Name::Function))
+    {
+      return Err(syntax_err!());
+    }
+
+    self.next_token()?;
+    if matches!(self.context.token, Token::Asterisk) {
+      return Err(syntax_err!());
+    }
+
+    self.read_function_declaration(false, start)
+  }
+
   fn read_do_while_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
-    let body = self.read_statement()?.ok_or(syntax_err!())?;
+    let body = self.read_body_statement(false)?;
     required_token!(self, Token::Name(Name::While));
     required_token!(self, Token::LeftParenthesis);
     let condition = self.read_expression()?.ok_or(syntax_err!())?;
     required_token!(self, Token::RightParenthesis);
 
-    match &self.context.token {
-      Token::Semicolon => self.next_token()?,
-      _ => {}
+    if let Token::Semicolon = &self.context.token {
+      self.next_token()?
     }
 
-    let statement = DoWhileStatement { body, condition };
+    let statement = DoWhileStatement {
+      body,
+      condition,
+      span: self.span_from(start),
+    };
     Ok(Statement::DoWhile(self.allocator.alloc(statement)))
   }
 
   fn read_while_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     required_token!(self, Token::LeftParenthesis);
     let condition = self.read_expression()?.ok_or(syntax_err!())?;
     required_token!(self, Token::RightParenthesis);
-    let body = self.read_statement()?.ok_or(syntax_err!())?;
-    let statement = WhileStatement { body, condition };
+    let body = self.read_body_statement(false)?;
+    let statement = WhileStatement {
+      body,
+      condition,
+      span: self.span_from(start),
+    };
     Ok(Statement::While(self.allocator.alloc(statement)))
   }
 
   fn read_for_statement(&mut self) -> Result<Statement<'a>, ParseError> {
-    todo!()
+    let start = self.context.token_start;
+    self.next_token()?;
+
+    let r#await = match &self.context.token {
+      Token::Name(Name::Await) => {
+        if !self.context.flags.param_await || self.context.flags.formal_parameters {
+          return Err(syntax_err!());
+        }
+
+        if !self.context.flags.param_return {
+          self.context.top_level_await = true;
+        }
+
+        self.next_token()?;
+        true
+      }
+      _ => false,
+    };
+
+    required_token!(self, Token::LeftParenthesis);
+
+    let param_in = self.context.flags.param_in;
+    self.context.flags.param_in = false;
+
+    let initializer_start = self.context.token_start;
+    let initializer = match &self.context.token {
+      Token::Semicolon => None,
+      Token::Name(Name::Var) => {
+        self.next_token()?;
+        let bindings = self.read_binding_list()?;
+        let declaration = VariableDeclaration {
+          bindings,
+          span: self.span_from(initializer_start),
+        };
+        Some(ForInitializer::Variable(self.allocator.alloc(declaration)))
+      }
+      Token::Name(Name::Const) => {
+        self.next_token()?;
+        let bindings = self.read_binding_list()?;
+        let declaration = LexicalDeclaration {
+          bindings,
+          kind: LexicalKind::Const,
+          span: self.span_from(initializer_start),
+        };
+        Some(ForInitializer::Lexical(self.allocator.alloc(declaration)))
+      }
+      token => {
+        let snapshot = self.context.clone();
+        let is_lexical = match token {
+          Token::Name(Name::Let) => {
+            self.next_token()?;
+            self.is_lexical_binding_start()
+          }
+          _ => false,
+        };
+
+        if is_lexical {
+          let bindings = self.read_binding_list()?;
+          let declaration = LexicalDeclaration {
+            bindings,
+            kind: LexicalKind::Let,
+            span: self.span_from(initializer_start),
+          };
+          Some(ForInitializer::Lexical(self.allocator.alloc(declaration)))
+        } else {
+          self.context = snapshot;
+          self.context.flags.param_in = false;
+          let expression = self.read_expression()?.ok_or(syntax_err!())?;
+          Some(ForInitializer::Expression(expression))
+        }
+      }
+    };
+
+    self.context.flags.param_in = param_in;
+
+    // a for-of can't start with `async of`, that's where an async arrow could start. the name
+    // written with an escape is fine
+    if let (
+      false,
+      Token::Name(Name::Of),
+      Some(ForInitializer::Expression(Expression::Identifier(identifier))),
+    ) = (r#await, &self.context.token, &initializer)
+    {
+      if identifier.name == "async" && identifier.span.end - identifier.span.start == 5 {
+        return Err(syntax_err!());
+      }
+    }
+
+    if let Token::Name(Name::Of | Name::In) = &self.context.token {
+      let binding = match initializer {
+        Some(ForInitializer::Expression(expression)) => {
+          check_assignment_target(&expression)?;
+          ForBinding::Expression(expression)
+        }
+        Some(ForInitializer::Variable(declaration)) => match declaration.bindings.as_slice() {
+          [binding @ BindingPatternInitializer {
+            initializer: Some(_),
+            pattern: BindingPattern::Identifier(_),
+            ..
+          }] if self.annex_b
+            && !self.context.flags.strict_mode
+            && matches!(self.context.token, Token::Name(Name::In)) =>
+          {
+            ForBinding::Variable(*binding, declaration.span)
+          }
+          _ => {
+            let pattern = single_binding(&declaration.bindings)?;
+            let binding = BindingPatternInitializer {
+              initializer: None,
+              pattern,
+              span: pattern.span(),
+            };
+
+            ForBinding::Variable(binding, declaration.span)
+          }
+        },
+        Some(ForInitializer::Lexical(declaration)) => ForBinding::Lexical(
+          declaration.kind,
+          single_binding(&declaration.bindings)?,
+          declaration.span,
+        ),
+        None => return Err(syntax_err!()),
+      };
+
+      let statement = match &self.context.token {
+        Token::Name(Name::Of) => {
+          self.next_token()?;
+          let expression = self.read_assignment_expression()?.ok_or(syntax_err!())?;
+          required_token!(self, Token::RightParenthesis);
+          let body = self.read_body_statement(false)?;
+          let statement = ForOfStatement {
+            r#await,
+            binding,
+            body,
+            expression,
+            span: self.span_from(start),
+          };
+
+          Statement::ForOf(self.allocator.alloc(statement))
+        }
+        _ => {
+          if r#await {
+            return Err(syntax_err!());
+          }
+
+          self.next_token()?;
+          let expression = self.read_expression()?.ok_or(syntax_err!())?;
+          required_token!(self, Token::RightParenthesis);
+          let body = self.read_body_statement(false)?;
+          let statement = ForInStatement {
+            binding,
+            body,
+            expression,
+            span: self.span_from(start),
+          };
+
+          Statement::ForIn(self.allocator.alloc(statement))
+        }
+      };
+
+      return Ok(statement);
+    }
+
+    if r#await {
+      return Err(syntax_err!());
+    }
+
+    match initializer {
+      Some(ForInitializer::Variable(declaration)) => {
+        check_binding_initializers(&declaration.bindings, false)?;
+      }
+      Some(ForInitializer::Lexical(declaration)) => {
+        let is_const = matches!(declaration.kind, LexicalKind::Const);
+        check_binding_initializers(&declaration.bindings, is_const)?;
+      }
+      _ => {}
+    }
+
+    required_token!(self, Token::Semicolon);
+
+    let condition = match &self.context.token {
+      Token::Semicolon => None,
+      _ => Some(self.read_expression()?.ok_or(syntax_err!())?),
+    };
+
+    required_token!(self, Token::Semicolon);
+
+    let update = match &self.context.token {
+      Token::RightParenthesis => None,
+      _ => Some(self.read_expression()?.ok_or(syntax_err!())?),
+    };
+
+    required_token!(self, Token::RightParenthesis);
+
+    let body = self.read_body_statement(false)?;
+    let statement = ForStatement {
+      body,
+      condition,
+      initializer,
+      span: self.span_from(start),
+      update,
+    };
+
+    Ok(Statement::For(self.allocator.alloc(statement)))
   }
 
   fn read_switch_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     required_token!(self, Token::LeftParenthesis);
     let expression = self.read_expression()?.ok_or(syntax_err!())?;
     required_token!(self, Token::RightParenthesis);
     required_token!(self, Token::LeftCurlyBracket);
 
-    let mut cases = Vec::<SwitchCase<'a>>::new_in(&self.allocator);
+    let mut cases = Vec::<SwitchCase<'a>>::new_in(self.allocator);
 
     loop {
+      let case_start = self.context.token_start;
       let expression = match &self.context.token {
         Token::Name(Name::Case) => {
           self.next_token()?;
@@ -233,69 +600,105 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         _ => return Err(syntax_err!()),
       };
 
-      let mut body = Vec::<Statement<'a>>::new_in(&self.allocator);
+      let mut body = Vec::<Statement<'a>>::new_in(self.allocator);
 
       loop {
         match &self.context.token {
           Token::RightCurlyBracket | Token::Name(Name::Default | Name::Case) => break,
           _ => {
-            let statement = self.read_statement()?.ok_or(syntax_err!())?;
+            let statement = self.read_statement_list_item()?.ok_or(syntax_err!())?;
             body.push(statement);
           }
         }
       }
 
-      let case = SwitchCase { body, expression };
+      let case = SwitchCase {
+        body,
+        expression,
+        span: self.span_from(case_start),
+      };
       cases.push(case);
     }
 
-    let statement = SwitchStatement { cases, expression };
+    let statement = SwitchStatement {
+      cases,
+      expression,
+      span: self.span_from(start),
+    };
     Ok(Statement::Switch(self.allocator.alloc(statement)))
   }
 
   fn read_return_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    if !self.context.flags.param_return {
+      return Err(syntax_err!());
+    }
+
+    let start = self.context.token_start;
     self.next_token()?;
-    let expression = if self.context.line_terminator {
+    let argument = if self.context.line_terminator {
       None
     } else {
       self.read_expression()?
     };
     self.auto_semicolon()?;
-    Ok(Statement::Return(self.allocator.alloc(expression)))
+    let statement = ReturnStatement {
+      argument,
+      span: self.span_from(start),
+    };
+
+    Ok(Statement::Return(self.allocator.alloc(statement)))
   }
 
   fn read_with_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
+    if self.context.flags.strict_mode {
+      return Err(syntax_err!());
+    }
+
     self.next_token()?;
     required_token!(self, Token::LeftParenthesis);
     let expression = self.read_expression()?.ok_or(syntax_err!())?;
     required_token!(self, Token::RightParenthesis);
-    let body = self.read_statement()?.ok_or(syntax_err!())?;
-    let statement = WithStatement { body, expression };
+    let body = self.read_body_statement(false)?;
+    let statement = WithStatement {
+      body,
+      expression,
+      span: self.span_from(start),
+    };
     Ok(Statement::With(self.allocator.alloc(statement)))
   }
 
   fn read_throw_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     if self.context.line_terminator {
       return Err(syntax_err!());
     }
-    let expression = self.read_expression()?.ok_or(syntax_err!())?;
+    let argument = self.read_expression()?.ok_or(syntax_err!())?;
     self.auto_semicolon()?;
-    Ok(Statement::Throw(self.allocator.alloc(expression)))
+    let statement = ThrowStatement {
+      argument,
+      span: self.span_from(start),
+    };
+
+    Ok(Statement::Throw(self.allocator.alloc(statement)))
   }
 
   fn read_try_statement(&mut self) -> Result<Statement<'a>, ParseError> {
+    let start = self.context.token_start;
     self.next_token()?;
     let body = self.read_block_statement()?;
 
     let catch = match &self.context.token {
       Token::Name(Name::Catch) => {
+        let catch_start = self.context.token_start;
         self.next_token()?;
 
         let parameter = match &self.context.token {
           Token::LeftParenthesis => {
             self.next_token()?;
             let pattern = self.read_binding_pattern()?;
+            self.skip_type_annotation()?;
             required_token!(self, Token::RightParenthesis);
             Some(pattern)
           }
@@ -303,7 +706,11 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
         };
 
         let body = self.read_block_statement()?;
-        Some(CatchBlock { body, parameter })
+        Some(CatchBlock {
+          body,
+          parameter,
+          span: self.span_from(catch_start),
+        })
       }
       _ => None,
     };
@@ -320,8 +727,39 @@ impl<'r, 'a: 'r> Parser<'r, 'a> {
       body,
       catch,
       finally,
+      span: self.span_from(start),
     };
 
     Ok(Statement::Try(self.allocator.alloc(statement)))
   }
 }
+
+// destructuring patterns always need an initializer, and so does every `const` binding
+fn check_binding_initializers(
+  bindings: &[BindingPatternInitializer],
+  required: bool,
+) -> Result<(), ParseError> {
+  for binding in bindings {
+    if binding.initializer.is_none()
+      && (required || !matches!(binding.pattern, BindingPattern::Identifier(_)))
+    {
+      return Err(syntax_err!());
+    }
+  }
+
+  Ok(())
+}
+
+// the binding of a for-in/of head has to be a single declaration without an initializer
+fn single_binding<'a>(
+  bindings: &[BindingPatternInitializer<'a>],
+) -> Result<BindingPattern<'a>, ParseError> {
+  match bindings {
+    [BindingPatternInitializer {
+      initializer: None,
+      pattern,
+      ..
+    }] => Ok(*pattern),
+    _ => Err(syntax_err!()),
+  }
+}
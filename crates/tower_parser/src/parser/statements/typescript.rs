@@ -0,0 +1,160 @@
+use crate::parser::{
+  ast::statement::Statement,
+  error::{ParseError, ParseErrorCode},
+  lexer::token::{Name, Token},
+  required_token, syntax_err, Parser,
+};
+
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  // interfaces, type aliases and ambient declarations only exist for the type checker, so they
+  // leave an empty statement behind, returns None when the word starts some other statement
+  pub fn read_typescript_declaration(&mut self) -> Result<Option<Statement<'a>>, ParseError> {
+    let keyword = match &self.context.token {
+      Token::Name(Name::Unclassified(name)) => match name.as_str() {
+        "interface" => "interface",
+        "type" => "type",
+        "abstract" => "abstract",
+        "declare" => "declare",
+        _ => return Ok(None),
+      },
+      _ => return Ok(None),
+    };
+
+    let snapshot = self.context.clone();
+    let start = self.context.token_start;
+    self.next_token()?;
+
+    let statement = match (keyword, &self.context.token) {
+      _ if self.context.line_terminator => None,
+      ("interface", Token::Name(_)) => {
+        self.skip_interface_declaration()?;
+        Some(Statement::Empty(self.span_from(start)))
+      }
+      ("type", Token::Name(_)) => {
+        self.skip_type_alias_declaration()?;
+        Some(Statement::Empty(self.span_from(start)))
+      }
+      ("abstract", Token::Name(Name::Class)) => Some(self.read_class_declaration()?),
+      ("declare", Token::Name(_)) => {
+        self.skip_ambient_declaration()?;
+        Some(Statement::Empty(self.span_from(start)))
+      }
+      _ => None,
+    };
+
+    if statement.is_none() {
+      self.context = snapshot;
+    }
+
+    Ok(statement)
+  }
+
+  fn skip_interface_declaration(&mut self) -> Result<(), ParseError> {
+    self.read_binding_identifier()?;
+    self.skip_type_parameters()?;
+
+    if let Token::Name(Name::Extends) = &self.context.token {
+      self.next_token()?;
+      self.skip_type()?;
+
+      while let Token::Comma = &self.context.token {
+        self.next_token()?;
+        self.skip_type()?;
+      }
+    }
+
+    self.skip_object_type()
+  }
+
+  fn skip_type_alias_declaration(&mut self) -> Result<(), ParseError> {
+    self.read_binding_identifier()?;
+    self.skip_type_parameters()?;
+    required_token!(self, Token::Equals);
+    self.skip_type()?;
+    self.auto_semicolon()
+  }
+
+  // everything after `declare` is thrown away, even classes and functions, since nothing in them
+  // exists at runtime
+  fn skip_ambient_declaration(&mut self) -> Result<(), ParseError> {
+    match &self.context.token {
+      Token::Name(Name::Var | Name::Let) => {
+        self.next_token()?;
+        self.read_binding_list()?;
+        self.auto_semicolon()?;
+      }
+      Token::Name(Name::Const) => {
+        self.next_token()?;
+        if let Token::Name(Name::Enum) = &self.context.token {
+          return self.skip_enum_declaration();
+        }
+
+        self.read_binding_list()?;
+        self.auto_semicolon()?;
+      }
+      Token::Name(Name::Function) => {
+        let start = self.context.token_start;
+        self.next_token()?;
+        self.read_function_declaration(false, start)?;
+      }
+      Token::Name(Name::Class) => {
+        self.read_class_declaration()?;
+      }
+      Token::Name(Name::Enum) => self.skip_enum_declaration()?,
+      Token::Name(Name::Unclassified(name)) => match name.as_str() {
+        "abstract" | "interface" | "type" => {
+          self.read_typescript_declaration()?.ok_or(syntax_err!())?;
+        }
+        "module" | "namespace" | "global" => {
+          self.next_token()?;
+
+          // `declare module "name";` declares a module without saying what's in it
+          if let Token::StringLiteral(_) = &self.context.token {
+            self.next_token()?;
+            if !matches!(self.context.token, Token::LeftCurlyBracket) {
+              return self.auto_semicolon();
+            }
+          }
+
+          while let Token::Name(_) | Token::FullStop = &self.context.token {
+            self.next_token()?;
+          }
+
+          self.skip_balanced_block()?;
+        }
+        _ => return Err(syntax_err!()),
+      },
+      _ => return Err(syntax_err!()),
+    }
+
+    Ok(())
+  }
+
+  fn skip_enum_declaration(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::Name(Name::Enum));
+    self.read_binding_identifier()?;
+    self.skip_balanced_block()
+  }
+
+  // the bodies of ambient modules and enums are only skipped over, by counting braces
+  fn skip_balanced_block(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::LeftCurlyBracket);
+    let mut depth = 1;
+
+    loop {
+      match &self.context.token {
+        Token::LeftCurlyBracket => depth += 1,
+        Token::RightCurlyBracket => {
+          depth -= 1;
+          if depth == 0 {
+            return self.next_token();
+          }
+        }
+        Token::EndOfInput => return Err(syntax_err!()),
+        _ => {}
+      }
+
+      self.next_token()?;
+    }
+  }
+}
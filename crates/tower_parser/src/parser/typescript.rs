@@ -0,0 +1,695 @@
+use super::{
+  error::{ParseError, ParseErrorCode},
+  lexer::token::{Name, Token},
+  required_token, syntax_err, Parser,
+};
+
+// typescript types are only checked for syntax and then thrown away, so none of these functions
+// build anything, the program that comes out is plain ecmascript
+impl<'r, 'a: 'r> Parser<'r, 'a> {
+  // `: Type` after a binding, a parameter or a class field
+  pub fn skip_type_annotation(&mut self) -> Result<(), ParseError> {
+    if self.typescript && matches!(self.context.token, Token::Colon) {
+      self.next_token()?;
+      self.skip_type()?;
+    }
+
+    Ok(())
+  }
+
+  // return types can also be type predicates, like `x is string`, `asserts x` or `asserts x is T`
+  pub fn skip_return_type_annotation(&mut self) -> Result<(), ParseError> {
+    if self.typescript && matches!(self.context.token, Token::Colon) {
+      self.next_token()?;
+      self.skip_return_type()?;
+    }
+
+    Ok(())
+  }
+
+  // `<T, U extends V = W>` before the parameters of a function or after the name of a class
+  pub fn skip_type_parameters(&mut self) -> Result<(), ParseError> {
+    if !self.typescript || !matches!(self.context.token, Token::LessThan) {
+      return Ok(());
+    }
+
+    self.next_token()?;
+
+    loop {
+      // `const`, `in` and `out` are modifiers when a name follows them
+      while matches!(self.context.token, Token::Name(Name::Const | Name::In))
+        || self.is_contextual_name("out")
+      {
+        if !matches!(self.peek_token()?, Token::Name(_)) {
+          break;
+        }
+
+        self.next_token()?;
+      }
+
+      if !matches!(self.context.token, Token::Name(_)) {
+        return Err(syntax_err!());
+      }
+
+      self.next_token()?;
+
+      if let Token::Name(Name::Extends) = &self.context.token {
+        self.next_token()?;
+        self.skip_type()?;
+      }
+
+      if let Token::Equals = &self.context.token {
+        self.next_token()?;
+        self.skip_type()?;
+      }
+
+      match &self.context.token {
+        Token::Comma => {
+          self.next_token()?;
+          if matches!(self.context.token, Token::GreaterThan) {
+            break;
+          }
+        }
+        _ => break,
+      }
+    }
+
+    required_token!(self, Token::GreaterThan);
+    Ok(())
+  }
+
+  pub fn skip_type_arguments(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::LessThan);
+
+    loop {
+      self.skip_type()?;
+      match &self.context.token {
+        Token::Comma => self.next_token()?,
+        _ => break,
+      }
+    }
+
+    self.skip_closing_angle_bracket()
+  }
+
+  // tells if the `<` after an expression starts the type arguments of a call or an instantiation
+  // expression like `f<T>`, instead of being a comparison, and skips them if so
+  pub fn try_skip_type_arguments(&mut self) -> Result<bool, ParseError> {
+    let snapshot = self.context.clone();
+
+    if self.skip_type_arguments().is_err() {
+      self.context = snapshot;
+      return Ok(false);
+    }
+
+    let is_type_arguments = self.context.line_terminator
+      || matches!(
+        self.context.token,
+        Token::LeftParenthesis
+          | Token::NoSubstitutionTemplate(_, _)
+          | Token::TemplateHead(_, _)
+          | Token::RightParenthesis
+          | Token::RightSquareBracket
+          | Token::RightCurlyBracket
+          | Token::Colon
+          | Token::Semicolon
+          | Token::Comma
+          | Token::FullStop
+          | Token::QuestionMarkStop
+          | Token::QuestionMark
+          | Token::DoubleEquals
+          | Token::ExclamationEquals
+          | Token::TripleEquals
+          | Token::ExclamationDoubleEquals
+          | Token::DoubleAmpersand
+          | Token::DoubleVerticalLine
+          | Token::DoubleQuestionMark
+          | Token::EndOfInput
+      );
+
+    if !is_type_arguments {
+      self.context = snapshot;
+    }
+
+    Ok(is_type_arguments)
+  }
+
+  // the lexer reads `>>`, `>=` and friends as one token, but types close one bracket at a time
+  fn skip_closing_angle_bracket(&mut self) -> Result<(), ParseError> {
+    let rest = match &self.context.token {
+      Token::GreaterThan => return self.next_token(),
+      Token::DoubleGreaterThan => Token::GreaterThan,
+      Token::TripleGreaterThan => Token::DoubleGreaterThan,
+      Token::GreaterThanEquals => Token::Equals,
+      Token::DoubleGreaterThanEquals => Token::GreaterThanEquals,
+      Token::TripleGreaterThanEquals => Token::DoubleGreaterThanEquals,
+      _ => return Err(syntax_err!()),
+    };
+
+    self.context.token = rest;
+    self.context.line_terminator = false;
+    Ok(())
+  }
+
+  // with jsx, `<T>(x) => x` would be an element, so generic arrows have to be written as
+  // `<T,>(x) => x` or `<T extends U>(x) => x`
+  pub fn is_generic_arrow_start(&mut self) -> Result<bool, ParseError> {
+    if !self.jsx {
+      return Ok(true);
+    }
+
+    let snapshot = self.context.clone();
+    self.next_token()?;
+    let is_generic = match &self.context.token {
+      Token::Name(_) => {
+        self.next_token()?;
+        matches!(
+          self.context.token,
+          Token::Comma | Token::Name(Name::Extends)
+        )
+      }
+      _ => false,
+    };
+
+    self.context = snapshot;
+    Ok(is_generic)
+  }
+
+  pub fn is_contextual_name(&self, name: &str) -> bool {
+    matches!(&self.context.token, Token::Name(Name::Unclassified(word)) if word == name)
+  }
+
+  pub fn peek_token(&mut self) -> Result<Token, ParseError> {
+    let snapshot = self.context.clone();
+    self.next_token()?;
+    let token = std::mem::replace(&mut self.context.token, Token::EndOfInput);
+    self.context = snapshot;
+    Ok(token)
+  }
+
+  pub fn skip_type(&mut self) -> Result<(), ParseError> {
+    self.skip_type_with_conditional(true)
+  }
+
+  fn skip_return_type(&mut self) -> Result<(), ParseError> {
+    let snapshot = self.context.clone();
+
+    if self.is_contextual_name("asserts") {
+      self.next_token()?;
+      if !self.context.line_terminator && matches!(self.context.token, Token::Name(_)) {
+        self.next_token()?;
+        if self.is_contextual_name("is") && !self.context.line_terminator {
+          self.next_token()?;
+          self.skip_type()?;
+        }
+
+        return Ok(());
+      }
+
+      self.context = snapshot.clone();
+    }
+
+    if let Token::Name(_) = &self.context.token {
+      self.next_token()?;
+      if self.is_contextual_name("is") && !self.context.line_terminator {
+        self.next_token()?;
+        return self.skip_type();
+      }
+
+      self.context = snapshot;
+    }
+
+    self.skip_type()
+  }
+
+  // the type after `extends` in a conditional type can't be a conditional type itself
+  fn skip_type_with_conditional(&mut self, conditional: bool) -> Result<(), ParseError> {
+    if self.try_skip_function_type()? {
+      return Ok(());
+    }
+
+    self.skip_union_type()?;
+
+    if conditional
+      && !self.context.line_terminator
+      && matches!(self.context.token, Token::Name(Name::Extends))
+    {
+      self.next_token()?;
+      self.skip_type_with_conditional(false)?;
+      required_token!(self, Token::QuestionMark);
+      self.skip_type()?;
+      required_token!(self, Token::Colon);
+      self.skip_type()?;
+    }
+
+    Ok(())
+  }
+
+  // `(a: A) => B`, `<T>(a: T) => T`, `new () => T` and `abstract new () => T`
+  fn try_skip_function_type(&mut self) -> Result<bool, ParseError> {
+    match &self.context.token {
+      Token::Name(Name::New) => {
+        self.next_token()?;
+        self.skip_function_type_rest()?;
+        Ok(true)
+      }
+      Token::Name(Name::Unclassified(name)) if name == "abstract" => {
+        if !matches!(self.peek_token()?, Token::Name(Name::New)) {
+          return Ok(false);
+        }
+
+        self.next_token()?;
+        self.next_token()?;
+        self.skip_function_type_rest()?;
+        Ok(true)
+      }
+      Token::LessThan => {
+        self.skip_function_type_rest()?;
+        Ok(true)
+      }
+      Token::LeftParenthesis => {
+        let snapshot = self.context.clone();
+        let parameters = self.read_formal_parameters();
+
+        if parameters.is_err() || !matches!(self.context.token, Token::FatArrow) {
+          self.context = snapshot;
+          return Ok(false);
+        }
+
+        self.next_token()?;
+        self.skip_return_type()?;
+        Ok(true)
+      }
+      _ => Ok(false),
+    }
+  }
+
+  fn skip_function_type_rest(&mut self) -> Result<(), ParseError> {
+    self.skip_type_parameters()?;
+    self.read_formal_parameters()?;
+    required_token!(self, Token::FatArrow);
+    self.skip_return_type()
+  }
+
+  fn skip_union_type(&mut self) -> Result<(), ParseError> {
+    if let Token::VerticalLine = &self.context.token {
+      self.next_token()?;
+    }
+
+    self.skip_intersection_type()?;
+
+    while let Token::VerticalLine = &self.context.token {
+      self.next_token()?;
+      self.skip_intersection_type()?;
+    }
+
+    Ok(())
+  }
+
+  fn skip_intersection_type(&mut self) -> Result<(), ParseError> {
+    if let Token::Ampersand = &self.context.token {
+      self.next_token()?;
+    }
+
+    self.skip_type_operator()?;
+
+    while let Token::Ampersand = &self.context.token {
+      self.next_token()?;
+      self.skip_type_operator()?;
+    }
+
+    Ok(())
+  }
+
+  fn skip_type_operator(&mut self) -> Result<(), ParseError> {
+    let operator = match &self.context.token {
+      Token::Name(Name::Unclassified(name)) => match name.as_str() {
+        "keyof" | "unique" | "readonly" => true,
+        "infer" => {
+          self.next_token()?;
+          if !matches!(self.context.token, Token::Name(_)) {
+            return Err(syntax_err!());
+          }
+
+          self.next_token()?;
+
+          // `infer U extends C` has a constraint, unless it's the start of a conditional type
+          if !self.context.line_terminator
+            && matches!(self.context.token, Token::Name(Name::Extends))
+          {
+            let snapshot = self.context.clone();
+            self.next_token()?;
+            self.skip_type_with_conditional(false)?;
+
+            if matches!(self.context.token, Token::QuestionMark) {
+              self.context = snapshot;
+            }
+          }
+
+          return Ok(());
+        }
+        _ => false,
+      },
+      _ => false,
+    };
+
+    if operator {
+      self.next_token()?;
+      return self.skip_type_operator();
+    }
+
+    self.skip_postfix_type()
+  }
+
+  // array types and indexed access types, like `T[]` and `T["key"]`
+  fn skip_postfix_type(&mut self) -> Result<(), ParseError> {
+    self.skip_primary_type()?;
+
+    while !self.context.line_terminator && matches!(self.context.token, Token::LeftSquareBracket) {
+      self.next_token()?;
+      if !matches!(self.context.token, Token::RightSquareBracket) {
+        self.skip_type()?;
+      }
+
+      required_token!(self, Token::RightSquareBracket);
+    }
+
+    Ok(())
+  }
+
+  fn skip_primary_type(&mut self) -> Result<(), ParseError> {
+    match &self.context.token {
+      Token::LeftParenthesis => {
+        self.next_token()?;
+        self.skip_type()?;
+        required_token!(self, Token::RightParenthesis);
+      }
+      Token::LeftSquareBracket => self.skip_tuple_type()?,
+      Token::LeftCurlyBracket => self.skip_object_type()?,
+      Token::StringLiteral(_)
+      | Token::NumberLiteral(_)
+      | Token::BigIntLiteral(_)
+      | Token::NoSubstitutionTemplate(_, _) => self.next_token()?,
+      Token::Minus => {
+        self.next_token()?;
+        match &self.context.token {
+          Token::NumberLiteral(_) | Token::BigIntLiteral(_) => self.next_token()?,
+          _ => return Err(syntax_err!()),
+        }
+      }
+      Token::TemplateHead(_, _) => {
+        self.next_token()?;
+        loop {
+          self.skip_type()?;
+          self.rescan_template_continuation()?;
+          match &self.context.token {
+            Token::TemplateMiddle(_, _) => self.next_token()?,
+            Token::TemplateTail(_, _) => {
+              self.next_token()?;
+              break;
+            }
+            _ => return Err(syntax_err!()),
+          }
+        }
+      }
+      Token::Name(Name::Typeof) => {
+        self.next_token()?;
+        match &self.context.token {
+          Token::Name(Name::Import) => self.skip_import_type()?,
+          _ => self.skip_type_reference()?,
+        }
+      }
+      Token::Name(Name::Import) => self.skip_import_type()?,
+      Token::Name(_) => self.skip_type_reference()?,
+      _ => return Err(syntax_err!()),
+    }
+
+    Ok(())
+  }
+
+  // a possibly qualified name with type arguments, like `Map<K, V>` or `A.B.C`
+  fn skip_type_reference(&mut self) -> Result<(), ParseError> {
+    if !matches!(self.context.token, Token::Name(_)) {
+      return Err(syntax_err!());
+    }
+
+    self.next_token()?;
+
+    while let Token::FullStop = &self.context.token {
+      self.next_token()?;
+      if !matches!(self.context.token, Token::Name(_)) {
+        return Err(syntax_err!());
+      }
+
+      self.next_token()?;
+    }
+
+    if !self.context.line_terminator && matches!(self.context.token, Token::LessThan) {
+      self.skip_type_arguments()?;
+    }
+
+    Ok(())
+  }
+
+  // `import("module").Name<T>`
+  fn skip_import_type(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::Name(Name::Import));
+    required_token!(self, Token::LeftParenthesis);
+    required_token!(self, Token::StringLiteral(_));
+    required_token!(self, Token::RightParenthesis);
+
+    while let Token::FullStop = &self.context.token {
+      self.next_token()?;
+      required_token!(self, Token::Name(_));
+    }
+
+    if !self.context.line_terminator && matches!(self.context.token, Token::LessThan) {
+      self.skip_type_arguments()?;
+    }
+
+    Ok(())
+  }
+
+  // `[A, B?, ...C[]]`, the elements can have names like `[first: A, rest?: B]`
+  fn skip_tuple_type(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::LeftSquareBracket);
+
+    while !matches!(self.context.token, Token::RightSquareBracket) {
+      if let Token::TripleStop = &self.context.token {
+        self.next_token()?;
+      }
+
+      if let Token::Name(_) = &self.context.token {
+        let snapshot = self.context.clone();
+        self.next_token()?;
+
+        if let Token::QuestionMark = &self.context.token {
+          self.next_token()?;
+        }
+
+        match &self.context.token {
+          Token::Colon => self.next_token()?,
+          _ => self.context = snapshot,
+        }
+      }
+
+      self.skip_type()?;
+
+      if let Token::QuestionMark = &self.context.token {
+        self.next_token()?;
+      }
+
+      match &self.context.token {
+        Token::Comma => self.next_token()?,
+        Token::RightSquareBracket => break,
+        _ => return Err(syntax_err!()),
+      }
+    }
+
+    self.next_token()
+  }
+
+  // object types, mapped types and the bodies of interfaces
+  pub fn skip_object_type(&mut self) -> Result<(), ParseError> {
+    required_token!(self, Token::LeftCurlyBracket);
+
+    if self.try_skip_mapped_type()? {
+      return Ok(());
+    }
+
+    while !matches!(self.context.token, Token::RightCurlyBracket) {
+      self.skip_type_member()?;
+
+      match &self.context.token {
+        Token::Comma | Token::Semicolon => self.next_token()?,
+        Token::RightCurlyBracket => break,
+        _ if self.context.line_terminator => {}
+        _ => return Err(syntax_err!()),
+      }
+    }
+
+    self.next_token()
+  }
+
+  // `{ readonly [K in keyof T as N]?: T[K] }`, with optional `+` and `-` before the modifiers
+  fn try_skip_mapped_type(&mut self) -> Result<bool, ParseError> {
+    let snapshot = self.context.clone();
+
+    if let Token::Plus | Token::Minus = &self.context.token {
+      self.next_token()?;
+    }
+
+    if self.is_contextual_name("readonly") {
+      self.next_token()?;
+    }
+
+    let is_mapped = match &self.context.token {
+      Token::LeftSquareBracket => {
+        self.next_token()?;
+        match &self.context.token {
+          Token::Name(_) => {
+            self.next_token()?;
+            matches!(self.context.token, Token::Name(Name::In))
+          }
+          _ => false,
+        }
+      }
+      _ => false,
+    };
+
+    if !is_mapped {
+      self.context = snapshot;
+      return Ok(false);
+    }
+
+    self.next_token()?;
+    self.skip_type()?;
+
+    if self.is_contextual_name("as") {
+      self.next_token()?;
+      self.skip_type()?;
+    }
+
+    required_token!(self, Token::RightSquareBracket);
+
+    if let Token::Plus | Token::Minus = &self.context.token {
+      self.next_token()?;
+      if !matches!(self.context.token, Token::QuestionMark) {
+        return Err(syntax_err!());
+      }
+    }
+
+    if let Token::QuestionMark = &self.context.token {
+      self.next_token()?;
+    }
+
+    self.skip_type_annotation()?;
+
+    if let Token::Semicolon | Token::Comma = &self.context.token {
+      self.next_token()?;
+    }
+
+    required_token!(self, Token::RightCurlyBracket);
+    Ok(true)
+  }
+
+  fn skip_type_member(&mut self) -> Result<(), ParseError> {
+    match &self.context.token {
+      // call signatures
+      Token::LeftParenthesis | Token::LessThan => return self.skip_signature(),
+      // construct signatures, or a member named `new`
+      Token::Name(Name::New) => {
+        if matches!(self.peek_token()?, Token::LeftParenthesis | Token::LessThan) {
+          self.next_token()?;
+          return self.skip_signature();
+        }
+      }
+      _ => {}
+    }
+
+    if self.is_contextual_name("readonly") && self.is_modifier_before_member_name()? {
+      self.next_token()?;
+    }
+
+    if self.try_skip_index_signature()? {
+      return Ok(());
+    }
+
+    if matches!(self.context.token, Token::Name(Name::Get | Name::Set))
+      && self.is_modifier_before_member_name()?
+    {
+      self.next_token()?;
+    }
+
+    match &self.context.token {
+      Token::Name(_)
+      | Token::StringLiteral(_)
+      | Token::NumberLiteral(_)
+      | Token::BigIntLiteral(_) => self.next_token()?,
+      Token::LeftSquareBracket => {
+        self.next_token()?;
+        self.read_assignment_expression()?.ok_or(syntax_err!())?;
+        required_token!(self, Token::RightSquareBracket);
+      }
+      _ => return Err(syntax_err!()),
+    }
+
+    if let Token::QuestionMark = &self.context.token {
+      self.next_token()?;
+    }
+
+    match &self.context.token {
+      Token::LeftParenthesis | Token::LessThan => self.skip_signature(),
+      _ => self.skip_type_annotation(),
+    }
+  }
+
+  // tells if the current word is followed by the name of a member, instead of being the name
+  fn is_modifier_before_member_name(&mut self) -> Result<bool, ParseError> {
+    let is_modifier = matches!(
+      self.peek_token()?,
+      Token::Name(_)
+        | Token::StringLiteral(_)
+        | Token::NumberLiteral(_)
+        | Token::BigIntLiteral(_)
+        | Token::LeftSquareBracket
+        | Token::NumberSign
+    );
+
+    Ok(is_modifier)
+  }
+
+  fn skip_signature(&mut self) -> Result<(), ParseError> {
+    self.skip_type_parameters()?;
+    self.read_formal_parameters()?;
+    self.skip_return_type_annotation()
+  }
+
+  // `[key: string]: T`, used in object types and classes
+  pub fn try_skip_index_signature(&mut self) -> Result<bool, ParseError> {
+    if !matches!(self.context.token, Token::LeftSquareBracket) {
+      return Ok(false);
+    }
+
+    let snapshot = self.context.clone();
+    self.next_token()?;
+
+    let is_index_signature = match &self.context.token {
+      Token::Name(_) => {
+        self.next_token()?;
+        matches!(self.context.token, Token::Colon)
+      }
+      _ => false,
+    };
+
+    if !is_index_signature {
+      self.context = snapshot;
+      return Ok(false);
+    }
+
+    self.skip_type_annotation()?;
+    required_token!(self, Token::RightSquareBracket);
+    self.skip_type_annotation()?;
+    Ok(true)
+  }
+}
@@ -0,0 +1,119 @@
+use std::{collections::BTreeMap, error::Error, fs};
+
+use crate::{
+  parse_hex,
+  tables::{escape, hex, value_table, write_array, CODE_POINTS, VALUE_BLOCK},
+  ucd::{parse_code_points, read_fields, read_version},
+};
+
+pub fn write() -> Result<(), Box<dyn Error>> {
+  let mut uppercase = (0..CODE_POINTS as u32).collect::<Vec<_>>();
+  let mut lowercase = uppercase.clone();
+  let mut folding = uppercase.clone();
+
+  // the simple mappings, where the ranges of First and Last lines have none
+  for record in read_fields("UnicodeData.txt")? {
+    let c = parse_hex(&record[0]) as usize;
+    if !record[12].is_empty() {
+      uppercase[c] = parse_hex(&record[12]);
+    }
+    if !record[13].is_empty() {
+      lowercase[c] = parse_hex(&record[13]);
+    }
+  }
+
+  // the common and simple foldings, the full ones are for matching strings and the Turkic ones
+  // depend on the language
+  for record in read_fields("CaseFolding.txt")? {
+    if matches!(record[1].as_str(), "C" | "S") {
+      folding[parse_hex(&record[0]) as usize] = parse_hex(&record[2]);
+    }
+  }
+
+  // the mappings to more than one code point, or that aren't the simple one. the conditional
+  // ones depend on the language, besides Final_Sigma which is applied to strings
+  let mut special_uppercase = BTreeMap::new();
+  let mut special_lowercase = BTreeMap::new();
+  for record in read_fields("SpecialCasing.txt")? {
+    if record.get(4).is_some_and(|condition| !condition.is_empty()) {
+      continue;
+    }
+
+    let c = parse_hex(&record[0]);
+    let lower = parse_code_points(&record[1]);
+    let upper = parse_code_points(&record[3]);
+    if upper != [uppercase[c as usize]] {
+      special_uppercase.insert(c, escape(&upper));
+    }
+    if lower != [lowercase[c as usize]] {
+      special_lowercase.insert(c, escape(&lower));
+    }
+  }
+
+  // each code point gets the differences to its uppercase, lowercase and folded code points, with
+  // each different set of them kept once
+  let mut mappings = vec![(0, 0, 0)];
+  let mut values = vec![0u8; CODE_POINTS];
+  for c in 0..CODE_POINTS {
+    let mapping = (
+      uppercase[c] as i32 - c as i32,
+      lowercase[c] as i32 - c as i32,
+      folding[c] as i32 - c as i32,
+    );
+
+    let id = match mappings.iter().position(|existing| *existing == mapping) {
+      Some(id) => id,
+      None => {
+        mappings.push(mapping);
+        mappings.len() - 1
+      }
+    };
+    values[c] = u8::try_from(id).map_err(|_| "more than 256 case mappings")?;
+  }
+
+  // the blocks after the last code point with a mapping aren't kept
+  let last = values.iter().rposition(|id| *id != 0).unwrap_or_default();
+  let (index, data) = value_table(&values[..(last / VALUE_BLOCK + 1) * VALUE_BLOCK]);
+
+  let mut output = format!(
+    "pub const UNICODE_VERSION: &str = \"{}\";\n\npub const VALUE_BLOCK: usize = {VALUE_BLOCK};\n\n",
+    read_version("CaseFolding.txt")?
+  );
+
+  let mappings_items = mappings
+    .iter()
+    .map(|(upper, lower, fold)| format!("({upper}, {lower}, {fold})"))
+    .collect::<Vec<_>>();
+  write_array(
+    &mut output,
+    "CASE_MAPPINGS",
+    "(i32, i32, i32)",
+    &mappings_items,
+  )?;
+  write_array(&mut output, "CASE_INDEX", "u16", &hex(&index))?;
+  write_array(&mut output, "CASE_DATA", "u8", &hex(&data))?;
+
+  for (name, special) in [
+    ("SPECIAL_UPPERCASE", &special_uppercase),
+    ("SPECIAL_LOWERCASE", &special_lowercase),
+  ] {
+    let items = special
+      .iter()
+      .map(|(c, string)| format!("({c:#06X}, {string})"))
+      .collect::<Vec<_>>();
+    write_array(&mut output, name, "(u32, &str)", &items)?;
+  }
+
+  fs::write("../src/casing_tables.rs", output)?;
+  println!("Wrote to ../src/casing_tables.rs");
+
+  println!(
+    "Case mappings: {} differences, {} blocks up to {last:#06X}, {} special uppercase, {} special lowercase",
+    mappings.len(),
+    data.len() / VALUE_BLOCK,
+    special_uppercase.len(),
+    special_lowercase.len()
+  );
+
+  Ok(())
+}
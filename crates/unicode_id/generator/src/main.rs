@@ -5,9 +5,35 @@ use std::{
   io::{self, BufRead, Write},
 };
 
+mod casing;
+mod properties;
+mod tables;
+mod ucd;
+
 const ROW_SIZE: usize = 64;
 
+const TABLES: [&str; 3] = ["identifiers", "properties", "casing"];
+
+// `cargo run -- properties` writes only the tables named, all of them without arguments
 fn main() -> Result<(), Box<dyn Error>> {
+  let mut tables = std::env::args().skip(1).collect::<Vec<_>>();
+  if tables.is_empty() {
+    tables = TABLES.map(String::from).to_vec();
+  }
+
+  for table in tables {
+    match table.as_str() {
+      "identifiers" => write_identifiers()?,
+      "properties" => properties::write()?,
+      "casing" => casing::write()?,
+      _ => Err(format!("unknown table {table}, expected one of {TABLES:?}"))?,
+    }
+  }
+
+  Ok(())
+}
+
+fn write_identifiers() -> Result<(), Box<dyn Error>> {
   let (charset_id_start, charset_id_continue) = generate_charsets();
   let mut unmerged_chunks: Vec<(u8, Vec<u8>)> = Vec::new();
 
@@ -19,16 +45,12 @@ fn main() -> Result<(), Box<dyn Error>> {
 
   let (full_data_chunk, chunk_index_map) = merge_chunks(unmerged_chunks);
 
-  for i in 0..id_start_table.len() {
-    id_start_table[i] = *chunk_index_map
-      .get(&id_start_table[i])
-      .expect("Missing chunk index");
+  for index in id_start_table.iter_mut() {
+    *index = *chunk_index_map.get(index).expect("Missing chunk index");
   }
 
-  for i in 0..id_continue_table.len() {
-    id_continue_table[i] = *chunk_index_map
-      .get(&id_continue_table[i])
-      .expect("Missing chunk index");
+  for index in id_continue_table.iter_mut() {
+    *index = *chunk_index_map.get(index).expect("Missing chunk index");
   }
 
   for c in 0..=max_bound_start {
@@ -55,46 +77,50 @@ fn main() -> Result<(), Box<dyn Error>> {
 
   let mut out_file = File::create("../src/lib.rs")?;
 
-  out_file.write(b"pub const ROW_SIZE: usize = ")?;
-  out_file.write(ROW_SIZE.to_string().as_bytes())?;
-  out_file.write(b";\n\npub const MAX_BOUND_ID_START: u32 = ")?;
-  out_file.write(format!("{max_bound_start:#08X}").as_bytes())?;
-  out_file.write(b";\n\npub const MAX_BOUND_ID_CONTINUE: u32 = ")?;
-  out_file.write(format!("{max_bound_continue:#08X}").as_bytes())?;
-  out_file.write(b";\n\npub const ID_START_TABLE: [u8;")?;
-  out_file.write(&id_start_table.len().to_string().as_bytes())?;
-  out_file.write(b"] = [")?;
+  out_file.write_all(
+    b"pub mod casing;
+mod properties;\nmod property_tables;\n\n",
+  )?;
+  out_file.write_all(b"pub const ROW_SIZE: usize = ")?;
+  out_file.write_all(ROW_SIZE.to_string().as_bytes())?;
+  out_file.write_all(b";\n\npub const MAX_BOUND_ID_START: u32 = ")?;
+  out_file.write_all(format!("{max_bound_start:#08X}").as_bytes())?;
+  out_file.write_all(b";\n\npub const MAX_BOUND_ID_CONTINUE: u32 = ")?;
+  out_file.write_all(format!("{max_bound_continue:#08X}").as_bytes())?;
+  out_file.write_all(b";\n\npub const ID_START_TABLE: [u8;")?;
+  out_file.write_all(id_start_table.len().to_string().as_bytes())?;
+  out_file.write_all(b"] = [")?;
 
   for (i, index) in id_start_table.iter().enumerate() {
     if i > 0 {
-      out_file.write(b", ")?;
+      out_file.write_all(b", ")?;
     }
-    out_file.write(format!("{index:#04X}").as_bytes())?;
+    out_file.write_all(format!("{index:#04X}").as_bytes())?;
   }
 
-  out_file.write(b"];\n\npub const ID_CONTINUE_TABLE: [u8;")?;
-  out_file.write(&id_continue_table.len().to_string().as_bytes())?;
-  out_file.write(b"] = [")?;
+  out_file.write_all(b"];\n\npub const ID_CONTINUE_TABLE: [u8;")?;
+  out_file.write_all(id_continue_table.len().to_string().as_bytes())?;
+  out_file.write_all(b"] = [")?;
 
   for (i, index) in id_continue_table.iter().enumerate() {
     if i > 0 {
-      out_file.write(b", ")?;
+      out_file.write_all(b", ")?;
     }
-    out_file.write(format!("{index:#04X}").as_bytes())?;
+    out_file.write_all(format!("{index:#04X}").as_bytes())?;
   }
 
-  out_file.write(b"];\n\npub const DATA_TABLE: [u8;")?;
-  out_file.write(&full_data_chunk.len().to_string().as_bytes())?;
-  out_file.write(b"] = [")?;
+  out_file.write_all(b"];\n\npub const DATA_TABLE: [u8;")?;
+  out_file.write_all(full_data_chunk.len().to_string().as_bytes())?;
+  out_file.write_all(b"] = [")?;
 
   for (i, byte) in full_data_chunk.iter().enumerate() {
     if i > 0 {
-      out_file.write(b", ")?;
+      out_file.write_all(b", ")?;
     }
-    out_file.write(format!("{byte:#04X}").as_bytes())?;
+    out_file.write_all(format!("{byte:#04X}").as_bytes())?;
   }
 
-  out_file.write(b"];\n")?;
+  out_file.write_all(b"];\n")?;
 
   println!("Wrote to ../src/lib.rs");
 
@@ -135,7 +161,10 @@ fn generate_charsets() -> (HashSet<u32>, HashSet<u32>) {
   let unicode_data_file = File::open("./ucd/UnicodeData.txt").unwrap();
   let prop_list_file = File::open("./ucd/PropList.txt").unwrap();
 
-  for line in io::BufReader::new(unicode_data_file).lines().flatten() {
+  for line in io::BufReader::new(unicode_data_file)
+    .lines()
+    .map_while(Result::ok)
+  {
     let data = line.split(';').collect::<Vec<&str>>();
     let char_code = data[0];
     let char_class = data[2];
@@ -154,7 +183,10 @@ fn generate_charsets() -> (HashSet<u32>, HashSet<u32>) {
     }
   }
 
-  for line in io::BufReader::new(prop_list_file).lines().flatten() {
+  for line in io::BufReader::new(prop_list_file)
+    .lines()
+    .map_while(Result::ok)
+  {
     if line.is_empty() || line.starts_with('#') {
       continue;
     }
@@ -271,7 +303,7 @@ fn merge_chunks(mut unmerged_chunks: Vec<(u8, Vec<u8>)>) -> (Vec<u8>, HashMap<u8
   full_data_chunk.append(&mut first_chunk.1);
 
   'main_loop: loop {
-    if unmerged_chunks.len() == 0 {
+    if unmerged_chunks.is_empty() {
       break;
     }
 
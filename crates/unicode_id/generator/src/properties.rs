@@ -0,0 +1,415 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  error::Error,
+  fmt::Write as _,
+  fs,
+};
+
+use crate::{
+  tables::{escape, hex, join, value_table, write_array, CODE_POINTS, VALUE_BLOCK},
+  ucd::{parse_code_points, parse_range, read_fields, read_version},
+};
+
+// code points to a row of bits, the same 64 byte rows as the identifier tables
+const BIT_BLOCK: usize = 512;
+
+// the binary properties RegExp property escapes can name, from table-binary-unicode-properties.
+// Any, ASCII and Assigned are there too but aren't in the UCD
+const BINARY_PROPERTIES: [&str; 50] = [
+  "ASCII_Hex_Digit",
+  "Alphabetic",
+  "Bidi_Control",
+  "Bidi_Mirrored",
+  "Case_Ignorable",
+  "Cased",
+  "Changes_When_Casefolded",
+  "Changes_When_Casemapped",
+  "Changes_When_Lowercased",
+  "Changes_When_NFKC_Casefolded",
+  "Changes_When_Titlecased",
+  "Changes_When_Uppercased",
+  "Dash",
+  "Default_Ignorable_Code_Point",
+  "Deprecated",
+  "Diacritic",
+  "Emoji",
+  "Emoji_Component",
+  "Emoji_Modifier",
+  "Emoji_Modifier_Base",
+  "Emoji_Presentation",
+  "Extended_Pictographic",
+  "Extender",
+  "Grapheme_Base",
+  "Grapheme_Extend",
+  "Hex_Digit",
+  "IDS_Binary_Operator",
+  "IDS_Trinary_Operator",
+  "ID_Continue",
+  "ID_Start",
+  "Ideographic",
+  "Join_Control",
+  "Logical_Order_Exception",
+  "Lowercase",
+  "Math",
+  "Noncharacter_Code_Point",
+  "Pattern_Syntax",
+  "Pattern_White_Space",
+  "Quotation_Mark",
+  "Radical",
+  "Regional_Indicator",
+  "Sentence_Terminal",
+  "Soft_Dotted",
+  "Terminal_Punctuation",
+  "Unified_Ideograph",
+  "Uppercase",
+  "Variation_Selector",
+  "White_Space",
+  "XID_Continue",
+  "XID_Start",
+];
+
+const BINARY_PROPERTY_FILES: [&str; 5] = [
+  "PropList.txt",
+  "DerivedCoreProperties.txt",
+  "extracted/DerivedBinaryProperties.txt",
+  "DerivedNormalizationProps.txt",
+  "emoji/emoji-data.txt",
+];
+
+// the properties of strings from table-binary-unicode-properties-of-strings, RGI_Emoji is all of
+// them together
+const STRING_PROPERTIES: [&str; 6] = [
+  "Basic_Emoji",
+  "Emoji_Keycap_Sequence",
+  "RGI_Emoji_Modifier_Sequence",
+  "RGI_Emoji_Flag_Sequence",
+  "RGI_Emoji_Tag_Sequence",
+  "RGI_Emoji_ZWJ_Sequence",
+];
+
+const STRING_PROPERTY_FILES: [&str; 2] =
+  ["emoji/emoji-sequences.txt", "emoji/emoji-zwj-sequences.txt"];
+
+// the General_Category values that stand for a group of others, from UAX #44
+const CATEGORY_GROUPS: [(&str, &[&str]); 8] = [
+  ("C", &["Cc", "Cf", "Cn", "Co", "Cs"]),
+  ("L", &["Ll", "Lm", "Lo", "Lt", "Lu"]),
+  ("LC", &["Ll", "Lt", "Lu"]),
+  ("M", &["Mc", "Me", "Mn"]),
+  ("N", &["Nd", "Nl", "No"]),
+  ("P", &["Pc", "Pd", "Pe", "Pf", "Pi", "Po", "Ps"]),
+  ("S", &["Sc", "Sk", "Sm", "So"]),
+  ("Z", &["Zl", "Zp", "Zs"]),
+];
+
+pub fn write() -> Result<(), Box<dyn Error>> {
+  let value_aliases = read_fields("PropertyValueAliases.txt")?;
+  let mut output = format!(
+    "pub const UNICODE_VERSION: &str = \"{}\";\n\npub const VALUE_BLOCK: usize = {VALUE_BLOCK};\n\npub const BIT_BLOCK: usize = {BIT_BLOCK};\n\n",
+    read_version("PropertyValueAliases.txt")?
+  );
+
+  write_general_category(&mut output, &value_aliases)?;
+  write_scripts(&mut output, &value_aliases)?;
+  write_binary_properties(&mut output)?;
+  write_string_properties(&mut output)?;
+
+  fs::write("../src/property_tables.rs", output)?;
+  println!("Wrote to ../src/property_tables.rs");
+
+  Ok(())
+}
+
+// each category is a bit, and the name of a group has the bits of its categories
+fn write_general_category(
+  output: &mut String,
+  value_aliases: &[Vec<String>],
+) -> Result<(), Box<dyn Error>> {
+  let categories = value_aliases
+    .iter()
+    .filter(|record| record[0] == "gc")
+    .filter(|record| !CATEGORY_GROUPS.iter().any(|(group, _)| *group == record[1]))
+    .collect::<Vec<_>>();
+  let ids = categories
+    .iter()
+    .enumerate()
+    .map(|(id, record)| (record[1].as_str(), id as u8))
+    .collect::<HashMap<_, _>>();
+
+  let unassigned = *ids.get("Cn").ok_or("there's no Cn category")?;
+  let mut values = vec![unassigned; CODE_POINTS];
+  for record in read_fields("extracted/DerivedGeneralCategory.txt")? {
+    let (start, end) = parse_range(&record[0]);
+    let id = *ids
+      .get(record[1].as_str())
+      .ok_or(format!("unknown category {}", record[1]))?;
+    values[start as usize..=end as usize].fill(id);
+  }
+
+  let mut names = BTreeMap::new();
+  for record in value_aliases.iter().filter(|record| record[0] == "gc") {
+    let mask = match CATEGORY_GROUPS
+      .iter()
+      .find(|(group, _)| *group == record[1])
+    {
+      Some((_, members)) => members.iter().map(|member| 1u32 << ids[member]).sum(),
+      None => 1u32 << ids[record[1].as_str()],
+    };
+
+    for name in &record[1..] {
+      names.insert(name.clone(), format!("{mask:#010X}"));
+    }
+  }
+
+  let (index, data) = value_table(&values);
+  writeln!(output, "pub const UNASSIGNED: u8 = {unassigned};\n")?;
+  write_names(output, "GENERAL_CATEGORY_NAMES", "u32", &names)?;
+  write_array(output, "GENERAL_CATEGORY_INDEX", "u16", &hex(&index))?;
+  write_array(output, "GENERAL_CATEGORY_DATA", "u8", &hex(&data))?;
+
+  println!(
+    "General_Category: {} categories, {} blocks",
+    categories.len(),
+    data.len() / VALUE_BLOCK
+  );
+
+  Ok(())
+}
+
+// Script and Script_Extensions share the script names. code points without extensions have the
+// script they're in, the others one of a list of sets of scripts. scripts no code point is in
+// aren't named, like RegExp doesn't
+fn write_scripts(output: &mut String, value_aliases: &[Vec<String>]) -> Result<(), Box<dyn Error>> {
+  let all_scripts = value_aliases
+    .iter()
+    .filter(|record| record[0] == "sc")
+    .collect::<Vec<_>>();
+  let find = |name: &str| {
+    all_scripts
+      .iter()
+      .position(|record| record[1..].iter().any(|alias| alias == name))
+      .ok_or(format!("unknown script {name}"))
+  };
+
+  let unknown = find("Zzzz")?;
+  let mut used = vec![unknown];
+  let mut ids = |script: usize| match used.iter().position(|used| *used == script) {
+    Some(id) => id as u8,
+    None => {
+      used.push(script);
+      (used.len() - 1) as u8
+    }
+  };
+
+  let mut scripts = vec![0u8; CODE_POINTS];
+  for record in read_fields("Scripts.txt")? {
+    let (start, end) = parse_range(&record[0]);
+    let id = ids(find(&record[1])?);
+    scripts[start as usize..=end as usize].fill(id);
+  }
+
+  // set 0 is the script of the code point
+  let mut sets = vec![Vec::new()];
+  let mut extensions = vec![0u8; CODE_POINTS];
+  for record in read_fields("ScriptExtensions.txt")? {
+    let (start, end) = parse_range(&record[0]);
+    let mut set = Vec::new();
+    for name in record[1].split_whitespace() {
+      set.push(ids(find(name)?));
+    }
+    set.sort();
+
+    let id = match sets.iter().position(|existing| *existing == set) {
+      Some(id) => id,
+      None => {
+        sets.push(set);
+        sets.len() - 1
+      }
+    };
+    extensions[start as usize..=end as usize].fill(id as u8);
+  }
+
+  let mut names = BTreeMap::new();
+  for (id, script) in used.iter().enumerate() {
+    for name in &all_scripts[*script][1..] {
+      names.insert(name.clone(), id.to_string());
+    }
+  }
+
+  let (index, data) = value_table(&scripts);
+  write_names(output, "SCRIPT_NAMES", "u8", &names)?;
+  write_array(output, "SCRIPT_INDEX", "u16", &hex(&index))?;
+  write_array(output, "SCRIPT_DATA", "u8", &hex(&data))?;
+
+  let (extensions_index, extensions_data) = value_table(&extensions);
+  let sets = sets
+    .iter()
+    .map(|set| format!("&[{}]", join(set)))
+    .collect::<Vec<_>>();
+  write_array(output, "SCRIPT_EXTENSION_SETS", "&[u8]", &sets)?;
+  write_array(
+    output,
+    "SCRIPT_EXTENSIONS_INDEX",
+    "u16",
+    &hex(&extensions_index),
+  )?;
+  write_array(
+    output,
+    "SCRIPT_EXTENSIONS_DATA",
+    "u8",
+    &hex(&extensions_data),
+  )?;
+
+  println!(
+    "Script: {} scripts, {} blocks, Script_Extensions: {} sets, {} blocks",
+    used.len(),
+    data.len() / VALUE_BLOCK,
+    sets.len(),
+    extensions_data.len() / VALUE_BLOCK
+  );
+
+  Ok(())
+}
+
+// a row of bits for every BIT_BLOCK code points up to the last one that has the property, the
+// rows shared between all of them
+fn write_binary_properties(output: &mut String) -> Result<(), Box<dyn Error>> {
+  let mut sets = vec![Vec::<(u32, u32)>::new(); BINARY_PROPERTIES.len()];
+  for file in BINARY_PROPERTY_FILES {
+    for record in read_fields(file)? {
+      if let Some(property) = BINARY_PROPERTIES.iter().position(|name| *name == record[1]) {
+        sets[property].push(parse_range(&record[0]));
+      }
+    }
+  }
+
+  let mut names = BTreeMap::new();
+  for record in read_fields("PropertyAliases.txt")? {
+    if let Some(property) = BINARY_PROPERTIES
+      .iter()
+      .position(|name| record.contains(&name.to_string()))
+    {
+      for name in &record {
+        names.insert(name.clone(), property.to_string());
+      }
+    }
+  }
+
+  let mut rows = HashMap::<Vec<u8>, u16>::new();
+  let mut data = Vec::new();
+  let mut indexes = Vec::new();
+
+  for (property, ranges) in sets.iter().enumerate() {
+    let last = ranges
+      .iter()
+      .map(|(_, end)| *end as usize)
+      .max()
+      .ok_or(format!(
+        "{} has no code points",
+        BINARY_PROPERTIES[property]
+      ))?;
+
+    let mut bits = vec![0u8; (last / BIT_BLOCK + 1) * BIT_BLOCK / 8];
+    for (start, end) in ranges {
+      for c in *start as usize..=*end as usize {
+        bits[c / 8] |= 1 << (c % 8);
+      }
+    }
+
+    let mut index = Vec::new();
+    for row in bits.chunks(BIT_BLOCK / 8) {
+      let next = rows.len() as u16;
+      let position = *rows.entry(row.to_vec()).or_insert_with(|| {
+        data.extend_from_slice(row);
+        next
+      });
+      index.push(position);
+    }
+
+    indexes.push(format!("&[{}]", hex(&index).join(", ")));
+  }
+
+  write_names(output, "BINARY_PROPERTY_NAMES", "u8", &names)?;
+  write_array(output, "BINARY_PROPERTY_INDEXES", "&[u16]", &indexes)?;
+  write_array(output, "BINARY_PROPERTY_DATA", "u8", &hex(&data))?;
+
+  println!(
+    "Binary properties: {} names, {} rows",
+    names.len(),
+    rows.len()
+  );
+
+  Ok(())
+}
+
+// the code points and sequences of each, sorted so a code point can be searched for
+fn write_string_properties(output: &mut String) -> Result<(), Box<dyn Error>> {
+  let mut strings = vec![Vec::<String>::new(); STRING_PROPERTIES.len()];
+  for file in STRING_PROPERTY_FILES {
+    for record in read_fields(file)? {
+      let Some(property) = STRING_PROPERTIES.iter().position(|name| *name == record[1]) else {
+        continue;
+      };
+
+      if record[0].contains("..") {
+        let (start, end) = parse_range(&record[0]);
+        for c in start..=end {
+          strings[property].push(escape(&[c]));
+        }
+      } else {
+        strings[property].push(escape(&parse_code_points(&record[0])));
+      }
+    }
+  }
+
+  let mut names = BTreeMap::new();
+  for (property, name) in STRING_PROPERTIES.iter().enumerate() {
+    names.insert(name.to_string(), format!("{:#04X}", 1 << property));
+  }
+  names.insert(
+    "RGI_Emoji".to_string(),
+    format!("{:#04X}", (1 << STRING_PROPERTIES.len()) - 1),
+  );
+
+  let lists = strings
+    .iter_mut()
+    .map(|list| {
+      list.sort_by_key(|string| unescape(string));
+      format!("&[{}]", list.join(", "))
+    })
+    .collect::<Vec<_>>();
+
+  write_names(output, "STRING_PROPERTY_NAMES", "u8", &names)?;
+  write_array(output, "STRING_PROPERTY_STRINGS", "&[&str]", &lists)?;
+
+  println!(
+    "Properties of strings: {} strings",
+    strings.iter().map(Vec::len).sum::<usize>()
+  );
+
+  Ok(())
+}
+
+fn unescape(literal: &str) -> Vec<u32> {
+  literal
+    .trim_matches('"')
+    .split("\\u")
+    .filter(|part| !part.is_empty())
+    .map(|part| u32::from_str_radix(part.trim_matches(['{', '}']), 16).unwrap())
+    .collect()
+}
+
+// sorted by name so they can be searched
+fn write_names(
+  output: &mut String,
+  name: &str,
+  value_type: &str,
+  names: &BTreeMap<String, String>,
+) -> Result<(), Box<dyn Error>> {
+  let entries = names
+    .iter()
+    .map(|(name, value)| format!("(\"{name}\", {value})"))
+    .collect::<Vec<_>>();
+
+  write_array(output, name, &format!("(&str, {value_type})"), &entries)
+}
@@ -0,0 +1,70 @@
+use std::{
+  collections::HashMap,
+  error::Error,
+  fmt::{Display, UpperHex, Write as _},
+};
+
+pub const CODE_POINTS: usize = 0x110000;
+
+// code points to a block of byte values
+pub const VALUE_BLOCK: usize = 128;
+
+// the values split into blocks with each different block kept once, and the block each run of
+// code points has
+pub fn value_table(values: &[u8]) -> (Vec<u16>, Vec<u8>) {
+  let mut blocks = HashMap::<&[u8], u16>::new();
+  let mut index = Vec::new();
+  let mut data = Vec::new();
+
+  for block in values.chunks(VALUE_BLOCK) {
+    let next = blocks.len() as u16;
+    let position = *blocks.entry(block).or_insert_with(|| {
+      data.extend_from_slice(block);
+      next
+    });
+    index.push(position);
+  }
+
+  (index, data)
+}
+
+// a string literal of the code points
+pub fn escape(code_points: &[u32]) -> String {
+  let escaped = code_points
+    .iter()
+    .map(|c| format!("\\u{{{c:X}}}"))
+    .collect::<String>();
+  format!("\"{escaped}\"")
+}
+
+pub fn write_array(
+  output: &mut String,
+  name: &str,
+  item_type: &str,
+  items: &[String],
+) -> Result<(), Box<dyn Error>> {
+  writeln!(
+    output,
+    "pub static {name}: [{item_type}; {}] = [{}];\n",
+    items.len(),
+    items.join(", ")
+  )?;
+
+  Ok(())
+}
+
+pub fn hex<T: UpperHex>(values: &[T]) -> Vec<String> {
+  let width = std::mem::size_of::<T>() * 2 + 2;
+  values
+    .iter()
+    .map(|value| format!("{value:#0width$X}"))
+    .collect()
+}
+
+pub fn join<T: Display>(values: &[T]) -> String {
+  values
+    .iter()
+    .map(|value| value.to_string())
+    .collect::<Vec<_>>()
+    .join(", ")
+}
@@ -0,0 +1,61 @@
+use std::{
+  error::Error,
+  fs::File,
+  io::{self, BufRead},
+};
+
+use crate::parse_hex;
+
+// the fields of each data line of a file in ./ucd, without the comments
+pub fn read_fields(path: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+  let file = File::open(format!("./ucd/{path}")).map_err(|error| format!("{path}: {error}"))?;
+  let mut records = Vec::new();
+
+  for line in io::BufReader::new(file).lines() {
+    let line = line?;
+    let data = line.split('#').next().unwrap_or_default().trim();
+    if data.is_empty() {
+      continue;
+    }
+
+    records.push(
+      data
+        .split(';')
+        .map(|field| field.trim().to_string())
+        .collect(),
+    );
+  }
+
+  Ok(records)
+}
+
+// the version in the first line of a file, like `# PropList-15.0.0.txt`
+pub fn read_version(path: &str) -> Result<String, Box<dyn Error>> {
+  let file = File::open(format!("./ucd/{path}")).map_err(|error| format!("{path}: {error}"))?;
+  let line = io::BufReader::new(file)
+    .lines()
+    .next()
+    .ok_or(format!("{path} is empty"))??;
+
+  let version = line
+    .trim_end_matches(".txt")
+    .rsplit('-')
+    .next()
+    .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+    .ok_or(format!("{path} doesn't start with its version"))?;
+
+  Ok(version.to_string())
+}
+
+// `0041` or `0041..005A`
+pub fn parse_range(field: &str) -> (u32, u32) {
+  match field.split_once("..") {
+    Some((start, end)) => (parse_hex(start), parse_hex(end)),
+    None => (parse_hex(field), parse_hex(field)),
+  }
+}
+
+// `1F468 200D 1F469`
+pub fn parse_code_points(field: &str) -> Vec<u32> {
+  field.split_whitespace().map(parse_hex).collect()
+}
@@ -0,0 +1,150 @@
+use crate::{casing_tables::*, properties::Property};
+
+pub use crate::casing_tables::UNICODE_VERSION;
+
+// the code points a code point maps to, one for most of them and up to three like `ß` to `SS`
+#[derive(Debug, Clone)]
+pub struct CaseMapping {
+  code_points: [u32; 3],
+  len: usize,
+  position: usize,
+}
+
+impl CaseMapping {
+  fn single(c: u32) -> CaseMapping {
+    CaseMapping {
+      code_points: [c, 0, 0],
+      len: 1,
+      position: 0,
+    }
+  }
+
+  fn special(string: &str) -> CaseMapping {
+    let mut code_points = [0; 3];
+    let mut len = 0;
+    for c in string.chars() {
+      code_points[len] = c as u32;
+      len += 1;
+    }
+
+    CaseMapping {
+      code_points,
+      len,
+      position: 0,
+    }
+  }
+}
+
+impl Iterator for CaseMapping {
+  type Item = u32;
+
+  fn next(&mut self) -> Option<u32> {
+    let c = self.code_points[..self.len].get(self.position)?;
+    self.position += 1;
+    Some(*c)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.len - self.position;
+    (remaining, Some(remaining))
+  }
+}
+
+impl ExactSizeIterator for CaseMapping {}
+
+// the full uppercase mapping of a code point, without any context
+pub fn uppercase(c: u32) -> CaseMapping {
+  match special(&SPECIAL_UPPERCASE, c) {
+    Some(string) => CaseMapping::special(string),
+    None => CaseMapping::single(add(c, mapping(c).0)),
+  }
+}
+
+// the full lowercase mapping of a code point, without any context, so a capital sigma is always a
+// small sigma here
+pub fn lowercase(c: u32) -> CaseMapping {
+  match special(&SPECIAL_LOWERCASE, c) {
+    Some(string) => CaseMapping::special(string),
+    None => CaseMapping::single(add(c, mapping(c).1)),
+  }
+}
+
+// the simple case folding of CaseFolding.txt, its common and simple mappings
+pub fn simple_fold(c: u32) -> u32 {
+  add(c, mapping(c).2)
+}
+
+// Canonicalize from the RegExp `i` flag: the simple case folding with the `u` or `v` flags, and
+// without them the uppercase of a code unit as long as it's a single code unit and doesn't map
+// something outside of ASCII into it
+pub fn canonicalize(c: u32, unicode_mode: bool) -> u32 {
+  if unicode_mode {
+    return simple_fold(c);
+  }
+
+  let mut upper = uppercase(c);
+  match (upper.next(), upper.next()) {
+    (Some(upper), None) if upper <= 0xFFFF && (c < 0x80 || upper >= 0x80) => upper,
+    _ => c,
+  }
+}
+
+// the full uppercase of a string, like `toUpperCase`
+pub fn to_uppercase(code_points: &[u32]) -> Vec<u32> {
+  let mut output = Vec::with_capacity(code_points.len());
+  for c in code_points {
+    output.extend(uppercase(*c));
+  }
+  output
+}
+
+// the full lowercase of a string, like `toLowerCase`, where a capital sigma at the end of a word
+// is a final sigma
+pub fn to_lowercase(code_points: &[u32]) -> Vec<u32> {
+  let mut output = Vec::with_capacity(code_points.len());
+  for (index, c) in code_points.iter().enumerate() {
+    if *c == 0x3A3 && is_final_sigma(code_points, index) {
+      output.push(0x3C2);
+    } else {
+      output.extend(lowercase(*c));
+    }
+  }
+  output
+}
+
+// the Final_Sigma condition of SpecialCasing.txt: a cased letter before the sigma and none after
+// it, skipping case-ignorable code points either way
+fn is_final_sigma(code_points: &[u32], index: usize) -> bool {
+  let cased = Property::lookup("Cased", None).expect("Cased is a binary property");
+  let case_ignorable =
+    Property::lookup("Case_Ignorable", None).expect("Case_Ignorable is a binary property");
+
+  let before = code_points[..index]
+    .iter()
+    .rev()
+    .find(|c| !case_ignorable.contains(**c));
+  let after = code_points[index + 1..]
+    .iter()
+    .find(|c| !case_ignorable.contains(**c));
+
+  before.is_some_and(|c| cased.contains(*c)) && !after.is_some_and(|c| cased.contains(*c))
+}
+
+fn mapping(c: u32) -> (i32, i32, i32) {
+  let id = match CASE_INDEX.get(c as usize / VALUE_BLOCK) {
+    Some(block) => CASE_DATA[*block as usize * VALUE_BLOCK + c as usize % VALUE_BLOCK],
+    None => 0,
+  };
+  CASE_MAPPINGS[id as usize]
+}
+
+fn special(table: &'static [(u32, &'static str)], c: u32) -> Option<&'static str> {
+  table
+    .binary_search_by_key(&c, |(code_point, _)| *code_point)
+    .ok()
+    .map(|index| table[index].1)
+}
+
+fn add(c: u32, difference: i32) -> u32 {
+  c.wrapping_add_signed(difference)
+}
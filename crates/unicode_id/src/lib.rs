@@ -1,3 +1,8 @@
+pub mod casing;
+mod casing_tables;
+pub mod properties;
+mod property_tables;
+
 pub const ROW_SIZE: usize = 64;
 
 pub const MAX_BOUND_ID_START: u32 = 0x0323AF;
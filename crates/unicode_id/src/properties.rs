@@ -0,0 +1,132 @@
+use crate::property_tables::*;
+
+pub use crate::property_tables::UNICODE_VERSION;
+
+// a property a RegExp property escape like `\p{Script=Greek}` can name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+  Any,
+  Ascii,
+  Assigned,
+  Binary(u8),
+  // a bit for each category the name covers
+  GeneralCategory(u32),
+  Script(u8),
+  ScriptExtensions(u8),
+  // a bit for each of the properties of strings the name covers
+  Strings(u8),
+}
+
+impl Property {
+  // a lone name is a General_Category value or a binary property, a name and value can only be
+  // General_Category, Script or Script_Extensions. names are matched exactly, like RegExp does
+  pub fn lookup(name: &str, value: Option<&str>) -> Option<Property> {
+    let Some(value) = value else {
+      return match name {
+        "Any" => Some(Property::Any),
+        "ASCII" => Some(Property::Ascii),
+        "Assigned" => Some(Property::Assigned),
+        _ => find(&GENERAL_CATEGORY_NAMES, name)
+          .map(Property::GeneralCategory)
+          .or_else(|| find(&BINARY_PROPERTY_NAMES, name).map(Property::Binary))
+          .or_else(|| find(&STRING_PROPERTY_NAMES, name).map(Property::Strings)),
+      };
+    };
+
+    match name {
+      "General_Category" | "gc" => {
+        find(&GENERAL_CATEGORY_NAMES, value).map(Property::GeneralCategory)
+      }
+      "Script" | "sc" => find(&SCRIPT_NAMES, value).map(Property::Script),
+      "Script_Extensions" | "scx" => find(&SCRIPT_NAMES, value).map(Property::ScriptExtensions),
+      _ => None,
+    }
+  }
+
+  // properties of strings can only be used with the `v` flag and can't be negated
+  pub fn has_strings(self) -> bool {
+    matches!(self, Property::Strings(_))
+  }
+
+  pub fn contains(self, c: u32) -> bool {
+    if c > 0x10FFFF {
+      return false;
+    }
+
+    match self {
+      Property::Any => true,
+      Property::Ascii => c < 0x80,
+      Property::Assigned => general_category(c) != UNASSIGNED,
+      Property::Binary(property) => {
+        let index = BINARY_PROPERTY_INDEXES[property as usize];
+        match index.get(c as usize / BIT_BLOCK) {
+          Some(row) => {
+            let byte =
+              BINARY_PROPERTY_DATA[*row as usize * BIT_BLOCK / 8 + c as usize % BIT_BLOCK / 8];
+            byte >> (c % 8) & 1 == 1
+          }
+          None => false,
+        }
+      }
+      Property::GeneralCategory(mask) => mask >> general_category(c) & 1 == 1,
+      Property::Script(script) => value(&SCRIPT_INDEX, &SCRIPT_DATA, c) == script,
+      Property::ScriptExtensions(script) => {
+        match value(&SCRIPT_EXTENSIONS_INDEX, &SCRIPT_EXTENSIONS_DATA, c) {
+          // without extensions it's only the script of the code point
+          0 => value(&SCRIPT_INDEX, &SCRIPT_DATA, c) == script,
+          set => SCRIPT_EXTENSION_SETS[set as usize].contains(&script),
+        }
+      }
+      Property::Strings(mask) => {
+        let Some(c) = char::from_u32(c) else {
+          return false;
+        };
+        let mut buffer = [0; 4];
+        let single = &*c.encode_utf8(&mut buffer);
+
+        strings_of(mask).any(|strings| {
+          strings
+            .binary_search_by(|string| string.chars().cmp(single.chars()))
+            .is_ok()
+        })
+      }
+    }
+  }
+
+  // the sequences of more than one code point a property of strings has, the single code points
+  // are the ones it contains
+  pub fn strings(self) -> impl Iterator<Item = &'static str> {
+    let mask = match self {
+      Property::Strings(mask) => mask,
+      _ => 0,
+    };
+
+    strings_of(mask)
+      .flat_map(|strings| strings.iter().copied())
+      .filter(|string| string.chars().nth(1).is_some())
+  }
+}
+
+fn find<T: Copy>(names: &[(&str, T)], name: &str) -> Option<T> {
+  names
+    .binary_search_by(|(entry, _)| (*entry).cmp(name))
+    .ok()
+    .map(|index| names[index].1)
+}
+
+fn value(index: &[u16], data: &[u8], c: u32) -> u8 {
+  let block = index[c as usize / VALUE_BLOCK] as usize;
+  data[block * VALUE_BLOCK + c as usize % VALUE_BLOCK]
+}
+
+fn general_category(c: u32) -> u8 {
+  value(&GENERAL_CATEGORY_INDEX, &GENERAL_CATEGORY_DATA, c)
+}
+
+fn strings_of(mask: u8) -> impl Iterator<Item = &'static [&'static str]> {
+  STRING_PROPERTY_STRINGS
+    .iter()
+    .enumerate()
+    .filter(move |(property, _)| mask >> property & 1 == 1)
+    .map(|(_, strings)| *strings)
+}